@@ -792,7 +792,7 @@ fn draw_ui() -> BoxedSystem {
 
                 if let Some((material, health, _difficulty)) = result {
                     draw_text(
-                        &format!("Hull: {}%", health.round()),
+                        format!("Hull: {}%", health.round()),
                         10.0,
                         32.0,
                         32.0,
@@ -802,17 +802,17 @@ fn draw_ui() -> BoxedSystem {
                     );
 
                     draw_text(
-                        &format!("Material: {}kg", material.round()),
+                        format!("Material: {}kg", material.round()),
                         10.0,
                         64.0,
                         32.0,
                         BLUE,
                     );
 
-                    draw_text(&format!("Entities: {count}"), 10.0, 96.0, 16.0, GRAY);
+                    draw_text(format!("Entities: {count}"), 10.0, 96.0, 16.0, GRAY);
 
                     draw_text(
-                        &format!(
+                        format!(
                             "Archetype Gen: {}, Change Tick: {}, Frametime: {}",
                             world.archetype_gen(),
                             world.change_tick(),
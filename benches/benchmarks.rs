@@ -51,6 +51,16 @@ fn benchmarks(c: &mut Criterion) {
             b.iter(|| bench.run_manual_flatten())
         });
 
+    c.benchmark_group("sparse_iter")
+        .bench_function("iter", |b| {
+            let mut bench = sparse_iter::Benchmark::new();
+            b.iter(|| bench.run())
+        })
+        .bench_function("batched", |b| {
+            let mut bench = sparse_iter::Benchmark::new();
+            b.iter(|| bench.run_batched())
+        });
+
     c.benchmark_group("heavy_compute")
         .bench_function("par", |b| {
             let mut bench = heavy_compute::Benchmark::new();
@@ -75,6 +85,24 @@ fn benchmarks(c: &mut Criterion) {
             b.iter(|| bench.run_seq())
         });
 
+    c.benchmark_group("event_dispatch")
+        .bench_function("par", |b| {
+            let mut bench = event_dispatch::Benchmark::new(true);
+            b.iter(|| bench.run())
+        })
+        .bench_function("seq", |b| {
+            let mut bench = event_dispatch::Benchmark::new(false);
+            b.iter(|| bench.run())
+        })
+        .bench_function("structural_par", |b| {
+            let mut bench = event_dispatch::Benchmark::new(true);
+            b.iter(|| bench.run_structural())
+        })
+        .bench_function("structural_seq", |b| {
+            let mut bench = event_dispatch::Benchmark::new(false);
+            b.iter(|| bench.run_structural())
+        });
+
     #[cfg(feature = "serde")]
     c.benchmark_group("benchmark")
         .bench_function("binary_row", |b| {
@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use std::iter::repeat_n;
 
 use flax::{components::child_of, *};
 
@@ -10,10 +10,10 @@ component! {
 pub struct Benchmark(World, Entity);
 
 fn spawn_children(world: &mut World, parent: Entity) {
-    let a = repeat((Some(a()), None)).take(100);
+    let a = repeat_n((Some(a()), None), 100);
 
-    let b = repeat((None, Some(b()))).take(100);
-    let ab = repeat((None, None)).take(100);
+    let b = repeat_n((None, Some(b())), 100);
+    let ab = repeat_n((None, None), 100);
 
     a.chain(b).chain(ab).for_each(|(a, b)| {
         let mut builder = Entity::builder();
@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use std::iter::repeat_n;
 
 use flax::{components::child_of, filter::All, *};
 
@@ -7,16 +7,15 @@ component! {
     b: f32,
 }
 
-pub struct Benchmark(
-    World,
-    Query<(EntityIds, Opt<Component<f32>>, Opt<Component<f32>>), All, Dfs<()>>,
-);
+type DfsQuery = Query<(EntityIds, Opt<Component<f32>>, Opt<Component<f32>>), All, Dfs<()>>;
+
+pub struct Benchmark(World, DfsQuery);
 
 fn spawn_children(world: &mut World, parent: Entity) {
-    let a = repeat((Some(a()), None)).take(100);
+    let a = repeat_n((Some(a()), None), 100);
 
-    let b = repeat((None, Some(b()))).take(100);
-    let ab = repeat((None, None)).take(100);
+    let b = repeat_n((None, Some(b())), 100);
+    let ab = repeat_n((None, None), 100);
 
     a.chain(b).chain(ab).for_each(|(a, b)| {
         let mut builder = Entity::builder();
@@ -54,7 +53,7 @@ impl Benchmark {
     }
 
     pub fn run(&mut self) {
-        let mut query = self.1.borrow(&self.0);
+        let mut query = self.1.borrow(&self.0).unwrap();
         query.traverse(
             &0.0,
             |(_id, a, b): (Entity, Option<&f32>, Option<&f32>), _: Option<&()>, depth: &f32| {
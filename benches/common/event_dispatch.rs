@@ -0,0 +1,98 @@
+use core::{
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::sync::Arc;
+
+use flax::{
+    events::{Event, EventSubscriber},
+    sink::Sink,
+    *,
+};
+
+component! {
+    position: f32,
+    velocity: f32,
+    mass: f32,
+    health: f32,
+}
+
+/// A subscriber which does a fair bit of work for each event
+struct HeavySubscriber {
+    state: Arc<AtomicU64>,
+}
+
+impl Sink<Event> for HeavySubscriber {
+    fn send(&self, event: Event) {
+        let mut hash = event.id.index() as u64;
+        for _ in 0..200 {
+            hash = black_box(hash.wrapping_mul(6364136223846793005).wrapping_add(1));
+        }
+
+        self.state.fetch_add(hash, Ordering::Relaxed);
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+pub struct Benchmark {
+    world: World,
+    ids: Vec<Entity>,
+}
+
+impl Benchmark {
+    pub fn new(parallel: bool) -> Self {
+        let mut world = World::new();
+
+        let state = Arc::new(AtomicU64::new(0));
+        for _ in 0..32 {
+            world.subscribe(
+                HeavySubscriber {
+                    state: state.clone(),
+                }
+                .filter_components([
+                    position().key(),
+                    velocity().key(),
+                    mass().key(),
+                    health().key(),
+                ]),
+            );
+        }
+
+        if parallel {
+            world.set_parallel_dispatch(Some(4));
+        }
+
+        let ids = (0..1000)
+            .map(|i| {
+                Entity::builder()
+                    .set(position(), i as f32)
+                    .spawn(&mut world)
+            })
+            .collect();
+
+        Self { world, ids }
+    }
+
+    pub fn run(&mut self) {
+        for &id in &self.ids {
+            self.world.set(id, position(), 1.0).unwrap();
+        }
+    }
+
+    /// Spawns and despawns entities, which each generate an event for all of their components
+    pub fn run_structural(&mut self) {
+        for i in 0..100 {
+            let id = Entity::builder()
+                .set(position(), i as f32)
+                .set(velocity(), 1.0)
+                .set(mass(), 1.0)
+                .set(health(), 1.0)
+                .spawn(&mut self.world);
+
+            self.world.despawn(id).unwrap();
+        }
+    }
+}
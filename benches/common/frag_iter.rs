@@ -30,7 +30,7 @@ impl Benchmark {
     }
 
     pub fn run(&mut self) {
-        for data in &mut Query::new(data().as_mut()).borrow(&self.0) {
+        for data in &mut Query::new(data().as_mut()).borrow(&self.0).unwrap() {
             *data *= 2.0;
         }
     }
@@ -38,13 +38,14 @@ impl Benchmark {
     pub fn run_for_each(&mut self) {
         Query::new(data().as_mut())
             .borrow(&self.0)
+            .unwrap()
             .for_each(|data| {
                 *data *= 2.0;
             })
     }
 
     pub fn run2(&mut self) {
-        for data in &mut Query::new(data().as_mut()).borrow(&self.0) {
+        for data in &mut Query::new(data().as_mut()).borrow(&self.0).unwrap() {
             *data *= 2.0;
         }
     }
@@ -52,6 +53,7 @@ impl Benchmark {
     pub fn run_for_each2(&mut self) {
         Query::new(data().as_mut())
             .borrow(&self.0)
+            .unwrap()
             .for_each(|data| {
                 *data *= 2.0;
             })
@@ -10,15 +10,6 @@ component! {
     velocity: Vec3,
 }
 
-#[derive(Copy, Clone)]
-struct Position(Vec3);
-
-#[derive(Copy, Clone)]
-struct Rotation(Vec3);
-
-#[derive(Copy, Clone)]
-struct Velocity(Vec3);
-
 pub struct Benchmark(World);
 
 impl Benchmark {
@@ -44,6 +35,7 @@ impl Benchmark {
         Query::new((position().as_mut(), mat().as_mut()))
             .batch_size(64)
             .borrow(&self.0)
+            .unwrap()
             .par_for_each(|(pos, mat)| {
                 for _ in 0..100 {
                     *mat = mat.inverse();
@@ -56,6 +48,7 @@ impl Benchmark {
     pub fn run_seq(&mut self) {
         Query::new((position().as_mut(), mat().as_mut()))
             .borrow(&self.0)
+            .unwrap()
             .for_each(|(pos, mat)| {
                 for _ in 0..100 {
                     *mat = mat.inverse();
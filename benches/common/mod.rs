@@ -3,6 +3,7 @@
 pub mod add_remove;
 pub mod despawn_children;
 pub mod dfs;
+pub mod event_dispatch;
 pub mod frag_iter;
 pub mod heavy_compute;
 pub mod schedule;
@@ -13,3 +14,4 @@ pub mod serialize_binary;
 pub mod serialize_text;
 pub mod simple_insert;
 pub mod simple_iter;
+pub mod sparse_iter;
@@ -32,9 +32,7 @@ impl Benchmark {
     }
 
     pub fn run(&mut self) {
-        for (velocity, position) in
-            &mut Query::new((velocity(), position().as_mut())).borrow(&self.0)
-        {
+        for (velocity, position) in &mut self.1.borrow(&self.0).unwrap() {
             *position += *velocity
         }
     }
@@ -42,6 +40,7 @@ impl Benchmark {
     pub fn run_manual_flatten(&mut self) {
         for (velocity, position) in &mut Query::new((velocity(), position().as_mut()))
             .borrow(&self.0)
+            .unwrap()
             .iter_batched()
             .flatten()
         {
@@ -0,0 +1,53 @@
+use flax::{components::child_of, *};
+use std::iter::repeat;
+
+component! {
+    transform: [f32; 16],
+    velocity: [f32; 3],
+}
+
+/// Many small archetypes with large components, where each archetype is a separate allocation.
+///
+/// Compare with and without the `prefetch` feature.
+pub struct Benchmark(World);
+
+impl Benchmark {
+    pub fn new() -> Self {
+        let mut world = World::default();
+
+        // Fragment the entities into an archetype per parent
+        for _ in 0..1000 {
+            let parent = world.spawn();
+
+            let mut batch = BatchSpawn::new(16);
+            batch.set(transform(), repeat([1.0; 16])).unwrap();
+            batch.set(velocity(), repeat([1.0; 3])).unwrap();
+            batch.set(child_of(parent), repeat(())).unwrap();
+            batch.spawn(&mut world);
+        }
+
+        Self(world)
+    }
+
+    pub fn run(&mut self) {
+        for (t, v) in &mut Query::new((transform().as_mut(), velocity()))
+            .borrow(&self.0)
+            .unwrap()
+        {
+            t[12] += v[0];
+            t[13] += v[1];
+            t[14] += v[2];
+        }
+    }
+
+    pub fn run_batched(&mut self) {
+        // Split each archetype into several chunks
+        let mut query = Query::new((transform().as_mut(), velocity())).batch_size(4);
+
+        for (t, v) in &mut query.borrow(&self.0).unwrap() {
+            t[12] += v[0];
+            t[13] += v[1];
+            t[14] += v[2];
+        }
+    }
+}
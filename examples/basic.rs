@@ -22,14 +22,14 @@ fn main() {
         .spawn(&mut world);
 
     let mut query = Query::new(health());
-    for health in &mut query.borrow(&world) {
+    for health in &mut query.borrow(&world).unwrap() {
         eprintln!("Health: {health}");
     }
 
     let mut query = Query::new((health().as_mut(), regen()));
 
     // Apply health regen for all match entites
-    for (health, regen) in &mut query.borrow(&world) {
+    for (health, regen) in &mut query.borrow(&world).unwrap() {
         *health = (*health + regen).min(100.0);
     }
 }
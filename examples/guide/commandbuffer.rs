@@ -28,6 +28,7 @@ fn main() -> anyhow::Result<()> {
     let id = Query::new(entity_ids())
         .filter(name().eq("a"))
         .borrow(&world)
+        .unwrap()
         .iter()
         .next()
         .context("Missing entity")?;
@@ -27,7 +27,7 @@ fn main() -> anyhow::Result<()> {
 
     let mut query = Query::new((position(), health()));
 
-    for (pos, health) in &mut query.borrow(&world) {
+    for (pos, health) in &mut query.borrow(&world).unwrap() {
         println!("pos: {pos:?}, health: {health}");
     }
 
@@ -53,7 +53,7 @@ fn main() -> anyhow::Result<()> {
         .filter(position().modified() & health().gt(0.0));
 
     println!("Updating distances");
-    for (id, pos, dist) in &mut query.borrow(&world) {
+    for (id, pos, dist) in &mut query.borrow(&world).unwrap() {
         println!("Updating distance for {id} with position: {pos:?}");
         *dist = pos.length();
     }
@@ -63,7 +63,7 @@ fn main() -> anyhow::Result<()> {
     // ANCHOR: query_repeat
 
     println!("Running query again");
-    for (id, pos, dist) in &mut query.borrow(&world) {
+    for (id, pos, dist) in &mut query.borrow(&world).unwrap() {
         println!("Updating distance for {id} with position: {pos:?}");
         *dist = pos.length();
     }
@@ -74,7 +74,7 @@ fn main() -> anyhow::Result<()> {
     *world.get_mut(id2, position())? = vec2(8.0, 3.0);
 
     println!("... and again");
-    for (id, pos, dist) in &mut query.borrow(&world) {
+    for (id, pos, dist) in &mut query.borrow(&world).unwrap() {
         println!("Updating distance for {id} with position: {pos:?}");
         *dist = pos.length();
     }
@@ -246,7 +246,7 @@ fn main() -> anyhow::Result<()> {
         // Change the query strategy to only iterate the `resources` entity
         .entity(resources());
 
-    let mut borrow = query.borrow(&world);
+    let mut borrow = query.borrow(&world).unwrap();
     let (width, height, vsync) = borrow.get().unwrap();
     println!("width: {width} height: {height}, vsync: {vsync}");
 
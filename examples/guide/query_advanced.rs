@@ -45,7 +45,10 @@ fn main() -> anyhow::Result<()> {
 
     // Since this query accessed `position`, `velocity` **and** `mass` only the
     // first group of entities will be matched
-    for (pos, vel, mass) in &mut Query::new((position(), velocity(), mass())).borrow(&world) {
+    for (pos, vel, mass) in &mut Query::new((position(), velocity(), mass()))
+        .borrow(&world)
+        .unwrap()
+    {
         tracing::debug!("pos: {pos}, vel: {vel}, mass: {mass}");
     }
 
@@ -53,7 +56,10 @@ fn main() -> anyhow::Result<()> {
     // ANCHOR: opt
 
     // Use an optional fetch to yield an `Option<T>`, works for any query
-    for (pos, vel, mass) in &mut Query::new((position(), velocity(), mass().opt())).borrow(&world) {
+    for (pos, vel, mass) in &mut Query::new((position(), velocity(), mass().opt()))
+        .borrow(&world)
+        .unwrap()
+    {
         if mass.is_some() {
             tracing::debug!("Has mass");
         }
@@ -109,7 +115,11 @@ fn main() -> anyhow::Result<()> {
         .with_system(update_world_matrix)
         .build();
 
-    let all_ids = Query::new(entity_ids()).borrow(&world).iter().collect_vec();
+    let all_ids = Query::new(entity_ids())
+        .borrow(&world)
+        .unwrap()
+        .iter()
+        .collect_vec();
 
     tracing::info!("Schedule: {schedule:#?}");
 
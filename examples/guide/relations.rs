@@ -51,6 +51,7 @@ fn basic() -> anyhow::Result<()> {
         "Connections from child1({child1}): {:?}",
         Query::new(relations_like(child_of))
             .borrow(&world)
+            .unwrap()
             .get(child1)?
             .collect_vec()
     );
@@ -53,6 +53,7 @@ fn main() {
 
     query
         .borrow(&world)
+        .unwrap()
         .traverse(&None, |(id, name, &pos), strength, parent| {
             if let (Some(spring), Some((parent_name, parent_pos))) = (strength, parent) {
                 let distance = pos.distance(*parent_pos) - spring.length;
@@ -61,7 +61,7 @@ fn main() {
 
         let mut query = Query::new(name());
 
-        for name in &mut query.borrow(&world) {
+        for name in &mut query.borrow(&world).unwrap() {
             tracing::info!("Entity: {name:?}");
         }
 
@@ -74,7 +74,7 @@ fn main() {
 
         let mut query = Query::new((name(), position(), health()));
 
-        for (name, pos, health) in &mut query.borrow(&world) {
+        for (name, pos, health) in &mut query.borrow(&world).unwrap() {
             tracing::info!("Entity: {name:?} pos: {pos}, health: {health}");
         }
 
@@ -89,7 +89,7 @@ fn main() {
 
         fn lightning_strike(world: &World, rng: &mut StdRng) {
             let mut query = Query::new(health().as_mut());
-            for h in &mut query.borrow(world) {
+            for h in &mut query.borrow(world).unwrap() {
                 // &mut f32
                 *h -= rng.gen_range(10.0..20.0);
             }
@@ -106,7 +106,7 @@ fn main() {
 
         let mut query = Query::new((name(), position(), health().opt()));
 
-        (&mut query.borrow(&world)).into_iter().for_each(
+        (&mut query.borrow(&world).unwrap()).into_iter().for_each(
             |(name, pos, health): (&String, &Vec3, Option<&f32>)| {
                 tracing::info!("Entity: {name:?} pos: {pos}, health: {health:?}");
             },
@@ -121,7 +121,7 @@ fn main() {
 
         let mut query = Query::new((name(), health())).filter(player().with());
 
-        let mut borrow = query.borrow(&world);
+        let mut borrow = query.borrow(&world).unwrap();
 
         if let Some((name, health)) = borrow.iter().next() {
             tracing::info!("The player {name} is alive and well at {health} health");
@@ -138,7 +138,7 @@ fn main() {
 
         let mut query = Query::new((name(), health())).filter(player().without());
 
-        for (name, health) in &mut query.borrow(&world) {
+        for (name, health) in &mut query.borrow(&world).unwrap() {
             tracing::info!("Npc: {name} at {health} health");
         }
 
@@ -152,7 +152,7 @@ fn main() {
         let mut query =
             Query::new((name(), health().opt())).filter(player().with() | health().without());
 
-        for (name, health) in &mut query.borrow(&world) {
+        for (name, health) in &mut query.borrow(&world).unwrap() {
             if let Some(health) = health {
                 tracing::info!("{name} at {health}");
             } else {
@@ -168,7 +168,7 @@ fn main() {
         // ANCHOR: query_cmp
 
         let mut query = Query::new(name()).filter(health().without() | health().ge(35.0));
-        for name in &mut query.borrow(&world) {
+        for name in &mut query.borrow(&world).unwrap() {
             tracing::info!("{name} is still standing strong");
         }
 
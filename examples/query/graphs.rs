@@ -67,7 +67,7 @@ fn main() {
         let mut query = Query::new((entity_ids(), name())).with_strategy(Dfs::new(child_of));
 
         tracing::info!("Dfs:");
-        for (id, name) in query.borrow(&world).iter() {
+        for (id, name) in query.borrow(&world).unwrap().iter() {
             tracing::info!(?id, ?name);
         }
 
@@ -80,7 +80,7 @@ fn main() {
 
         let mut query = Query::new((entity_ids(), name())).with_strategy(Topo::new(child_of));
 
-        for (id, name) in &mut query.borrow(&world) {
+        for (id, name) in &mut query.borrow(&world).unwrap() {
             tracing::info!(?id, ?name);
         }
 
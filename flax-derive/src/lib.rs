@@ -27,7 +27,7 @@ use syn::{
 ///
 /// # Field Attributes
 /// - `ignore`: ignore slot-filtering and transformations for a field.
-///     Useful for including a `Mutable` in a change query.
+///   Useful for including a `Mutable` in a change query.
 #[proc_macro_derive(Fetch, attributes(fetch))]
 pub fn derive_fetch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let crate_name = match proc_macro_crate::crate_name("flax").expect("Failed to get crate name") {
@@ -121,6 +121,7 @@ fn derive_fetch_struct(params: &Params) -> TokenStream {
 
     let fetch_impl = params.w_impl();
     let fetch_ty = params.base_ty();
+    let base_impl = params.base_impl();
 
     let item_fields = fields
         .iter()
@@ -189,6 +190,13 @@ fn derive_fetch_struct(params: &Params) -> TokenStream {
                 #(#crate_name::Fetch::searcher(&self.#field_names, searcher);)*
             }
         }
+
+        // The bounds are higher ranked so that they are not trivially false for concrete fields
+        #[automatically_derived]
+        unsafe impl #base_impl #crate_name::fetch::TrustedAccess for #fetch_name #fetch_ty
+            where #(for<'x> #field_types: #crate_name::fetch::TrustedAccess,)*
+        {
+        }
     }
 }
 
@@ -409,7 +417,10 @@ fn derive_prepared_struct(params: &Params) -> TokenStream {
             }
 
             #[inline]
-            unsafe fn create_chunk(&'q mut self, slots: #crate_name::archetype::Slice) -> Self::Chunk {
+            unsafe fn create_chunk(&mut self, slots: #crate_name::archetype::Slice) -> Self::Chunk
+            where
+                Self: 'q,
+            {
                 (
                     #(#crate_name::fetch::PreparedFetch::create_chunk(&mut self.#field_names, slots),)*
                 )
@@ -622,27 +633,31 @@ impl<'a> Params<'a> {
         })
     }
 
-    fn q_impl(&self) -> ImplGenerics {
+    fn q_impl(&self) -> ImplGenerics<'_> {
         self.q_generics.split_for_impl().0
     }
 
-    fn wq_impl(&self) -> ImplGenerics {
+    fn wq_impl(&self) -> ImplGenerics<'_> {
         self.wq_generics.split_for_impl().0
     }
 
-    fn w_impl(&self) -> ImplGenerics {
+    fn w_impl(&self) -> ImplGenerics<'_> {
         self.w_generics.split_for_impl().0
     }
 
-    fn base_ty(&self) -> TypeGenerics {
+    fn base_impl(&self) -> ImplGenerics<'_> {
+        self.generics.split_for_impl().0
+    }
+
+    fn base_ty(&self) -> TypeGenerics<'_> {
         self.generics.split_for_impl().1
     }
 
-    fn q_ty(&self) -> TypeGenerics {
+    fn q_ty(&self) -> TypeGenerics<'_> {
         self.q_generics.split_for_impl().1
     }
 
-    fn w_ty(&self) -> TypeGenerics {
+    fn w_ty(&self) -> TypeGenerics<'_> {
         self.w_generics.split_for_impl().1
     }
 }
@@ -108,7 +108,7 @@ mod test {
     use glam::{Mat4, Vec3};
     use itertools::Itertools;
 
-    use crate::{component, components::name, FetchExt, Query, World};
+    use crate::{components::name, FetchExt, Query, World};
 
     use super::*;
     #[test]
@@ -176,6 +176,7 @@ mod test {
                 velocity().copied()
             ))
             .borrow(&world)
+            .unwrap()
             .iter()
             .collect_vec(),
             (0..100)
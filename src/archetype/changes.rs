@@ -13,6 +13,15 @@ use super::{Remainder, Slice, Slot};
 // Sorted by the start range of the slices
 //
 // Adjacent of the same tick are merged together
+//
+// Updates binary search for the affected region, and only visit the changes which overlap or
+// are adjacent to the updated slots. Each kind of change is kept in its own list, see
+// [`Changes`].
+//
+// The changes are kept contiguous so that filters can binary search them directly. Inserting a
+// change which is not merged into an existing one therefore still shifts the changes after it,
+// which is cheap for the common case of updating the latest slots, but linear in the number of
+// changes past the inserted one.
 pub struct ChangeList {
     pub(crate) inner: Vec<Change>,
 }
@@ -42,6 +51,11 @@ impl ChangeList {
 
         // Merge forward
         while let Some(next) = changes.get_mut(i + 1) {
+            // The remaining changes are neither overlapping nor adjacent
+            if next.slice.start > slice.end {
+                break;
+            }
+
             if next.tick == tick {
                 if let Some(u) = slice.union(&next.slice) {
                     slice = u;
@@ -65,21 +79,26 @@ impl ChangeList {
     }
 
     pub(crate) fn set(&mut self, value: Change) -> &mut Self {
-        // let orig = self.inner.clone();
-        let mut insert_point = 0;
-        let mut i = 0;
-
         // #[cfg(debug_assertions)]
         // self.assert_normal("Not sorted before");
 
         let changes = &mut self.inner;
 
+        // Changes ending before the new change can neither overlap nor be merged with it
+        let mut i = changes.partition_point(|v| v.slice.end < value.slice.start);
+        let mut insert_point = i;
+
         while i < changes.len() {
             let change = &mut changes[i];
             let slice = change.slice;
 
+            // The remaining changes are past the new change
+            if slice.start > value.slice.end {
+                break;
+            }
+
+            // Changes starting before the new change stay before it, even when truncated
             if slice.start < value.slice.start {
-                // TODO: break
                 insert_point = i + 1;
             }
 
@@ -149,18 +168,22 @@ impl ChangeList {
     }
 
     pub(crate) fn set_slot(&mut self, slot: Slot, tick: u32) -> &mut Self {
-        let mut insert_point = 0;
-        let mut i = 0;
-
         // #[cfg(debug_assertions)]
         // self.assert_normal("Not sorted at beginning");
 
         let changes = &mut self.inner;
 
+        let mut i = changes.partition_point(|v| v.slice.end < slot);
+        let mut insert_point = i;
+
         while i < changes.len() {
             let change = &mut changes[i];
             let slice = change.slice;
 
+            if slice.start > slot {
+                break;
+            }
+
             if slice.start < slot {
                 insert_point = i + 1;
             }
@@ -265,28 +288,24 @@ impl ChangeList {
         mut on_removed: impl FnMut(Change),
     ) {
         let mut to_swap = None;
-        let orig = self.inner.clone();
+        let changes = &mut self.inner;
 
-        // Truncate all ranges from the swapped slot
+        // Truncate the range ending at the swapped slot.
+        //
+        // As the changes do not overlap there is at most one such range
         if slot != swap {
-            self.inner.retain_mut(|v| {
-                // assert_eq!(v.slice.end, swap + 1);
-                // 0 or more in the tail may become empty
-                if v.slice.end == swap + 1 {
-                    v.slice.end = swap;
-                    assert!(
-                        to_swap.is_none(),
-                        "Multiple changes for the same tick {slot} {swap} {orig:?}"
-                    );
-                    to_swap = Some((slot, v.tick));
-                }
+            let i = changes.partition_point(|v| v.slice.end <= swap);
+            if let Some(v) = changes.get_mut(i).filter(|v| v.slice.end == swap + 1) {
+                v.slice.end = swap;
+                to_swap = Some((slot, v.tick));
 
-                !v.slice.is_empty()
-            });
+                if v.slice.is_empty() {
+                    changes.remove(i);
+                }
+            }
         }
 
-        let mut i = 0;
-        let changes = &mut self.inner;
+        let mut i = changes.partition_point(|v| v.slice.end <= slot);
 
         while i < changes.len() {
             let change = &mut changes[i];
@@ -392,7 +411,6 @@ impl ChangeList {
 
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 /// Represents a change for a slice of entities for a specific component
-#[doc(hidden)]
 pub enum ChangeKind {
     /// Component was modified
     Modified = 0,
@@ -522,6 +540,13 @@ impl Changes {
         self.map[2].swap_remove_with(slot, dst, |v| on_removed(ChangeKind::Removed, v));
     }
 
+    /// Moves the changes of `slot` to `dst_slot` in `dst`, swapping `last` into `slot`
+    pub(crate) fn migrate_to(&mut self, slot: Slot, last: Slot, dst: &mut Self, dst_slot: Slot) {
+        self.swap_remove(slot, last, |kind, v| {
+            dst.set_slot(kind, dst_slot, v.tick);
+        });
+    }
+
     #[inline(always)]
     pub(crate) fn zip_map(
         &mut self,
@@ -542,6 +567,13 @@ impl Changes {
         self.track_modified.load(sync::atomic::Ordering::Relaxed)
     }
 
+    /// Discards all changes which occurred at or before `tick`
+    pub(crate) fn discard_until(&mut self, tick: u32) {
+        for list in &mut self.map {
+            list.inner.retain(|v| v.tick > tick);
+        }
+    }
+
     pub(crate) fn clear(&mut self) {
         self.map[0].inner.clear();
         self.map[1].inner.clear();
@@ -773,4 +805,220 @@ mod tests {
 
         assert_eq!(changes.as_slice(), [Change::new(Slice::new(0, 3), 2),]);
     }
+
+    /// Asserts that the change list is sorted, non-empty, and non-overlapping
+    fn assert_normal(changes: &ChangeList) {
+        for v in changes.iter() {
+            assert!(!v.slice.is_empty(), "empty slice {v:?} in {changes:?}");
+        }
+
+        for (a, b) in changes.iter().tuple_windows() {
+            assert!(
+                a.slice.end <= b.slice.start,
+                "overlapping or unordered changes {a:?} {b:?} in {changes:?}"
+            );
+        }
+    }
+
+    /// Asserts that the change list matches the naive per-slot model
+    fn assert_model(changes: &ChangeList, model: &[Option<u32>]) {
+        assert_normal(changes);
+
+        let expected = model
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, &tick)| Some((slot, tick?)))
+            .collect_vec();
+
+        assert_eq!(changes.iter_collapsed().collect_vec(), expected);
+    }
+
+    /// Applies random sequences of `set`, `set_slot`, `swap_remove` and migrations and
+    /// compares the result to a naive per-slot model
+    #[test]
+    fn fuzz_model() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        #[cfg(not(miri))]
+        let (runs, steps) = (64, 256);
+        #[cfg(miri)]
+        let (runs, steps) = (4, 32);
+
+        for seed in 0..runs {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            let mut src = ChangeList::default();
+            let mut dst = ChangeList::default();
+            let mut src_model: Vec<Option<u32>> = vec![None; 32];
+            let mut dst_model: Vec<Option<u32>> = Vec::new();
+            let mut tick = 1;
+
+            for _ in 0..steps {
+                if rng.gen_bool(0.3) {
+                    tick += 1;
+                }
+
+                let len = src_model.len();
+                match rng.gen_range(0..5) {
+                    // Set a range
+                    0 if len > 0 => {
+                        let start = rng.gen_range(0..len);
+                        let end = rng.gen_range(start + 1..=len);
+                        src.set(Change::new(Slice::new(start, end), tick));
+                        src_model[start..end].fill(Some(tick));
+                    }
+                    // Set a single slot
+                    1 if len > 0 => {
+                        let slot = rng.gen_range(0..len);
+                        src.set_slot(slot, tick);
+                        src_model[slot] = Some(tick);
+                    }
+                    // Remove a slot by swapping in the last
+                    2 if len > 0 => {
+                        let slot = rng.gen_range(0..len);
+                        let last = len - 1;
+
+                        let removed = src.swap_remove_collect(slot, last);
+                        let expected = src_model[slot].map(|tick| Change::single(slot, tick));
+                        assert_eq!(removed, expected.into_iter().collect_vec());
+
+                        src_model.swap_remove(slot);
+                    }
+                    // Migrate a slot to another list
+                    3 if len > 0 => {
+                        let slot = rng.gen_range(0..len);
+                        let last = len - 1;
+                        let dst_slot = dst_model.len();
+
+                        src.swap_remove_with(slot, last, |v| {
+                            dst.set_slot(dst_slot, v.tick);
+                        });
+
+                        dst_model.push(src_model.swap_remove(slot));
+                    }
+                    // Grow the archetype without any changes
+                    _ => src_model.push(None),
+                }
+
+                assert_model(&src, &src_model);
+                assert_model(&dst, &dst_model);
+            }
+        }
+    }
+
+    /// Asserts that each change kind matches its naive per-slot model
+    fn assert_changes_model(changes: &Changes, model: &[Vec<Option<u32>>; 3]) {
+        for kind in [ChangeKind::Modified, ChangeKind::Added, ChangeKind::Removed] {
+            assert_model(changes.get(kind), &model[kind as usize]);
+        }
+    }
+
+    /// Applies random sequences of additions, modifications, removals, migrations and discards
+    /// to [`Changes`] and compares each kind to a naive per-slot model
+    #[test]
+    fn fuzz_model_migrate() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        const KINDS: [ChangeKind; 3] =
+            [ChangeKind::Modified, ChangeKind::Added, ChangeKind::Removed];
+
+        #[cfg(not(miri))]
+        let (runs, steps) = (64, 256);
+        #[cfg(miri)]
+        let (runs, steps) = (4, 32);
+
+        for seed in 0..runs {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            let mut src = Changes::new();
+            let mut dst = Changes::new();
+            let mut src_model: [Vec<Option<u32>>; 3] = Default::default();
+            let mut dst_model: [Vec<Option<u32>>; 3] = Default::default();
+            src_model.iter_mut().for_each(|v| v.resize(32, None));
+            let mut tick = 1;
+
+            for _ in 0..steps {
+                if rng.gen_bool(0.3) {
+                    tick += 1;
+                }
+
+                let len = src_model[0].len();
+                match rng.gen_range(0..7) {
+                    // Add a range
+                    0 if len > 0 => {
+                        let start = rng.gen_range(0..len);
+                        let end = rng.gen_range(start + 1..=len);
+                        src.set_added(Change::new(Slice::new(start, end), tick));
+                        src_model[ChangeKind::Added as usize][start..end].fill(Some(tick));
+                        src_model[ChangeKind::Modified as usize][start..end].fill(Some(tick));
+                    }
+                    // Modify a range
+                    1 if len > 0 => {
+                        let start = rng.gen_range(0..len);
+                        let end = rng.gen_range(start + 1..=len);
+                        src.set_modified(Change::new(Slice::new(start, end), tick));
+                        src_model[ChangeKind::Modified as usize][start..end].fill(Some(tick));
+                    }
+                    // Set a single slot of any kind
+                    2 if len > 0 => {
+                        let kind = KINDS[rng.gen_range(0..3)];
+                        let slot = rng.gen_range(0..len);
+                        src.set_slot(kind, slot, tick);
+                        src_model[kind as usize][slot] = Some(tick);
+                    }
+                    // Remove a slot by swapping in the last
+                    3 if len > 0 => {
+                        let slot = rng.gen_range(0..len);
+                        let last = len - 1;
+
+                        let mut removed = Vec::new();
+                        src.swap_remove(slot, last, |kind, v| removed.push((kind, v)));
+
+                        let expected = KINDS
+                            .iter()
+                            .filter_map(|&kind| {
+                                let tick = src_model[kind as usize][slot]?;
+                                Some((kind, Change::single(slot, tick)))
+                            })
+                            .collect_vec();
+
+                        assert_eq!(removed, expected);
+
+                        src_model.iter_mut().for_each(|v| {
+                            v.swap_remove(slot);
+                        });
+                    }
+                    // Migrate a slot to another archetype
+                    4 if len > 0 => {
+                        let slot = rng.gen_range(0..len);
+                        let last = len - 1;
+                        let dst_slot = dst_model[0].len();
+
+                        src.migrate_to(slot, last, &mut dst, dst_slot);
+
+                        for (src, dst) in src_model.iter_mut().zip(&mut dst_model) {
+                            dst.push(src.swap_remove(slot));
+                        }
+                    }
+                    // Discard old changes
+                    5 => {
+                        let until = rng.gen_range(0..=tick);
+                        src.discard_until(until);
+                        dst.discard_until(until);
+
+                        for v in src_model.iter_mut().chain(&mut dst_model).flatten() {
+                            if v.is_some_and(|v| v <= until) {
+                                *v = None;
+                            }
+                        }
+                    }
+                    // Grow the archetype without any changes
+                    _ => src_model.iter_mut().for_each(|v| v.push(None)),
+                }
+
+                assert_changes_model(&src, &src_model);
+                assert_changes_model(&dst, &dst_model);
+            }
+        }
+    }
 }
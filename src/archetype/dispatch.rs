@@ -0,0 +1,106 @@
+use smallvec::SmallVec;
+
+use crate::{
+    events::{EventData, EventKind, EventSubscriber},
+    Entity,
+};
+
+use super::{CellData, Slice};
+
+struct BatchedEvent<'a> {
+    kind: EventKind,
+    data: &'a CellData,
+    event: EventData<'a>,
+}
+
+impl BatchedEvent<'_> {
+    fn handle(&self, subscriber: &dyn EventSubscriber) {
+        match self.kind {
+            EventKind::Added => subscriber.on_added(&self.data.storage, &self.event),
+            EventKind::Modified => subscriber.on_modified(&self.event),
+            EventKind::Removed => subscriber.on_removed(&self.data.storage, &self.event),
+        }
+    }
+}
+
+/// The events generated by a single operation on an archetype, such as moving an entity.
+///
+/// The events are dispatched together, which allows a parallel dispatch to join once for the
+/// whole operation rather than once for each event. Each subscriber handles its events in the
+/// order they were pushed, and all events have been handled once [`Self::dispatch`] returns.
+#[derive(Default)]
+pub(super) struct EventBatch<'a> {
+    events: SmallVec<[BatchedEvent<'a>; 4]>,
+}
+
+impl<'a> EventBatch<'a> {
+    /// Adds an event for the slots of `data`, where `ids` are the entities in `slots`
+    pub(super) fn push(
+        &mut self,
+        kind: EventKind,
+        data: &'a CellData,
+        ids: &'a [Entity],
+        slots: Slice,
+    ) {
+        if data.subscribers.is_empty() {
+            return;
+        }
+
+        let event = EventData {
+            ids,
+            slots,
+            key: data.key,
+        };
+
+        self.events.push(BatchedEvent { kind, data, event });
+    }
+
+    pub(super) fn dispatch(self) {
+        #[cfg(feature = "rayon")]
+        if self
+            .events
+            .iter()
+            .any(|v| v.data.subscribers.len() >= v.data.parallel_threshold)
+        {
+            self.dispatch_parallel();
+            return;
+        }
+
+        for event in &self.events {
+            for subscriber in &event.data.subscribers {
+                event.handle(&**subscriber);
+            }
+        }
+    }
+
+    /// Visits each subscriber in parallel, where each subscriber handles the events of the cells
+    /// it is subscribed to in order
+    #[cfg(feature = "rayon")]
+    fn dispatch_parallel(&self) {
+        use alloc::{collections::BTreeMap, vec::Vec};
+        use rayon::prelude::*;
+
+        // The same subscriber is shared between the cells it is interested in
+        let mut indices = BTreeMap::new();
+        let mut subscribers: Vec<(&dyn EventSubscriber, SmallVec<[usize; 4]>)> = Vec::new();
+
+        for (i, event) in self.events.iter().enumerate() {
+            for subscriber in &event.data.subscribers {
+                let index = *indices
+                    .entry(alloc::sync::Arc::as_ptr(subscriber) as *const ())
+                    .or_insert_with(|| {
+                        subscribers.push((&**subscriber, SmallVec::new()));
+                        subscribers.len() - 1
+                    });
+
+                subscribers[index].1.push(i);
+            }
+        }
+
+        subscribers.par_iter().for_each(|(subscriber, events)| {
+            for &i in events {
+                self.events[i].handle(*subscriber);
+            }
+        });
+    }
+}
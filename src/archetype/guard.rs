@@ -2,6 +2,7 @@ use core::{
     fmt::Debug,
     ops::{Deref, DerefMut},
     ptr::NonNull,
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
 };
 
 use atomic_refcell::{AtomicRef, AtomicRefMut};
@@ -10,8 +11,8 @@ use crate::{component::ComponentValue, Entity};
 
 use super::{CellData, Changes, Slice, Slot};
 
-/// Type safe abstraction over a borrowed cell data
-pub(crate) struct CellMutGuard<'a, T: ?Sized> {
+/// Type safe abstraction over a mutably borrowed component storage
+pub struct CellMutGuard<'a, T: ?Sized> {
     data: AtomicRefMut<'a, CellData>,
     // From the refcell
     storage: NonNull<T>,
@@ -38,7 +39,7 @@ impl<'a, T: ?Sized> CellMutGuard<'a, T> {
         data.set_modified(ids, slots, tick)
     }
 
-    pub(crate) fn filter_map<U>(
+    pub(crate) fn filter_map<U: ?Sized>(
         mut self,
         f: impl FnOnce(&mut T) -> Option<&mut U>,
     ) -> Option<CellMutGuard<'a, U>> {
@@ -54,11 +55,17 @@ impl<'a, T: ?Sized> CellMutGuard<'a, T> {
         self.storage
     }
 
-    pub(crate) fn get(&self) -> &T {
+    /// Returns the borrowed storage
+    pub fn get(&self) -> &T {
         unsafe { self.storage.as_ref() }
     }
 
-    pub(crate) fn get_mut(&mut self) -> &mut T {
+    /// Returns the borrowed storage mutably.
+    ///
+    /// Public guards are only handed out by
+    /// [`Archetype::get_slice_mut`](super::Archetype::get_slice_mut), which has already marked
+    /// the borrowed slots as modified.
+    pub fn get_mut(&mut self) -> &mut T {
         unsafe { self.storage.as_mut() }
     }
 }
@@ -69,8 +76,8 @@ impl<'a, T: Debug + ?Sized> Debug for CellMutGuard<'a, T> {
     }
 }
 
-/// Type safe abstraction over a borrowed cell data
-pub(crate) struct CellGuard<'a, T: ?Sized> {
+/// Type safe abstraction over a borrowed component storage
+pub struct CellGuard<'a, T: ?Sized> {
     data: AtomicRef<'a, CellData>,
     storage: NonNull<T>,
 }
@@ -90,8 +97,9 @@ impl<'a, T: ComponentValue + Sized> CellGuard<'a, [T]> {
 }
 
 impl<'a, T: ?Sized> CellGuard<'a, T> {
+    /// Converts the guard into a plain borrow of the storage
     #[inline]
-    pub(crate) fn into_inner(self) -> AtomicRef<'a, T> {
+    pub fn into_inner(self) -> AtomicRef<'a, T> {
         AtomicRef::map(self.data, |_| unsafe { self.storage.as_ref() })
     }
 
@@ -100,8 +108,9 @@ impl<'a, T: ?Sized> CellGuard<'a, T> {
         &self.data.changes
     }
 
+    /// Returns the borrowed storage
     #[inline]
-    pub(crate) fn get(&self) -> &T {
+    pub fn get(&self) -> &T {
         unsafe { self.storage.as_ref() }
     }
 }
@@ -120,6 +129,8 @@ pub struct RefMut<'a, T> {
     id: Entity,
     slot: Slot,
     tick: u32,
+    /// Bitset of the written slots, see [`WriteTracker`](crate::fetch::WriteTracker)
+    written: Option<&'a [AtomicU64]>,
 }
 
 impl<'a, T: ComponentValue> RefMut<'a, T> {
@@ -137,8 +148,15 @@ impl<'a, T: ComponentValue> RefMut<'a, T> {
             id,
             slot,
             tick,
+            written: None,
         })
     }
+
+    /// Marks the slot in `written` when mutably dereferenced
+    pub(crate) fn track_written(mut self, written: Option<&'a [AtomicU64]>) -> Self {
+        self.written = written;
+        self
+    }
 }
 
 impl<'a, T: Debug> Debug for RefMut<'a, T> {
@@ -163,6 +181,10 @@ impl<'a, T> DerefMut for RefMut<'a, T> {
             .data
             .set_modified(&[self.id], Slice::single(self.slot), self.tick);
 
+        if let Some(written) = self.written {
+            written[self.slot / 64].fetch_or(1 << (self.slot % 64), Relaxed);
+        }
+
         self.guard.get_mut()
     }
 }
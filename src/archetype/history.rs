@@ -0,0 +1,72 @@
+use alloc::collections::{BTreeMap, VecDeque};
+
+use crate::Entity;
+
+use super::ChangeKind;
+
+/// A single change of a component retained by [`History`](crate::metadata::History)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangeRecord {
+    /// The kind of change
+    pub kind: ChangeKind,
+    /// The change tick at which the change occurred
+    pub tick: u32,
+}
+
+/// Retains the last `capacity` changes of each entity in a cell
+#[derive(Debug)]
+pub(crate) struct ChangeHistory {
+    capacity: usize,
+    records: BTreeMap<Entity, VecDeque<ChangeRecord>>,
+}
+
+impl ChangeHistory {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, ids: &[Entity], kind: ChangeKind, tick: u32) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        for &id in ids {
+            let records = self.records.entry(id).or_default();
+            if records.len() == self.capacity {
+                records.pop_front();
+            }
+
+            records.push_back(ChangeRecord { kind, tick });
+        }
+    }
+
+    /// Returns the changes of `id`, oldest first
+    pub(crate) fn get(&self, id: Entity) -> impl Iterator<Item = &ChangeRecord> {
+        self.records.get(&id).into_iter().flatten()
+    }
+
+    pub(crate) fn remove(&mut self, ids: &[Entity]) {
+        for id in ids {
+            self.records.remove(id);
+        }
+    }
+
+    /// Moves the changes of `id` to another history
+    pub(crate) fn move_to(&mut self, id: Entity, dst: &mut Self) {
+        if let Some(records) = self.records.remove(&id) {
+            dst.records.insert(id, records);
+        }
+    }
+
+    /// Moves the changes of all entities to another history
+    pub(crate) fn move_all(&mut self, dst: &mut Self) {
+        dst.records.append(&mut self.records);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.records.clear();
+    }
+}
@@ -6,12 +6,15 @@ use alloc::{
 };
 use core::{fmt::Debug, mem};
 
-use atomic_refcell::{AtomicRef, AtomicRefCell, BorrowError, BorrowMutError};
+use atomic_refcell::{AtomicRef, AtomicRefCell, AtomicRefMut, BorrowError, BorrowMutError};
 use itertools::Itertools;
+use smallvec::SmallVec;
 
 use crate::{
+    blob::{Blob, BlobSlab},
     component::{ComponentDesc, ComponentKey, ComponentValue},
-    events::{EventData, EventSubscriber},
+    events::{EventKind, EventSubscriber},
+    metadata::{history_capacity, is_immutable, is_no_lock},
     writer::ComponentUpdater,
     Component, Entity,
 };
@@ -23,12 +26,23 @@ pub type Slot = usize;
 
 mod batch;
 mod changes;
+mod dispatch;
 mod guard;
-mod slice;
+mod history;
+mod pool;
+/// Contiguous ranges of slots and operations on them
+pub mod slice;
 mod storage;
 
 pub use batch::*;
 pub use changes::*;
+use dispatch::EventBatch;
+pub(crate) use history::ChangeHistory;
+pub use history::ChangeRecord;
+#[cfg(feature = "leak-detection")]
+pub use pool::ComponentCounts;
+pub(crate) use pool::StoragePool;
+pub use pool::StoragePoolStats;
 pub use slice::*;
 pub use storage::Storage;
 
@@ -39,6 +53,7 @@ pub use guard::*;
 pub struct StorageInfo {
     cap: usize,
     len: usize,
+    align: usize,
 }
 
 impl StorageInfo {
@@ -52,6 +67,11 @@ impl StorageInfo {
         self.len
     }
 
+    /// Returns the alignment of the buffer, which is that of the component type
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
     #[must_use]
     /// Returns true if the storage is empty
     pub fn is_empty(&self) -> bool {
@@ -88,66 +108,201 @@ impl ArchetypeInfo {
     }
 }
 
+/// Read-only view of an archetype, used to filter archetypes with custom logic.
+///
+/// See [`Query::filter_arch`](crate::Query::filter_arch)
+#[derive(Clone, Copy)]
+pub struct ArchInfo<'a> {
+    id: ArchetypeId,
+    arch: &'a Archetype,
+}
+
+impl<'a> ArchInfo<'a> {
+    pub(crate) fn new(id: ArchetypeId, arch: &'a Archetype) -> Self {
+        Self { id, arch }
+    }
+
+    /// Returns the id of the archetype
+    pub fn id(&self) -> ArchetypeId {
+        self.id
+    }
+
+    /// Returns true if the archetype contains `component`
+    pub fn has(&self, component: ComponentKey) -> bool {
+        self.arch.has(component)
+    }
+
+    /// Returns the components in the archetype
+    pub fn components(&self) -> impl Iterator<Item = ComponentDesc> + 'a {
+        self.arch.components_desc()
+    }
+
+    /// Returns the entities in the archetype
+    pub fn entities(&self) -> &'a [Entity] {
+        self.arch.entities()
+    }
+
+    /// Returns the number of entities in the archetype
+    pub fn len(&self) -> usize {
+        self.arch.len()
+    }
+
+    /// Returns true if the archetype contains no entities
+    pub fn is_empty(&self) -> bool {
+        self.arch.is_empty()
+    }
+}
+
+impl Debug for ArchInfo<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArchInfo")
+            .field("id", &self.id)
+            .field("entities", &self.len())
+            .finish()
+    }
+}
+
 pub(crate) struct CellData {
     pub(crate) storage: Storage,
     pub(crate) changes: Changes,
     subscribers: Vec<Arc<dyn EventSubscriber>>,
+    /// Added and modified events are not dispatched, but are recovered from the change lists
+    /// through [`Archetype::flush_deferred`]
+    deferred: bool,
+    /// Events are dispatched to the subscribers in parallel if there are at least this many
+    #[cfg(feature = "rayon")]
+    parallel_threshold: usize,
     pub(crate) key: ComponentKey,
+    /// Holds the payloads of [`Blob`] components
+    pub(crate) blobs: Option<BlobSlab>,
+    /// The retained changes of components declared with [`History`](crate::metadata::History)
+    pub(crate) history: Option<ChangeHistory>,
 }
 
 impl CellData {
-    /// Sets the specified entities and slots as modified and invokes subscribers
-    /// **Note**: `ids` must be the slice of entities pointed to by `slice`
-    pub(crate) fn set_modified(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) {
-        debug_assert_eq!(ids.len(), slots.len());
-        self.changes
-            .set_modified_if_tracking(Change::new(slots, change_tick));
+    /// Returns the payload of the blob at `slot`.
+    ///
+    /// # Panics
+    /// If the cell does not store blobs
+    pub(crate) fn blob(&self, slot: Slot) -> Option<&[u8]> {
+        let slab = self.blobs.as_ref().expect("Cell does not store blobs");
+        let blob = self.storage.downcast_ref::<Blob>().get(slot)?;
+        Some(blob.bytes(slab))
+    }
 
-        let event = EventData {
-            ids,
-            slots,
-            key: self.key,
-        };
+    /// Mutable version of [`Self::blob`]
+    pub(crate) fn blob_mut(&mut self, slot: Slot) -> Option<&mut [u8]> {
+        let slab = self.blobs.as_mut().expect("Cell does not store blobs");
+        let blob = self.storage.downcast_mut::<Blob>().get_mut(slot)?;
+        Some(blob.bytes_mut(slab))
+    }
 
-        for handler in self.subscribers.iter() {
-            handler.on_modified(&event)
+    /// Moves the blobs into the slab, discarding the bytes of replaced and removed blobs.
+    ///
+    /// Returns the number of bytes reclaimed.
+    pub(crate) fn compact_blobs(&mut self) -> usize {
+        match &mut self.blobs {
+            Some(slab) => slab.compact(self.storage.downcast_mut::<Blob>()),
+            None => 0,
         }
     }
 
-    /// Sets the specified entities and slots as modified and invokes subscribers
-    /// **Note**: `ids` must be the slice of entities pointed to by `slice`
-    pub(crate) fn set_added(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) {
+    /// Records the slots as modified, and returns true if the subscribers are to be notified
+    fn record_modified(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) -> bool {
+        debug_assert_eq!(ids.len(), slots.len());
+        if let Some(history) = &mut self.history {
+            history.record(ids, ChangeKind::Modified, change_tick);
+        }
+
+        let changes = &mut self.changes;
+        if self.deferred {
+            changes.set_modified(Change::new(slots, change_tick));
+            return false;
+        }
+
+        changes.set_modified_if_tracking(Change::new(slots, change_tick));
+        true
+    }
+
+    /// Records the slots as added, and returns true if the subscribers are to be notified
+    fn record_added(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) -> bool {
         self.changes.set_added(Change::new(slots, change_tick));
 
-        let event = EventData {
-            ids,
-            slots,
-            key: self.key,
-        };
+        if let Some(history) = &mut self.history {
+            history.record(ids, ChangeKind::Added, change_tick);
+        }
 
-        for handler in self.subscribers.iter() {
-            handler.on_added(&self.storage, &event);
+        !self.deferred
+    }
+
+    /// Forgets the history of the removed entities
+    fn record_removed(&mut self, ids: &[Entity]) {
+        if let Some(history) = &mut self.history {
+            history.remove(ids);
         }
     }
 
-    #[inline]
-    pub(crate) fn set_removed(&mut self, ids: &[Entity], slots: Slice) {
-        let event = EventData {
-            ids,
-            slots,
-            key: self.key,
-        };
+    /// Sets the specified entities and slots as modified and invokes subscribers
+    /// **Note**: `ids` must be the slice of entities pointed to by `slice`
+    pub(crate) fn set_modified(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) {
+        if self.record_modified(ids, slots, change_tick) {
+            let mut batch = EventBatch::default();
+            batch.push(EventKind::Modified, self, ids, slots);
+            batch.dispatch();
+        }
+    }
 
-        for handler in self.subscribers.iter() {
-            handler.on_removed(&self.storage, &event);
+    /// Sets the specified entities and slots as modified and invokes subscribers
+    /// **Note**: `ids` must be the slice of entities pointed to by `slice`
+    pub(crate) fn set_added(&mut self, ids: &[Entity], slots: Slice, change_tick: u32) {
+        if self.record_added(ids, slots, change_tick) {
+            let mut batch = EventBatch::default();
+            batch.push(EventKind::Added, self, ids, slots);
+            batch.dispatch();
         }
     }
 }
 
+/// Notifies the subscribers of all cells that `slots` were removed, where `ids` are the entities
+/// in `slots`
+fn dispatch_removed(cells: &mut [Cell], ids: &[Entity], slots: Slice) {
+    let mut batch = EventBatch::default();
+    for cell in cells {
+        let data = cell.data.get_mut();
+        data.record_removed(ids);
+        batch.push(EventKind::Removed, data, ids, slots);
+    }
+
+    batch.dispatch();
+}
+
+/// Returns the parts of `slice` not covered by the ascending non-overlapping `other`
+fn subtract_all(slice: Slice, other: &[Slice]) -> impl Iterator<Item = Slice> + '_ {
+    let mut start = slice.start;
+    other
+        .iter()
+        .filter(move |v| v.overlaps(slice))
+        .map(Some)
+        .chain([None])
+        .filter_map(move |v| {
+            let end = v.map(|v| v.start.max(start)).unwrap_or(slice.end);
+            let gap = Slice::new(start, end.min(slice.end));
+            if let Some(v) = v {
+                start = v.end.max(start);
+            }
+
+            (!gap.is_empty()).then_some(gap)
+        })
+}
+
 /// Stores a list of component values, changes, and subscribers
 pub(crate) struct Cell {
     pub(crate) data: AtomicRefCell<CellData>,
     desc: ComponentDesc,
+    /// The component is declared [`NoLock`](crate::NoLock)
+    no_lock: bool,
+    /// The component is declared [`Immutable`](crate::Immutable)
+    immutable: bool,
 }
 
 impl Cell {
@@ -157,14 +312,21 @@ impl Cell {
                 storage: Storage::new(desc),
                 changes: Changes::new(),
                 subscribers: Vec::new(),
+                deferred: false,
+                #[cfg(feature = "rayon")]
+                parallel_threshold: usize::MAX,
                 key: desc.key,
+                blobs: desc.is::<Blob>().then(BlobSlab::default),
+                history: history_capacity(&desc).map(ChangeHistory::new),
             }),
             desc,
+            no_lock: is_no_lock(&desc),
+            immutable: is_immutable(&desc),
         }
     }
 
     /// Moves a slot in the cell to another cell and slot while migrating all changes.
-    fn move_to(&mut self, slot: Slot, dst: &mut Self, dst_slot: Slot) {
+    fn move_to(&mut self, id: Entity, slot: Slot, dst: &mut Self, dst_slot: Slot) {
         let data = self.data.get_mut();
 
         let last = data.storage.len() - 1;
@@ -175,10 +337,18 @@ impl Cell {
             dst.storage.extend(p, 1);
         });
 
+        if let (Some(src_blobs), Some(dst_blobs)) = (&data.blobs, &mut dst.blobs) {
+            let blob = dst.storage.downcast_mut::<Blob>().last_mut().unwrap();
+            blob.rebase(src_blobs, dst_blobs);
+        }
+
         // Replace this slot with the last slot and move everything to the dst archetype
-        data.changes.swap_remove(slot, last, |kind, v| {
-            dst.changes.set_slot(kind, dst_slot, v.tick);
-        });
+        data.changes
+            .migrate_to(slot, last, &mut dst.changes, dst_slot);
+
+        if let (Some(src_history), Some(dst_history)) = (&mut data.history, &mut dst.history) {
+            src_history.move_to(id, dst_history);
+        }
 
         // Do not notify of removal, since the component is still intact, but in another archetype
     }
@@ -192,6 +362,13 @@ impl Cell {
         debug_assert_eq!(dst.storage.len(), dst_start);
         unsafe { dst.storage.append(&mut data.storage) }
 
+        if let (Some(src_blobs), Some(dst_blobs)) = (&mut data.blobs, &mut dst.blobs) {
+            for blob in &mut dst.storage.downcast_mut::<Blob>()[dst_start..] {
+                blob.rebase(src_blobs, dst_blobs);
+            }
+            src_blobs.clear();
+        }
+
         data.changes.zip_map(&mut dst.changes, |_, a, b| {
             a.inner.drain(..).for_each(|mut change| {
                 change.slice.start += dst_start;
@@ -200,6 +377,10 @@ impl Cell {
                 b.set(change);
             })
         });
+
+        if let (Some(src_history), Some(dst_history)) = (&mut data.history, &mut dst.history) {
+            src_history.move_all(dst_history);
+        }
     }
 
     /// Move a slot out of the cell by swapping with the last
@@ -208,7 +389,16 @@ impl Cell {
 
         let last = data.storage.len() - 1;
 
-        data.storage.swap_remove(slot, |p| on_move(self.desc, p));
+        let blobs = &data.blobs;
+        data.storage.swap_remove(slot, |p| {
+            // The value leaves the archetype, and can no longer refer to the slab
+            if let Some(blobs) = blobs {
+                unsafe { (*p.cast::<Blob>()).detach(blobs) }
+            }
+
+            on_move(self.desc, p)
+        });
+        data.storage.record_dropped(1);
         data.changes.swap_remove(slot, last, |_, _| {});
     }
 
@@ -216,15 +406,36 @@ impl Cell {
     fn clear(&mut self) {
         let data = self.data.get_mut();
 
+        data.storage.record_dropped(data.storage.len());
         data.storage.clear();
         data.changes.clear();
+        if let Some(blobs) = &mut data.blobs {
+            blobs.clear();
+        }
+        if let Some(history) = &mut data.history {
+            history.clear();
+        }
     }
 
     /// Drain the values in the cell.
     pub(crate) fn drain(&mut self) -> Storage {
         let data = self.data.get_mut();
+        if let Some(blobs) = &mut data.blobs {
+            for blob in data.storage.downcast_mut::<Blob>() {
+                blob.detach(blobs);
+            }
+            blobs.clear();
+        }
+
+        data.storage.record_dropped(data.storage.len());
         let storage = mem::replace(&mut data.storage, Storage::new(self.desc));
+        if let Some(pool) = storage.pool() {
+            data.storage.set_pool(pool.clone());
+        }
         data.changes.clear();
+        if let Some(history) = &mut data.history {
+            history.clear();
+        }
 
         storage
     }
@@ -232,7 +443,7 @@ impl Cell {
     /// # Safety
     ///
     /// Assumes `self` is of type `T`
-    pub(crate) unsafe fn get<T: ComponentValue>(&self, slot: Slot) -> Option<AtomicRef<T>> {
+    pub(crate) unsafe fn get<T: ComponentValue>(&self, slot: Slot) -> Option<AtomicRef<'_, T>> {
         let data = self.data.borrow();
         AtomicRef::filter_map(data, |v| v.storage.downcast_ref::<T>().get(slot))
     }
@@ -243,7 +454,7 @@ impl Cell {
     pub(crate) unsafe fn try_get<T: ComponentValue>(
         &self,
         slot: Slot,
-    ) -> Result<Option<AtomicRef<T>>, BorrowError> {
+    ) -> Result<Option<AtomicRef<'_, T>>, BorrowError> {
         let data = self.data.try_borrow()?;
         Ok(AtomicRef::filter_map(data, |v| {
             v.storage.downcast_ref::<T>().get(slot)
@@ -251,13 +462,85 @@ impl Cell {
     }
 
     #[inline]
-    pub fn borrow<T: ComponentValue>(&self) -> CellGuard<[T]> {
+    pub fn borrow<T: ComponentValue>(&self) -> CellGuard<'_, [T]> {
         CellGuard::new(self.data.borrow())
     }
 
     #[inline]
-    pub fn borrow_mut<T: ComponentValue>(&self) -> CellMutGuard<[T]> {
-        CellMutGuard::new(self.data.borrow_mut())
+    pub fn borrow_mut<T: ComponentValue>(&self) -> CellMutGuard<'_, [T]> {
+        CellMutGuard::new(self.lock_mut())
+    }
+
+    /// Borrows the storage for reading, returning the guard which must be held while the storage
+    /// is accessed.
+    ///
+    /// [`NoLock`](crate::NoLock) components are never borrowed mutably through a shared
+    /// reference, and are as such read without a guard. The same applies when `exclusive` is set,
+    /// see [`FetchPrepareData::exclusive`](crate::fetch::FetchPrepareData).
+    #[inline]
+    pub(crate) fn borrow_shared<T: ComponentValue>(
+        &self,
+        exclusive: bool,
+    ) -> (Option<AtomicRef<'_, CellData>>, &[T]) {
+        if self.no_lock || exclusive {
+            // Safety: mutable access requires `&mut self`, see `lock_mut`, or the world is
+            // borrowed exclusively by a read-only query
+            let data = unsafe { &*self.data.as_ptr() };
+            (None, data.storage.downcast_ref::<T>())
+        } else {
+            let data = self.data.borrow();
+            // Safety: the storage is borrowed for as long as the guard is held
+            let storage = unsafe { &*(data.storage.downcast_ref::<T>() as *const [T]) };
+            (Some(data), storage)
+        }
+    }
+
+    /// Borrows the cell data mutably through a shared reference, for mutable access to the values.
+    ///
+    /// # Panics
+    /// See [`Self::lock_data`]. Also panics if the component is declared
+    /// [`Immutable`](crate::Immutable) or stores [`Blob`]s, as a blob moved out through a mutable
+    /// reference would still refer to the slab of this cell.
+    #[inline]
+    pub(crate) fn lock_mut(&self) -> AtomicRefMut<'_, CellData> {
+        assert!(
+            !self.immutable,
+            "The component {} is declared `Immutable` and can not be accessed mutably",
+            self.desc.name()
+        );
+        assert!(
+            !self.desc.is::<Blob>(),
+            "The blob component {} can not be accessed mutably, use `World::update_blob` instead",
+            self.desc.name()
+        );
+
+        self.lock_data()
+    }
+
+    /// Borrows the cell data mutably through a shared reference, without handing out mutable
+    /// access to the values.
+    ///
+    /// # Panics
+    /// If the component is declared [`NoLock`](crate::NoLock), as it may be read concurrently
+    /// without a borrow.
+    #[inline]
+    pub(crate) fn lock_data(&self) -> AtomicRefMut<'_, CellData> {
+        assert!(
+            !self.no_lock,
+            "The component {} is declared `NoLock` and can not be borrowed mutably",
+            self.desc.name()
+        );
+
+        self.data.borrow_mut()
+    }
+
+    /// Returns true if the storage can currently be borrowed, mutably if `mutable` is set
+    pub(crate) fn can_borrow(&self, mutable: bool) -> bool {
+        if mutable {
+            self.data.try_borrow_mut().is_ok()
+        } else {
+            self.data.try_borrow().is_ok()
+        }
     }
 
     // #[inline]
@@ -276,7 +559,7 @@ impl Cell {
         id: Entity,
         slot: Slot,
         tick: u32,
-    ) -> Option<RefMut<T>> {
+    ) -> Option<RefMut<'_, T>> {
         RefMut::new(self.borrow_mut(), id, slot, tick)
     }
 
@@ -286,9 +569,15 @@ impl Cell {
 }
 
 // #[derive(Debug)]
-#[doc(hidden)]
 /// A collection of entities with the same components.
 /// Stored as columns of contiguous component data.
+///
+/// Each entity occupies a [`Slot`] in the archetype, which indexes both [`Self::entities`] and the
+/// column of each component. This allows writing custom per-archetype algorithms using
+/// [`Self::get_slice`] and [`Self::get_slice_mut`].
+///
+/// Acquired through [`World::archetype`](crate::World::archetype), or for each batch yielded
+/// by [`QueryBorrow::iter_batched`](crate::QueryBorrow::iter_batched).
 pub struct Archetype {
     components: BTreeMap<ComponentKey, usize>,
     cells: Box<[Cell]>,
@@ -344,7 +633,10 @@ impl Archetype {
         self.components.keys().filter(|v| v.is_relation()).copied()
     }
 
-    pub(crate) fn relations_like(&self, relation: Entity) -> btree_map::Range<ComponentKey, usize> {
+    pub(crate) fn relations_like(
+        &self,
+        relation: Entity,
+    ) -> btree_map::Range<'_, ComponentKey, usize> {
         self.components.range(
             ComponentKey::new(relation, Some(Entity::MIN))
                 ..=ComponentKey::new(relation, Some(Entity::MAX)),
@@ -387,13 +679,25 @@ impl Archetype {
         debug_assert!(existing.is_none());
     }
 
-    pub(crate) fn borrow<T: ComponentValue>(
-        &self,
-        component: ComponentKey,
-    ) -> Option<CellGuard<[T]>> {
+    /// Access a component storage.
+    ///
+    /// # Panics
+    /// If the storage is already borrowed mutably
+    pub fn borrow<T: ComponentValue>(&self, component: ComponentKey) -> Option<CellGuard<'_, [T]>> {
         Some(self.cell(component)?.borrow())
     }
 
+    /// Returns the changes of `kind` recorded for `component`, in ascending slot order.
+    ///
+    /// Returns `None` if the archetype does not have the component.
+    ///
+    /// # Panics
+    /// If the component is already borrowed mutably
+    pub fn changes(&self, component: ComponentKey, kind: ChangeKind) -> Option<Vec<Change>> {
+        let data = self.cell(component)?.data.borrow();
+        Some(data.changes.get(kind).as_slice().to_vec())
+    }
+
     /// Access a component storage mutably.
     ///
     /// Return a reference to the change list, which must be used to push the slots which where
@@ -404,12 +708,46 @@ impl Archetype {
     pub(crate) fn borrow_mut<T: ComponentValue>(
         &self,
         component: ComponentKey,
-    ) -> Option<CellMutGuard<[T]>> {
+    ) -> Option<CellMutGuard<'_, [T]>> {
         let cell = self.cell(component)?;
         let data = cell.borrow_mut();
         Some(data)
     }
 
+    /// Returns the values of `component` for all slots in the archetype.
+    ///
+    /// Returns `None` if the archetype does not have the component.
+    ///
+    /// # Panics
+    /// If the component is already borrowed mutably
+    pub fn get_slice<T: ComponentValue>(
+        &self,
+        component: Component<T>,
+    ) -> Option<CellGuard<'_, [T]>> {
+        self.borrow(component.key())
+    }
+
+    /// Mutably access the values of `component` at `slots`, which are marked as modified at
+    /// `change_tick`.
+    ///
+    /// Use [`World::advance_change_tick`](crate::World::advance_change_tick) to acquire a new
+    /// change tick. Returns `None` if the archetype does not have the component, or if `slots`
+    /// is out of bounds.
+    ///
+    /// # Panics
+    /// If the component is already borrowed, or is declared [`Immutable`](crate::Immutable)
+    pub fn get_slice_mut<T: ComponentValue>(
+        &self,
+        component: Component<T>,
+        slots: Slice,
+        change_tick: u32,
+    ) -> Option<CellMutGuard<'_, [T]>> {
+        let ids = self.entities.get(slots.as_range())?;
+        let mut guard = self.borrow_mut::<T>(component.key())?;
+        guard.set_modified(ids, slots, change_tick);
+        guard.filter_map(|v| v.get_mut(slots.as_range()))
+    }
+
     /// Removes a slot and swaps in the last slot
     #[inline(always)]
     unsafe fn remove_slot(&mut self, slot: Slot) -> Option<(Entity, Slot)> {
@@ -436,6 +774,7 @@ impl Archetype {
                     StorageInfo {
                         cap: data.storage.capacity(),
                         len: data.storage.len(),
+                        align: v.desc.align(),
                     },
                 )
             })
@@ -454,7 +793,7 @@ impl Archetype {
         slot: Slot,
         component: Component<T>,
         tick: u32,
-    ) -> Option<RefMut<T>> {
+    ) -> Option<RefMut<'_, T>> {
         self.cell(component.key())?
             .get_mut(self.entities[slot], slot, tick)
     }
@@ -465,7 +804,7 @@ impl Archetype {
         slot: Slot,
         component: Component<T>,
         tick: u32,
-    ) -> Result<Option<RefMut<T>>, BorrowMutError> {
+    ) -> Result<Option<RefMut<'_, T>>, BorrowMutError> {
         let cell = match self.cell(component.key()) {
             Some(v) => v,
             None => return Ok(None),
@@ -510,7 +849,7 @@ impl Archetype {
     ) -> Option<U::Updated> {
         let cell = self.cell(component.key())?;
 
-        let mut data = cell.data.borrow_mut();
+        let mut data = cell.lock_mut();
 
         let res = unsafe { writer.update(&mut data, slot, self.entities[slot], tick) };
 
@@ -522,7 +861,7 @@ impl Archetype {
         &self,
         slot: Slot,
         component: Component<T>,
-    ) -> Option<AtomicRef<T>> {
+    ) -> Option<AtomicRef<'_, T>> {
         let cell = self.cell(component.key())?;
         unsafe { cell.get(slot) }
     }
@@ -532,7 +871,7 @@ impl Archetype {
         &self,
         slot: Slot,
         component: Component<T>,
-    ) -> Result<Option<AtomicRef<T>>, BorrowError> {
+    ) -> Result<Option<AtomicRef<'_, T>>, BorrowError> {
         let cell = match self.cell(component.key()) {
             Some(v) => v,
             None => return Ok(None),
@@ -574,7 +913,7 @@ impl Archetype {
 
         #[cfg(debug_assertions)]
         {
-            if self.entities.iter().any(|&v| v == id) {
+            if self.entities.contains(&id) {
                 panic!("Entity already in archetype");
             }
         }
@@ -614,6 +953,7 @@ impl Archetype {
         let slot = data.storage.len();
         assert_eq!(slot, len - 1, "Not inserting at end");
         data.storage.extend(src, 1);
+        data.storage.record_constructed(1);
 
         // TODO remove and make internal
         assert!(
@@ -626,27 +966,90 @@ impl Archetype {
         data.set_added(&self.entities[slot..=slot], Slice::single(slot), tick);
     }
 
-    /// Moves the components in `storage` to the not yet initialized space in a
-    /// new allocation.
+    /// Pushes all components of the last slot, see [`Self::push`].
+    ///
+    /// The subscribers are notified of the added components together.
+    ///
     /// # Safety
-    /// The length of the passed data must be equal to the slice and the slice
-    /// must point to a currently uninitialized region in the archetype.
-    pub(crate) unsafe fn extend(&mut self, src: &mut Storage, tick: u32) {
-        if src.is_empty() {
-            return;
+    /// The components must match the type of their desc, and each must only be pushed **ONCE**
+    pub(crate) unsafe fn push_all(
+        &mut self,
+        components: impl IntoIterator<Item = (ComponentDesc, *mut u8)>,
+        tick: u32,
+    ) {
+        let slot = self.len() - 1;
+        let mut added: SmallVec<[ComponentKey; 8]> = SmallVec::new();
+
+        for (desc, src) in components {
+            let data = self.cells[self.components[&desc.key()]].data.get_mut();
+
+            assert_eq!(data.storage.len(), slot, "Not inserting at end");
+            data.storage.extend(src, 1);
+            data.storage.record_constructed(1);
+
+            added.push(desc.key());
         }
 
+        self.set_added_all(&added, Slice::single(slot), tick);
+    }
+
+    /// Moves the components in each storage to the not yet initialized space in a
+    /// new allocation.
+    ///
+    /// The subscribers are notified of the added components together.
+    ///
+    /// # Safety
+    /// The storages must all be of the same length, which must be equal to the slice and the
+    /// slice must point to a currently uninitialized region in the archetype.
+    pub(crate) unsafe fn extend_all(
+        &mut self,
+        storages: impl IntoIterator<Item = Storage>,
+        tick: u32,
+    ) {
         let len = self.len();
-        let cell = &mut self.cells[self.components[&src.desc().key()]];
-        let data = cell.data.get_mut();
+        let mut slots = Slice::new(len, len);
+        let mut added: SmallVec<[ComponentKey; 8]> = SmallVec::new();
+
+        for mut src in storages {
+            if src.is_empty() {
+                continue;
+            }
+
+            let data = self.cells[self.components[&src.desc().key()]]
+                .data
+                .get_mut();
 
-        let slots = Slice::new(data.storage.len(), data.storage.len() + src.len());
-        debug_assert!(slots.start <= len);
+            let start = data.storage.len();
+            debug_assert!(added.is_empty() || slots.start == start);
+            slots = Slice::new(start, start + src.len());
+            debug_assert!(slots.end <= len);
 
-        data.storage.append(src);
-        debug_assert!(data.storage.len() <= len);
+            data.storage.record_constructed(src.len());
+            data.storage.append(&mut src);
 
-        data.set_added(&self.entities[slots.as_range()], slots, tick);
+            added.push(src.desc().key());
+        }
+
+        self.set_added_all(&added, slots, tick);
+    }
+
+    /// Records `slots` of `components` as added, and notifies the subscribers of all components
+    /// together
+    fn set_added_all(&mut self, components: &[ComponentKey], slots: Slice, tick: u32) {
+        let ids = &self.entities[slots.as_range()];
+        let mut batch = EventBatch::default();
+        for cell in self.cells.iter_mut() {
+            if !components.contains(&cell.desc.key()) {
+                continue;
+            }
+
+            let data = cell.data.get_mut();
+            if data.record_added(ids, slots, tick) {
+                batch.push(EventKind::Added, data, ids, slots);
+            }
+        }
+
+        batch.dispatch();
     }
 
     /// Move all components in `slot` to archetype of `dst`. The components not
@@ -668,18 +1071,27 @@ impl Archetype {
 
         let dst_slot = dst.allocate(id);
 
-        for cell in &mut *self.cells {
+        // Notify the subscribers that the components were removed
+        let ids = [id];
+        let mut batch = EventBatch::default();
+        for cell in self.cells.iter_mut() {
+            if !dst.components.contains_key(&cell.desc.key()) {
+                let data = cell.data.get_mut();
+                data.record_removed(&ids);
+                batch.push(EventKind::Removed, data, &ids, Slice::single(slot));
+            }
+        }
+
+        batch.dispatch();
+
+        for cell in self.cells.iter_mut() {
             let key = cell.desc.key();
-            let data = cell.data.get_mut();
 
             let dst_cell = dst.cell_mut(key);
 
             if let Some(dst_cell) = dst_cell {
-                cell.move_to(slot, dst_cell, dst_slot);
+                cell.move_to(id, slot, dst_cell, dst_slot);
             } else {
-                // Notify the subscribers that the component was removed
-                data.set_removed(&[id], Slice::single(slot));
-
                 cell.take(slot, &mut on_drop);
             }
         }
@@ -708,11 +1120,9 @@ impl Archetype {
         //     subscriber.on_despawned(id, slot, self);
         // }
 
-        for cell in &mut *self.cells {
-            let data = cell.data.get_mut();
-            // data.on_event(&self.entities, Slice::single(slot), EventKind::Removed);
-            data.set_removed(&[id], Slice::single(slot));
+        dispatch_removed(&mut self.cells, &[id], Slice::single(slot));
 
+        for cell in self.cells.iter_mut() {
             cell.take(slot, &mut on_move)
         }
 
@@ -743,6 +1153,20 @@ impl Archetype {
     /// Leaves `self` empty.
     /// Returns the new location of all entities
     pub fn move_all(&mut self, dst: &mut Self) -> Vec<(Entity, Slot)> {
+        self.move_all_mapped(dst, |key| key, 0)
+    }
+
+    /// Move all entities from one archetype to another, where each component is moved to the
+    /// component given by `map`.
+    ///
+    /// Components which are mapped to a different key are reported as removed and then added
+    /// at `change_tick`.
+    pub(crate) fn move_all_mapped(
+        &mut self,
+        dst: &mut Self,
+        map: impl Fn(ComponentKey) -> ComponentKey,
+        change_tick: u32,
+    ) -> Vec<(Entity, Slot)> {
         let len = self.len();
         if len == 0 {
             return Vec::new();
@@ -753,16 +1177,46 @@ impl Archetype {
 
         let dst_slots = dst.allocate_n(&entities);
 
-        for cell in &mut *self.cells {
+        // Notify the subscribers of the components which are removed, or moved to a different
+        // component
+        let mut batch = EventBatch::default();
+        for cell in self.cells.iter_mut() {
             let key = cell.desc.key();
+            let dst_key = map(key);
+            if dst_key == key && dst.components.contains_key(&key) {
+                continue;
+            }
+
             let data = cell.data.get_mut();
+            data.record_removed(&entities);
+            batch.push(EventKind::Removed, data, &entities, slots);
+        }
 
-            let dst_cell = dst.cell_mut(key);
+        batch.dispatch();
+
+        let mut added: SmallVec<[ComponentKey; 4]> = SmallVec::new();
+
+        for cell in self.cells.iter_mut() {
+            let key = cell.desc.key();
+            let dst_key = map(key);
+            let data = cell.data.get_mut();
 
-            if let Some(dst) = dst_cell {
+            let dst_cell = dst.cell_mut(dst_key);
+
+            if let Some(dst_cell) = dst_cell {
                 assert_eq!(data.storage.len(), len);
-                cell.move_all(dst, dst_slots.start);
-                // let dst_changes = dst.changes.get_mut();
+                if dst_key != key {
+                    // The values now belong to a different component
+                    data.storage.record_dropped(len);
+                }
+
+                cell.move_all(dst_cell, dst_slots.start);
+
+                if dst_key != key {
+                    dst_cell.data.get_mut().storage.record_constructed(len);
+                    added.push(dst_key);
+                }
+                // let dst_changes = &mut dst.changes;
 
                 // // Move the changes of all slots
                 // for (src, dst) in self.slots().iter().zip(dst_slots) {
@@ -779,15 +1233,14 @@ impl Archetype {
                 // // Copy this storage to the end of dst
                 // unsafe { dst.storage.get_mut().append(storage) }
             } else {
-                // Notify the subscribers that the component was removed
-                data.set_removed(&entities[slots.as_range()], slots);
-
                 cell.clear();
             }
         }
 
         debug_assert_eq!(self.len(), 0);
 
+        dst.set_added_all(&added, dst_slots, change_tick);
+
         entities.into_iter().zip_eq(dst_slots.iter()).collect_vec()
     }
 
@@ -809,12 +1262,9 @@ impl Archetype {
     /// Drops all components and entities, including changes.
     pub(crate) fn clear(&mut self) {
         let slots = self.slots();
-        for cell in &mut *self.cells {
-            let data = cell.data.get_mut();
-            // Notify the subscribers that the component was removed
-            // data.on_event(&self.entities, slots, EventKind::Removed);
-            data.set_removed(&self.entities[slots.as_range()], slots);
+        dispatch_removed(&mut self.cells, &self.entities[slots.as_range()], slots);
 
+        for cell in self.cells.iter_mut() {
             cell.clear()
         }
 
@@ -833,6 +1283,12 @@ impl Archetype {
         self.entities.is_empty()
     }
 
+    #[must_use]
+    /// Number of entities the archetype can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.entities.capacity()
+    }
+
     /// Get a reference to the archetype's components.
     pub(crate) fn components_desc(&self) -> impl Iterator<Item = ComponentDesc> + '_ {
         self.cells.iter().map(|v| v.desc)
@@ -844,7 +1300,7 @@ impl Archetype {
     }
 
     /// Returns a iterator which attempts to borrows each storage in the archetype
-    pub(crate) fn try_borrow_all(&self) -> impl Iterator<Item = Option<AtomicRef<CellData>>> {
+    pub(crate) fn try_borrow_all(&self) -> impl Iterator<Item = Option<AtomicRef<'_, CellData>>> {
         self.cells.iter().map(|v| v.data.try_borrow().ok())
     }
     /// Access the entities in the archetype for each slot. Entity is None if
@@ -858,12 +1314,13 @@ impl Archetype {
         &self.cells
     }
 
+    pub(crate) fn cells_mut(&mut self) -> &mut [Cell] {
+        &mut self.cells
+    }
+
     pub(crate) fn drain(&mut self) -> ArchetypeDrain {
         let slots = self.slots();
-        for cell in &mut *self.cells {
-            let data = cell.data.get_mut();
-            data.set_removed(&self.entities[slots.as_range()], slots)
-        }
+        dispatch_removed(&mut self.cells, &self.entities[slots.as_range()], slots);
 
         ArchetypeDrain {
             entities: mem::take(&mut self.entities),
@@ -892,6 +1349,89 @@ impl Archetype {
         }
     }
 
+    /// Recycles the allocations of the storages through `pool`
+    pub(crate) fn set_pool(&mut self, pool: &Arc<StoragePool>) {
+        for cell in self.cells.iter_mut() {
+            cell.data.get_mut().storage.set_pool(pool.clone());
+        }
+    }
+
+    /// Defers added and modified events of all cells with subscribers until
+    /// [`Self::flush_deferred`]
+    pub(crate) fn set_deferred(&mut self, deferred: bool) {
+        for cell in self.cells.iter_mut() {
+            let data = cell.data.get_mut();
+            data.deferred = deferred && !data.subscribers.is_empty();
+        }
+    }
+
+    /// Dispatches the events of each cell in parallel if it has at least `threshold` subscribers
+    #[cfg(feature = "rayon")]
+    pub(crate) fn set_parallel_threshold(&mut self, threshold: usize) {
+        for cell in self.cells.iter_mut() {
+            cell.data.get_mut().parallel_threshold = threshold;
+        }
+    }
+
+    /// Dispatches a single coalesced event for the slots added or modified after `since` while
+    /// the events were deferred.
+    ///
+    /// Slots which were added are not reported as modified.
+    pub(crate) fn flush_deferred(&mut self, since: u32) {
+        let mut batch = EventBatch::default();
+        for cell in self.cells.iter_mut() {
+            let data = cell.data.get_mut();
+            if !data.deferred {
+                continue;
+            }
+
+            data.deferred = false;
+
+            let changes = &data.changes;
+
+            let added = changes
+                .get(ChangeKind::Added)
+                .iter()
+                .filter(|v| v.tick > since)
+                .map(|v| v.slice)
+                .collect_vec();
+
+            let modified = changes
+                .get(ChangeKind::Modified)
+                .iter()
+                .filter(|v| v.tick > since)
+                .flat_map(|v| subtract_all(v.slice, &added))
+                .collect_vec();
+
+            for slots in added {
+                batch.push(
+                    EventKind::Added,
+                    data,
+                    &self.entities[slots.as_range()],
+                    slots,
+                );
+            }
+
+            for slots in modified {
+                batch.push(
+                    EventKind::Modified,
+                    data,
+                    &self.entities[slots.as_range()],
+                    slots,
+                );
+            }
+        }
+
+        batch.dispatch();
+    }
+
+    /// Discards the changes of all cells which occurred at or before `tick`
+    pub(crate) fn discard_changes(&mut self, tick: u32) {
+        for cell in self.cells.iter_mut() {
+            cell.data.get_mut().changes.discard_until(tick);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn cell(&self, key: ComponentKey) -> Option<&Cell> {
         Some(&self.cells[*self.components.get(&key)?])
@@ -919,6 +1459,7 @@ impl Archetype {
         Some(&mut self.cell_mut(component)?.data.get_mut().changes)
     }
 
+    /// Returns the components in the archetype, and the index of their column
     pub fn components(&self) -> &BTreeMap<ComponentKey, usize> {
         &self.components
     }
@@ -939,8 +1480,8 @@ pub(crate) struct ArchetypeDrain {
 mod tests {
 
     use crate::buffer::ComponentBuffer;
+    use crate::entity::EntityKind;
     use crate::entity::DEFAULT_GEN;
-    use crate::{component, entity::EntityKind};
     use alloc::string::{String, ToString};
     use alloc::sync::Arc;
 
@@ -994,4 +1535,32 @@ mod tests {
 
         assert_eq!(Arc::strong_count(&shared), 1);
     }
+
+    #[test]
+    fn get_slice() {
+        use crate::{entity_ids, FetchExt, Query, World};
+
+        let mut world = World::new();
+        let ids = (0..4)
+            .map(|i| Entity::builder().set(a(), i).spawn(&mut world))
+            .collect_vec();
+
+        let loc = world.location(ids[0]).unwrap();
+        let mut changed = Query::new(entity_ids()).filter(a().modified());
+        changed.borrow(&world).unwrap().iter().for_each(drop);
+
+        let arch = world.archetype(loc.arch_id).unwrap();
+        assert_eq!(arch.entities(), ids);
+        assert_eq!(arch.get_slice(a()).unwrap().get(), [0, 1, 2, 3]);
+
+        let tick = world.advance_change_tick();
+        let slots = Slice::new(1, 3);
+        for v in arch.get_slice_mut(a(), slots, tick).unwrap().get_mut() {
+            *v *= 10;
+        }
+
+        assert!(arch.get_slice_mut(a(), Slice::new(2, 5), tick).is_none());
+        assert_eq!(arch.get_slice(a()).unwrap().get(), [0, 10, 20, 3]);
+        assert_eq!(changed.collect_vec(&world), [ids[1], ids[2]]);
+    }
 }
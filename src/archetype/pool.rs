@@ -0,0 +1,274 @@
+use core::{ops::DerefMut, ptr::NonNull};
+
+use alloc::{
+    alloc::{dealloc, Layout},
+    collections::BTreeMap,
+    vec::Vec,
+};
+
+#[cfg(feature = "leak-detection")]
+use crate::component::ComponentKey;
+
+/// The default upper bound of the bytes retained by a [`StoragePool`]
+pub(crate) const DEFAULT_POOL_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Returns the size class of an allocation of `size` bytes.
+///
+/// Pooled storages allocate whole size classes, so that a block freed by one component can be
+/// reused by another component with a different size.
+#[inline]
+pub(crate) fn size_class(size: usize) -> usize {
+    size.next_power_of_two()
+}
+
+#[cfg(feature = "std")]
+type Lock<T> = std::sync::Mutex<T>;
+#[cfg(not(feature = "std"))]
+type Lock<T> = atomic_refcell::AtomicRefCell<T>;
+
+/// Recycles the allocations of freed storages, grouped by their size class and alignment.
+///
+/// Owned by the world and shared by all its storages, so that archetype churn, such as when
+/// archetypes are pruned and recreated, does not repeatedly go through the global allocator.
+///
+/// Storages may be dropped outside of the world, such as when drained, so the pool is locked.
+/// Without `std` the pool can not be waited on, and is bypassed while in use elsewhere.
+pub(crate) struct StoragePool {
+    inner: Lock<PoolInner>,
+}
+
+struct PoolInner {
+    /// Free blocks keyed by `(size class, align)`
+    blocks: BTreeMap<(usize, usize), Vec<NonNull<u8>>>,
+    /// The maximum number of bytes retained by the free blocks
+    limit: usize,
+    stats: StoragePoolStats,
+    #[cfg(feature = "leak-detection")]
+    counts: BTreeMap<ComponentKey, ComponentCounts>,
+}
+
+impl Default for StoragePool {
+    fn default() -> Self {
+        Self {
+            inner: Lock::new(PoolInner {
+                blocks: BTreeMap::new(),
+                limit: DEFAULT_POOL_LIMIT,
+                stats: StoragePoolStats::default(),
+                #[cfg(feature = "leak-detection")]
+                counts: BTreeMap::new(),
+            }),
+        }
+    }
+}
+
+// Safety: the pooled blocks are owned by the pool and not aliased
+unsafe impl Send for StoragePool {}
+unsafe impl Sync for StoragePool {}
+
+impl StoragePool {
+    /// Locks the pool, or returns `None` if the pool is in use and can not be waited on
+    #[cfg(feature = "std")]
+    fn lock(&self) -> Option<impl DerefMut<Target = PoolInner> + '_> {
+        // The pool is left consistent by each operation, and can be used after a panic
+        Some(self.inner.lock().unwrap_or_else(|v| v.into_inner()))
+    }
+
+    /// Locks the pool, or returns `None` if the pool is in use and can not be waited on
+    #[cfg(not(feature = "std"))]
+    fn lock(&self) -> Option<impl DerefMut<Target = PoolInner> + '_> {
+        self.inner.try_borrow_mut().ok()
+    }
+
+    /// Takes a free block of the size class and alignment of `layout`.
+    ///
+    /// The size of `layout` must be a size class, see [`size_class`].
+    pub(crate) fn take(&self, layout: Layout) -> Option<NonNull<u8>> {
+        debug_assert_eq!(layout.size(), size_class(layout.size()));
+        let mut inner = self.lock()?;
+        let ptr = inner
+            .blocks
+            .get_mut(&(layout.size(), layout.align()))?
+            .pop()?;
+
+        inner.stats.blocks -= 1;
+        inner.stats.bytes -= layout.size();
+        inner.stats.reused += 1;
+        Some(ptr)
+    }
+
+    /// Returns a block to the pool, or deallocates it if the pool is full.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been allocated by the global allocator with `layout`, and must not be used
+    /// afterwards.
+    pub(crate) unsafe fn release(&self, ptr: NonNull<u8>, layout: Layout) {
+        debug_assert_eq!(layout.size(), size_class(layout.size()));
+        let Some(mut inner) = self.lock() else {
+            dealloc(ptr.as_ptr(), layout);
+            return;
+        };
+
+        if inner.stats.bytes + layout.size() > inner.limit {
+            drop(inner);
+            dealloc(ptr.as_ptr(), layout);
+            return;
+        }
+
+        inner
+            .blocks
+            .entry((layout.size(), layout.align()))
+            .or_default()
+            .push(ptr);
+
+        inner.stats.blocks += 1;
+        inner.stats.bytes += layout.size();
+        inner.stats.released += 1;
+    }
+
+    /// Deallocates all free blocks, returning the number of bytes freed
+    pub(crate) fn trim(&self) -> usize {
+        self.trim_to(0)
+    }
+
+    /// Deallocates the largest free blocks until at most `max_bytes` are retained, returning the
+    /// number of bytes freed
+    fn trim_to(&self, max_bytes: usize) -> usize {
+        let Some(mut inner) = self.lock() else {
+            return 0;
+        };
+
+        let inner = &mut *inner;
+        let mut freed = 0;
+        while inner.stats.bytes > max_bytes {
+            let Some(mut entry) = inner.blocks.last_entry() else {
+                break;
+            };
+
+            let (size, align) = *entry.key();
+            let Some(ptr) = entry.get_mut().pop() else {
+                entry.remove();
+                continue;
+            };
+
+            let layout = Layout::from_size_align(size, align).unwrap();
+            unsafe { dealloc(ptr.as_ptr(), layout) }
+
+            inner.stats.blocks -= 1;
+            inner.stats.bytes -= size;
+            freed += size;
+        }
+
+        freed
+    }
+
+    /// Sets the maximum number of bytes retained by the pool, freeing the excess blocks
+    pub(crate) fn set_limit(&self, max_bytes: usize) {
+        if let Some(mut inner) = self.lock() {
+            inner.limit = max_bytes;
+        }
+
+        self.trim_to(max_bytes);
+    }
+
+    pub(crate) fn stats(&self) -> StoragePoolStats {
+        self.lock().map(|v| v.stats).unwrap_or_default()
+    }
+
+    /// Records `count` values of `key` being moved into the storages of the world
+    #[cfg(feature = "leak-detection")]
+    pub(crate) fn record_constructed(&self, key: ComponentKey, count: usize) {
+        self.lock()
+            .expect("Leak detection locks the pool through std")
+            .counts
+            .entry(key)
+            .or_default()
+            .constructed += count as u64;
+    }
+
+    /// Records `count` values of `key` being dropped or moved out of the storages of the world
+    #[cfg(feature = "leak-detection")]
+    pub(crate) fn record_dropped(&self, key: ComponentKey, count: usize) {
+        self.lock()
+            .expect("Leak detection locks the pool through std")
+            .counts
+            .entry(key)
+            .or_default()
+            .dropped += count as u64;
+    }
+
+    #[cfg(feature = "leak-detection")]
+    pub(crate) fn component_counts(&self) -> BTreeMap<ComponentKey, ComponentCounts> {
+        self.lock()
+            .expect("Leak detection locks the pool through std")
+            .counts
+            .clone()
+    }
+}
+
+impl Drop for StoragePool {
+    fn drop(&mut self) {
+        self.trim();
+    }
+}
+
+/// Statistics of the pool of freed component storage allocations.
+///
+/// See [`World::storage_pool_stats`](crate::World::storage_pool_stats)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StoragePoolStats {
+    blocks: usize,
+    bytes: usize,
+    reused: u64,
+    released: u64,
+}
+
+impl StoragePoolStats {
+    /// Returns the number of free blocks held by the pool
+    pub fn blocks(&self) -> usize {
+        self.blocks
+    }
+
+    /// Returns the total size of the free blocks held by the pool
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the number of allocations which were served by the pool
+    pub fn reused(&self) -> u64 {
+        self.reused
+    }
+
+    /// Returns the number of allocations which were returned to the pool
+    pub fn released(&self) -> u64 {
+        self.released
+    }
+}
+
+/// The number of values of a component which have entered and left the world.
+///
+/// See [`World::debug_component_counts`](crate::World::debug_component_counts)
+#[cfg(feature = "leak-detection")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentCounts {
+    constructed: u64,
+    dropped: u64,
+}
+
+#[cfg(feature = "leak-detection")]
+impl ComponentCounts {
+    /// Returns the number of values which were moved into the world
+    pub fn constructed(&self) -> u64 {
+        self.constructed
+    }
+
+    /// Returns the number of values which were dropped or moved out of the world
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Returns the number of values currently stored in the world
+    pub fn live(&self) -> u64 {
+        self.constructed.wrapping_sub(self.dropped)
+    }
+}
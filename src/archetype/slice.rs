@@ -18,11 +18,33 @@ impl Slice {
         Self { start, end }
     }
 
+    /// Creates a new slice containing only `slot`
     #[inline]
-    pub(crate) const fn single(slot: Slot) -> Slice {
+    pub const fn single(slot: Slot) -> Slice {
         Self::new(slot, slot + 1)
     }
 
+    /// Returns the first slot of the slice
+    #[inline]
+    pub fn start(&self) -> Slot {
+        self.start
+    }
+
+    /// Returns the slot one past the end of the slice
+    #[inline]
+    pub fn end(&self) -> Slot {
+        self.end
+    }
+
+    /// Returns an empty slice positioned at the end of `self`.
+    ///
+    /// This is what [`PreparedFetch::filter_slots`](crate::fetch::PreparedFetch::filter_slots)
+    /// should return when none of the slots match.
+    #[inline]
+    pub fn empty_tail(&self) -> Self {
+        Self::new(self.end, self.end)
+    }
+
     #[inline]
     /// Returns the number of slots in the slice
     pub fn len(&self) -> Slot {
@@ -125,7 +147,8 @@ impl Slice {
         }
     }
 
-    pub(crate) fn subtract(&self, other: &Self) -> Remainder {
+    /// Subtracts `other` from the slice, returning what remains of `self`.
+    pub fn subtract(&self, other: &Self) -> Remainder {
         //    *------*
         // *--*
         if self.end <= other.start || self.start >= other.end {
@@ -203,11 +226,18 @@ impl IntoIterator for Slice {
     }
 }
 
-pub(crate) enum Remainder {
+/// The result of [`Slice::subtract`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Remainder {
+    /// The slices do not overlap, `self` is unchanged
     NoOverlap,
+    /// `self` is fully covered by the subtracted slice
     FullOverlap,
+    /// Only the left part of `self` remains
     Left(Slice),
+    /// Only the right part of `self` remains
     Right(Slice),
+    /// The subtracted slice was in the middle of `self`, leaving both a left and right part
     Split(Slice, Slice),
 }
 
@@ -272,6 +302,29 @@ mod tests {
         assert_eq!(a.difference(e), None);
     }
 
+    #[test]
+    fn subtract() {
+        let a = Slice::new(10, 20);
+
+        assert_eq!(a.subtract(&Slice::new(20, 30)), Remainder::NoOverlap);
+        assert_eq!(a.subtract(&Slice::new(0, 30)), Remainder::FullOverlap);
+        assert_eq!(
+            a.subtract(&Slice::new(0, 15)),
+            Remainder::Right(Slice::new(15, 20))
+        );
+        assert_eq!(
+            a.subtract(&Slice::new(15, 30)),
+            Remainder::Left(Slice::new(10, 15))
+        );
+        assert_eq!(
+            a.subtract(&Slice::new(12, 15)),
+            Remainder::Split(Slice::new(10, 12), Slice::new(15, 20))
+        );
+
+        assert!(a.empty_tail().is_empty());
+        assert_eq!(a.empty_tail().start(), a.end());
+    }
+
     #[test]
     fn slice_overlaps() {
         pub fn overlaps(a: Slice, b: Slice) {
@@ -2,11 +2,15 @@ use core::{mem, ptr::NonNull};
 
 use alloc::{
     alloc::alloc, alloc::dealloc, alloc::handle_alloc_error, alloc::realloc, alloc::Layout,
+    sync::Arc,
 };
 
 use crate::component::{ComponentDesc, ComponentKey, ComponentValue};
 
-use super::Slot;
+use super::{
+    pool::{size_class, StoragePool},
+    Slot,
+};
 
 /// Type erased but managed component store.
 #[doc(hidden)]
@@ -16,8 +20,17 @@ pub struct Storage {
     len: usize,
     cap: usize,
     desc: ComponentDesc,
+    /// Recycles the allocation when the storage grows or is dropped
+    pool: Option<Arc<StoragePool>>,
 }
 
+// Safety: the storage owns values of the component described by `desc`, whose vtable can only be
+// created for a `T: ComponentValue`, which requires `T: Send + Sync`. The values are only
+// accessed through `&self` and `&mut self`, so sending or sharing the storage is equivalent to
+// sending or sharing a `Vec<T>`.
+unsafe impl Send for Storage {}
+unsafe impl Sync for Storage {}
+
 impl core::fmt::Debug for Storage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Storage")
@@ -34,15 +47,17 @@ impl Storage {
     }
 
     pub fn with_capacity(desc: ComponentDesc, cap: usize) -> Self {
-        if cap == 0 {
+        // Zero sized allocations are not allowed, zero sized types are never allocated
+        if cap == 0 || desc.size() == 0 {
             let data = (desc.vtable.dangling)();
 
             assert_eq!(data.as_ptr() as usize % desc.layout().align(), 0);
             return Self {
                 data,
-                cap: 0,
+                cap,
                 len: 0,
                 desc,
+                pool: None,
             };
         }
 
@@ -60,6 +75,7 @@ impl Storage {
                 cap,
                 len: 0,
                 desc,
+                pool: None,
             }
         }
     }
@@ -80,10 +96,8 @@ impl Storage {
         //     self.desc().name()
         // );
 
-        let old_layout =
-            Layout::from_size_align(self.desc.size() * old_cap, self.desc.align()).unwrap();
-        let new_layout =
-            Layout::from_size_align(self.desc.size() * new_cap, self.desc.align()).unwrap();
+        let old_layout = self.layout(old_cap);
+        let new_layout = self.layout(new_cap);
 
         // Handle zst
         if new_layout.size() == 0 {
@@ -93,12 +107,25 @@ impl Storage {
 
         assert!(new_layout.size() < isize::MAX as usize);
 
-        let ptr = if old_cap == 0 {
+        let pooled = self.pool.as_ref().and_then(|v| v.take(new_layout));
+
+        let ptr = match pooled {
+            Some(ptr) if old_cap == 0 => ptr.as_ptr(),
+            Some(ptr) => unsafe {
+                core::ptr::copy_nonoverlapping(
+                    self.data.as_ptr(),
+                    ptr.as_ptr(),
+                    self.len * self.desc.size(),
+                );
+                self.free(old_layout);
+                ptr.as_ptr()
+            },
             // Old pointer is dangling
-            unsafe { alloc(new_layout) }
-        } else {
-            let ptr = self.data.as_ptr();
-            unsafe { realloc(ptr, old_layout, new_layout.size()) }
+            None if old_cap == 0 => unsafe { alloc(new_layout) },
+            None => {
+                let ptr = self.data.as_ptr();
+                unsafe { realloc(ptr, old_layout, new_layout.size()) }
+            }
         };
 
         let data = match NonNull::new(ptr) {
@@ -106,10 +133,30 @@ impl Storage {
             None => handle_alloc_error(new_layout),
         };
 
-        self.cap = new_cap;
+        debug_assert_eq!(
+            data.as_ptr() as usize % self.desc.align(),
+            0,
+            "Misaligned storage for {}",
+            self.desc.name()
+        );
+
+        // Pooled allocations fill the whole size class
+        self.cap = new_layout.size() / self.desc.size();
         self.data = data
     }
 
+    /// Returns the layout of the allocation for `cap` values.
+    ///
+    /// Pooled storages allocate whole size classes, see [`size_class`].
+    fn layout(&self, cap: usize) -> Layout {
+        let mut size = self.desc.size() * cap;
+        if self.pool.is_some() && size > 0 {
+            size = size_class(size);
+        }
+
+        Layout::from_size_align(size, self.desc.align()).unwrap()
+    }
+
     pub fn swap_remove(&mut self, slot: Slot, on_move: impl FnOnce(*mut u8)) {
         if slot >= self.len() {
             panic!("Index out of bounds")
@@ -169,8 +216,13 @@ impl Storage {
 
         // This is faster than copying everything over if there is no elements
         // in self
-        if self.len == 0 {
-            mem::swap(self, other);
+        //
+        // The allocations are interchangeable between pools, but pooled allocations are rounded
+        // to a size class and can as such not be exchanged with unpooled ones
+        if self.len == 0 && self.pool.is_some() == other.pool.is_some() {
+            mem::swap(&mut self.data, &mut other.data);
+            mem::swap(&mut self.len, &mut other.len);
+            mem::swap(&mut self.cap, &mut other.cap);
             return;
         }
 
@@ -254,6 +306,56 @@ impl Storage {
     pub(crate) fn desc(&self) -> ComponentDesc {
         self.desc
     }
+
+    pub(crate) fn pool(&self) -> Option<&Arc<StoragePool>> {
+        self.pool.as_ref()
+    }
+
+    /// Sets the pool which recycles the allocation.
+    ///
+    /// Must be set before the storage allocates, as pooled allocations are rounded to a size class
+    pub(crate) fn set_pool(&mut self, pool: Arc<StoragePool>) {
+        debug_assert!(self.cap == 0 || self.desc.size() == 0);
+        self.pool = Some(pool);
+    }
+
+    /// Records `count` values entering the world through this storage.
+    ///
+    /// Only tracked with the `leak-detection` feature.
+    #[inline]
+    pub(crate) fn record_constructed(&self, count: usize) {
+        #[cfg(feature = "leak-detection")]
+        if let Some(pool) = &self.pool {
+            pool.record_constructed(self.desc.key(), count)
+        }
+        #[cfg(not(feature = "leak-detection"))]
+        let _ = count;
+    }
+
+    /// Records `count` values being dropped or leaving the world through this storage.
+    ///
+    /// Only tracked with the `leak-detection` feature.
+    #[inline]
+    pub(crate) fn record_dropped(&self, count: usize) {
+        #[cfg(feature = "leak-detection")]
+        if let Some(pool) = &self.pool {
+            pool.record_dropped(self.desc.key(), count)
+        }
+        #[cfg(not(feature = "leak-detection"))]
+        let _ = count;
+    }
+
+    /// Frees the current allocation of `layout`, returning it to the pool if any
+    ///
+    /// # Safety
+    ///
+    /// The data must not be used afterwards
+    unsafe fn free(&mut self, layout: Layout) {
+        match &self.pool {
+            Some(pool) => pool.release(self.data, layout),
+            None => dealloc(self.data.as_ptr(), layout),
+        }
+    }
 }
 
 impl Drop for Storage {
@@ -265,12 +367,10 @@ impl Drop for Storage {
             return;
         }
 
-        let ptr = self.as_ptr();
-        let layout =
-            Layout::from_size_align(self.desc.size() * self.cap, self.desc.align()).unwrap();
+        let layout = self.layout(self.cap);
 
         unsafe {
-            dealloc(ptr, layout);
+            self.free(layout);
         }
     }
 }
@@ -1,11 +1,15 @@
 use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 
+use smallvec::SmallVec;
+
 use crate::{
-    archetype::{Archetype, ArchetypeId},
+    archetype::{Archetype, ArchetypeId, StoragePool},
     component::{dummy, ComponentDesc, ComponentKey},
     entity::{EntityKind, EntityStore, EntityStoreIter, EntityStoreIterMut},
-    events::EventSubscriber,
+    error::{Error, Result},
+    events::{EntityEvent, EntityEventKind, EventSubscriber},
     metadata::exclusive,
+    world::{EdgeCacheStats, PrunePolicy},
     Entity,
 };
 
@@ -17,7 +21,13 @@ pub(crate) struct Archetypes {
 
     // These trickle down to the archetypes
     subscribers: Vec<Arc<dyn EventSubscriber>>,
+    deferred: bool,
+    #[cfg(feature = "rayon")]
+    parallel_threshold: usize,
     pub(crate) index: ArchetypeIndex,
+    /// Recycles the storage allocations of pruned archetypes
+    pub(crate) pool: Arc<StoragePool>,
+    pub(crate) edge_stats: EdgeCacheStats,
 }
 
 impl Archetypes {
@@ -35,7 +45,12 @@ impl Archetypes {
             gen: 2,
             reserved,
             subscribers: Vec::new(),
+            deferred: false,
+            #[cfg(feature = "rayon")]
+            parallel_threshold: usize::MAX,
             index: ArchetypeIndex::new(),
+            pool: Arc::new(StoragePool::default()),
+            edge_stats: EdgeCacheStats::default(),
         }
     }
 
@@ -49,6 +64,17 @@ impl Archetypes {
         }
     }
 
+    pub fn try_get(&self, arch_id: ArchetypeId) -> Option<&Archetype> {
+        self.inner.get(arch_id)
+    }
+
+    /// Returns the archetype, or an error if it does not exist
+    pub fn get_checked(&self, arch_id: ArchetypeId) -> Result<&Archetype> {
+        self.inner
+            .get(arch_id)
+            .ok_or(Error::NoSuchArchetype(arch_id))
+    }
+
     #[track_caller]
     pub fn get_mut(&mut self, arch_id: ArchetypeId) -> &mut Archetype {
         let arch = self.inner.get_mut(arch_id).expect("Invalid archetype");
@@ -56,7 +82,7 @@ impl Archetypes {
         arch
     }
 
-    /// Prunes a leaf and its ancestors from empty archetypes
+    // Prunes a leaf and its ancestors from empty archetypes
     // pub(crate) fn prune_arch(&mut self, arch_id: ArchetypeId) -> usize {
     //     let arch = self.get(arch_id);
     //     if arch_id == self.root
@@ -81,11 +107,12 @@ impl Archetypes {
     //     count
     // }
 
-    /// Prunes a leaf and its ancestors from empty archetypes
-    pub(crate) fn prune_all(&mut self) -> usize {
+    /// Prunes empty archetypes according to `policy`
+    pub(crate) fn prune(&mut self, policy: PrunePolicy) -> usize {
         fn prune(
             archetypes: &EntityStore<Archetype>,
             id: ArchetypeId,
+            policy: PrunePolicy,
             res: &mut Vec<ArchetypeId>,
         ) -> bool {
             let arch = archetypes.get(id).unwrap();
@@ -93,10 +120,17 @@ impl Archetypes {
             // An archetype can be removed iff all its children are removed
             let mut pruned_children = true;
             for &id in arch.children.values() {
-                pruned_children = prune(archetypes, id, res) && pruned_children;
+                pruned_children = prune(archetypes, id, policy, res) && pruned_children;
             }
 
-            if pruned_children && arch.is_empty() {
+            let allowed = match policy {
+                PrunePolicy::Never => false,
+                PrunePolicy::Aggressive => true,
+                PrunePolicy::OnlyLeaves => arch.children.is_empty(),
+                PrunePolicy::SizeThreshold(max) => arch.capacity() <= max,
+            };
+
+            if allowed && pruned_children && arch.is_empty() {
                 res.push(id);
                 true
             } else {
@@ -104,9 +138,13 @@ impl Archetypes {
             }
         }
 
+        if policy == PrunePolicy::Never {
+            return 0;
+        }
+
         let mut to_remove = Vec::new();
         for &id in self.get(self.root()).children.values() {
-            prune(&self.inner, id, &mut to_remove);
+            prune(&self.inner, id, policy, &mut to_remove);
         }
 
         if to_remove.is_empty() {
@@ -173,6 +211,11 @@ impl Archetypes {
                         }
                     }
 
+                    new.set_deferred(self.deferred);
+                    #[cfg(feature = "rayon")]
+                    new.set_parallel_threshold(self.parallel_threshold);
+                    new.set_pool(&self.pool);
+
                     // Increase gen
                     self.gen = self.gen.wrapping_add(1);
                     let new_id = self.inner.spawn(new);
@@ -191,6 +234,50 @@ impl Archetypes {
         (cursor, self.inner.get_mut(cursor).unwrap())
     }
 
+    /// Returns the archetype which has the components of `src_id` except `removed`.
+    ///
+    /// Removes one component at a time by following the cached removal edges, and only searches
+    /// for the next archetype by its full component set on a miss, after which the edge is cached.
+    pub(crate) fn find_removed(
+        &mut self,
+        src_id: ArchetypeId,
+        removed: &[ComponentKey],
+    ) -> ArchetypeId {
+        let mut cursor = src_id;
+
+        for &key in removed {
+            let cur = self.get(cursor);
+            if !cur.has(key) {
+                continue;
+            }
+
+            if let Some(dst_id) = cur.incoming(key) {
+                self.edge_stats.hits += 1;
+                cursor = dst_id;
+                continue;
+            }
+
+            let mut components = cur
+                .components_desc()
+                .filter(|v| v.key != key)
+                .collect::<SmallVec<[_; 8]>>();
+
+            components.sort_unstable();
+
+            self.edge_stats.misses += 1;
+
+            let (dst_id, _) = self.find_create(components);
+
+            let (src, dst) = self.get_disjoint(cursor, dst_id).unwrap();
+            src.add_incoming(key, dst_id);
+            dst.add_outgoing(key, cursor);
+
+            cursor = dst_id;
+        }
+
+        cursor
+    }
+
     pub fn root(&self) -> ArchetypeId {
         self.root
     }
@@ -205,11 +292,11 @@ impl Archetypes {
         Some((a, b))
     }
 
-    pub fn iter(&self) -> EntityStoreIter<Archetype> {
+    pub fn iter(&self) -> EntityStoreIter<'_, Archetype> {
         self.inner.iter()
     }
 
-    pub fn iter_mut(&mut self) -> EntityStoreIterMut<Archetype> {
+    pub fn iter_mut(&mut self) -> EntityStoreIterMut<'_, Archetype> {
         self.inner.iter_mut()
     }
 
@@ -244,12 +331,73 @@ impl Archetypes {
         for (_, arch) in self.inner.iter_mut() {
             if subscriber.matches_arch(arch) {
                 arch.add_handler(subscriber.clone());
+                arch.set_deferred(self.deferred);
             }
         }
 
         self.subscribers.push(subscriber)
     }
 
+    /// Dispatches events in parallel to components with at least `threshold` subscribers
+    #[cfg(feature = "rayon")]
+    pub(crate) fn set_parallel_threshold(&mut self, threshold: usize) {
+        self.parallel_threshold = threshold;
+        for (_, arch) in self.inner.iter_mut() {
+            arch.set_parallel_threshold(threshold);
+        }
+    }
+
+    /// Defers added and modified events until [`Self::flush_deferred`]
+    pub(crate) fn defer_events(&mut self) {
+        self.deferred = true;
+        for (_, arch) in self.inner.iter_mut() {
+            arch.set_deferred(true);
+        }
+    }
+
+    /// Dispatches the events deferred since [`Self::defer_events`] which occurred after `since`
+    pub(crate) fn flush_deferred(&mut self, since: u32) {
+        self.deferred = false;
+        for (_, arch) in self.inner.iter_mut() {
+            arch.flush_deferred(since);
+        }
+    }
+
+    /// Notifies the subscribers matching `arch_id` that `id` was spawned or despawned
+    pub(crate) fn entity_event(&self, id: Entity, arch_id: ArchetypeId, kind: EntityEventKind) {
+        if self.subscribers.is_empty() {
+            return;
+        }
+
+        let arch = self.get(arch_id);
+        let event = EntityEvent {
+            id,
+            entity_kind: id.kind(),
+            arch_id,
+            kind,
+        };
+
+        for subscriber in &self.subscribers {
+            if subscriber.matches_arch(arch) {
+                subscriber.on_entity_event(&event);
+            }
+        }
+    }
+
+    /// Delivers the events held back by the subscribers
+    pub(crate) fn flush_subscribers(&self) {
+        for subscriber in &self.subscribers {
+            subscriber.flush();
+        }
+    }
+
+    /// Discards the changes of all archetypes which occurred at or before `tick`
+    pub(crate) fn discard_changes(&mut self, tick: u32) {
+        for (_, arch) in self.inner.iter_mut() {
+            arch.discard_changes(tick);
+        }
+    }
+
     pub(crate) fn gen(&self) -> u32 {
         self.gen
     }
@@ -0,0 +1,254 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{self, Debug};
+
+/// A variable length byte payload, such as script defined data described by an external schema.
+///
+/// Blobs are stored in a per-archetype slab, where each value is an offset into the slab rather
+/// than a separate allocation for each entity. A newly created blob holds its own allocation
+/// until it is moved into the slab of its archetype by [`World::compact_blobs`] or
+/// [`World::maintain`].
+///
+/// The bytes of a blob component are accessed through [`World::get_blob`] and
+/// [`World::update_blob`]. A blob can not be borrowed mutably, such as through
+/// [`World::get_mut`] or [`Component::as_mut`], as a blob moved out of the archetype through a
+/// mutable reference would still refer to the slab. Replace the value through [`World::set`]
+/// instead, which like removing the component returns the old blob with its own allocation.
+///
+/// ```rust
+/// # use flax::*;
+/// component! {
+///     script_data: Blob,
+/// }
+///
+/// let mut world = World::new();
+/// let id = Entity::builder()
+///     .set(script_data(), Blob::new([1, 2, 3]))
+///     .spawn(&mut world);
+///
+/// world.compact_blobs();
+///
+/// world.update_blob(id, script_data(), |v| v[0] = 4).unwrap();
+/// assert_eq!(&*world.get_blob(id, script_data()).unwrap(), &[4, 2, 3]);
+/// ```
+///
+/// [`World::compact_blobs`]: crate::World::compact_blobs
+/// [`World::maintain`]: crate::World::maintain
+/// [`World::get_blob`]: crate::World::get_blob
+/// [`World::update_blob`]: crate::World::update_blob
+/// [`World::get_mut`]: crate::World::get_mut
+/// [`World::set`]: crate::World::set
+/// [`Component::as_mut`]: crate::Component::as_mut
+pub struct Blob {
+    repr: Repr,
+}
+
+enum Repr {
+    Owned(Box<[u8]>),
+    Slab { offset: usize, len: usize },
+}
+
+impl Blob {
+    /// Creates a new blob from the given bytes
+    pub fn new(bytes: impl Into<Box<[u8]>>) -> Self {
+        Self {
+            repr: Repr::Owned(bytes.into()),
+        }
+    }
+
+    /// Returns the length of the payload in bytes
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Owned(v) => v.len(),
+            Repr::Slab { len, .. } => *len,
+        }
+    }
+
+    /// Returns true if the payload is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn bytes<'a>(&'a self, slab: &'a BlobSlab) -> &'a [u8] {
+        match &self.repr {
+            Repr::Owned(v) => v,
+            &Repr::Slab { offset, len } => &slab.bytes[offset..offset + len],
+        }
+    }
+
+    pub(crate) fn bytes_mut<'a>(&'a mut self, slab: &'a mut BlobSlab) -> &'a mut [u8] {
+        match &mut self.repr {
+            Repr::Owned(v) => v,
+            &mut Repr::Slab { offset, len } => &mut slab.bytes[offset..offset + len],
+        }
+    }
+
+    /// Copies the payload from `src` into `dst`, used when the blob moves to another archetype
+    pub(crate) fn rebase(&mut self, src: &BlobSlab, dst: &mut BlobSlab) {
+        if let Repr::Slab { offset, len } = self.repr {
+            self.repr = Repr::Slab {
+                offset: dst.push(&src.bytes[offset..offset + len]),
+                len,
+            }
+        }
+    }
+
+    /// Gives the blob its own allocation, used when the blob leaves the archetype
+    pub(crate) fn detach(&mut self, slab: &BlobSlab) {
+        if let Repr::Slab { offset, len } = self.repr {
+            self.repr = Repr::Owned(slab.bytes[offset..offset + len].into())
+        }
+    }
+}
+
+impl Debug for Blob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blob").field("len", &self.len()).finish()
+    }
+}
+
+/// Contiguous storage for the blobs of a component in an archetype.
+///
+/// Replaced and removed blobs leave their bytes behind until the slab is compacted.
+#[derive(Default)]
+pub(crate) struct BlobSlab {
+    bytes: Vec<u8>,
+}
+
+impl BlobSlab {
+    /// Appends `bytes`, returning their offset
+    fn push(&mut self, bytes: &[u8]) -> usize {
+        let offset = self.bytes.len();
+        self.bytes.extend_from_slice(bytes);
+        offset
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.bytes.clear()
+    }
+
+    /// Moves all blobs into a new slab which only contains the bytes of `blobs`.
+    ///
+    /// Returns the number of bytes reclaimed.
+    pub(crate) fn compact(&mut self, blobs: &mut [Blob]) -> usize {
+        let mut bytes = Vec::with_capacity(blobs.iter().map(|v| v.len()).sum());
+
+        for blob in blobs {
+            let offset = bytes.len();
+            bytes.extend_from_slice(blob.bytes(self));
+            blob.repr = Repr::Slab {
+                offset,
+                len: blob.len(),
+            };
+        }
+
+        let reclaimed = self.bytes.len().saturating_sub(bytes.len());
+        self.bytes = bytes;
+        reclaimed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem;
+
+    use crate::{error::Error, Entity, Query, World};
+
+    use super::*;
+
+    component! {
+        data: Blob,
+        tag: (),
+    }
+
+    #[test]
+    fn blob_storage() {
+        let mut world = World::new();
+
+        let ids = (0..4u8)
+            .map(|i| {
+                Entity::builder()
+                    .set(data(), Blob::new(alloc::vec![i; i as usize + 1]))
+                    .spawn(&mut world)
+            })
+            .collect::<Vec<_>>();
+
+        // Owned blobs are moved into the slab
+        assert_eq!(world.compact_blobs(), 0);
+        assert_eq!(&*world.get_blob(ids[2], data()).unwrap(), &[2, 2, 2]);
+
+        // Moving to another archetype copies the bytes to the new slab
+        world.set(ids[1], tag(), ()).unwrap();
+        world.update_blob(ids[1], data(), |v| v[0] = 5).unwrap();
+        assert_eq!(&*world.get_blob(ids[1], data()).unwrap(), &[5, 1]);
+        assert_eq!(&*world.get_blob(ids[3], data()).unwrap(), &[3; 4]);
+
+        // The removed value keeps its bytes
+        let removed = world.remove(ids[1], data()).unwrap();
+        assert_eq!(removed.len(), 2);
+        world.set(ids[0], data(), removed).unwrap();
+        assert_eq!(&*world.get_blob(ids[0], data()).unwrap(), &[5, 1]);
+
+        // The bytes left behind by the moved and replaced blobs are reclaimed
+        assert_eq!(world.compact_blobs(), 3);
+        assert_eq!(&*world.get_blob(ids[0], data()).unwrap(), &[5, 1]);
+        assert_eq!(&*world.get_blob(ids[2], data()).unwrap(), &[2, 2, 2]);
+        assert_eq!(&*world.get_blob(ids[3], data()).unwrap(), &[3; 4]);
+    }
+
+    #[test]
+    fn replace_compacted() {
+        let mut world = World::new();
+
+        let a = Entity::builder()
+            .set(data(), Blob::new([1, 2]))
+            .spawn(&mut world);
+        let b = Entity::builder()
+            .set(data(), Blob::new([3; 8]))
+            .tag(tag())
+            .spawn(&mut world);
+
+        world.compact_blobs();
+
+        // The replaced value holds its own allocation, and can be moved to another archetype
+        let old = world.set(b, data(), Blob::new([4])).unwrap().unwrap();
+        world.set(a, data(), old).unwrap();
+
+        assert_eq!(&*world.get_blob(a, data()).unwrap(), &[3; 8]);
+        assert_eq!(&*world.get_blob(b, data()).unwrap(), &[4]);
+
+        world.compact_blobs();
+        assert_eq!(&*world.get_blob(a, data()).unwrap(), &[3; 8]);
+        assert_eq!(&*world.get_blob(b, data()).unwrap(), &[4]);
+    }
+
+    #[test]
+    fn mutable_access() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(data(), Blob::new([1, 2]))
+            .spawn(&mut world);
+
+        world.compact_blobs();
+
+        assert_eq!(
+            world.get_mut(id, data()).err(),
+            Some(Error::ImmutableComponent(data().desc()))
+        );
+        assert_eq!(
+            world
+                .update(id, data(), |v| mem::replace(v, Blob::new([])))
+                .err(),
+            Some(Error::ImmutableComponent(data().desc()))
+        );
+
+        world.update_blob(id, data(), |v| v[1] = 3).unwrap();
+        assert_eq!(&*world.get_blob(id, data()).unwrap(), &[1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be accessed mutably")]
+    fn mutable_query() {
+        Query::new(data().as_mut());
+    }
+}
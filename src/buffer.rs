@@ -322,7 +322,7 @@ impl ComponentBuffer {
     /// The returned pointers must be manually dropped
     /// If the returned iterator is dropped before being fully consumed, the
     /// remaining values will be safely dropped.
-    pub(crate) fn drain(&mut self) -> ComponentBufferIter {
+    pub(crate) fn drain(&mut self) -> ComponentBufferIter<'_> {
         ComponentBufferIter {
             entries: &mut self.entries,
             storage: &mut self.storage,
@@ -342,6 +342,18 @@ impl ComponentBuffer {
         self.entries.is_empty()
     }
 
+    /// Removes and drops all components in the buffer
+    pub fn clear(&mut self) {
+        for (_, (desc, offset)) in mem::take(&mut self.entries) {
+            unsafe {
+                let ptr = self.storage.at_mut(offset);
+                desc.drop(ptr);
+            }
+        }
+
+        self.storage.reset();
+    }
+
     /// Retains only the components specified by the predicate
     /// If the closure returns true the element is removed and **not** dropped at the end of
     /// collection
@@ -408,6 +420,26 @@ impl MultiComponentBuffer {
         self.storage.at_mut(offset)
     }
 
+    /// Moves the value at `offset` in `other` into this buffer
+    ///
+    /// # Safety
+    /// The value at `offset` must be of the type of `desc`
+    pub unsafe fn move_from(
+        &mut self,
+        other: &mut Self,
+        desc: ComponentDesc,
+        offset: Offset,
+    ) -> Offset {
+        let drop = other.drops.remove(&offset).unwrap();
+        let src = other.storage.at_mut(offset);
+
+        let dst = self.storage.allocate(desc.layout());
+        self.storage.write_dyn(dst, desc, src);
+        self.drops.insert(dst, drop);
+
+        dst
+    }
+
     pub fn clear(&mut self) {
         for (&offset, drop) in &mut self.drops {
             unsafe {
@@ -436,8 +468,6 @@ mod tests {
 
     use alloc::{string::String, sync::Arc};
 
-    use crate::component;
-
     use super::*;
     component! {
         a: i32,
@@ -483,6 +513,21 @@ mod tests {
         assert_eq!(Arc::strong_count(&shared_2), 2);
     }
 
+    #[test]
+    pub fn component_buffer_clear() {
+        let mut buffer = ComponentBuffer::new();
+
+        let shared: Arc<String> = Arc::new("abc".into());
+        buffer.set(f(), shared.clone());
+        buffer.clear();
+
+        assert!(buffer.is_empty());
+        assert_eq!(Arc::strong_count(&shared), 1);
+
+        buffer.set(f(), shared.clone());
+        assert_eq!(buffer.get(f()), Some(&shared));
+    }
+
     #[test]
     pub fn component_buffer_reinsert_dyn() {
         let mut buffer = ComponentBuffer::new();
@@ -1,13 +1,21 @@
 use core::fmt;
 
-use alloc::{boxed::Box, format, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    vec::Vec,
+};
 use anyhow::Context;
+use itertools::Itertools;
 
 use crate::{
     buffer::MultiComponentBuffer,
-    component::{ComponentDesc, ComponentValue},
+    component::{ComponentDesc, ComponentKey, ComponentValue},
+    error::{MissingComponent, Result},
+    metadata::exclusive,
     writer::{MissingDyn, SingleComponentWriter, WriteDedupDyn},
-    BatchSpawn, Component, Entity, EntityBuilder, World,
+    BatchSpawn, Component, Entity, EntityBuilder, Error, World,
 };
 
 type DeferFn = Box<dyn Fn(&mut World) -> anyhow::Result<()> + Send + Sync>;
@@ -99,6 +107,97 @@ impl fmt::Debug for Command {
     }
 }
 
+/// A recorded command of a [`CommandBuffer`], as returned by [`CommandBuffer::iter`]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum PendingCommand<'a> {
+    /// Spawn a new entity
+    Spawn(&'a EntityBuilder),
+    /// Spawn an entity at a specific id
+    SpawnAt(Entity, &'a EntityBuilder),
+    /// Append components to an existing entity
+    AppendTo(Entity, &'a EntityBuilder),
+    /// Spawn a batch of entities with the same components
+    SpawnBatch(&'a BatchSpawn),
+    /// Spawn a batch of entities at specific ids
+    SpawnBatchAt(&'a [Entity], &'a BatchSpawn),
+    /// Set a component for an entity
+    Set {
+        /// The target entity
+        id: Entity,
+        /// The component to set
+        desc: ComponentDesc,
+    },
+    /// Set a component for an entity, unless the value is unchanged
+    SetDedup {
+        /// The target entity
+        id: Entity,
+        /// The component to set
+        desc: ComponentDesc,
+    },
+    /// Set a component for an entity if it does not already exist
+    SetMissing {
+        /// The target entity
+        id: Entity,
+        /// The component to set
+        desc: ComponentDesc,
+    },
+    /// Despawn an entity
+    Despawn(Entity),
+    /// Remove a component from an entity
+    Remove {
+        /// The target entity
+        id: Entity,
+        /// The component to remove
+        desc: ComponentDesc,
+    },
+    /// Execute an arbitrary function with a mutable reference to the world
+    Defer,
+}
+
+impl<'a> PendingCommand<'a> {
+    /// Returns the single entity the command targets, if any
+    pub fn entity(&self) -> Option<Entity> {
+        match *self {
+            Self::SpawnAt(id, _)
+            | Self::AppendTo(id, _)
+            | Self::Set { id, .. }
+            | Self::SetDedup { id, .. }
+            | Self::SetMissing { id, .. }
+            | Self::Despawn(id)
+            | Self::Remove { id, .. } => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Returns the component the command sets or removes, if any
+    pub fn component(&self) -> Option<ComponentDesc> {
+        match *self {
+            Self::Set { desc, .. }
+            | Self::SetDedup { desc, .. }
+            | Self::SetMissing { desc, .. }
+            | Self::Remove { desc, .. } => Some(desc),
+            _ => None,
+        }
+    }
+
+    fn new(cmd: &'a Command) -> Self {
+        match *cmd {
+            Command::Spawn(ref entity) => Self::Spawn(entity),
+            Command::AppendTo(ref entity, id) => Self::AppendTo(id, entity),
+            Command::SpawnAt(ref entity, id) => Self::SpawnAt(id, entity),
+            Command::SpawnBatch(ref batch) => Self::SpawnBatch(batch),
+            Command::SpawnBatchAt(ref batch, ref ids) => Self::SpawnBatchAt(ids, batch),
+            Command::Set { id, desc, .. } => Self::Set { id, desc },
+            Command::SetDedup { id, desc, .. } => Self::SetDedup { id, desc },
+            Command::SetMissing { id, desc, .. } => Self::SetMissing { id, desc },
+            Command::Despawn(id) => Self::Despawn(id),
+            Command::Remove { id, desc } => Self::Remove { id, desc },
+            Command::Defer(_) => Self::Defer,
+        }
+    }
+}
+
 /// Records commands into the world.
 /// Allows insertion and removal of components when the world is not available
 /// mutably, such as in systems or during iteration.
@@ -264,6 +363,72 @@ impl CommandBuffer {
         self
     }
 
+    /// Moves all commands of `other` to the end of this buffer, leaving `other` empty.
+    ///
+    /// This allows command buffers recorded in parallel, such as by the jobs of a fork-join job
+    /// system, to be merged and applied at once. The commands are applied in the order of the
+    /// appended buffers.
+    ///
+    /// Ids for entities spawned by different buffers should be acquired through
+    /// [`World::reserve_one`], which is thread safe and guarantees that the ids stay unique after
+    /// merging.
+    pub fn append(&mut self, other: &mut Self) -> &mut Self {
+        for mut cmd in other.commands.drain(..) {
+            if let Command::Set { desc, offset, .. }
+            | Command::SetDedup { desc, offset, .. }
+            | Command::SetMissing { desc, offset, .. } = &mut cmd
+            {
+                // Safety: the value at `offset` was pushed with the type of `desc`
+                *offset = unsafe { self.inserts.move_from(&mut other.inserts, *desc, *offset) };
+            }
+
+            self.commands.push(cmd);
+        }
+
+        other.inserts.clear();
+        self
+    }
+
+    /// Iterates the recorded commands in the order they will be applied
+    pub fn iter(&self) -> impl Iterator<Item = PendingCommand<'_>> {
+        self.commands.iter().map(PendingCommand::new)
+    }
+
+    /// Returns the number of recorded commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if no commands are recorded
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Checks which commands would fail if the buffer was applied to `world`, without modifying
+    /// it.
+    ///
+    /// Returns the index of each failing command, see [`Self::iter`], along with the error it
+    /// would cause. Commands are validated against the state of the world after the preceding
+    /// commands, where failing commands are treated as not applied.
+    ///
+    /// Setting an [`Exclusive`](crate::Exclusive) relation to an entity which already has the
+    /// relation to another target is reported as [`Error::ExclusiveRelation`], even though it does
+    /// not fail.
+    ///
+    /// **Note**: deferred functions are not executed, and their effects are not accounted for.
+    pub fn validate(&self, world: &World) -> Vec<(usize, Error)> {
+        let mut dry_run = DryRun {
+            world,
+            entities: BTreeMap::new(),
+        };
+
+        self.commands
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cmd)| dry_run.apply(cmd).err().map(|err| (i, err)))
+            .collect()
+    }
+
     /// Applies all contents of the command buffer to the world.
     /// The commandbuffer is cleared and can be reused.
     pub fn apply(&mut self, world: &mut World) -> anyhow::Result<()> {
@@ -349,9 +514,143 @@ impl CommandBuffer {
     }
 }
 
+/// The simulated state of an entity during a dry run
+struct Simulated {
+    /// The entity is reserved, but not yet spawned
+    reserved: bool,
+    components: BTreeSet<ComponentKey>,
+}
+
+/// Tracks the effects of commands on the entities they touch without modifying the world
+struct DryRun<'w> {
+    world: &'w World,
+    /// `None` for entities which do not exist
+    entities: BTreeMap<Entity, Option<Simulated>>,
+}
+
+impl<'w> DryRun<'w> {
+    fn get(&mut self, id: Entity) -> Option<&mut Simulated> {
+        let world = self.world;
+        self.entities
+            .entry(id)
+            .or_insert_with(|| {
+                let loc = world.location(id).ok()?;
+                let arch = world.archetypes.get(loc.arch_id);
+                Some(Simulated {
+                    reserved: loc.arch_id == world.archetypes.reserved,
+                    components: arch.components().keys().copied().collect(),
+                })
+            })
+            .as_mut()
+    }
+
+    fn get_alive(&mut self, id: Entity) -> Result<&mut BTreeSet<ComponentKey>> {
+        self.get(id)
+            .map(|v| &mut v.components)
+            .ok_or(Error::NoSuchEntity(id))
+    }
+
+    fn spawn_at(
+        &mut self,
+        id: Entity,
+        components: impl Iterator<Item = ComponentKey>,
+    ) -> Result<()> {
+        if self.get(id).is_some_and(|v| !v.reserved) {
+            return Err(Error::EntityOccupied(id));
+        }
+
+        self.entities.insert(
+            id,
+            Some(Simulated {
+                reserved: false,
+                components: components.collect(),
+            }),
+        );
+
+        Ok(())
+    }
+
+    /// Adds the components to an existing entity, replacing exclusive relations
+    fn insert(&mut self, id: Entity, components: &[ComponentDesc]) -> Result<()> {
+        let existing = self.get_alive(id)?;
+
+        let mut result = Ok(());
+        for desc in components {
+            let key = desc.key();
+            if key.is_relation() && desc.meta_ref().has(exclusive()) {
+                let start = ComponentKey::new(key.id(), Some(Entity::MIN));
+                let end = ComponentKey::new(key.id(), Some(Entity::MAX));
+
+                let replaced = existing
+                    .range(start..=end)
+                    .copied()
+                    .filter(|&v| v != key)
+                    .collect_vec();
+
+                for other in replaced {
+                    existing.remove(&other);
+                    if result.is_ok() {
+                        result = Err(Error::ExclusiveRelation(
+                            id,
+                            ComponentDesc {
+                                key: other,
+                                ..*desc
+                            },
+                        ));
+                    }
+                }
+            }
+
+            existing.insert(key);
+        }
+
+        result
+    }
+
+    fn apply(&mut self, cmd: &Command) -> Result<()> {
+        match cmd {
+            Command::Spawn(entity) => entity.validate(self.world, None),
+            Command::SpawnAt(entity, id) => {
+                entity.validate(self.world, None)?;
+                self.spawn_at(*id, entity.components().map(|v| v.key()))
+            }
+            Command::AppendTo(entity, id) => {
+                if self.world.is_alive(*id) {
+                    entity.validate(self.world, Some(*id))?;
+                }
+
+                self.insert(*id, &entity.components().copied().collect_vec())
+            }
+            Command::SpawnBatch(_) => Ok(()),
+            Command::SpawnBatchAt(batch, ids) => ids
+                .iter()
+                .try_for_each(|&id| self.spawn_at(id, batch.components().map(|v| v.key()))),
+            Command::Set { id, desc, .. }
+            | Command::SetDedup { id, desc, .. }
+            | Command::SetMissing { id, desc, .. } => self.insert(*id, &[*desc]),
+            Command::Despawn(id) => {
+                self.get_alive(*id)?;
+                self.entities.insert(*id, None);
+                Ok(())
+            }
+            Command::Remove { id, desc } => {
+                if self.get_alive(*id)?.remove(&desc.key()) {
+                    Ok(())
+                } else {
+                    Err(Error::MissingComponent(MissingComponent {
+                        id: *id,
+                        desc: *desc,
+                    }))
+                }
+            }
+            Command::Defer(_) => Ok(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{component, FetchExt, Query};
+    use crate::{FetchExt, Query};
 
     use super::*;
 
@@ -422,4 +721,121 @@ mod tests {
         cmd.apply(&mut world).unwrap();
         assert_eq!(query.collect_vec(&world), [(false, "Baz".to_string())]);
     }
+
+    #[test]
+    fn append() {
+        use alloc::string::{String, ToString};
+
+        use crate::{entity::EntityKind, Entity};
+
+        component! {
+            a: String,
+            b: i32,
+        }
+
+        let mut world = World::new();
+
+        let id = world.reserve_one(EntityKind::empty());
+        let other = Entity::builder().set(b(), 0).spawn(&mut world);
+
+        let mut cmd = CommandBuffer::new();
+        let mut cmd2 = CommandBuffer::new();
+
+        cmd.spawn_at(id, EntityBuilder::new().set(b(), 1))
+            .set(other, a(), "first".into());
+
+        cmd2.set(id, a(), "spawned".into())
+            .set(other, a(), "second".into())
+            .set_dedup(other, b(), 2);
+
+        cmd.append(&mut cmd2);
+        assert!(cmd2.commands.is_empty());
+
+        cmd.apply(&mut world).unwrap();
+
+        assert_eq!(world.get(id, b()).as_deref(), Ok(&1));
+        assert_eq!(world.get(id, a()).as_deref(), Ok(&"spawned".to_string()));
+        assert_eq!(world.get(other, a()).as_deref(), Ok(&"second".to_string()));
+        assert_eq!(world.get(other, b()).as_deref(), Ok(&2));
+
+        // The appended buffer can be reused
+        cmd2.set(other, b(), 3);
+        cmd2.apply(&mut world).unwrap();
+        assert_eq!(world.get(other, b()).as_deref(), Ok(&3));
+    }
+
+    #[test]
+    fn validate() {
+        use alloc::vec;
+
+        use crate::{components::name, entity::EntityKind, metadata::Exclusive};
+
+        component! {
+            a: i32,
+            parent(id): () => [ Exclusive ],
+        }
+
+        let mut world = World::new();
+
+        let x = Entity::builder().set(a(), 1).spawn(&mut world);
+        let y = Entity::builder().spawn(&mut world);
+        let z = Entity::builder().set(parent(x), ()).spawn(&mut world);
+        let dead = Entity::builder().spawn(&mut world);
+        world.despawn(dead).unwrap();
+        let reserved = world.reserve_one(EntityKind::empty());
+
+        let mut cmd = CommandBuffer::new();
+        cmd.set(x, name(), "x".into())
+            .remove(y, a())
+            .set(dead, a(), 5)
+            .spawn_at(y, EntityBuilder::new())
+            .spawn_at(reserved, EntityBuilder::new().set(a(), 2))
+            .remove(reserved, a())
+            .despawn(x)
+            .set(x, a(), 3)
+            .set(z, parent(y), ())
+            .defer(|_| Ok(()));
+
+        assert_eq!(cmd.len(), 10);
+        assert_eq!(
+            cmd.iter()
+                .map(|v| (v.entity(), v.component()))
+                .collect_vec(),
+            [
+                (Some(x), Some(name().desc())),
+                (Some(y), Some(a().desc())),
+                (Some(dead), Some(a().desc())),
+                (Some(y), None),
+                (Some(reserved), None),
+                (Some(reserved), Some(a().desc())),
+                (Some(x), None),
+                (Some(x), Some(a().desc())),
+                (Some(z), Some(parent(y).desc())),
+                (None, None),
+            ]
+        );
+
+        assert_eq!(
+            cmd.validate(&world),
+            vec![
+                (
+                    1,
+                    Error::MissingComponent(MissingComponent {
+                        id: y,
+                        desc: a().desc()
+                    })
+                ),
+                (2, Error::NoSuchEntity(dead)),
+                (3, Error::EntityOccupied(y)),
+                (7, Error::NoSuchEntity(x)),
+                (8, Error::ExclusiveRelation(z, parent(x).desc())),
+            ]
+        );
+
+        // Validation does not modify the world
+        assert!(world.is_alive(x));
+        assert!(!world.has(x, name()));
+
+        assert!(cmd.apply(&mut world).is_err());
+    }
 }
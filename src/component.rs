@@ -19,11 +19,11 @@ use crate::{
     buffer::ComponentBuffer,
     entity::EntityKind,
     fetch::MaybeMut,
-    filter::{ChangeFilter, With, WithRelation, Without, WithoutRelation},
-    metadata::Metadata,
+    filter::{ChangeFilter, Unchanged, With, WithRelation, Without, WithoutRelation},
+    metadata::{is_immutable, Metadata},
     relation::RelationExt,
     vtable::{ComponentVTable, UntypedVTable},
-    Entity, Mutable,
+    Blob, Entity, Mutable,
 };
 
 /// Trait alias for a 'static + Send + Sync type which can be used as a
@@ -248,13 +248,34 @@ impl<T: ComponentValue> Component<T> {
     }
 
     /// Transform this into a mutable fetch
-    pub const fn as_mut(self) -> Mutable<T> {
+    ///
+    /// # Panics
+    /// If the component is declared [`Immutable`](crate::metadata::Immutable) or is a [`Blob`]
+    pub fn as_mut(self) -> Mutable<T> {
+        self.assert_mutable();
         Mutable(self)
     }
 
     /// Transform this into a (maybe) mutable fetch
+    ///
+    /// # Panics
+    /// If the component is declared [`Immutable`](crate::metadata::Immutable) or is a [`Blob`]
     pub fn maybe_mut(self) -> MaybeMut<T> {
-        MaybeMut(self)
+        self.assert_mutable();
+        MaybeMut(self, None)
+    }
+
+    fn assert_mutable(self) {
+        assert!(
+            !is_immutable(&self.desc()),
+            "The component {} is declared `Immutable` and can not be accessed mutably",
+            self.name()
+        );
+        assert!(
+            !self.desc().is::<Blob>(),
+            "The blob component {} can not be accessed mutably, use `World::update_blob` instead",
+            self.name()
+        );
     }
 
     /// Construct a fine grained change detection filter.
@@ -264,6 +285,21 @@ impl<T: ComponentValue> Component<T> {
         ChangeFilter::new(self, kind)
     }
 
+    /// Construct a filter yielding entities whose component has not been added or modified since
+    /// the last time the query ran.
+    pub fn unchanged(self) -> Unchanged<T> {
+        Unchanged::new(self, None)
+    }
+
+    /// Construct a filter yielding entities whose component has not been added or modified after
+    /// the change `tick`.
+    ///
+    /// For example, `static_since(world.change_tick() - n)` yields the entities which have not
+    /// been changed during the last `n` ticks.
+    pub fn static_since(self, tick: u32) -> Unchanged<T> {
+        Unchanged::new(self, Some(tick))
+    }
+
     /// Construct a new filter yielding entities without this component.
     pub fn without(self) -> Without {
         Without {
@@ -397,8 +433,9 @@ impl ComponentDesc {
         (self.vtable.type_id)() == TypeId::of::<T>()
     }
 
+    /// Returns the size of the component type in bytes
     #[inline]
-    pub(crate) fn size(&self) -> usize {
+    pub fn size(&self) -> usize {
         self.vtable.layout.size()
     }
 
@@ -414,8 +451,12 @@ impl ComponentDesc {
         self.key
     }
 
+    /// Returns the alignment of the component type in bytes.
+    ///
+    /// Every value in the archetype storages is aligned to at least this, including for
+    /// over-aligned types such as SIMD vectors.
     #[inline]
-    pub(crate) fn align(&self) -> usize {
+    pub fn align(&self) -> usize {
         self.vtable.layout.align()
     }
 
@@ -457,7 +498,6 @@ impl ComponentDesc {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
 
     component! {
         foo: i32,
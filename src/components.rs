@@ -3,10 +3,10 @@
 
 use alloc::string::String;
 
-use crate::component;
 use crate::Exclusive;
 
 use crate::component::ComponentDesc;
+use crate::metadata::Cloneable;
 use crate::Debuggable;
 
 component! {
@@ -14,12 +14,12 @@ component! {
     /// kind of component.
     ///
     /// This name will be used in *Display* and *Debug* impls of entities to make them more readable, as opposed to just the id.
-    pub name: String => [ Debuggable ],
+    pub name: String => [ Debuggable, Cloneable ],
     /// Exclusive parent-child relation ship.
     ///
     /// Only one parent can exist for an entity. Adding a second relationship will override the
     /// existing one, effectively moving the subtree.
-    pub child_of(parent): () => [ Debuggable, Exclusive ],
+    pub child_of(parent): () => [ Debuggable, Exclusive, Cloneable ],
 
     /// Contains type erased metadata.
     ///
@@ -29,4 +29,14 @@ component! {
 
     /// Added automatically to all STATIC entities
     pub is_static: () => [ Debuggable ],
+
+    /// Marks an entity as disabled.
+    ///
+    /// Disabled entities are skipped by queries unless
+    /// [`Query::include_disabled`](crate::Query::include_disabled) is used.
+    ///
+    /// This also applies to queries which mention `disabled` themselves, such as
+    /// `Query::new(entity_ids()).with(disabled())`, which match nothing without
+    /// `include_disabled`.
+    pub disabled: () => [ Debuggable, Cloneable ],
 }
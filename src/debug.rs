@@ -0,0 +1,137 @@
+//! Utilities for inspecting the state of a world.
+use core::fmt::Write;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{component::ComponentValue, components::name, relation::RelationExt, Entity, World};
+
+/// Formats the graph induced by `relation` in the graphviz dot language.
+///
+/// Each entity with the relation has an edge to each of its targets, such as from a child to its
+/// parent for [`child_of`](crate::components::child_of). Entities are labeled by their
+/// [`name`] when present.
+///
+/// Targets which are no longer alive are drawn dashed, and entities which are part of a cycle are
+/// highlighted, as both indicate a corrupted hierarchy.
+pub fn relation_graph<T: ComponentValue>(world: &World, relation: impl RelationExt<T>) -> String {
+    let relation = relation.id();
+
+    let mut edges: BTreeMap<Entity, Vec<Entity>> = BTreeMap::new();
+    for (_, arch) in world.archetypes.iter() {
+        let targets = arch
+            .relations_like(relation)
+            .filter_map(|(key, _)| key.target())
+            .collect::<Vec<_>>();
+
+        if targets.is_empty() {
+            continue;
+        }
+
+        for &id in arch.entities() {
+            edges.entry(id).or_default().extend(&targets);
+        }
+    }
+
+    let nodes: BTreeSet<Entity> = edges
+        .iter()
+        .flat_map(|(&id, targets)| core::iter::once(id).chain(targets.iter().copied()))
+        .collect();
+
+    let cycles = find_cycles(&edges);
+
+    let mut s = String::new();
+    writeln!(s, "digraph relations {{").unwrap();
+
+    for &id in &nodes {
+        let label = match world.get(id, name()) {
+            Ok(name) => escape(&name),
+            Err(_) => id.to_string(),
+        };
+
+        write!(s, "    \"{id}\" [label=\"{label}\"").unwrap();
+        if !world.is_alive(id) {
+            write!(s, ", style=dashed").unwrap();
+        }
+        if cycles.contains(&id) {
+            write!(s, ", color=red").unwrap();
+        }
+        writeln!(s, "];").unwrap();
+    }
+
+    for (id, targets) in &edges {
+        for target in targets {
+            writeln!(s, "    \"{id}\" -> \"{target}\";").unwrap();
+        }
+    }
+
+    writeln!(s, "}}").unwrap();
+    s
+}
+
+/// Returns the entities which can reach themselves
+fn find_cycles(edges: &BTreeMap<Entity, Vec<Entity>>) -> BTreeSet<Entity> {
+    edges
+        .keys()
+        .copied()
+        .filter(|&start| {
+            let mut visited = BTreeSet::new();
+            let mut stack = edges[&start].clone();
+
+            while let Some(id) = stack.pop() {
+                if id == start {
+                    return true;
+                }
+
+                if visited.insert(id) {
+                    stack.extend(edges.get(&id).into_iter().flatten());
+                }
+            }
+
+            false
+        })
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+
+    use crate::components::child_of;
+
+    use super::*;
+
+    #[test]
+    fn relation_graph() {
+        let mut world = World::new();
+
+        let root = Entity::builder()
+            .set(name(), "root".into())
+            .spawn(&mut world);
+
+        let child = Entity::builder()
+            .set(name(), "child".into())
+            .set(child_of(root), ())
+            .spawn(&mut world);
+
+        let a = world.spawn();
+        let b = Entity::builder().set(child_of(a), ()).spawn(&mut world);
+        world.set(a, child_of(b), ()).unwrap();
+
+        let graph = super::relation_graph(&world, child_of);
+
+        assert!(graph.contains(&format!("\"{root}\" [label=\"root\"];")));
+        assert!(graph.contains(&format!("\"{child}\" -> \"{root}\";")));
+        assert!(graph.contains(&format!("\"{a}\" [label=\"{a}\", color=red];")));
+        assert!(graph.contains(&format!("\"{b}\" -> \"{a}\";")));
+        assert!(graph.contains(&format!("\"{a}\" -> \"{b}\";")));
+        assert!(!graph.contains(&format!("\"{child}\" [label=\"child\", color=red]")));
+    }
+}
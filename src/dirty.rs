@@ -0,0 +1,282 @@
+//! Partial uploads of component columns to packed buffers.
+//!
+//! [`DirtyRanges`] visits the slots of a component column which changed since the previous
+//! update, archetype by archetype, and maps them to byte ranges of a buffer holding the column's
+//! values tightly packed in slot order. This allows a renderer to keep one GPU buffer per archetype
+//! and issue partial writes for only the modified entities each frame.
+//!
+//! Besides the modified and added slots reported by [`World::changes`], slots which received a
+//! different entity since the previous update, such as when an entity is swapped in to fill the
+//! hole of a removed one, are reported as well.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::mem;
+
+use crate::{
+    archetype::{ArchetypeId, ChangeKind, Slice},
+    component::ComponentValue,
+    Component, Entity, World,
+};
+
+/// A range of bytes in a packed buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteRange {
+    /// The offset of the first byte
+    pub offset: usize,
+    /// The number of bytes
+    pub len: usize,
+}
+
+impl ByteRange {
+    /// Returns the byte range of `slots` in a buffer where each element occupies `stride` bytes
+    pub fn from_slots(slots: Slice, stride: usize) -> Self {
+        Self {
+            offset: slots.start * stride,
+            len: slots.len() * stride,
+        }
+    }
+
+    /// Returns the offset one past the last byte
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+}
+
+/// The dirty slots of a component column in a single archetype
+pub struct DirtyColumn<'a, T> {
+    /// The archetype containing the column
+    pub arch_id: ArchetypeId,
+    /// The values of the whole column, in slot order
+    pub values: &'a [T],
+    /// The dirty slots in ascending order, without overlaps
+    pub slots: &'a [Slice],
+}
+
+impl<'a, T> DirtyColumn<'a, T> {
+    /// Returns the byte ranges of the dirty slots in a buffer of tightly packed `T`
+    pub fn byte_ranges(&self) -> impl Iterator<Item = ByteRange> + 'a {
+        self.byte_ranges_with_stride(mem::size_of::<T>())
+    }
+
+    /// Returns the byte ranges of the dirty slots in a buffer where each element occupies `stride`
+    /// bytes.
+    ///
+    /// Use this when the values are converted to a different representation before uploading.
+    pub fn byte_ranges_with_stride(&self, stride: usize) -> impl Iterator<Item = ByteRange> + 'a {
+        self.slots
+            .iter()
+            .map(move |&slots| ByteRange::from_slots(slots, stride))
+    }
+
+    /// Returns the values of the dirty slots, along with the slots
+    pub fn values(&self) -> impl Iterator<Item = (Slice, &'a [T])> + 'a {
+        let values = self.values;
+        self.slots
+            .iter()
+            .map(move |&slots| (slots, &values[slots.as_range()]))
+    }
+}
+
+/// Tracks which slots of a component column need to be uploaded to a packed buffer per archetype.
+///
+/// The first update reports every slot as dirty.
+pub struct DirtyRanges<T> {
+    component: Component<T>,
+    change_tick: u32,
+    /// The entities of each archetype at the previous update
+    archetypes: BTreeMap<ArchetypeId, Vec<Entity>>,
+}
+
+impl<T: ComponentValue> DirtyRanges<T> {
+    /// Creates a new tracker for `component`
+    pub fn new(component: Component<T>) -> Self {
+        Self {
+            component,
+            change_tick: 0,
+            archetypes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the tracked component
+    pub fn component(&self) -> Component<T> {
+        self.component
+    }
+
+    /// Visits the dirty slots of each archetype with the component since the previous update.
+    ///
+    /// Archetypes without any dirty slots are not visited. Archetypes which no longer exist are
+    /// forgotten, and are visited in full if they are recreated.
+    ///
+    /// # Panics
+    /// If the component is borrowed mutably
+    pub fn update(&mut self, world: &World, mut func: impl FnMut(DirtyColumn<T>)) {
+        let change_tick = world.change_tick();
+        let key = self.component.key();
+
+        self.archetypes
+            .retain(|&arch_id, _| world.archetype(arch_id).is_ok_and(|v| v.has(key)));
+
+        let mut slots = Vec::new();
+        for (arch_id, arch) in world.archetypes.iter() {
+            let Some(values) = arch.borrow::<T>(key) else {
+                continue;
+            };
+
+            // Modifications are only recorded once tracking is enabled, which happens on the
+            // first visit when every slot is dirty regardless
+            values.changes().set_track_modified();
+
+            let entities = arch.entities();
+            let prev = self.archetypes.entry(arch_id).or_default();
+
+            slots.clear();
+            slots.extend(
+                world
+                    .changes(arch_id, key, ChangeKind::Modified)
+                    .filter(|&(_, tick)| tick > self.change_tick)
+                    .map(|(slice, _)| slice),
+            );
+
+            // Slots which were filled by a different entity than before
+            let mut moved = entities
+                .iter()
+                .enumerate()
+                .filter(|&(slot, id)| prev.get(slot) != Some(id))
+                .map(|(slot, _)| slot)
+                .peekable();
+
+            while let Some(start) = moved.next() {
+                let mut end = start + 1;
+                while moved.next_if_eq(&end).is_some() {
+                    end += 1;
+                }
+
+                slots.push(Slice::new(start, end));
+            }
+
+            if prev.as_slice() != entities {
+                prev.clear();
+                prev.extend_from_slice(entities);
+            }
+
+            merge_slices(&mut slots);
+
+            if slots.is_empty() {
+                continue;
+            }
+
+            func(DirtyColumn {
+                arch_id,
+                values: values.get(),
+                slots: &slots,
+            });
+        }
+
+        self.change_tick = change_tick;
+    }
+}
+
+/// Sorts the slices and merges those which overlap or are adjacent
+fn merge_slices(slices: &mut Vec<Slice>) {
+    slices.sort_unstable_by_key(|v| v.start);
+    slices.dedup_by(|cur, prev| match prev.union(cur) {
+        Some(v) => {
+            *prev = v;
+            true
+        }
+        None => false,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use itertools::Itertools;
+
+    use super::*;
+
+    component! {
+        position: [f32; 3],
+        name: &'static str,
+    }
+
+    fn collect(
+        dirty: &mut DirtyRanges<[f32; 3]>,
+        world: &World,
+    ) -> Vec<(ArchetypeId, Vec<ByteRange>)> {
+        let mut result = Vec::new();
+        dirty.update(world, |column| {
+            result.push((column.arch_id, column.byte_ranges().collect_vec()))
+        });
+        result
+    }
+
+    #[test]
+    fn dirty_ranges() {
+        let mut world = World::new();
+
+        let ids = (0..4)
+            .map(|i| {
+                Entity::builder()
+                    .set(position(), [i as f32; 3])
+                    .spawn(&mut world)
+            })
+            .collect_vec();
+
+        let arch_id = world.location(ids[0]).unwrap().arch_id;
+        let mut dirty = DirtyRanges::new(position());
+
+        assert_eq!(
+            collect(&mut dirty, &world),
+            [(arch_id, vec![ByteRange { offset: 0, len: 48 }])]
+        );
+        assert_eq!(collect(&mut dirty, &world), []);
+
+        world.set(ids[1], position(), [5.0; 3]).unwrap();
+        world.set(ids[2], position(), [6.0; 3]).unwrap();
+
+        assert_eq!(
+            collect(&mut dirty, &world),
+            [(arch_id, vec![ByteRange::from_slots(Slice::new(1, 3), 12)])]
+        );
+
+        // The last entity is swapped into the removed slot
+        world.despawn(ids[0]).unwrap();
+        assert_eq!(
+            collect(&mut dirty, &world),
+            [(arch_id, vec![ByteRange { offset: 0, len: 12 }])]
+        );
+
+        // Moving an entity to another archetype
+        world.set(ids[1], name(), "b").unwrap();
+        let other = world.location(ids[1]).unwrap().arch_id;
+
+        let mut columns = Vec::new();
+        dirty.update(&world, |column| {
+            columns.push((column.arch_id, column.values().collect_vec().len()));
+            assert_eq!(
+                column.byte_ranges_with_stride(16).collect_vec(),
+                [ByteRange::from_slots(column.slots[0], 16)]
+            );
+        });
+
+        assert_eq!(columns, [(arch_id, 1), (other, 1)]);
+    }
+
+    #[test]
+    fn merge() {
+        let mut slices = vec![
+            Slice::new(4, 6),
+            Slice::new(0, 1),
+            Slice::new(1, 2),
+            Slice::new(5, 8),
+            Slice::new(10, 11),
+        ];
+
+        merge_slices(&mut slices);
+        assert_eq!(
+            slices,
+            [Slice::new(0, 2), Slice::new(4, 8), Slice::new(10, 11)]
+        );
+    }
+}
@@ -1,11 +1,20 @@
 use crate::{
+    archetype::{BatchSpawn, Storage},
     buffer::ComponentBuffer,
-    component::{ComponentDesc, ComponentValue},
+    component::{dummy, ComponentDesc, ComponentValue},
     error::Result,
+    metadata::cloneable,
     relation::RelationExt,
     CommandBuffer, Component, Entity, World,
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+use core::mem;
+use itertools::Itertools;
+
+#[cfg(feature = "serde")]
+use crate::serialize::DeserializeContext;
+
+use super::EntityKind;
 
 type ModifyFunc = Box<dyn FnOnce(Entity, &mut EntityBuilder) + Send + Sync>;
 struct Child {
@@ -22,7 +31,7 @@ impl core::fmt::Debug for Child {
 impl Child {
     fn spawn(mut self, world: &mut World, parent: Entity) -> Entity {
         (self.modify)(parent, &mut self.builder);
-        self.builder.spawn(world)
+        self.builder.spawn_unchecked(world)
     }
 }
 
@@ -49,6 +58,7 @@ impl Child {
 pub struct EntityBuilder {
     buffer: ComponentBuffer,
     children: Vec<Child>,
+    kind: EntityKind,
 }
 
 impl EntityBuilder {
@@ -59,9 +69,18 @@ impl EntityBuilder {
         Self {
             buffer: ComponentBuffer::new(),
             children: Vec::new(),
+            kind: EntityKind::empty(),
         }
     }
 
+    /// Sets the user defined kind of the spawned entity.
+    ///
+    /// See [`World::spawn_kind`]
+    pub fn kind(&mut self, kind: EntityKind) -> &mut Self {
+        self.kind = kind;
+        self
+    }
+
     /// Sets the component of the entity.
     pub fn set<T: ComponentValue>(&mut self, component: Component<T>, value: T) -> &mut Self {
         self.buffer.set(component, value);
@@ -98,6 +117,25 @@ impl EntityBuilder {
         self
     }
 
+    /// Convenience function for only setting the component if `cond` is true.
+    pub fn set_if<T: ComponentValue>(
+        &mut self,
+        cond: bool,
+        component: Component<T>,
+        value: T,
+    ) -> &mut Self {
+        if cond {
+            self.buffer.set(component, value);
+        }
+        self
+    }
+
+    /// Invoke `func` with the builder, allowing groups of components to be set in the same chain.
+    pub fn with(&mut self, func: impl FnOnce(&mut Self)) -> &mut Self {
+        func(self);
+        self
+    }
+
     /// Return a mutable reference to the stored component.
     pub fn get_mut<T: ComponentValue>(&mut self, component: Component<T>) -> Option<&mut T> {
         self.buffer.get_mut(component)
@@ -108,7 +146,6 @@ impl EntityBuilder {
         self.buffer.get(component)
     }
     /// Returns true if the entity builder contains the given component
-
     pub fn has<T: ComponentValue>(&self, component: Component<T>) -> bool {
         self.buffer.has(component)
     }
@@ -148,9 +185,123 @@ impl EntityBuilder {
     ///
     /// Clears the builder and allows it to be used again, reusing the builder
     /// will reuse the inner storage, even for different components.
+    ///
+    /// Panics if a component [requires](crate::metadata::Requires) another component which is not
+    /// present and has no default, or if the entity is rejected by a
+    /// [spawn validator](World::add_spawn_validator). Use [`Self::try_spawn`] to handle these
+    /// cases instead. Spawning never fails when all requirements have defaults and no
+    /// validators are registered.
     pub fn spawn(&mut self, world: &mut World) -> Entity {
+        if let Err(err) = self.validate(world, None) {
+            panic!("Failed to spawn entity: {err}");
+        }
+
+        self.spawn_unchecked(world)
+    }
+
+    /// Spawns `count` entities with the components of the builder, calling `modify` with the
+    /// index and builder of each entity to change its values.
+    ///
+    /// The components of the builder are cloned for each entity, and thus need to be
+    /// [`Cloneable`](crate::metadata::Cloneable) unless they are set by `modify`. All entities
+    /// must end up with the same components, which allows them to be inserted into their
+    /// archetype at once. Children added by `modify` are spawned after the entities, while the
+    /// children of the builder itself are discarded.
+    ///
+    /// Clears the builder.
+    ///
+    /// Panics if the entities do not have the same components, or for the same reasons as
+    /// [`Self::spawn`].
+    pub fn spawn_many(
+        &mut self,
+        world: &mut World,
+        count: usize,
+        mut modify: impl FnMut(usize, &mut EntityBuilder),
+    ) -> Vec<Entity> {
+        profile_function!();
+        let base = mem::take(self);
+
+        let mut instances = (0..count)
+            .map(|i| {
+                let mut builder = EntityBuilder::new();
+                for &desc in base.buffer.components() {
+                    if let Some(vtable) = desc.meta_ref().get(cloneable()) {
+                        vtable.clone_from_buffer(&base.buffer, desc, None, &mut builder.buffer);
+                    }
+                }
+
+                modify(i, &mut builder);
+
+                if let Err(err) = world.fill_required(dummy(), &mut builder.buffer) {
+                    panic!("Failed to spawn entity: {err}");
+                }
+
+                if let Err(err) = builder.validate(world, None) {
+                    panic!("Failed to spawn entity: {err}");
+                }
+
+                builder
+            })
+            .collect_vec();
+
+        let Some(first) = instances.first() else {
+            return Vec::new();
+        };
+
+        let components = first.buffer.components().copied().collect_vec();
+        if let Some((i, instance)) = instances
+            .iter()
+            .enumerate()
+            .find(|(_, v)| !v.buffer.components().eq(&components))
+        {
+            panic!(
+                "Entity {i} has different components than the first entity.\nExpected: {:#?}\nFound: {:#?}",
+                components,
+                instance.buffer.components().collect_vec()
+            );
+        }
+
+        let mut storage = components
+            .iter()
+            .map(|&desc| (desc.key(), Storage::with_capacity(desc, count)))
+            .collect::<BTreeMap<_, _>>();
+
+        for instance in &mut instances {
+            for (desc, src) in instance.buffer.drain() {
+                // Safety: the storage is of the same type, and takes ownership of the value
+                unsafe { storage.get_mut(&desc.key()).unwrap().extend(src, 1) }
+            }
+        }
+
+        let mut batch = BatchSpawn::new(count);
+        for storage in storage.into_values() {
+            batch.append(storage).expect("Batch is complete");
+        }
+
+        let ids = world.spawn_batch_kind(&mut batch, base.kind);
+
+        for (instance, &id) in instances.iter_mut().zip(&ids) {
+            instance.children.drain(..).for_each(|child| {
+                child.spawn(world, id);
+            });
+        }
+
+        ids
+    }
+
+    /// Spawns the built entity into the world, returning an error if the entity or any of its
+    /// children are missing a [required](crate::metadata::Requires) component which has no
+    /// default, or are rejected by a [spawn validator](World::add_spawn_validator).
+    ///
+    /// The builder is left unchanged if the entity is rejected.
+    pub fn try_spawn(&mut self, world: &mut World) -> Result<Entity> {
+        self.validate(world, None)?;
+        Ok(self.spawn_unchecked(world))
+    }
+
+    fn spawn_unchecked(&mut self, world: &mut World) -> Entity {
         profile_function!();
-        let id = world.spawn_with(&mut self.buffer);
+        let id = world.spawn_with(&mut self.buffer, mem::take(&mut self.kind));
 
         self.children.drain(..).for_each(|child| {
             child.spawn(world, id);
@@ -165,6 +316,7 @@ impl EntityBuilder {
     ///
     /// Fails if an entity with the same index already exists.
     pub fn spawn_at(&mut self, world: &mut World, id: Entity) -> Result<Entity> {
+        self.validate(world, None)?;
         let (id, _) = world.spawn_at_with(id, &mut self.buffer)?;
 
         self.children.drain(..).for_each(|child| {
@@ -179,6 +331,7 @@ impl EntityBuilder {
     /// New components will overwrite existing components.
     pub fn append_to(&mut self, world: &mut World, id: Entity) -> Result<Entity> {
         profile_function!();
+        self.validate(world, Some(id))?;
         world.set_with(id, &mut self.buffer)?;
 
         self.children.drain(..).for_each(|child| {
@@ -188,11 +341,39 @@ impl EntityBuilder {
         Ok(id)
     }
 
+    /// Runs the spawn validators of the world for the entity and its children
+    pub(crate) fn validate(&self, world: &World, id: Option<Entity>) -> Result<()> {
+        world.check_required(id.unwrap_or(dummy()), &self.buffer)?;
+        world.validate_spawn(id, &self.buffer)?;
+        self.children
+            .iter()
+            .try_for_each(|child| child.builder.validate(world, None))
+    }
+
     /// Spawns the entity into the world through a commandbuffer
     pub fn spawn_into(&mut self, cmd: &mut CommandBuffer) {
         cmd.spawn(core::mem::take(self));
     }
 
+    /// Deserializes a builder from the components of a single entity.
+    ///
+    /// See [`DeserializeContext::deserialize_entity`]
+    #[cfg(feature = "serde")]
+    pub fn from_serialized<'de, D>(
+        deserializer: D,
+        context: &DeserializeContext,
+    ) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        context.deserialize_entity(deserializer)
+    }
+
+    /// Returns the components in the builder
+    pub fn components(&self) -> impl Iterator<Item = &ComponentDesc> {
+        self.buffer.components()
+    }
+
     /// Returns the number of component in the builder
     pub fn component_count(&self) -> usize {
         self.buffer.len()
@@ -220,7 +401,11 @@ impl From<&mut EntityBuilder> for EntityBuilder {
 
 #[cfg(test)]
 mod test {
-    use crate::{component, components::name, error::MissingComponent, Entity, Error, World};
+    use crate::{
+        components::{child_of, name},
+        error::MissingComponent,
+        Entity, Error, Query, World,
+    };
 
     #[test]
     fn builder() {
@@ -261,4 +446,92 @@ mod test {
             }))
         );
     }
+
+    #[test]
+    fn spawn_validator() {
+        component! {
+            health: f32,
+            is_dead: (),
+        }
+
+        let mut world = World::new();
+        world.add_spawn_validator(|components| {
+            if components.contains(&health().desc()) && components.contains(&is_dead().desc()) {
+                Err("dead entities can not have health".into())
+            } else {
+                Ok(())
+            }
+        });
+
+        let id = Entity::builder().set(health(), 1.0).spawn(&mut world);
+
+        let mut builder = Entity::builder();
+        builder.tag(is_dead());
+
+        assert_eq!(
+            builder.append_to(&mut world, id),
+            Err(Error::SpawnRejected(
+                "dead entities can not have health".into()
+            ))
+        );
+        assert!(!world.has(id, is_dead()));
+
+        let mut builder = Entity::builder();
+        builder.set(name(), "parent".into()).attach(
+            child_of,
+            Entity::builder().set(health(), 1.0).tag(is_dead()),
+        );
+
+        // The child is rejected before the parent is spawned
+        assert!(builder.try_spawn(&mut world).is_err());
+        assert_eq!(Query::new(name()).borrow(&world).unwrap().count(), 0);
+        assert!(builder.has(name()));
+    }
+
+    #[test]
+    fn spawn_many() {
+        use crate::{metadata::Cloneable, FetchExt};
+        use alloc::{format, string::String};
+
+        component! {
+            health: f32 => [ Cloneable ],
+            position: (i32, i32) => [ Cloneable ],
+        }
+
+        let mut world = World::new();
+
+        let ids = Entity::builder()
+            .set(health(), 100.0)
+            .set(position(), (0, 0))
+            .spawn_many(&mut world, 4, |i, builder| {
+                let i = i as i32;
+                builder
+                    .set(position(), (i % 2, i / 2))
+                    .set(name(), format!("cell.{i}"));
+
+                if i == 0 {
+                    builder.attach(child_of, Entity::builder().set(name(), "child".into()));
+                }
+            });
+
+        assert_eq!(ids.len(), 4);
+        let arch = world.location(ids[0]).unwrap().arch_id;
+        assert!(ids
+            .iter()
+            .all(|&id| world.location(id).unwrap().arch_id == arch));
+
+        let mut query = Query::new((name().cloned(), health().copied(), position().copied()));
+        assert_eq!(
+            query.collect_vec(&world),
+            [
+                (String::from("cell.0"), 100.0, (0, 0)),
+                ("cell.1".into(), 100.0, (1, 0)),
+                ("cell.2".into(), 100.0, (0, 1)),
+                ("cell.3".into(), 100.0, (1, 1)),
+            ]
+        );
+
+        let mut children = Query::new(name().cloned()).with(child_of(ids[0]));
+        assert_eq!(children.collect_vec(&world), ["child"]);
+    }
 }
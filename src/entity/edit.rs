@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentKey, ComponentValue},
+    error::Result,
+    Component, Entity, World,
+};
+
+/// Records modifications to an existing entity, which are applied as a single archetype
+/// transition.
+///
+/// Setting and removing components one at a time moves the entity once for each added or removed
+/// component. The edit builder instead collects the changes and moves the entity directly to the
+/// final archetype.
+///
+/// Created by [`World::edit`].
+///
+/// ```rust
+/// # use flax::{*, components::*};
+/// # component! {
+/// #     health: f32,
+/// #     is_player: (),
+/// #     is_dead: (),
+/// # }
+/// # let mut world = World::new();
+/// let id = Entity::builder()
+///     .set(health(), 0.0)
+///     .tag(is_player())
+///     .spawn(&mut world);
+///
+/// world
+///     .edit(id)
+///     .remove(health())
+///     .remove(is_player())
+///     .tag(is_dead())
+///     .apply()
+///     .unwrap();
+///
+/// assert!(!world.has(id, health()));
+/// assert!(world.has(id, is_dead()));
+/// ```
+#[derive(Debug)]
+pub struct EditBuilder<'w> {
+    world: &'w mut World,
+    id: Entity,
+    buffer: ComponentBuffer,
+    removed: Vec<ComponentKey>,
+}
+
+impl<'w> EditBuilder<'w> {
+    pub(crate) fn new(world: &'w mut World, id: Entity) -> Self {
+        Self {
+            world,
+            id,
+            buffer: ComponentBuffer::new(),
+            removed: Vec::new(),
+        }
+    }
+
+    /// Returns the entity being edited
+    pub fn id(&self) -> Entity {
+        self.id
+    }
+
+    /// Sets the component of the entity.
+    ///
+    /// Cancels a previous removal of the component.
+    pub fn set<T: ComponentValue>(&mut self, component: Component<T>, value: T) -> &mut Self {
+        self.removed.retain(|&v| v != component.key());
+        self.buffer.set(component, value);
+        self
+    }
+
+    /// Sets a component with the default value of `T`
+    pub fn set_default<T: ComponentValue + Default>(
+        &mut self,
+        component: Component<T>,
+    ) -> &mut Self {
+        self.set(component, Default::default())
+    }
+
+    /// Shorthand for setting a unit type component
+    pub fn tag<T: From<()> + ComponentValue>(&mut self, component: Component<T>) -> &mut Self {
+        self.set(component, ().into())
+    }
+
+    /// Convenience function for only setting the component if Some.
+    pub fn set_opt<T: ComponentValue>(
+        &mut self,
+        component: Component<T>,
+        value: Option<T>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            self.set(component, value);
+        }
+        self
+    }
+
+    /// Removes the component from the entity.
+    ///
+    /// Cancels a previous set of the component. Removing a component which the entity does not
+    /// have is not an error.
+    pub fn remove<T: ComponentValue>(&mut self, component: Component<T>) -> &mut Self {
+        self.buffer.remove(component);
+        if !self.removed.contains(&component.key()) {
+            self.removed.push(component.key());
+        }
+        self
+    }
+
+    /// Applies the recorded modifications to the entity.
+    ///
+    /// Clears the builder, allowing it to be reused for further edits of the same entity.
+    pub fn apply(&mut self) -> Result<()> {
+        let res = self
+            .world
+            .edit_with(self.id, &mut self.buffer, &self.removed);
+
+        self.buffer.clear();
+        self.removed.clear();
+        res
+    }
+}
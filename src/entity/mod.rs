@@ -1,16 +1,20 @@
 mod builder;
+mod edit;
 mod store;
+mod tree;
 
 use core::fmt;
 use core::num::NonZeroU16;
 use core::sync::atomic::{AtomicU32, Ordering};
 
 pub use builder::*;
+pub use edit::EditBuilder;
 pub(crate) use store::*;
+pub use tree::EntityTree;
 
 use crate::EntityIds;
 
-pub(crate) const DEFAULT_GEN: EntityGen = unsafe { EntityGen::new_unchecked(1) };
+pub(crate) const DEFAULT_GEN: EntityGen = EntityGen::new(1).unwrap();
 
 /// Represents an entity identifier.
 /// An entity can either declare an identifier spawned into the world,
@@ -26,23 +30,19 @@ impl Entity {
     /// The lowest possible entity
     ///
     /// May or may not refer to a valid entity.
-    pub(crate) const MIN: Self = unsafe {
-        Entity {
-            index: 0,
-            gen: NonZeroU16::new_unchecked(1),
-            kind: EntityKind::empty(),
-        }
+    pub(crate) const MIN: Self = Entity {
+        index: 0,
+        gen: NonZeroU16::new(1).unwrap(),
+        kind: EntityKind::empty(),
     };
 
     /// The greatest possible entity
     ///
     /// May or may not refer to a valid entity.
-    pub(crate) const MAX: Self = unsafe {
-        Entity {
-            index: u32::MAX,
-            gen: NonZeroU16::new_unchecked(u16::MAX),
-            kind: EntityKind::all(),
-        }
+    pub(crate) const MAX: Self = Entity {
+        index: u32::MAX,
+        gen: NonZeroU16::new(u16::MAX).unwrap(),
+        kind: EntityKind::all(),
     };
 
     pub(crate) fn from_parts(index: EntityIndex, gen: EntityGen, kind: EntityKind) -> Self {
@@ -104,6 +104,34 @@ impl Entity {
     pub fn kind(&self) -> EntityKind {
         self.kind
     }
+
+    /// Encodes the entity into a stable `u64` representation.
+    ///
+    /// The layout is, from least to most significant bits:
+    /// - `0..32`: index
+    /// - `32..48`: generation
+    /// - `48..64`: kind
+    ///
+    /// See: [`Self::from_bits`]
+    #[inline]
+    pub fn to_bits(&self) -> u64 {
+        (self.index as u64) | ((self.gen.get() as u64) << 32) | ((self.kind.bits() as u64) << 48)
+    }
+
+    /// Decodes an entity encoded by [`Self::to_bits`].
+    ///
+    /// Returns `None` if the generation is zero or the kind contains unknown bits.
+    ///
+    /// **Note**: This does not check if the entity is alive in any world, see
+    /// [`World::entity_from_bits`](crate::World::entity_from_bits)
+    #[inline]
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        let index = bits as EntityIndex;
+        let gen = EntityGen::new((bits >> 32) as u16)?;
+        let kind = EntityKind::from_bits((bits >> 48) as u16)?;
+
+        Some(Self::from_parts(index, gen, kind))
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -221,6 +249,19 @@ bitflags::bitflags! {
         /// The entity is created via static initialization and is never
         /// despawned
         const STATIC = 2;
+        /// User defined kind
+        const USER_0 = 1 << 8;
+        /// User defined kind
+        const USER_1 = 1 << 9;
+        /// User defined kind
+        const USER_2 = 1 << 10;
+        /// User defined kind
+        const USER_3 = 1 << 11;
+        /// All user defined kinds
+        const USER = Self::USER_0.bits()
+            | Self::USER_1.bits()
+            | Self::USER_2.bits()
+            | Self::USER_3.bits();
     }
 }
 
@@ -294,6 +335,24 @@ mod tests {
         assert_eq!(store.get(d), Some(&"d"));
     }
 
+    #[test]
+    fn entity_bits() {
+        let mut store = EntityStore::new(EntityKind::COMPONENT);
+        store.spawn(());
+        let id = store.spawn(());
+        store.despawn(id).unwrap();
+        let id = store.spawn(());
+
+        let bits = id.to_bits();
+        assert_eq!(bits, 1 | (2 << 32) | (1 << 48));
+        assert_eq!(Entity::from_bits(bits), Some(id));
+
+        // Zero generation
+        assert_eq!(Entity::from_bits(1 | (1 << 48)), None);
+        // Unknown kind
+        assert_eq!(Entity::from_bits(1 | (1 << 32) | (0x8000 << 48)), None);
+    }
+
     #[test]
     fn entity_size() {
         assert_eq!(size_of::<Entity>(), 8);
@@ -143,7 +143,7 @@ impl<V> EntityStore<V> {
         }
     }
     /// Reserves `count` new entity ids
-    pub fn reserve(&self, count: usize) -> ReservedIter<V> {
+    pub fn reserve(&self, count: usize) -> ReservedIter<'_, V> {
         // Use as many free as possible
         let cursor = self.cursor.fetch_sub(count as _, Relaxed);
 
@@ -359,14 +359,14 @@ impl<V> EntityStore<V> {
             .is_some()
     }
 
-    pub fn iter(&self) -> EntityStoreIter<V> {
+    pub fn iter(&self) -> EntityStoreIter<'_, V> {
         EntityStoreIter {
             iter: self.slots.iter().enumerate(),
             namespace: self.kind,
         }
     }
 
-    pub fn iter_mut(&mut self) -> EntityStoreIterMut<V> {
+    pub fn iter_mut(&mut self) -> EntityStoreIterMut<'_, V> {
         EntityStoreIterMut {
             iter: self.slots.iter_mut().enumerate(),
             namespace: self.kind,
@@ -0,0 +1,80 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{buffer::ComponentBuffer, metadata::cloneable, Entity, World};
+
+#[derive(Debug)]
+struct ClonedEntity {
+    id: Entity,
+    buffer: ComponentBuffer,
+}
+
+/// A standalone copy of a selection of entities, including the relations between them.
+///
+/// Created by [`World::clone_entities_into`], and can be spawned any number of times into the
+/// same or another world. Relations between the selected entities are remapped to the newly
+/// spawned entities, while relations to entities outside the selection are kept as is.
+///
+/// Only components with the [`Cloneable`](crate::metadata::Cloneable) metadata are copied.
+#[derive(Debug, Default)]
+pub struct EntityTree {
+    entities: Vec<ClonedEntity>,
+}
+
+impl EntityTree {
+    /// Creates a new empty tree
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn push(&mut self, id: Entity, buffer: ComponentBuffer) {
+        self.entities.push(ClonedEntity { id, buffer })
+    }
+
+    /// Returns the ids of the entities the tree was cloned from
+    pub fn ids(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter().map(|v| v.id)
+    }
+
+    /// Returns the number of entities in the tree
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns true if the tree contains no entities
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Spawns a copy of the entities into `world`.
+    ///
+    /// Returns a mapping from the original ids to the spawned entities.
+    pub fn spawn(&self, world: &mut World) -> BTreeMap<Entity, Entity> {
+        profile_function!();
+        let map = self
+            .entities
+            .iter()
+            .map(|v| (v.id, world.spawn()))
+            .collect::<BTreeMap<_, _>>();
+
+        for entity in &self.entities {
+            let mut buffer = ComponentBuffer::new();
+            for &desc in entity.buffer.components() {
+                let target = desc.key().target().and_then(|v| map.get(&v).copied());
+
+                let vtable = desc
+                    .meta_ref()
+                    .get(cloneable())
+                    .expect("Only cloneable components are stored");
+
+                vtable.clone_from_buffer(&entity.buffer, desc, target, &mut buffer);
+            }
+
+            world
+                .set_with(map[&entity.id], &mut buffer)
+                .expect("Entity was just spawned");
+        }
+
+        map
+    }
+}
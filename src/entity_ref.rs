@@ -9,11 +9,11 @@ use once_cell::unsync::OnceCell;
 
 use crate::{
     archetype::{Archetype, RefMut},
-    component::{ComponentKey, ComponentValue},
+    component::{ComponentDesc, ComponentKey, ComponentValue},
     components::name,
     entity::EntityLocation,
     entry::{Entry, OccupiedEntry, VacantEntry},
-    error::MissingComponent,
+    error::{Error, MissingComponent},
     format::EntityFormatter,
     query::QueryOne,
     relation::{RelationExt, RelationIter, RelationIterMut},
@@ -21,6 +21,9 @@ use crate::{
     Component, Entity, Fetch, World,
 };
 
+#[cfg(feature = "serde")]
+use crate::serialize::SerializeContext;
+
 /// Borrow all the components of an entity at once.
 ///
 /// This is handy to borrow an entity and perform multiple operations on it
@@ -36,7 +39,7 @@ impl<'a> EntityRefMut<'a> {
     pub fn get<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> Result<AtomicRef<T>, MissingComponent> {
+    ) -> Result<AtomicRef<'_, T>, MissingComponent> {
         self.world
             .get_at(self.loc(), component)
             .ok_or_else(|| MissingComponent {
@@ -49,7 +52,7 @@ impl<'a> EntityRefMut<'a> {
     pub fn get_mut<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> Result<RefMut<T>, MissingComponent> {
+    ) -> Result<RefMut<'_, T>, MissingComponent> {
         self.world
             .get_mut_at(self.loc(), component)
             .ok_or_else(|| MissingComponent {
@@ -66,6 +69,16 @@ impl<'a> EntityRefMut<'a> {
         self.get(component).map(|v| *v)
     }
 
+    /// Shorthand to clone and not use a borrowing references.
+    ///
+    /// The borrow is released before returning.
+    pub fn get_cloned<T: ComponentValue + Clone>(
+        &self,
+        component: Component<T>,
+    ) -> Result<T, MissingComponent> {
+        self.get(component).map(|v| v.clone())
+    }
+
     /// Check if the entity currently has the specified component without
     /// borrowing.
     pub fn has<T: ComponentValue>(&self, component: Component<T>) -> bool {
@@ -120,7 +133,7 @@ impl<'a> EntityRefMut<'a> {
     pub fn try_get<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> core::result::Result<Option<AtomicRef<T>>, BorrowError> {
+    ) -> core::result::Result<Option<AtomicRef<'_, T>>, BorrowError> {
         self.world.try_get_at(self.loc(), component)
     }
 
@@ -128,7 +141,7 @@ impl<'a> EntityRefMut<'a> {
     pub fn try_get_mut<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> core::result::Result<Option<RefMut<T>>, BorrowMutError> {
+    ) -> core::result::Result<Option<RefMut<'_, T>>, BorrowMutError> {
         self.world.try_get_mut_at(self.loc(), component)
     }
 
@@ -140,7 +153,10 @@ impl<'a> EntityRefMut<'a> {
     }
 
     /// Returns all relations to other entities of the specified kind
-    pub fn relations<T: ComponentValue>(&self, relation: impl RelationExt<T>) -> RelationIter<T> {
+    pub fn relations<T: ComponentValue>(
+        &self,
+        relation: impl RelationExt<T>,
+    ) -> RelationIter<'_, T> {
         let (_, loc, arch) = self.parts();
         RelationIter::new(relation, arch, loc.slot)
     }
@@ -149,7 +165,7 @@ impl<'a> EntityRefMut<'a> {
     pub fn relations_mut<T: ComponentValue>(
         &self,
         relation: impl RelationExt<T>,
-    ) -> RelationIterMut<T> {
+    ) -> RelationIterMut<'_, T> {
         let (world, loc, arch) = self.parts();
         RelationIterMut::new(relation, arch, loc.slot, world.advance_change_tick())
     }
@@ -233,6 +249,41 @@ impl<'a> EntityRefMut<'a> {
         self.retain(|_| false)
     }
 
+    /// Set the relation to `target` for the entity
+    pub fn set_relation<T: ComponentValue>(
+        &mut self,
+        relation: impl RelationExt<T>,
+        target: Entity,
+        value: T,
+    ) -> Option<T> {
+        self.set(relation.of(target), value)
+    }
+
+    /// Remove the relation to `target`
+    pub fn remove_relation<T: ComponentValue>(
+        &mut self,
+        relation: impl RelationExt<T>,
+        target: Entity,
+    ) -> Result<T, MissingComponent> {
+        self.remove(relation.of(target))
+    }
+
+    /// Remove all relations of the specified kind, regardless of target.
+    ///
+    /// The relations are removed in a single archetype move.
+    pub fn clear_relations<T: ComponentValue>(&mut self, relation: impl RelationExt<T>) {
+        let relation = relation.id();
+        self.retain(|key| key.id() != relation || key.target().is_none())
+    }
+
+    /// Despawns the entity, consuming the reference.
+    ///
+    /// See: [`crate::World::despawn`]
+    pub fn despawn(self) -> Result<(), Error> {
+        let loc = self.loc();
+        self.world.despawn_at(self.id, loc)
+    }
+
     /// Returns the entity id
     pub fn id(&self) -> Entity {
         self.id
@@ -255,7 +306,7 @@ impl<'a> EntityRefMut<'a> {
     }
 
     /// Non consuming version of [`Self::entry`]
-    pub fn entry_ref<T: ComponentValue>(&mut self, component: Component<T>) -> Entry<T> {
+    pub fn entry_ref<T: ComponentValue>(&mut self, component: Component<T>) -> Entry<'_, T> {
         if self.has(component) {
             let loc = self.loc();
             Entry::Occupied(OccupiedEntry {
@@ -279,7 +330,7 @@ impl<'a> EntityRefMut<'a> {
 
     /// Non consuming version of [`Self::downgrade`]
     #[inline]
-    pub fn downgrade_ref(&self) -> EntityRef {
+    pub fn downgrade_ref(&self) -> EntityRef<'_> {
         let loc = self.loc();
         EntityRef {
             arch: self.world.archetypes.get(loc.arch_id),
@@ -313,9 +364,33 @@ impl<'a> EntityRefMut<'a> {
 
     /// Shorthand for retrieving the [`name`](crate::components::name) component
     #[inline]
-    pub fn name(&self) -> Option<AtomicRef<String>> {
+    pub fn name(&self) -> Option<AtomicRef<'_, String>> {
         self.get(name()).ok()
     }
+
+    /// Returns the components present on the entity, excluding
+    /// [hidden](World::hide_component) components
+    pub fn components(&self) -> impl Iterator<Item = ComponentDesc> + '_ {
+        let (world, _, arch) = self.parts();
+        arch.components_desc().filter(|v| !world.is_hidden(v.key()))
+    }
+
+    /// Returns all components present on the entity, including hidden components
+    pub fn components_with_hidden(&self) -> impl Iterator<Item = ComponentDesc> + '_ {
+        let (_, _, arch) = self.parts();
+        arch.components_desc()
+    }
+
+    /// Returns the number of components present on the entity
+    pub fn len(&self) -> usize {
+        let (_, _, arch) = self.parts();
+        arch.components().len()
+    }
+
+    /// Returns true if the entity has no components
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Borrow all the components of an entity at once.
@@ -365,6 +440,16 @@ impl<'a> EntityRef<'a> {
         self.get(component).map(|v| *v)
     }
 
+    /// Shorthand to clone and not use a borrowing references.
+    ///
+    /// The borrow is released before returning.
+    pub fn get_cloned<T: ComponentValue + Clone>(
+        &self,
+        component: Component<T>,
+    ) -> Result<T, MissingComponent> {
+        self.get(component).map(|v| v.clone())
+    }
+
     /// Check if the entity currently has the specified component without
     /// borrowing.
     pub fn has<T: ComponentValue>(&self, component: Component<T>) -> bool {
@@ -404,7 +489,7 @@ impl<'a> EntityRef<'a> {
     pub fn try_get<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> core::result::Result<Option<AtomicRef<T>>, BorrowError> {
+    ) -> core::result::Result<Option<AtomicRef<'_, T>>, BorrowError> {
         self.arch.try_get(self.loc.slot, component)
     }
 
@@ -412,7 +497,7 @@ impl<'a> EntityRef<'a> {
     pub fn try_get_mut<T: ComponentValue>(
         &self,
         component: Component<T>,
-    ) -> core::result::Result<Option<RefMut<T>>, BorrowMutError> {
+    ) -> core::result::Result<Option<RefMut<'_, T>>, BorrowMutError> {
         self.arch
             .try_get_mut(self.loc.slot, component, self.world.advance_change_tick())
     }
@@ -452,9 +537,48 @@ impl<'a> EntityRef<'a> {
 
     /// Shorthand for retrieving the [`name`](crate::components::name) component
     #[inline]
-    pub fn name(&self) -> Option<AtomicRef<String>> {
+    pub fn name(&self) -> Option<AtomicRef<'_, String>> {
         self.get(name()).ok()
     }
+
+    /// Returns the components present on the entity, excluding
+    /// [hidden](World::hide_component) components
+    pub fn components(&self) -> impl Iterator<Item = ComponentDesc> + 'a {
+        let world = self.world;
+        self.arch
+            .components_desc()
+            .filter(move |v| !world.is_hidden(v.key()))
+    }
+
+    /// Returns all components present on the entity, including hidden components
+    pub fn components_with_hidden(&self) -> impl Iterator<Item = ComponentDesc> + 'a {
+        self.arch.components_desc()
+    }
+
+    /// Returns the number of components present on the entity
+    pub fn len(&self) -> usize {
+        self.arch.components().len()
+    }
+
+    /// Returns true if the entity has no components
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Serializes the components of the entity which are registered in `context`.
+    ///
+    /// See [`SerializeContext::serialize_entity`]
+    #[cfg(feature = "serde")]
+    pub fn serialize<S>(
+        &self,
+        serializer: S,
+        context: &SerializeContext,
+    ) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&context.serialize_entity(self), serializer)
+    }
 }
 
 impl<'a> Debug for EntityRef<'a> {
@@ -511,7 +635,6 @@ impl Display for EntityRefMut<'_> {
 mod test {
 
     use crate::{
-        component,
         components::{is_static, name},
         EntityBuilder, FetchExt, Query,
     };
@@ -767,4 +890,39 @@ mod test {
 
         assert_eq!(query.collect_vec(&world), ["Bar"]);
     }
+
+    #[test]
+    fn relations() {
+        use alloc::vec::Vec;
+
+        use crate::components::child_of;
+
+        component! {
+            likes(id): f32,
+        }
+
+        let mut world = World::new();
+
+        let [a, b, c] =
+            ["a", "b", "c"].map(|v| EntityBuilder::new().set(name(), v.into()).spawn(&mut world));
+
+        let mut entity = world.entity_mut(c).unwrap();
+        assert_eq!(entity.set_relation(likes, a, 0.5), None);
+        assert_eq!(entity.set_relation(likes, b, 1.0), None);
+        assert_eq!(entity.set_relation(likes, b, 2.0), Some(1.0));
+        entity.set_relation(child_of, a, ());
+
+        assert_eq!(entity.remove_relation(likes, a), Ok(0.5));
+        assert!(entity.remove_relation(likes, a).is_err());
+        entity.set_relation(likes, a, 0.25);
+
+        let mut targets = entity.relations(likes).map(|v| v.0).collect::<Vec<_>>();
+        targets.sort();
+        assert_eq!(targets, [a, b]);
+
+        entity.clear_relations(likes);
+        assert_eq!(entity.relations(likes).count(), 0);
+        assert!(entity.has(child_of(a)));
+        assert!(entity.has(name()));
+    }
 }
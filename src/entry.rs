@@ -3,6 +3,7 @@ use core::mem;
 use crate::{
     archetype::RefMut,
     component::ComponentValue,
+    metadata::object_default_of,
     writer::{Replace, SingleComponentWriter},
     Component, Entity, World,
 };
@@ -71,12 +72,18 @@ where
     }
 
     /// Return the component in the entry or insert the default value.
+    ///
+    /// Relations use the default of their [`ObjectDefault`](crate::metadata::ObjectDefault), if
+    /// registered.
     pub fn or_default(self) -> RefMut<'a, T>
     where
         T: Default,
     {
         match self {
-            Entry::Vacant(slot) => slot.insert(Default::default()),
+            Entry::Vacant(slot) => {
+                let value = object_default_of(slot.component).unwrap_or_default();
+                slot.insert(value)
+            }
             Entry::Occupied(slot) => slot.into_mut(),
         }
     }
@@ -1,6 +1,7 @@
+use alloc::{boxed::Box, string::String};
 use core::fmt::Display;
 
-use crate::{component::ComponentDesc, Entity};
+use crate::{archetype::ArchetypeId, component::ComponentDesc, system::AccessKind, Entity};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -18,9 +19,100 @@ pub enum Error {
     IncompleteBatch,
     /// Attempt to spawn entity with occupied entity id
     EntityOccupied(Entity),
+    /// The property path could not be resolved
+    InvalidPath(String),
+    /// The requested archetype did not exist
+    NoSuchArchetype(ArchetypeId),
+    /// The entity was rejected by a spawn validator.
+    ///
+    /// See [`World::add_spawn_validator`](crate::World::add_spawn_validator)
+    SpawnRejected(String),
+    /// A component is accessed by more than one [`WorldCell`](crate::WorldCell), where at least
+    /// one of the accesses is mutable.
+    ConflictingAccess(ComponentDesc),
+    /// An error annotated with the operation which caused it.
+    ///
+    /// See [`Error::context`]
+    Context(&'static str, Box<Error>),
+    /// No component marked as [`Unique`](crate::metadata::Unique) is registered for the type.
+    NoUniqueComponent(&'static str),
+    /// The component is declared [`Immutable`](crate::metadata::Immutable) and can not be
+    /// accessed mutably.
+    ImmutableComponent(ComponentDesc),
+    /// The component is declared [`NoLock`](crate::metadata::NoLock) and can not be written
+    /// through a shared world.
+    NoLockComponent(ComponentDesc),
+    /// The entity already has an [`Exclusive`](crate::Exclusive) relation to another target,
+    /// which would be replaced.
+    ///
+    /// Holds the entity and the existing relation.
+    ExclusiveRelation(Entity, ComponentDesc),
+    /// The component can not be removed as another component of the entity
+    /// [requires](crate::metadata::Requires) it.
+    ///
+    /// Holds the entity, the removed component, and the component requiring it.
+    RequiredComponent(Entity, ComponentDesc, ComponentDesc),
+    /// A query could not be borrowed as a component it accesses is already borrowed.
+    ///
+    /// See [`Query::borrow`](crate::Query::borrow)
+    AccessConflict(AccessConflict),
+    /// Two queries borrowed together both access something other than a component, such as the
+    /// world or an external resource, where at least one of the accesses is mutable.
+    ///
+    /// See [`Query::zip_disjoint`](crate::Query::zip_disjoint)
+    ConflictingAccessKind(AccessKind),
 }
 
 impl Error {
+    /// Annotates the error with the operation which caused it, such as `"despawn"`
+    pub fn context(self, operation: &'static str) -> Self {
+        Self::Context(operation, Box::new(self))
+    }
+
+    /// Returns the underlying error, skipping any context
+    pub fn root(&self) -> &Self {
+        match self {
+            Self::Context(_, inner) => inner.root(),
+            v => v,
+        }
+    }
+
+    /// Returns the outermost operation the error was annotated with
+    pub fn operation(&self) -> Option<&'static str> {
+        match self {
+            Self::Context(operation, _) => Some(operation),
+            _ => None,
+        }
+    }
+
+    /// Returns the entity the error concerns, if any
+    pub fn entity(&self) -> Option<Entity> {
+        match *self.root() {
+            Self::NoSuchEntity(id)
+            | Self::DoesNotMatch(id)
+            | Self::Filtered(id)
+            | Self::EntityOccupied(id)
+            | Self::ExclusiveRelation(id, _)
+            | Self::RequiredComponent(id, _, _) => Some(id),
+            Self::MissingComponent(ref v) => Some(v.id),
+            _ => None,
+        }
+    }
+
+    /// Returns the component the error concerns, if any
+    pub fn component(&self) -> Option<ComponentDesc> {
+        match self.root() {
+            Self::MissingComponent(v) => Some(v.desc),
+            Self::AccessConflict(v) => Some(v.desc),
+            Self::ConflictingAccess(desc)
+            | Self::ImmutableComponent(desc)
+            | Self::NoLockComponent(desc)
+            | Self::ExclusiveRelation(_, desc)
+            | Self::RequiredComponent(_, desc, _) => Some(*desc),
+            _ => None,
+        }
+    }
+
     /// Convert the error into an anyhow report, regardles of [std::error::Error] or not.
     pub(crate) fn into_anyhow(self) -> anyhow::Error {
         #[cfg(not(feature = "std"))]
@@ -45,6 +137,12 @@ impl From<MissingComponent> for Error {
     }
 }
 
+impl From<AccessConflict> for Error {
+    fn from(value: AccessConflict) -> Self {
+        Self::AccessConflict(value)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// Missing component
 pub struct MissingComponent {
@@ -54,6 +152,73 @@ pub struct MissingComponent {
     pub desc: ComponentDesc,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A component accessed by a query is already borrowed in a conflicting way
+pub struct AccessConflict {
+    /// The archetype containing the component
+    pub arch_id: ArchetypeId,
+    /// The borrowed component
+    pub desc: ComponentDesc,
+    /// True if the query accesses the component mutably
+    pub mutable: bool,
+}
+
+/// Explains why a component was missing from an entity.
+///
+/// See [`World::explain_missing`](crate::World::explain_missing)
+#[cfg(feature = "diagnostics")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingComponentHint {
+    /// The original error
+    pub missing: MissingComponent,
+    /// Names of the components on the entity which are similar to the missing component
+    pub similar: alloc::vec::Vec<String>,
+    /// The number of archetypes which have the missing component
+    pub archetypes: usize,
+}
+
+#[cfg(feature = "diagnostics")]
+impl Display for MissingComponentHint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.missing, f)?;
+
+        if !self.similar.is_empty() {
+            write!(f, ". Did you mean {}?", self.similar.join(", "))?;
+        }
+
+        match self.archetypes {
+            0 => write!(f, " The component is not present on any entity"),
+            n => write!(f, " The component exists in {n} other archetypes"),
+        }
+    }
+}
+
+/// Returns true if the component names are likely to be confused
+#[cfg(feature = "diagnostics")]
+pub(crate) fn is_similar(a: &str, b: &str) -> bool {
+    if a.contains(b) || b.contains(a) {
+        return true;
+    }
+
+    let a = a.chars().collect::<alloc::vec::Vec<_>>();
+    let b = b.chars().collect::<alloc::vec::Vec<_>>();
+
+    // Levenshtein distance using a single row
+    let mut row = (0..=b.len()).collect::<alloc::vec::Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = (prev + (ca != cb) as usize).min(row[j] + 1).min(cur + 1);
+            prev = cur;
+        }
+    }
+
+    row[b.len()] <= a.len().max(b.len()) / 3
+}
+
 /// Result alias for [crate::error::Result]
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -78,6 +243,37 @@ impl Display for Error {
             Error::EntityOccupied(current) => {
                 write!(f, "Attempt to spawn new entity occupied id {current}")
             }
+            Error::InvalidPath(path) => write!(f, "Invalid property path: {path}"),
+            Error::NoSuchArchetype(arch_id) => write!(f, "Archetype {arch_id} does not exist"),
+            Error::SpawnRejected(reason) => write!(f, "Spawn rejected: {reason}"),
+            Error::ConflictingAccess(desc) => {
+                write!(f, "Conflicting access to component {}", desc.name())
+            }
+            Error::Context(operation, inner) => write!(f, "Failed to {operation}: {inner}"),
+            Error::NoUniqueComponent(ty) => {
+                write!(f, "No unique component is registered for the type {ty}")
+            }
+            Error::ImmutableComponent(desc) => {
+                write!(f, "Component {} is immutable", desc.name())
+            }
+            Error::NoLockComponent(desc) => {
+                write!(
+                    f,
+                    "Component {} is not locked and can not be written",
+                    desc.name()
+                )
+            }
+            Error::ExclusiveRelation(id, desc) => {
+                write!(f, "Entity {id} already has the exclusive relation {desc:?}")
+            }
+            Error::RequiredComponent(id, desc, dependent) => write!(
+                f,
+                "Component {} of entity {id} is required by {}",
+                desc.name(),
+                dependent.name()
+            ),
+            Error::AccessConflict(inner) => Display::fmt(inner, f),
+            Error::ConflictingAccessKind(kind) => write!(f, "Conflicting access to {kind:?}"),
         }
     }
 }
@@ -91,3 +287,28 @@ impl Display for MissingComponent {
         )
     }
 }
+
+impl Display for AccessConflict {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let kind = if self.mutable { "mutably" } else { "immutably" };
+        write!(
+            f,
+            "Can not borrow the component {:?} {kind} in archetype {}, as it is already borrowed",
+            self.desc, self.arch_id
+        )
+    }
+}
+
+#[cfg(all(test, feature = "diagnostics"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn similar_names() {
+        assert!(is_similar("position", "positon"));
+        assert!(is_similar("velocity", "angular_velocity"));
+        assert!(is_similar("health", "heath"));
+        assert!(!is_similar("health", "name"));
+        assert!(!is_similar("a", "b"));
+    }
+}
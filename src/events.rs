@@ -2,11 +2,12 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 
 use crate::{
-    archetype::{Archetype, Slice, Storage},
+    archetype::{Archetype, ArchetypeId, ChangeKind, Slice, Storage},
     component::{ComponentDesc, ComponentKey, ComponentValue},
+    entity::EntityKind,
     filter::StaticFilter,
     sink::Sink,
-    Component, Entity,
+    Component, Entity, World,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,6 +32,28 @@ pub enum EventKind {
     Modified,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Represents an entity being spawned or despawned, as opposed to the events of its components
+pub struct EntityEvent {
+    /// The affected entity
+    pub id: Entity,
+    /// The kind of the affected entity
+    pub entity_kind: EntityKind,
+    /// The archetype the entity was spawned into, or despawned from
+    pub arch_id: ArchetypeId,
+    /// The type of event
+    pub kind: EntityEventKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The type of entity event
+pub enum EntityEventKind {
+    /// The entity was spawned
+    Spawned,
+    /// The entity was despawned
+    Despawned,
+}
+
 /// Represents the raw form of an event, where the archetype is available
 pub struct EventData<'a> {
     /// The affected entities
@@ -60,9 +83,21 @@ pub trait EventSubscriber: ComponentValue {
     /// Handle an incoming event
     fn on_removed(&self, storage: &Storage, event: &EventData);
 
+    /// Handle an entity being spawned or despawned.
+    ///
+    /// Only called if the subscriber matches the archetype of the entity.
+    #[inline]
+    fn on_entity_event(&self, _: &EntityEvent) {}
+
     /// Returns true if the subscriber is still connected
     fn is_connected(&self) -> bool;
 
+    /// Delivers any events held back by the subscriber.
+    ///
+    /// Called at the end of each [`World::maintain`].
+    #[inline]
+    fn flush(&self) {}
+
     /// Returns true if the subscriber is interested in this archetype
     #[inline]
     fn matches_arch(&self, _: &Archetype) -> bool {
@@ -244,11 +279,20 @@ where
         self.subscriber.on_removed(storage, event)
     }
 
+    fn on_entity_event(&self, event: &EntityEvent) {
+        self.subscriber.on_entity_event(event)
+    }
+
     #[inline]
     fn is_connected(&self) -> bool {
         self.subscriber.is_connected()
     }
 
+    #[inline]
+    fn flush(&self) {
+        self.subscriber.flush()
+    }
+
     #[inline]
     fn matches_arch(&self, arch: &Archetype) -> bool {
         self.filter.filter_static(arch) && self.subscriber.matches_arch(arch)
@@ -289,6 +333,10 @@ where
         }
     }
 
+    fn on_entity_event(&self, event: &EntityEvent) {
+        self.subscriber.on_entity_event(event)
+    }
+
     #[inline]
     fn matches_arch(&self, arch: &Archetype) -> bool {
         self.subscriber.matches_arch(arch)
@@ -303,6 +351,11 @@ where
     fn is_connected(&self) -> bool {
         self.subscriber.is_connected()
     }
+
+    #[inline]
+    fn flush(&self) {
+        self.subscriber.flush()
+    }
 }
 
 /// Filter a subscriber to only receive events for a specific set of components
@@ -327,6 +380,10 @@ where
         self.subscriber.on_removed(storage, event)
     }
 
+    fn on_entity_event(&self, event: &EntityEvent) {
+        self.subscriber.on_entity_event(event)
+    }
+
     #[inline]
     fn matches_arch(&self, arch: &Archetype) -> bool {
         self.components.iter().any(|&key| arch.has(key)) && self.subscriber.matches_arch(arch)
@@ -341,4 +398,240 @@ where
     fn is_connected(&self) -> bool {
         self.subscriber.is_connected()
     }
+
+    #[inline]
+    fn flush(&self) {
+        self.subscriber.flush()
+    }
+}
+
+/// Receive the spawn and despawn events of entities, without the events of their components.
+pub struct EntityEvents<S> {
+    sink: S,
+}
+
+impl<S> EntityEvents<S> {
+    /// Create a new `EntityEvents` subscriber
+    pub fn new(sink: S) -> Self {
+        Self { sink }
+    }
+}
+
+impl<S: 'static + Send + Sync + Sink<EntityEvent>> EventSubscriber for EntityEvents<S> {
+    fn on_added(&self, _: &Storage, _: &EventData) {}
+
+    fn on_modified(&self, _: &EventData) {}
+
+    fn on_removed(&self, _: &Storage, _: &EventData) {}
+
+    fn on_entity_event(&self, event: &EntityEvent) {
+        self.sink.send(event.clone())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.sink.is_connected()
+    }
+
+    fn matches_component(&self, _: ComponentDesc) -> bool {
+        false
+    }
+}
+
+/// Coalesces the events of a frame into a single deduplicated set, which is sent to the inner
+/// sink at the next [`World::maintain`].
+///
+/// Each entity and component is reported at most once per frame with the latest kind of change,
+/// where a component which was added and then modified is reported as added, and a component
+/// which was both added and removed during the frame is not reported at all.
+#[cfg(feature = "std")]
+pub struct Coalesce<S> {
+    pending: std::sync::Mutex<alloc::collections::BTreeMap<(Entity, ComponentKey), Pending>>,
+    sink: S,
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct Pending {
+    first: EventKind,
+    latest: EventKind,
+}
+
+#[cfg(feature = "std")]
+impl<S> Coalesce<S> {
+    /// Create a new `Coalesce` subscriber which sends the coalesced events to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            pending: Default::default(),
+            sink,
+        }
+    }
+
+    fn record(&self, event: &EventData, kind: EventKind) {
+        let mut pending = self.pending.lock().unwrap();
+        for &id in event.ids {
+            pending
+                .entry((id, event.key))
+                .and_modify(|v| v.latest = kind)
+                .or_insert(Pending {
+                    first: kind,
+                    latest: kind,
+                });
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: 'static + Send + Sync + Sink<Event>> EventSubscriber for Coalesce<S> {
+    fn on_added(&self, _: &Storage, event: &EventData) {
+        self.record(event, EventKind::Added)
+    }
+
+    fn on_modified(&self, event: &EventData) {
+        self.record(event, EventKind::Modified)
+    }
+
+    fn on_removed(&self, _: &Storage, event: &EventData) {
+        self.record(event, EventKind::Removed)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.sink.is_connected()
+    }
+
+    fn flush(&self) {
+        let pending = core::mem::take(&mut *self.pending.lock().unwrap());
+        for ((id, key), v) in pending {
+            let kind = match (v.first, v.latest) {
+                (EventKind::Added, EventKind::Removed) => continue,
+                (EventKind::Added, _) => EventKind::Added,
+                (_, latest) => latest,
+            };
+
+            self.sink.send(Event { id, key, kind });
+        }
+    }
+}
+
+/// A cursor over the changes of a component, which is polled by an external consumer.
+///
+/// This is a pull based alternative to [`EventSubscriber`], suitable for job based engines which
+/// process changes in batches rather than as they happen. Each call to [`Self::poll`] returns the
+/// entities whose component changed since the previous poll, along with the tick of the change.
+///
+/// Changes are read from the change tracking of the archetypes, and as such multiple changes to
+/// the same entity are coalesced into the latest one.
+///
+/// **Note**: removals are not tracked by the archetypes, use an [`EventSubscriber`] instead.
+#[derive(Clone)]
+pub struct ChangeFeed<T> {
+    component: Component<T>,
+    kind: ChangeKind,
+    cursor: u32,
+    max_retained: Option<u32>,
+    dropped: usize,
+}
+
+impl<T: ComponentValue> core::fmt::Debug for ChangeFeed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChangeFeed")
+            .field("component", &self.component)
+            .field("kind", &self.kind)
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ComponentValue> ChangeFeed<T> {
+    fn new(component: Component<T>, kind: ChangeKind) -> Self {
+        Self {
+            component,
+            kind,
+            cursor: 0,
+            max_retained: None,
+            dropped: 0,
+        }
+    }
+
+    /// Creates a feed which yields entities where `component` was added or modified.
+    ///
+    /// Modifications are only tracked once the component is queried for modification, which
+    /// happens on the first poll.
+    pub fn modified(component: Component<T>) -> Self {
+        Self::new(component, ChangeKind::Modified)
+    }
+
+    /// Creates a feed which yields entities where `component` was added.
+    pub fn added(component: Component<T>) -> Self {
+        Self::new(component, ChangeKind::Added)
+    }
+
+    /// Limits how far the consumer may fall behind the world, in change ticks.
+    ///
+    /// Changes which are older than `ticks` at the time of polling are discarded rather than
+    /// delivered in a single large batch, and counted by [`Self::dropped`].
+    pub fn with_max_retained(mut self, ticks: u32) -> Self {
+        self.max_retained = Some(ticks);
+        self
+    }
+
+    /// Returns the change tick up to which changes have been consumed
+    pub fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    /// Returns the total number of changes discarded because the consumer fell behind
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Returns the changes since the last poll in ascending tick order, and advances the cursor.
+    pub fn poll(&mut self, world: &World) -> Vec<(Entity, u32)> {
+        let mut records = Vec::new();
+        self.poll_into(world, &mut records);
+        records
+    }
+
+    /// Appends the changes since the last poll to `records`, and advances the cursor.
+    ///
+    /// Returns the number of appended changes.
+    pub fn poll_into(&mut self, world: &World, records: &mut Vec<(Entity, u32)>) -> usize {
+        let key = self.component.key();
+        let change_tick = world.change_tick();
+
+        let retained_since = match self.max_retained {
+            Some(max) => self.cursor.max(change_tick.saturating_sub(max)),
+            None => self.cursor,
+        };
+
+        let start = records.len();
+        for (_, arch) in world.archetypes.iter() {
+            let Some(cell) = arch.cell(key) else {
+                continue;
+            };
+
+            let data = cell.data.borrow();
+            let changes = &data.changes;
+            if self.kind.is_modified() {
+                changes.set_track_modified();
+            }
+
+            for (slot, tick) in changes.get(self.kind).iter_collapsed() {
+                if tick <= self.cursor {
+                    continue;
+                }
+
+                if tick <= retained_since {
+                    self.dropped += 1;
+                    continue;
+                }
+
+                records.push((arch.entities()[slot], tick));
+            }
+        }
+
+        records[start..].sort_by_key(|&(_, tick)| tick);
+        self.cursor = change_tick;
+
+        records.len() - start
+    }
 }
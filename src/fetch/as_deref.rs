@@ -1,4 +1,4 @@
-use super::{FetchAccessData, FmtQuery, PreparedFetch, RandomFetch};
+use super::{FetchAccessData, FmtQuery, PreparedFetch, RandomFetch, TrustedAccess};
 use crate::{query::ArchetypeSearcher, system::Access, Fetch, FetchItem};
 use alloc::vec::Vec;
 use core::{fmt, ops::Deref};
@@ -14,6 +14,8 @@ where
     type Item = &'q V::Target;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for AsDeref<F> {}
+
 impl<'w, F, V> Fetch<'w> for AsDeref<F>
 where
     F: Fetch<'w>,
@@ -64,7 +66,10 @@ where
         self.0.filter_slots(slots)
     }
 
-    unsafe fn create_chunk(&'q mut self, slots: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: crate::archetype::Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.0.create_chunk(slots)
     }
 
@@ -72,6 +77,10 @@ where
     unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
         F::fetch_next(chunk)
     }
+
+    fn prefetch(&self, slots: crate::archetype::Slice) {
+        self.0.prefetch(slots)
+    }
 }
 
 impl<'q, F, V> RandomFetch<'q> for AsDeref<F>
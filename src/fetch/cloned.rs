@@ -11,7 +11,9 @@ use crate::{
     Fetch, FetchItem,
 };
 
-use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TransformFetch};
+use super::{
+    FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TransformFetch, TrustedAccess,
+};
 
 #[derive(Debug, Clone)]
 /// Component which cloned the value.
@@ -29,6 +31,8 @@ where
     type Item = <<F as FetchItem<'q>>::Item as Deref>::Target;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for Cloned<F> {}
+
 impl<'w, F> Fetch<'w> for Cloned<F>
 where
     F: Fetch<'w>,
@@ -75,7 +79,10 @@ where
 
     const HAS_FILTER: bool = F::HAS_FILTER;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.0.create_chunk(slots)
     }
 
@@ -86,6 +93,10 @@ where
     unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
         self.0.filter_slots(slots)
     }
+
+    fn prefetch(&self, slots: Slice) {
+        self.0.prefetch(slots)
+    }
 }
 
 impl<'q, V, F> RandomFetch<'q> for Cloned<F>
@@ -1,12 +1,21 @@
 use atomic_refcell::AtomicRef;
 
-use crate::{archetype::Slot, component::ComponentValue, system::AccessKind, util::Ptr, Component};
+use crate::{
+    archetype::{CellData, Slot},
+    component::ComponentValue,
+    system::AccessKind,
+    util::{prefetch, Ptr},
+    Component,
+};
 
 use super::{read_only::RandomFetch, *};
 
 #[doc(hidden)]
 pub struct ReadComponent<'a, T> {
-    borrow: AtomicRef<'a, [T]>,
+    borrow: &'a [T],
+    /// Keeps the storage borrowed, unless the component is declared `NoLock` or the world is
+    /// borrowed exclusively
+    _guard: Option<AtomicRef<'a, CellData>>,
 }
 
 impl<'w, 'q, T: 'q> PreparedFetch<'q> for ReadComponent<'w, T> {
@@ -17,7 +26,10 @@ impl<'w, 'q, T: 'q> PreparedFetch<'q> for ReadComponent<'w, T> {
     const HAS_FILTER: bool = false;
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Ptr::new(self.borrow[slots.as_range()].as_ptr())
     }
 
@@ -28,6 +40,13 @@ impl<'w, 'q, T: 'q> PreparedFetch<'q> for ReadComponent<'w, T> {
         chunk.advance(1);
         &*old
     }
+
+    #[inline]
+    fn prefetch(&self, slots: Slice) {
+        if let Some(v) = self.borrow.get(slots.start) {
+            prefetch(v)
+        }
+    }
 }
 
 impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for ReadComponent<'w, T> {
@@ -42,6 +61,8 @@ impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for ReadComponent<'w, T> {
     }
 }
 
+unsafe impl<T: ComponentValue> TrustedAccess for Component<T> {}
+
 impl<'w, T> Fetch<'w> for Component<T>
 where
     T: ComponentValue,
@@ -52,9 +73,10 @@ where
 
     #[inline]
     fn prepare(&self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
-        let borrow = data.arch.borrow(self.key())?;
+        let (guard, borrow) = data.arch.cell(self.key())?.borrow_shared(data.exclusive);
         Some(ReadComponent {
-            borrow: borrow.into_inner(),
+            borrow,
+            _guard: guard,
         })
     }
 
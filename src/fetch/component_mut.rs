@@ -6,17 +6,19 @@ use crate::{
     archetype::{Archetype, CellMutGuard, Slice},
     component::ComponentValue,
     system::{Access, AccessKind},
-    util::PtrMut,
+    util::{prefetch, PtrMut},
     Component, Fetch, FetchItem,
 };
 
-use super::{FetchAccessData, FetchPrepareData, PreparedFetch};
+use super::{FetchAccessData, FetchPrepareData, PreparedFetch, TrustedAccess};
 
 #[derive(Debug, Clone)]
 /// Mutable component fetch
 /// See [crate::Component::as_mut]
 pub struct Mutable<T>(pub(crate) Component<T>);
 
+unsafe impl<T: ComponentValue> TrustedAccess for Mutable<T> {}
+
 impl<'w, T> Fetch<'w> for Mutable<T>
 where
     T: ComponentValue,
@@ -81,7 +83,10 @@ impl<'w, 'q, T: 'q + ComponentValue> PreparedFetch<'q> for WriteComponent<'w, T>
     
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.guard
             .set_modified(&self.arch.entities[slots.as_range()], slots, self.tick);
 
@@ -96,4 +101,13 @@ impl<'w, 'q, T: 'q + ComponentValue> PreparedFetch<'q> for WriteComponent<'w, T>
         chunk.advance(1);
         &mut *old
     }
+
+    #[inline]
+    fn prefetch(&self, slots: Slice) {
+        let storage = self.guard.storage();
+        if slots.start < storage.len() {
+            // Prefetching does not access the value, and can not alias the existing chunks
+            prefetch(unsafe { (storage.as_ptr() as *const T).add(slots.start) })
+        }
+    }
 }
@@ -7,7 +7,9 @@ use alloc::vec::Vec;
 
 use crate::{archetype::Slice, system::Access, Fetch, FetchItem};
 
-use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TransformFetch};
+use super::{
+    FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TransformFetch, TrustedAccess,
+};
 
 #[derive(Debug, Clone)]
 /// Component which copied the value.
@@ -25,6 +27,8 @@ where
     type Item = <<F as FetchItem<'q>>::Item as Deref>::Target;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for Copied<F> {}
+
 impl<'w, F> Fetch<'w> for Copied<F>
 where
     F: Fetch<'w>,
@@ -72,7 +76,10 @@ where
 
     const HAS_FILTER: bool = F::HAS_FILTER;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.0.create_chunk(slots)
     }
 
@@ -83,6 +90,10 @@ where
     unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
         self.0.filter_slots(slots)
     }
+
+    fn prefetch(&self, slots: Slice) {
+        self.0.prefetch(slots)
+    }
 }
 
 impl<'q, F, V> RandomFetch<'q> for Copied<F>
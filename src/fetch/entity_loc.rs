@@ -0,0 +1,132 @@
+use core::fmt::{self, Formatter};
+
+use alloc::vec::Vec;
+
+use crate::{
+    archetype::{ArchetypeId, Slice, Slot},
+    system::Access,
+    util::Ptr,
+    Entity, Fetch, FetchItem,
+};
+
+use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess};
+
+/// The location of an entity in the world at the time it was fetched.
+///
+/// This allows external lookup tables, such as picking buffers, to refer back into the world
+/// without a search through [`World::get_at_loc`](crate::World::get_at_loc).
+///
+/// The location is invalidated when the entity moves to another archetype or within its
+/// archetype, such as when another entity is despawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntityLoc {
+    /// The entity
+    pub id: Entity,
+    /// The archetype the entity resided in
+    pub arch_id: ArchetypeId,
+    /// The slot of the entity in the archetype
+    pub slot: Slot,
+}
+
+/// Yields the [`EntityLoc`] of each entity
+#[derive(Debug, Clone)]
+pub struct EntityLocs;
+
+/// Yields the [`EntityLoc`] of each entity
+pub fn entity_locs() -> EntityLocs {
+    EntityLocs
+}
+
+#[doc(hidden)]
+pub struct PreparedEntityLocs<'a> {
+    arch_id: ArchetypeId,
+    entities: &'a [Entity],
+}
+
+#[doc(hidden)]
+pub struct EntityLocChunk<'q> {
+    arch_id: ArchetypeId,
+    entities: Ptr<'q, Entity>,
+    slot: Slot,
+}
+
+impl<'q> FetchItem<'q> for EntityLocs {
+    type Item = EntityLoc;
+}
+
+unsafe impl TrustedAccess for EntityLocs {}
+
+impl<'w> Fetch<'w> for EntityLocs {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedEntityLocs<'w>;
+
+    fn prepare(&self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        Some(PreparedEntityLocs {
+            arch_id: data.arch_id,
+            entities: data.arch.entities(),
+        })
+    }
+
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        true
+    }
+
+    fn describe(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("entity_locs")
+    }
+
+    #[inline]
+    fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+}
+
+impl<'w, 'q> PreparedFetch<'q> for PreparedEntityLocs<'w> {
+    type Item = EntityLoc;
+    type Chunk = EntityLocChunk<'q>;
+
+    const HAS_FILTER: bool = false;
+
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        EntityLocChunk {
+            arch_id: self.arch_id,
+            entities: Ptr::new(self.entities[slots.as_range()].as_ptr()),
+            slot: slots.start,
+        }
+    }
+
+    unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
+        let id = *chunk.entities.as_ptr();
+        let slot = chunk.slot;
+
+        chunk.entities.advance(1);
+        chunk.slot += 1;
+
+        EntityLoc {
+            id,
+            arch_id: chunk.arch_id,
+            slot,
+        }
+    }
+}
+
+impl<'w, 'q> RandomFetch<'q> for PreparedEntityLocs<'w> {
+    #[inline]
+    unsafe fn fetch_shared(&self, slot: usize) -> Self::Item {
+        EntityLoc {
+            id: self.entities[slot],
+            arch_id: self.arch_id,
+            slot,
+        }
+    }
+
+    unsafe fn fetch_shared_chunk(chunk: &Self::Chunk, slot: Slot) -> Self::Item {
+        EntityLoc {
+            id: *chunk.entities.add(slot).as_ref(),
+            arch_id: chunk.arch_id,
+            slot: chunk.slot + slot,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     EntityRef, Fetch, FetchItem, World,
 };
 
-use super::{FetchAccessData, PreparedFetch};
+use super::{FetchAccessData, PreparedFetch, TrustedAccess};
 
 /// Access all components dynamically in a query
 pub struct EntityRefs;
@@ -22,6 +22,8 @@ impl<'q> FetchItem<'q> for EntityRefs {
     type Item = EntityRef<'q>;
 }
 
+unsafe impl TrustedAccess for EntityRefs {}
+
 impl<'w> Fetch<'w> for EntityRefs {
     ///  False since just having an `EntityRef` does not cause any mutation.
     ///
@@ -76,7 +78,10 @@ impl<'w, 'q> PreparedFetch<'q> for PreparedEntityRef<'w> {
     type Chunk = Batch<'q>;
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slice: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slice: crate::archetype::Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Batch {
             world: self.world,
             arch: self.arch,
@@ -106,9 +111,7 @@ impl<'w, 'q> PreparedFetch<'q> for PreparedEntityRef<'w> {
 mod test {
     use itertools::Itertools;
 
-    use crate::{
-        component, components::name, BatchSpawn, Entity, EntityIds, FetchExt, Query, World,
-    };
+    use crate::{components::name, BatchSpawn, Entity, EntityIds, FetchExt, Query, World};
 
     #[test]
     fn entity_refs_chunks() {
@@ -125,6 +128,7 @@ mod test {
         let mut query = Query::new(super::EntityRefs).filter(a().ge(0));
         let res = query
             .borrow(&world)
+            .unwrap()
             .iter()
             .map(|v| v.get_copy(a()).unwrap())
             .collect_vec();
@@ -158,13 +162,13 @@ mod test {
             Query::new((EntityIds, health().copied())).filter(health().modified());
 
         assert_eq!(
-            health_changed.borrow(&world).iter().collect_vec(),
+            health_changed.borrow(&world).unwrap().iter().collect_vec(),
             [(b, 50.0), (c, 100.0)]
         );
 
         let mut query = Query::new(super::EntityRefs);
 
-        for entity in &mut query.borrow(&world) {
+        for entity in &mut query.borrow(&world).unwrap() {
             if entity.has(mortal()) {
                 if let Ok(mut health) = entity.get_mut(health()) {
                     *health *= 0.5;
@@ -173,10 +177,13 @@ mod test {
         }
 
         assert_eq!(
-            health_changed.borrow(&world).iter().collect_vec(),
+            health_changed.borrow(&world).unwrap().iter().collect_vec(),
             [(c, 50.0)]
         );
 
-        assert_eq!(health_changed.borrow(&world).iter().collect_vec(), []);
+        assert_eq!(
+            health_changed.borrow(&world).unwrap().iter().collect_vec(),
+            []
+        );
     }
 }
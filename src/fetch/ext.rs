@@ -1,6 +1,6 @@
 use crate::{
     component::ComponentValue,
-    filter::{Cmp, Equal, Filtered, Greater, GreaterEq, Less, LessEq},
+    filter::{Cmp, Equal, Filtered, Greater, GreaterEq, Less, LessEq, NotEqual},
     relation::RelationExt,
     Fetch, FetchItem,
 };
@@ -91,7 +91,7 @@ pub trait FetchExt: Sized {
     /// Filter any component greater than `other`.
     fn gt<T>(self, other: T) -> Cmp<Self, Greater<T>>
     where
-        for<'x> Cmp<Self, GreaterEq<T>>: Fetch<'x>,
+        for<'x> Cmp<Self, Greater<T>>: Fetch<'x>,
     {
         Cmp::new(self, Greater(other))
     }
@@ -116,6 +116,13 @@ pub trait FetchExt: Sized {
     {
         Cmp::new(self, Equal(other))
     }
+    /// Filter any component not equal to `other`.
+    fn ne<T>(self, other: T) -> Cmp<Self, NotEqual<T>>
+    where
+        for<'x> Cmp<Self, NotEqual<T>>: Fetch<'x>,
+    {
+        Cmp::new(self, NotEqual(other))
+    }
 
     /// Set the source entity for the fetch.
     ///
@@ -204,7 +211,7 @@ pub trait FetchExt: Sized {
     where
         F: for<'x> Fetch<'x>,
     {
-        Filtered::new(self, filter, true)
+        Filtered::new(self, filter, true, true, true)
     }
 }
 
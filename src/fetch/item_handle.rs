@@ -0,0 +1,148 @@
+use core::fmt::{self, Formatter};
+
+use alloc::vec::Vec;
+
+use crate::{
+    archetype::{ArchetypeId, Slice, Slot},
+    system::Access,
+    util::Ptr,
+    Entity, Fetch, FetchItem,
+};
+
+use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess};
+
+/// A handle to a query result which can be cheaply revalidated.
+///
+/// The handle captures the archetype the entity was matched in. As long as the entity remains
+/// in the same archetype the archetype level filters of the query still hold, which allows
+/// selections in a UI to persist across frames using [`World::revalidate`] rather than
+/// re-running the query for each item.
+///
+/// **Note**: per-slot filters, such as change filters and value comparisons, are not revalidated.
+///
+/// [`World::revalidate`]: crate::World::revalidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QueryItemHandle {
+    pub(crate) id: Entity,
+    pub(crate) arch_id: ArchetypeId,
+    pub(crate) tick: u32,
+}
+
+impl QueryItemHandle {
+    /// Returns the entity
+    pub fn id(&self) -> Entity {
+        self.id
+    }
+
+    /// Returns the archetype the entity was matched in
+    pub fn arch_id(&self) -> ArchetypeId {
+        self.arch_id
+    }
+
+    /// Returns the world change tick at which the handle was created or last revalidated
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+}
+
+/// Yields a [`QueryItemHandle`] for each entity
+#[derive(Debug, Clone)]
+pub struct ItemHandles;
+
+/// Yields a [`QueryItemHandle`] for each entity
+pub fn item_handles() -> ItemHandles {
+    ItemHandles
+}
+
+#[doc(hidden)]
+pub struct PreparedItemHandles<'a> {
+    arch_id: ArchetypeId,
+    entities: &'a [Entity],
+    tick: u32,
+}
+
+#[doc(hidden)]
+pub struct ItemHandleChunk<'q> {
+    arch_id: ArchetypeId,
+    entities: Ptr<'q, Entity>,
+    tick: u32,
+}
+
+impl<'q> FetchItem<'q> for ItemHandles {
+    type Item = QueryItemHandle;
+}
+
+unsafe impl TrustedAccess for ItemHandles {}
+
+impl<'w> Fetch<'w> for ItemHandles {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedItemHandles<'w>;
+
+    fn prepare(&self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        Some(PreparedItemHandles {
+            arch_id: data.arch_id,
+            entities: data.arch.entities(),
+            tick: data.new_tick,
+        })
+    }
+
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        true
+    }
+
+    fn describe(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("item_handles")
+    }
+
+    #[inline]
+    fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+}
+
+impl<'w, 'q> PreparedFetch<'q> for PreparedItemHandles<'w> {
+    type Item = QueryItemHandle;
+    type Chunk = ItemHandleChunk<'q>;
+
+    const HAS_FILTER: bool = false;
+
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        ItemHandleChunk {
+            arch_id: self.arch_id,
+            entities: Ptr::new(self.entities[slots.as_range()].as_ptr()),
+            tick: self.tick,
+        }
+    }
+
+    unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
+        let id = *chunk.entities.as_ptr();
+        chunk.entities.advance(1);
+
+        QueryItemHandle {
+            id,
+            arch_id: chunk.arch_id,
+            tick: chunk.tick,
+        }
+    }
+}
+
+impl<'w, 'q> RandomFetch<'q> for PreparedItemHandles<'w> {
+    #[inline]
+    unsafe fn fetch_shared(&self, slot: Slot) -> Self::Item {
+        QueryItemHandle {
+            id: self.entities[slot],
+            arch_id: self.arch_id,
+            tick: self.tick,
+        }
+    }
+
+    unsafe fn fetch_shared_chunk(chunk: &Self::Chunk, slot: Slot) -> Self::Item {
+        QueryItemHandle {
+            id: *chunk.entities.add(slot).as_ref(),
+            arch_id: chunk.arch_id,
+            tick: chunk.tick,
+        }
+    }
+}
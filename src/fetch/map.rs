@@ -61,7 +61,7 @@ where
     }
 }
 
-impl<'w, 'q, Q, F, T> PreparedFetch<'q> for Map<Q, &'w F>
+impl<'q, Q, F, T> PreparedFetch<'q> for Map<Q, &F>
 where
     Q: PreparedFetch<'q>,
     F: Fn(Q::Item) -> T,
@@ -74,7 +74,10 @@ where
 
     const HAS_FILTER: bool = Q::HAS_FILTER;
 
-    unsafe fn create_chunk(&'q mut self, slots: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: crate::archetype::Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         (self.func, self.query.create_chunk(slots))
     }
 
@@ -1,6 +1,9 @@
-use alloc::vec::Vec;
-use atomic_refcell::AtomicRef;
-use core::marker::PhantomData;
+use alloc::{boxed::Box, collections::BTreeSet, sync::Arc, vec::Vec};
+use atomic_refcell::{AtomicRef, AtomicRefCell};
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
 
 use crate::{
     archetype::{Cell, RefMut, Slot},
@@ -9,7 +12,7 @@ use crate::{
     Component, Entity, Fetch, FetchItem,
 };
 
-use super::{FetchAccessData, PreparedFetch, RandomFetch};
+use super::{FetchAccessData, PreparedFetch, RandomFetch, TrustedAccess};
 
 /// A query for conservative mutablility.
 ///
@@ -18,12 +21,86 @@ use super::{FetchAccessData, PreparedFetch, RandomFetch};
 ///
 /// Implements `ReadOnlyFetch` as the mutation is explicit and the returned reference is limited
 /// to the loop body, rather than the iterator.
-pub struct MaybeMut<T>(pub(crate) Component<T>);
+pub struct MaybeMut<T>(pub(crate) Component<T>, pub(crate) Option<WriteTracker>);
+
+impl<T> MaybeMut<T> {
+    /// Records the entities which are written through [`MutGuard::write`] into `tracker` when
+    /// the query borrow ends.
+    pub fn track_writes(mut self, tracker: &WriteTracker) -> Self {
+        self.1 = Some(tracker.clone());
+        self
+    }
+}
+
+/// Records the entities which were written through a [`MaybeMut`] fetch.
+///
+/// An entity is only recorded if the [`RefMut`] returned by [`MutGuard::write`] was mutably
+/// dereferenced. Entities accumulate across query borrows until the tracker is cleared, which
+/// allows the same tracker to be reused each frame.
+///
+/// See [`MaybeMut::track_writes`]
+#[derive(Debug, Default, Clone)]
+pub struct WriteTracker {
+    written: Arc<AtomicRefCell<BTreeSet<Entity>>>,
+}
+
+impl WriteTracker {
+    /// Creates a new empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the written entities, in order
+    pub fn written(&self) -> Vec<Entity> {
+        self.written.borrow().iter().copied().collect()
+    }
+
+    /// Returns true if `id` was written
+    pub fn contains(&self, id: Entity) -> bool {
+        self.written.borrow().contains(&id)
+    }
+
+    /// Returns the number of written entities
+    pub fn len(&self) -> usize {
+        self.written.borrow().len()
+    }
+
+    /// Returns true if no entities were written
+    pub fn is_empty(&self) -> bool {
+        self.written.borrow().is_empty()
+    }
+
+    /// Returns and clears the written entities
+    pub fn take(&self) -> Vec<Entity> {
+        core::mem::take(&mut *self.written.borrow_mut())
+            .into_iter()
+            .collect()
+    }
+
+    /// Clears the written entities
+    pub fn clear(&self) {
+        self.written.borrow_mut().clear()
+    }
+
+    fn record(&self, ids: &[Entity], written: &[AtomicU64]) {
+        let mut dst = self.written.borrow_mut();
+        for (i, word) in written.iter().enumerate() {
+            let mut word = word.load(Relaxed);
+            while word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                dst.insert(ids[i * 64 + bit]);
+            }
+        }
+    }
+}
 
 impl<'q, T: ComponentValue> FetchItem<'q> for MaybeMut<T> {
     type Item = MutGuard<'q, T>;
 }
 
+unsafe impl<T: ComponentValue> TrustedAccess for MaybeMut<T> {}
+
 impl<'w, T: ComponentValue> Fetch<'w> for MaybeMut<T> {
     const MUTABLE: bool = false;
 
@@ -31,10 +108,17 @@ impl<'w, T: ComponentValue> Fetch<'w> for MaybeMut<T> {
 
     fn prepare(&'w self, data: super::FetchPrepareData<'w>) -> Option<Self::Prepared> {
         let cell = data.arch.cell(self.0.key())?;
+        let tracker = self.1.as_ref();
         Some(PreparedMaybeMut {
             cell,
             new_tick: data.new_tick,
             entities: data.arch.entities(),
+            written: tracker.map(|_| {
+                (0..data.arch.len().div_ceil(64))
+                    .map(|_| AtomicU64::new(0))
+                    .collect()
+            }),
+            tracker,
             _marker: PhantomData,
         })
     }
@@ -64,7 +148,7 @@ impl<'w, T: ComponentValue> Fetch<'w> for MaybeMut<T> {
         searcher.add_required(self.0.key())
     }
 
-    fn by_ref(&self) -> crate::filter::RefFetch<Self>
+    fn by_ref(&self) -> crate::filter::RefFetch<'_, Self>
     where
         Self: Sized,
     {
@@ -76,13 +160,25 @@ pub struct PreparedMaybeMut<'w, T> {
     cell: &'w Cell,
     new_tick: u32,
     entities: &'w [Entity],
+    /// Bitset of the written slots
+    written: Option<Box<[AtomicU64]>>,
+    tracker: Option<&'w WriteTracker>,
     _marker: PhantomData<T>,
 }
 
+impl<'w, T> Drop for PreparedMaybeMut<'w, T> {
+    fn drop(&mut self) {
+        if let (Some(tracker), Some(written)) = (self.tracker, &self.written) {
+            tracker.record(self.entities, written);
+        }
+    }
+}
+
 pub struct Batch<'a> {
     cell: &'a Cell,
     new_tick: u32,
     ids: &'a [Entity],
+    written: Option<&'a [AtomicU64]>,
     slot: Slot,
 }
 
@@ -92,11 +188,19 @@ impl<'w, 'q, T: ComponentValue> PreparedFetch<'q> for PreparedMaybeMut<'w, T> {
 
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slice: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slice: crate::archetype::Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Batch {
             cell: self.cell,
             new_tick: self.new_tick,
             ids: self.entities,
+            // Safety: the bitset is not dropped while the chunk is alive, see `PreparedFetch`
+            written: self
+                .written
+                .as_deref()
+                .map(|v| unsafe { &*(v as *const [AtomicU64]) }),
             slot: slice.start,
         }
     }
@@ -110,6 +214,7 @@ impl<'w, 'q, T: ComponentValue> PreparedFetch<'q> for PreparedMaybeMut<'w, T> {
             cell: chunk.cell,
             new_tick: chunk.new_tick,
             id: *chunk.ids.get_unchecked(slot),
+            written: chunk.written,
             _marker: PhantomData,
         }
     }
@@ -123,6 +228,7 @@ impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for PreparedMaybeMut<'w, T> {
             cell: self.cell,
             new_tick: self.new_tick,
             id: self.entities[slot],
+            written: self.written.as_deref(),
             _marker: PhantomData,
         }
     }
@@ -133,6 +239,7 @@ impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for PreparedMaybeMut<'w, T> {
             cell: chunk.cell,
             new_tick: chunk.new_tick,
             id: chunk.ids[slot],
+            written: chunk.written,
             _marker: PhantomData,
         }
     }
@@ -146,12 +253,13 @@ pub struct MutGuard<'w, T> {
     id: Entity,
     cell: &'w Cell,
     new_tick: u32,
+    written: Option<&'w [AtomicU64]>,
     _marker: PhantomData<T>,
 }
 
 impl<'w, T: ComponentValue> MutGuard<'w, T> {
     /// Acquire a shared reference to the current value without triggering a change
-    pub fn read(&self) -> AtomicRef<T> {
+    pub fn read(&self) -> AtomicRef<'_, T> {
         // Type is guaranteed by fetch constructor
         unsafe { self.cell.get(self.slot).unwrap() }
     }
@@ -159,10 +267,57 @@ impl<'w, T: ComponentValue> MutGuard<'w, T> {
     /// Acquire a mutable reference to the current value.
     ///
     /// Triggers a change
-    pub fn write(&self) -> RefMut<T> {
+    pub fn write(&self) -> RefMut<'_, T> {
         // Type is guaranteed by constructor
         self.cell
-            .get_mut(self.id, self.slot, self.new_tick)
+            .get_mut::<T>(self.id, self.slot, self.new_tick)
             .unwrap()
+            .track_written(self.written)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{entity_ids, Entity, Query, World};
+
+    use super::*;
+
+    component! {
+        health: f32,
+    }
+
+    #[test]
+    fn write_tracker() {
+        let mut world = World::new();
+        let ids = (0..100)
+            .map(|i| Entity::builder().set(health(), i as f32).spawn(&mut world))
+            .collect::<Vec<_>>();
+
+        let tracker = WriteTracker::new();
+        let mut query = Query::new((entity_ids(), health().maybe_mut().track_writes(&tracker)));
+
+        for (id, health) in &mut query.borrow(&world).unwrap() {
+            // Acquiring the guard does not count as a write
+            let mut value = health.write();
+            if *value >= 70.0 {
+                *value -= 1.0;
+            }
+
+            if id == ids[3] {
+                *value = 0.0;
+            }
+        }
+
+        assert_eq!(tracker.len(), 31);
+        assert!(tracker.contains(ids[3]));
+        assert!(!tracker.contains(ids[4]));
+        assert_eq!(tracker.take()[1..], ids[70..]);
+        assert!(tracker.is_empty());
+
+        for (_, health) in &mut query.borrow(&world).unwrap() {
+            let _ = health.read();
+        }
+
+        assert!(tracker.is_empty());
     }
 }
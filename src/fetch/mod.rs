@@ -3,8 +3,10 @@ mod cloned;
 mod component;
 mod component_mut;
 mod copied;
+mod entity_loc;
 mod entity_ref;
 mod ext;
+mod item_handle;
 mod map;
 mod maybe_mut;
 mod opt;
@@ -29,13 +31,18 @@ pub use as_deref::*;
 pub use cloned::*;
 pub use component::*;
 pub use component_mut::*;
+pub use entity_loc::{entity_locs, EntityLoc, EntityLocs};
 pub use entity_ref::*;
 pub use ext::FetchExt;
+pub use item_handle::{item_handles, ItemHandles, QueryItemHandle};
 pub use map::Map;
-pub use maybe_mut::{MaybeMut, MutGuard};
+pub use maybe_mut::{MaybeMut, MutGuard, WriteTracker};
 pub use opt::*;
 pub use read_only::*;
-pub use relations::{nth_relation, relations_like, NthRelation, Relations, RelationsIter};
+pub use relations::{
+    nth_relation, relations_like, relations_like_mut, NthRelation, Relations, RelationsIter,
+    RelationsIterMut, RelationsMut,
+};
 pub use satisfied::Satisfied;
 pub use source::Source;
 pub use transform::{Added, Modified, TransformFetch};
@@ -86,6 +93,11 @@ pub struct FetchPrepareData<'w> {
     pub old_tick: u32,
     /// The new tick to write if query is mutable
     pub new_tick: u32,
+    /// The world is borrowed exclusively by a query which accesses nothing mutably, and as such
+    /// no conflicting borrow can exist.
+    ///
+    /// See [`Query::borrow_exclusive`](crate::Query::borrow_exclusive)
+    pub(crate) exclusive: bool,
 }
 
 /// Trait which gives an associated `Item` fetch type
@@ -130,7 +142,7 @@ pub trait Fetch<'w>: for<'q> FetchItem<'q> {
 
     /// Convert the fetch to a reference type which works with `HRTB`
     #[inline]
-    fn by_ref(&self) -> RefFetch<Self>
+    fn by_ref(&self) -> RefFetch<'_, Self>
     where
         Self: Sized,
     {
@@ -138,7 +150,44 @@ pub trait Fetch<'w>: for<'q> FetchItem<'q> {
     }
 }
 
+/// A fetch which only accesses the world as described by [`Fetch::access`].
+///
+/// This allows read-only queries to skip acquiring the guards of the storage when the world is
+/// borrowed exclusively, see [`Query::borrow_exclusive`](crate::Query::borrow_exclusive).
+///
+/// Implemented for the fetches and filters provided by this crate, and for
+/// [`#[derive(Fetch)]`](crate::Fetch) structs whose fields are all `TrustedAccess`. Fetches which
+/// invoke a user provided function, such as [`FetchExt::map`](crate::fetch::FetchExt::map) or
+/// [`Query::filter_arch`](crate::Query::filter_arch), or which read an external index, are not
+/// trusted.
+///
+/// # Safety
+///
+/// The fetch must not access anything not reported by [`Fetch::access`], and must not borrow any
+/// storage mutably, such as through [`World::get_mut`] or
+/// [`Archetype::borrow_mut`](crate::archetype::Archetype::borrow_mut), in [`Fetch::prepare`] or
+/// while iterating.
+pub unsafe trait TrustedAccess {}
+
 /// Borrowed state for a fetch
+///
+/// # Chunks
+///
+/// Iteration is split into two steps. [`create_chunk`](Self::create_chunk) returns a chunk for a
+/// slice of the archetype, and [`fetch_next`](Self::fetch_next) advances the chunk one slot at a
+/// time without touching the prepared state.
+///
+/// The prepared state is only borrowed for the duration of `create_chunk`. The returned chunk
+/// does not borrow the prepared state, but the storage it has borrowed from the world for `'q`.
+/// This allows the query iterators to hold a single exclusive borrow of the prepared state and
+/// split several disjoint chunks off of it, while earlier chunks are still alive.
+///
+/// This is sound as long as the following invariants are upheld:
+///
+/// - A chunk may only access the slots it was created for.
+/// - Chunks alive at the same time never cover overlapping slots.
+/// - The prepared state outlives `'q`, and is not moved while any of its chunks are alive, as a
+///   chunk may point into state owned by the prepared fetch.
 pub trait PreparedFetch<'q> {
     /// Item returned by fetch
     type Item: 'q;
@@ -156,15 +205,22 @@ pub trait PreparedFetch<'q> {
     ///
     /// # Safety
     ///
-    /// `slots` must be disjoint to all other currently existing chunks
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk;
+    /// `slots` must be disjoint to all other currently existing chunks, and `self` must uphold
+    /// the invariants of [chunks](Self#chunks) for as long as the chunk is alive.
+    ///
+    /// The implementation must not invalidate any data accessed by existing chunks, e.g; by
+    /// reallocating a borrowed storage.
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q;
 
     /// Fetch the item from entity at the slot in the prepared storage.
     /// # Safety
     /// Must return non-aliased references to the underlying borrow of the
     /// prepared archetype.
     ///
-    /// The callee is responsible for assuring disjoint calls.
+    /// The callee is responsible for assuring disjoint calls, and must not call this more times
+    /// than the number of slots the chunk was created for.
     unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item;
 
     #[inline]
@@ -176,6 +232,13 @@ pub trait PreparedFetch<'q> {
     unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
         slots
     }
+
+    #[inline]
+    /// Hints the cpu to load the data of the start of `slots` into the cache, as they are about to
+    /// be visited.
+    ///
+    /// Only invoked when the `prefetch` feature is enabled.
+    fn prefetch(&self, _slots: Slice) {}
 }
 
 /// Allows filtering the constituent parts of a fetch using a set union
@@ -200,8 +263,11 @@ where
 
     const HAS_FILTER: bool = F::HAS_FILTER;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
-        (*self).create_chunk(slots)
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        F::create_chunk(self, slots)
     }
 
     unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
@@ -211,6 +277,10 @@ where
     unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
         (*self).filter_slots(slots)
     }
+
+    fn prefetch(&self, slots: Slice) {
+        (**self).prefetch(slots)
+    }
 }
 
 impl<'q> FetchItem<'q> for () {
@@ -225,6 +295,8 @@ impl UnionFilter for () {
     }
 }
 
+unsafe impl TrustedAccess for () {}
+
 impl<'w> Fetch<'w> for () {
     const MUTABLE: bool = false;
 
@@ -261,7 +333,11 @@ impl<'q> PreparedFetch<'q> for () {
     const HAS_FILTER: bool = false;
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -304,6 +380,8 @@ impl<'q> FetchItem<'q> for EntityIds {
     type Item = Entity;
 }
 
+unsafe impl TrustedAccess for EntityIds {}
+
 impl<'w> Fetch<'w> for EntityIds {
     const MUTABLE: bool = false;
 
@@ -333,7 +411,10 @@ impl<'w, 'q> PreparedFetch<'q> for ReadEntities<'w> {
 
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Ptr::new(self.entities[slots.as_range()].as_ptr())
     }
 
@@ -402,7 +483,10 @@ macro_rules! tuple_impl {
             }
 
             #[inline]
-            unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+            unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+            where
+                Self: 'q,
+            {
                 ($((self.$idx).create_chunk(slots),)*)
             }
 
@@ -415,6 +499,11 @@ macro_rules! tuple_impl {
 
                 slots
             }
+
+            #[inline]
+            fn prefetch(&self, slots: Slice) {
+                $((self.$idx).prefetch(slots);)*
+            }
         }
 
         impl<'q, $($ty, )*> UnionFilter for ($($ty,)*)
@@ -435,6 +524,8 @@ macro_rules! tuple_impl {
             }
         }
 
+        unsafe impl<$($ty: TrustedAccess, )*> TrustedAccess for ($($ty,)*) {}
+
         impl<'w, $($ty, )*> Fetch<'w> for ($($ty,)*)
         where $($ty: Fetch<'w>,)*
         {
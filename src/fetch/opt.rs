@@ -11,7 +11,7 @@ use crate::{
     Fetch,
 };
 
-use super::{FetchAccessData, FetchItem, RandomFetch, TransformFetch};
+use super::{FetchAccessData, FetchItem, RandomFetch, TransformFetch, TrustedAccess};
 
 /// Transform a fetch into a optional fetch
 #[derive(Debug, Clone)]
@@ -23,6 +23,8 @@ impl<'q, F: FetchItem<'q>> FetchItem<'q> for Opt<F> {
     type Item = Option<F::Item>;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for Opt<F> {}
+
 impl<'w, F> Fetch<'w> for Opt<F>
 where
     F: Fetch<'w>,
@@ -85,13 +87,22 @@ where
         }
     }
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.0.as_mut().map(|v| v.create_chunk(slots))
     }
 
     unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
         chunk.as_mut().map(|v| F::fetch_next(v))
     }
+
+    fn prefetch(&self, slots: Slice) {
+        if let Some(fetch) = &self.0 {
+            fetch.prefetch(slots)
+        }
+    }
 }
 
 /// Transform a fetch into a optional fetch
@@ -111,6 +122,8 @@ impl<F, V> OptOr<F, V> {
     }
 }
 
+unsafe impl<F: TrustedAccess, V> TrustedAccess for OptOr<F, V> {}
+
 impl<'w, F, V> Fetch<'w> for OptOr<F, V>
 where
     F: Fetch<'w> + for<'q> FetchItem<'q, Item = &'q V>,
@@ -146,7 +159,7 @@ impl<'q, F: FetchItem<'q, Item = &'q V>, V: 'static> FetchItem<'q> for OptOr<F,
     type Item = &'q V;
 }
 
-impl<'w, 'q, F, V> PreparedFetch<'q> for OptOr<Option<F>, &'w V>
+impl<'q, F, V> PreparedFetch<'q> for OptOr<Option<F>, &V>
 where
     F: PreparedFetch<'q, Item = &'q V>,
     V: 'q,
@@ -167,7 +180,10 @@ where
         }
     }
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         match self.fetch {
             Some(ref mut v) => Either::Left(v.create_chunk(slots)),
             None => Either::Right(self.value),
@@ -1,5 +1,7 @@
 use core::{
     fmt::{self, Formatter},
+    marker::PhantomData,
+    ptr::NonNull,
     slice,
 };
 
@@ -7,14 +9,14 @@ use alloc::vec::Vec;
 use smallvec::SmallVec;
 
 use crate::{
-    archetype::{CellGuard, Slot},
+    archetype::{CellGuard, CellMutGuard, Slice, Slot},
     component::ComponentValue,
     relation::{Relation, RelationExt},
     system::{Access, AccessKind},
     Entity, Fetch, FetchItem,
 };
 
-use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch};
+use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess};
 
 /// Returns a list of relations of a specified type
 #[derive(Debug, Clone)]
@@ -22,6 +24,8 @@ pub struct Relations<T: ComponentValue> {
     relation: Relation<T>,
 }
 
+unsafe impl<T: ComponentValue> TrustedAccess for Relations<T> {}
+
 impl<'w, T> Fetch<'w> for Relations<T>
 where
     T: ComponentValue,
@@ -75,7 +79,7 @@ pub struct PreparedRelations<'a, T> {
 }
 
 pub struct Batch<'a, T> {
-    borrows: &'a [(Entity, CellGuard<'a, [T]>)],
+    borrows: *const [(Entity, CellGuard<'a, [T]>)],
     slot: Slot,
 }
 
@@ -89,9 +93,12 @@ where
 
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slice: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slice: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Batch {
-            borrows: &self.borrows,
+            borrows: &*self.borrows,
             slot: slice.start,
         }
     }
@@ -101,7 +108,7 @@ where
         chunk.slot += 1;
 
         RelationsIter {
-            borrows: chunk.borrows.iter(),
+            borrows: unsafe { &*chunk.borrows }.iter(),
             slot,
         }
     }
@@ -132,6 +139,159 @@ pub fn relations_like<T: ComponentValue>(relation: impl RelationExt<T>) -> Relat
     }
 }
 
+/// Mutably query all relations of the specified kind.
+///
+/// The relations of each visited batch are marked as modified, similar to
+/// [`Component::as_mut`](crate::Component::as_mut).
+///
+/// **Note**: This still matches if there are `0` relations.
+pub fn relations_like_mut<T: ComponentValue>(relation: impl RelationExt<T>) -> RelationsMut<T> {
+    RelationsMut {
+        relation: relation.as_relation(),
+    }
+}
+
+/// Returns a mutable list of relations of a specified type
+#[derive(Debug, Clone)]
+pub struct RelationsMut<T: ComponentValue> {
+    relation: Relation<T>,
+}
+
+unsafe impl<T: ComponentValue> TrustedAccess for RelationsMut<T> {}
+
+impl<'w, T> Fetch<'w> for RelationsMut<T>
+where
+    T: ComponentValue,
+{
+    const MUTABLE: bool = true;
+
+    type Prepared = PreparedRelationsMut<'w, T>;
+
+    fn prepare(&self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let borrows: SmallVec<[_; 4]> = {
+            data.arch
+                .relations_like(self.relation.id())
+                .map(|(desc, &cell_index)| {
+                    (
+                        desc.target.unwrap(),
+                        data.arch.cells()[cell_index].borrow_mut(),
+                    )
+                })
+                .collect()
+        };
+
+        let storages = borrows
+            .iter()
+            .map(|(id, borrow)| (*id, borrow.storage().cast::<T>()))
+            .collect();
+
+        Some(PreparedRelationsMut {
+            entities: data.arch.entities(),
+            borrows,
+            storages,
+            tick: data.new_tick,
+        })
+    }
+
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        true
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        let relation = self.relation.id();
+        let val = data.arch.relations_like(relation).map(|v| Access {
+            kind: AccessKind::Archetype {
+                id: data.arch_id,
+                component: *v.0,
+            },
+            mutable: true,
+        });
+
+        dst.extend(val);
+    }
+
+    fn describe(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "mut relations({})", self.relation)
+    }
+}
+
+impl<'q, T: ComponentValue> FetchItem<'q> for RelationsMut<T> {
+    type Item = RelationsIterMut<'q, T>;
+}
+
+#[doc(hidden)]
+pub struct PreparedRelationsMut<'a, T> {
+    entities: &'a [Entity],
+    borrows: SmallVec<[(Entity, CellMutGuard<'a, [T]>); 4]>,
+    /// The storage of each borrow, which the chunks point into
+    storages: SmallVec<[(Entity, NonNull<T>); 4]>,
+    tick: u32,
+}
+
+pub struct BatchMut<'a, T> {
+    storages: *const [(Entity, NonNull<T>)],
+    slot: Slot,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'w, 'q, T> PreparedFetch<'q> for PreparedRelationsMut<'w, T>
+where
+    T: 'q + ComponentValue,
+{
+    type Item = RelationsIterMut<'q, T>;
+
+    type Chunk = BatchMut<'q, T>;
+
+    const HAS_FILTER: bool = false;
+
+    unsafe fn create_chunk(&mut self, slice: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        let ids = &self.entities[slice.as_range()];
+
+        for (_, borrow) in &mut self.borrows {
+            borrow.set_modified(ids, slice, self.tick);
+        }
+
+        BatchMut {
+            storages: &*self.storages,
+            slot: slice.start,
+            marker: PhantomData,
+        }
+    }
+
+    unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
+        let slot = chunk.slot;
+        chunk.slot += 1;
+
+        RelationsIterMut {
+            storages: unsafe { &*chunk.storages }.iter(),
+            slot,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Mutably iterates the relation targets and data for the yielded query item
+pub struct RelationsIterMut<'a, T> {
+    storages: slice::Iter<'a, (Entity, NonNull<T>)>,
+    slot: Slot,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for RelationsIterMut<'a, T> {
+    type Item = (Entity, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (id, storage) = self.storages.next()?;
+        // Safety: each item of a chunk accesses a distinct slot, and the storage is borrowed
+        // mutably for the lifetime of the chunk
+        let value = unsafe { &mut *storage.as_ptr().add(self.slot) };
+        Some((*id, value))
+    }
+}
+
 /// Query the nth relation of the specified kind.
 ///
 /// This is useful for [`Exclusive`](crate::metadata::Exclusive) relations where there is only one parent
@@ -152,6 +312,8 @@ pub struct NthRelation<T: ComponentValue> {
     n: usize,
 }
 
+unsafe impl<T: ComponentValue> TrustedAccess for NthRelation<T> {}
+
 impl<'w, T> Fetch<'w> for NthRelation<T>
 where
     T: ComponentValue,
@@ -238,7 +400,10 @@ where
 
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'q mut self, slice: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slice: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         NthBatch {
             borrow: &self.borrow,
             slot: slice.start,
@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 
 use crate::{archetype::Slice, Fetch, FetchItem};
 
-use super::{FetchAccessData, FmtQuery, PreparedFetch};
+use super::{FetchAccessData, FmtQuery, PreparedFetch, TrustedAccess};
 
 /// Yields true iff `F` would match the query
 pub struct Satisfied<F>(pub F);
@@ -11,6 +11,8 @@ impl<'q, F: FetchItem<'q>> FetchItem<'q> for Satisfied<F> {
     type Item = bool;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for Satisfied<F> {}
+
 impl<'w, F: Fetch<'w>> Fetch<'w> for Satisfied<F> {
     const MUTABLE: bool = false;
 
@@ -44,7 +46,10 @@ impl<'q, F: PreparedFetch<'q>> PreparedFetch<'q> for PreparedSatisfied<F> {
 
     const HAS_FILTER: bool = true;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         match &mut self.0 {
             Some(f) => {
                 let res = f.filter_slots(slots);
@@ -81,7 +86,7 @@ mod test {
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
 
-    use crate::{component, components::name, Entity, FetchExt, Query, World};
+    use crate::{components::name, Entity, FetchExt, Query, World};
 
     component! {
         a: i32,
@@ -8,7 +8,7 @@ use crate::{
     Entity, Fetch, FetchItem,
 };
 
-use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch};
+use super::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess};
 
 pub trait FetchSource {
     fn resolve<'a, 'w, Q: Fetch<'w>>(
@@ -123,7 +123,7 @@ impl FetchSource for Traverse {
         fetch: &Q,
         data: FetchAccessData<'a>,
     ) -> Option<(ArchetypeId, &'a Archetype, Option<Slot>)> {
-        return traverse_resolve(self.relation, fetch, data);
+        traverse_resolve(self.relation, fetch, data)
     }
 
     fn describe(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -158,6 +158,8 @@ where
     type Item = Q::Item;
 }
 
+unsafe impl<Q: TrustedAccess, S: FetchSource> TrustedAccess for Source<Q, S> {}
+
 impl<'w, Q, S> Fetch<'w> for Source<Q, S>
 where
     Q: Fetch<'w>,
@@ -178,6 +180,7 @@ where
             old_tick: data.old_tick,
             new_tick: data.new_tick,
             world: data.world,
+            exclusive: data.exclusive,
         })?;
 
         Some(PreparedSource {
@@ -243,7 +246,10 @@ where
 
     type Chunk = (Q::Chunk, bool);
 
-    unsafe fn create_chunk(&'q mut self, slice: crate::archetype::Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slice: crate::archetype::Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         if let Some(slot) = self.slot {
             (self.fetch.create_chunk(Slice::single(slot)), true)
         } else {
@@ -271,7 +277,6 @@ mod test {
     use itertools::Itertools;
 
     use crate::{
-        component,
         components::{child_of, name},
         entity_ids, FetchExt, Query, Topo, World,
     };
@@ -316,7 +321,7 @@ mod test {
         .with_strategy(Topo::new(child_of));
 
         pretty_assertions::assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [
                 ("root", None),
                 ("child.1", Some(("root", 4))),
@@ -354,7 +359,7 @@ mod test {
         .with_strategy(Topo::new(relation));
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [
                 ("parent", None),
                 ("parent2", None),
@@ -418,7 +423,7 @@ mod test {
         ));
 
         assert_eq!(
-            query.borrow(&world).iter().sorted().collect_vec(),
+            query.borrow(&world).unwrap().iter().sorted().collect_vec(),
             [
                 ("child_1", ("root", 5)),
                 ("child_1_1", ("root", 5)),
@@ -456,7 +461,7 @@ mod test {
         ));
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             &[
                 ("id1".to_string(), (id3, &5, "id3".to_string())),
                 ("id2".to_string(), (id3, &5, "id3".to_string())),
@@ -472,14 +477,14 @@ mod test {
             },
         ));
 
-        for (name, id3_a) in &mut query2.borrow(&world) {
+        for (name, id3_a) in &mut query2.borrow(&world).unwrap() {
             *id3_a.write() += name.len() as u32;
         }
 
         use alloc::string::ToString;
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             &[
                 ("id1".to_string(), (id3, &14, "id3".to_string())),
                 ("id2".to_string(), (id3, &14, "id3".to_string())),
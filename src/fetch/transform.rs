@@ -100,7 +100,7 @@ mod tests {
     use alloc::string::{String, ToString};
     use itertools::Itertools;
 
-    use crate::{component, entity_ids, CommandBuffer, Entity, FetchExt, Query, World};
+    use crate::{entity_ids, CommandBuffer, Entity, FetchExt, Query, World};
 
     #[test]
     fn query_modified() {
@@ -137,7 +137,7 @@ mod tests {
         let mut query = Query::new((entity_ids(), (a(), b(), other().as_mut().opt()).modified()));
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [
                 (id1, (&0, &"Hello".to_string(), None)),
                 (id2, (&1, &"World".to_string(), None)),
@@ -145,17 +145,17 @@ mod tests {
             ]
         );
 
-        assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+        assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 
         // Get mut *without* a mut deref is not a change
         assert_eq!(*world.get_mut(id2, a()).unwrap(), 1);
 
-        assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+        assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 
         *world.get_mut(id2, a()).unwrap() = 5;
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [(id2, (&5, &"World".to_string(), None))]
         );
 
@@ -164,14 +164,14 @@ mod tests {
         cmd.set(id3, a(), -1).apply(&mut world).unwrap();
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [(id3, (&-1, &"There".to_string(), None))]
         );
 
         cmd.set(id3, b(), ":P".into()).apply(&mut world).unwrap();
 
         assert_eq!(
-            query.borrow(&world).iter().collect_vec(),
+            query.borrow(&world).unwrap().iter().collect_vec(),
             [(id3, (&-1, &":P".to_string(), None))]
         );
     }
@@ -370,6 +370,7 @@ mod tests {
         use crate::{fetch::Cloned, Component, Fetch};
 
         #[derive(Fetch)]
+        #[allow(dead_code)]
         struct MyFetch {
             a: Component<i32>,
             b: Cloned<Component<String>>,
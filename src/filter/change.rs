@@ -4,12 +4,12 @@ use itertools::Itertools;
 
 use crate::archetype::{CellGuard, Change, Slot};
 use crate::component::ComponentValue;
-use crate::fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch};
-use crate::system::Access;
+use crate::fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess};
+use crate::system::{Access, AccessKind};
 use crate::util::Ptr;
 use crate::{
     archetype::{ChangeKind, Slice},
-    Component, Fetch, FetchItem,
+    Component, Entity, Fetch, FetchItem,
 };
 
 #[derive(Clone)]
@@ -53,6 +53,8 @@ impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for PreparedChangeFilter<'w, T>
     }
 }
 
+unsafe impl<T: ComponentValue> TrustedAccess for ChangeFilter<T> {}
+
 impl<'w, T> Fetch<'w> for ChangeFilter<T>
 where
     T: ComponentValue,
@@ -161,7 +163,10 @@ impl<'w, 'q, T: ComponentValue> PreparedFetch<'q> for PreparedChangeFilter<'w, T
 
     const HAS_FILTER: bool = true;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         Ptr::new(self.data.get()[slots.as_range()].as_ptr())
     }
 
@@ -187,6 +192,286 @@ impl<'w, 'q, T: ComponentValue> PreparedFetch<'q> for PreparedChangeFilter<'w, T
     }
 }
 
+/// Merges possibly overlapping slices into sorted and disjoint slices
+fn merge_slices(mut slices: Vec<Slice>) -> Vec<Slice> {
+    slices.sort_by_key(|v| v.start);
+
+    let mut merged: Vec<Slice> = Vec::with_capacity(slices.len());
+    for slice in slices {
+        match merged
+            .last_mut()
+            .and_then(|last| Some((last.union(&slice)?, last)))
+        {
+            Some((union, last)) => *last = union,
+            None => merged.push(slice),
+        }
+    }
+
+    merged
+}
+
+#[derive(Clone)]
+/// Filter which only yields entities whose component has *not* been added or modified.
+///
+/// See [`Component::unchanged`] and [`Component::static_since`]
+pub struct Unchanged<T> {
+    component: Component<T>,
+    /// Changes after this tick are considered. Defaults to the last run of the query.
+    since: Option<u32>,
+}
+
+impl<T: ComponentValue> core::fmt::Debug for Unchanged<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Unchanged")
+            .field("component", &self.component)
+            .field("since", &self.since)
+            .finish()
+    }
+}
+
+impl<T: ComponentValue> Unchanged<T> {
+    pub(crate) fn new(component: Component<T>, since: Option<u32>) -> Self {
+        Self { component, since }
+    }
+}
+
+impl<'q, T> FetchItem<'q> for Unchanged<T>
+where
+    T: ComponentValue,
+{
+    type Item = &'q T;
+}
+
+unsafe impl<T: ComponentValue> TrustedAccess for Unchanged<T> {}
+
+impl<'w, T> Fetch<'w> for Unchanged<T>
+where
+    T: ComponentValue,
+{
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedUnchanged<'w, T>;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let cell = data.arch.cell(self.component.key())?;
+        let guard = cell.borrow();
+
+        let since = self.since.unwrap_or(data.old_tick);
+
+        let mut slices = Vec::new();
+        {
+            let changes = guard.changes();
+            changes.set_track_modified();
+
+            for kind in [ChangeKind::Added, ChangeKind::Modified] {
+                slices.extend(
+                    changes
+                        .get(kind)
+                        .iter()
+                        .filter(|v| v.tick > since)
+                        .map(|v| v.slice),
+                );
+            }
+        }
+
+        Some(PreparedUnchanged {
+            data: guard,
+            changed: merge_slices(slices),
+        })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        self.component.filter_arch(data)
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        self.component.access(data, dst);
+    }
+
+    fn describe(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.since {
+            Some(tick) => write!(f, "unchanged {} since {tick}", self.component.name()),
+            None => write!(f, "unchanged {}", self.component.name()),
+        }
+    }
+
+    fn searcher(&self, searcher: &mut crate::ArchetypeSearcher) {
+        searcher.add_required(self.component.key())
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedUnchanged<'w, T> {
+    data: CellGuard<'w, [T]>,
+    /// Sorted and disjoint
+    changed: Vec<Slice>,
+}
+
+impl<'w, T> core::fmt::Debug for PreparedUnchanged<'w, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PreparedUnchanged")
+            .field("changed", &self.changed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'w, 'q, T: ComponentValue> RandomFetch<'q> for PreparedUnchanged<'w, T> {
+    unsafe fn fetch_shared(&'q self, slot: Slot) -> Self::Item {
+        unsafe { self.data.get().get_unchecked(slot) }
+    }
+
+    #[inline]
+    unsafe fn fetch_shared_chunk(chunk: &Self::Chunk, slot: Slot) -> Self::Item {
+        chunk.add(slot).as_ref()
+    }
+}
+
+impl<'w, 'q, T: ComponentValue> PreparedFetch<'q> for PreparedUnchanged<'w, T> {
+    type Item = &'q T;
+    type Chunk = Ptr<'q, T>;
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        Ptr::new(self.data.get()[slots.as_range()].as_ptr())
+    }
+
+    #[inline]
+    unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
+        let old = chunk.as_ptr();
+        chunk.advance(1);
+        &*old
+    }
+
+    #[inline]
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        // Yield the first gap between the changed slices
+        let idx = self.changed.partition_point(|v| v.end <= slots.start);
+
+        let (start, end) = match self.changed.get(idx) {
+            Some(v) if v.start <= slots.start => (
+                v.end,
+                self.changed.get(idx + 1).map_or(slots.end, |v| v.start),
+            ),
+            Some(v) => (slots.start, v.start),
+            None => (slots.start, slots.end),
+        };
+
+        let start = start.min(slots.end);
+        Slice::new(start, end.clamp(start, slots.end))
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Filter which yields entities where any relation of a kind was added or modified, regardless
+/// of the target.
+///
+/// See [`RelationExt::modified_relation`](crate::RelationExt::modified_relation)
+pub struct ModifiedRelation {
+    relation: Entity,
+    name: &'static str,
+}
+
+impl ModifiedRelation {
+    pub(crate) fn new(relation: Entity, name: &'static str) -> Self {
+        Self { relation, name }
+    }
+}
+
+impl<'q> FetchItem<'q> for ModifiedRelation {
+    type Item = ();
+}
+
+unsafe impl TrustedAccess for ModifiedRelation {}
+
+impl<'w> Fetch<'w> for ModifiedRelation {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedModifiedRelation;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let mut slices = Vec::new();
+        for (&key, _) in data.arch.relations_like(self.relation) {
+            let cell = data.arch.cell(key)?;
+            let cell = cell.data.borrow();
+            let changes = &cell.changes;
+            changes.set_track_modified();
+
+            for kind in [ChangeKind::Added, ChangeKind::Modified] {
+                slices.extend(
+                    changes
+                        .get(kind)
+                        .iter()
+                        .filter(|v| v.tick > data.old_tick)
+                        .map(|v| v.slice),
+                );
+            }
+        }
+
+        // Merge the changes of all edges into disjoint slices
+        Some(PreparedModifiedRelation {
+            slices: merge_slices(slices),
+        })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        data.arch.relations_like(self.relation).next().is_some()
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        dst.extend(
+            data.arch
+                .relations_like(self.relation)
+                .map(|(&component, _)| Access {
+                    kind: AccessKind::Archetype {
+                        id: data.arch_id,
+                        component,
+                    },
+                    mutable: false,
+                }),
+        )
+    }
+
+    fn describe(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "modified {}(*)", self.name)
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedModifiedRelation {
+    /// Sorted and disjoint
+    slices: Vec<Slice>,
+}
+
+impl<'q> PreparedFetch<'q> for PreparedModifiedRelation {
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let first = self.slices.partition_point(|v| v.end <= slots.start);
+
+        self.slices
+            .get(first)
+            .and_then(|v| v.intersect(&slots))
+            .unwrap_or(slots.empty_tail())
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
+}
+
 #[doc(hidden)]
 #[cfg(test)]
 pub struct ChangeFetch<'w> {
@@ -231,7 +516,11 @@ impl<'w, 'q> PreparedFetch<'q> for ChangeFetch<'w> {
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
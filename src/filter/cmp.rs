@@ -13,13 +13,17 @@ use core::{
 };
 
 use alloc::vec::Vec;
+use smallvec::SmallVec;
 
 use crate::{
-    archetype::{Slice, Slot},
+    archetype::{CellGuard, Slice, Slot},
+    component::ComponentValue,
     fetch::{
         FetchAccessData, FetchPrepareData, FmtQuery, PreparedFetch, RandomFetch, TransformFetch,
+        TrustedAccess,
     },
-    system::Access,
+    relation::{Relation, RelationExt},
+    system::{Access, AccessKind},
     Fetch, FetchItem,
 };
 
@@ -38,6 +42,9 @@ pub struct Greater<R>(pub R);
 pub struct Equal<R>(pub R);
 #[doc(hidden)]
 #[derive(Debug, Clone)]
+pub struct NotEqual<R>(pub R);
+#[doc(hidden)]
+#[derive(Debug, Clone)]
 pub struct LessEq<R>(pub R);
 #[doc(hidden)]
 #[derive(Debug, Clone)]
@@ -94,6 +101,15 @@ where
     }
 }
 
+impl<L, R> CmpMethod<L> for NotEqual<R>
+where
+    L: for<'x> PartialEq<&'x R>,
+{
+    fn compare(&self, lhs: L) -> bool {
+        lhs.ne(&&self.0)
+    }
+}
+
 impl<T, F> CmpMethod<T> for F
 where
     F: Fn(T) -> bool,
@@ -121,6 +137,18 @@ impl<'q, F: FetchItem<'q>, M> FetchItem<'q> for Cmp<F, M> {
     type Item = F::Item;
 }
 
+// Comparisons through a user provided function are not trusted, as the function may access
+// anything
+macro_rules! trusted_cmp {
+    ($($method:ident),*) => {
+        $(
+            unsafe impl<F: TrustedAccess, R> TrustedAccess for Cmp<F, $method<R>> {}
+        )*
+    };
+}
+
+trusted_cmp!(Less, Greater, Equal, NotEqual, LessEq, GreaterEq);
+
 impl<'w, F, M> Fetch<'w> for Cmp<F, M>
 where
     F: Fetch<'w>,
@@ -210,7 +238,10 @@ where
 
     type Chunk = <Q as PreparedFetch<'q>>::Chunk;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.fetch.create_chunk(slots)
     }
 
@@ -234,15 +265,154 @@ where
     }
 }
 
+/// Filter which matches entities with any relation of a kind whose value satisfies a predicate.
+///
+/// See [`RelationExt::filter_value`]
+pub struct RelationValue<T, F> {
+    relation: Relation<T>,
+    func: F,
+}
+
+impl<T: ComponentValue, F> RelationValue<T, F> {
+    /// Creates a new relation value filter
+    pub fn new(relation: impl RelationExt<T>, func: F) -> Self {
+        Self {
+            relation: relation.as_relation(),
+            func,
+        }
+    }
+}
+
+impl<T, F: Clone> Clone for RelationValue<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            relation: self.relation,
+            func: self.func.clone(),
+        }
+    }
+}
+
+impl<T, F> Debug for RelationValue<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelationValue")
+            .field("relation", &self.relation)
+            .finish()
+    }
+}
+
+impl<'q, T, F> FetchItem<'q> for RelationValue<T, F> {
+    type Item = ();
+}
+
+impl<'w, T, F> Fetch<'w> for RelationValue<T, F>
+where
+    T: ComponentValue,
+    F: 'w + Fn(&T) -> bool,
+{
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedRelationValue<'w, T, F>;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let borrows: SmallVec<[_; 4]> = data
+            .arch
+            .relations_like(self.relation.id())
+            .map(|(_, &cell_index)| data.arch.cells()[cell_index].borrow())
+            .collect();
+
+        if borrows.is_empty() {
+            return None;
+        }
+
+        Some(PreparedRelationValue {
+            borrows,
+            func: &self.func,
+        })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        data.arch
+            .relations_like(self.relation.id())
+            .next()
+            .is_some()
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        let val = data
+            .arch
+            .relations_like(self.relation.id())
+            .map(|v| Access {
+                kind: AccessKind::Archetype {
+                    id: data.arch_id,
+                    component: *v.0,
+                },
+                mutable: false,
+            });
+
+        dst.extend(val);
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "filter_value({}, {})", self.relation, type_name::<F>())
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedRelationValue<'w, T, F> {
+    borrows: SmallVec<[CellGuard<'w, [T]>; 4]>,
+    func: &'w F,
+}
+
+impl<'w, 'q, T, F> PreparedFetch<'q> for PreparedRelationValue<'w, T, F>
+where
+    T: ComponentValue,
+    F: Fn(&T) -> bool,
+{
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let cmp = |slot: Slot| {
+            self.borrows
+                .iter()
+                .any(|borrow| (self.func)(&borrow.get()[slot]))
+        };
+
+        // Find the first slot which yield true
+        let first = slots.iter().position(cmp).unwrap_or(slots.len());
+
+        let count = slots
+            .iter()
+            .skip(first)
+            .take_while(|&slot| cmp(slot))
+            .count();
+
+        Slice {
+            start: slots.start + first,
+            end: slots.start + first + count,
+        }
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
+}
+
 #[cfg(test)]
 mod test {
     use alloc::string::ToString;
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        component, components::name, entity_ids, BatchSpawn, CommandBuffer, FetchExt, Query, World,
-    };
+    use crate::{components::name, entity_ids, BatchSpawn, CommandBuffer, FetchExt, Query, World};
 
     #[test]
     fn cmp_mut() {
@@ -264,7 +434,7 @@ mod test {
 
         let mut cmd = CommandBuffer::new();
         let mut query = Query::new((entity_ids(), a().opt().cmp(|v: Option<&i32>| v > Some(&3))));
-        for (id, item) in query.borrow(&world).iter() {
+        for (id, item) in query.borrow(&world).unwrap().iter() {
             if let Some(item) = item {
                 cmd.set(id, a(), item * -1);
             }
@@ -302,7 +472,7 @@ mod test {
 
         let mut cmd = CommandBuffer::new();
         let mut query = Query::new((entity_ids(), a().gt(3).lt(7)));
-        for (id, item) in query.borrow(&world).iter() {
+        for (id, item) in query.borrow(&world).unwrap().iter() {
             cmd.set(id, a(), item * -1);
         }
 
@@ -317,4 +487,67 @@ mod test {
 
         assert_eq!(changed.collect_vec(&world), changed_ids);
     }
+
+    #[test]
+    fn cmp_batches() {
+        let mut batch = BatchSpawn::new(128);
+
+        component! {
+            a: i32,
+        }
+
+        batch.set(a(), (0..10).cycle()).unwrap();
+
+        let mut world = World::new();
+        batch.spawn(&mut world);
+
+        // The matching slots are yielded as contiguous batches
+        let mut query = Query::new(a()).filter(a().ge(5));
+        let batches = query
+            .borrow(&world)
+            .unwrap()
+            .iter_batched()
+            .map(|v| v.len())
+            .collect_vec();
+
+        assert_eq!(batches, [5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 3]);
+
+        let mut query = Query::new(a().copied()).filter(a().ne(0) & a().ne(9));
+        let mut borrow = query.borrow(&world).unwrap();
+        assert_eq!(
+            borrow.iter_batched().map(|v| v.len()).collect_vec(),
+            [8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 7]
+        );
+        assert_eq!(borrow.iter().filter(|&v| v == 0 || v == 9).count(), 0);
+    }
+
+    #[test]
+    fn relation_value() {
+        use crate::{relation::RelationExt, Entity};
+
+        component! {
+            distance(target): f32,
+        }
+
+        let mut world = World::new();
+
+        let a = world.spawn();
+        let b = world.spawn();
+
+        let id1 = Entity::builder()
+            .set(distance(a), 2.0)
+            .set(distance(b), 8.0)
+            .spawn(&mut world);
+        let id2 = Entity::builder().set(distance(a), 6.0).spawn(&mut world);
+        let id3 = Entity::builder().set(distance(b), 1.0).spawn(&mut world);
+        Entity::builder()
+            .set(name(), "none".into())
+            .spawn(&mut world);
+
+        let mut query = Query::new(entity_ids()).filter(distance.filter_value(|v: &f32| *v < 5.0));
+        assert_eq!(query.collect_sorted_vec(&world), [id1, id3]);
+
+        let mut query = Query::new(entity_ids()).filter(distance.filter_value(|v: &f32| *v > 5.0));
+        assert_eq!(query.collect_sorted_vec(&world), [id1, id2]);
+    }
 }
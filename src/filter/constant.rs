@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Slice, Slot},
-    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch},
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, RandomFetch, TrustedAccess},
     system::Access,
     Entity, Fetch, FetchItem,
 };
@@ -17,6 +17,8 @@ impl<'q> FetchItem<'q> for Nothing {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for Nothing {}
+
 impl<'a> Fetch<'a> for Nothing {
     const MUTABLE: bool = false;
 
@@ -55,7 +57,11 @@ impl<'q> PreparedFetch<'q> for Nothing {
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -69,6 +75,8 @@ impl<'q> FetchItem<'q> for All {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for All {}
+
 impl<'w> Fetch<'w> for All {
     const MUTABLE: bool = false;
 
@@ -103,7 +111,11 @@ impl<'q> PreparedFetch<'q> for All {
     const HAS_FILTER: bool = false;
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -118,6 +130,8 @@ impl<'q> FetchItem<'q> for NoEntities {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for NoEntities {}
+
 impl<'a> Fetch<'a> for NoEntities {
     const MUTABLE: bool = false;
 
@@ -156,7 +170,11 @@ impl<'q> PreparedFetch<'q> for NoEntities {
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -165,6 +183,8 @@ impl<'w> FetchItem<'w> for Entity {
     type Item = Entity;
 }
 
+unsafe impl TrustedAccess for Entity {}
+
 impl<'w> Fetch<'w> for Entity {
     const MUTABLE: bool = false;
 
@@ -221,7 +241,10 @@ impl<'w> PreparedFetch<'w> for PreparedEntity {
 
     const HAS_FILTER: bool = false;
 
-    unsafe fn create_chunk(&'w mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'w,
+    {
         assert!(slots.start == self.slot && slots.end == self.slot);
         self.id
     }
@@ -243,6 +266,8 @@ impl<'q> FetchItem<'q> for Slice {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for Slice {}
+
 impl<'w> Fetch<'w> for Slice {
     const MUTABLE: bool = false;
     type Prepared = Self;
@@ -276,7 +301,11 @@ impl<'q> PreparedFetch<'q> for Slice {
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -286,6 +315,8 @@ impl<'q> FetchItem<'q> for bool {
     type Item = bool;
 }
 
+unsafe impl TrustedAccess for bool {}
+
 impl<'w> Fetch<'w> for bool {
     const MUTABLE: bool = false;
 
@@ -322,7 +353,10 @@ impl<'q> PreparedFetch<'q> for bool {
     const HAS_FILTER: bool = true;
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         *self
     }
 
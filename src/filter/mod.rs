@@ -5,47 +5,56 @@ mod set;
 
 use alloc::vec::Vec;
 use core::{
-    any::type_name,
     fmt::{self, Formatter},
     iter::FusedIterator,
     ops,
 };
 
 use crate::{
-    archetype::{Archetype, Slice, Slot},
+    archetype::{ArchInfo, Archetype, Slice, Slot},
     component::ComponentKey,
-    components::component_info,
-    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch},
+    components::{component_info, disabled, is_static},
+    entity::EntityKind,
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, TrustedAccess},
     system::Access,
     ArchetypeSearcher, Entity, Fetch, FetchItem,
 };
 
-pub use change::ChangeFilter;
-pub use cmp::{Cmp, Equal, Greater, GreaterEq, Less, LessEq};
+pub use change::{ChangeFilter, ModifiedRelation, Unchanged};
+pub use cmp::{Cmp, Equal, Greater, GreaterEq, Less, LessEq, NotEqual, RelationValue};
 pub(crate) use constant::NoEntities;
 pub use constant::{All, Nothing};
-pub use set::{And, Not, Or, Union};
+pub use set::{And, ExactlyOneOf, Not, Or, Union, Xor};
 
 macro_rules! gen_bitops {
     ($ty:ident[$($p: tt),*]) => {
-        impl<R, $($p),*> ops::BitOr<R> for $ty<$($p),*>
+        impl<Rhs, $($p),*> ops::BitOr<Rhs> for $ty<$($p),*>
         {
-            type Output = Or<(Self, R)>;
+            type Output = Or<(Self, Rhs)>;
 
-            fn bitor(self, rhs: R) -> Self::Output {
+            fn bitor(self, rhs: Rhs) -> Self::Output {
                 Or((self, rhs))
             }
         }
 
-        impl<R, $($p),*> ops::BitAnd<R> for $ty<$($p),*>
+        impl<Rhs, $($p),*> ops::BitAnd<Rhs> for $ty<$($p),*>
         {
-            type Output = And<Self, R>;
+            type Output = And<Self, Rhs>;
 
-            fn bitand(self, rhs: R) -> Self::Output {
+            fn bitand(self, rhs: Rhs) -> Self::Output {
                 And(self, rhs)
             }
         }
 
+        impl<Rhs, $($p),*> ops::BitXor<Rhs> for $ty<$($p),*>
+        {
+            type Output = Xor<Self, Rhs>;
+
+            fn bitxor(self, rhs: Rhs) -> Self::Output {
+                Xor(self, rhs)
+            }
+        }
+
         impl<$($p),*> ops::Not for $ty<$($p),*>
         {
             type Output = Not<Self>;
@@ -70,14 +79,24 @@ pub struct Filtered<Q, F> {
     pub(crate) fetch: Q,
     pub(crate) filter: F,
     pub(crate) include_components: bool,
+    pub(crate) include_disabled: bool,
+    pub(crate) include_static: bool,
 }
 
 impl<Q, F> Filtered<Q, F> {
-    pub(crate) fn new(fetch: Q, filter: F, include_components: bool) -> Self {
+    pub(crate) fn new(
+        fetch: Q,
+        filter: F,
+        include_components: bool,
+        include_disabled: bool,
+        include_static: bool,
+    ) -> Self {
         Self {
             fetch,
             filter,
             include_components,
+            include_disabled,
+            include_static,
         }
     }
 }
@@ -89,6 +108,8 @@ where
     type Item = Q::Item;
 }
 
+unsafe impl<Q: TrustedAccess, F: TrustedAccess> TrustedAccess for Filtered<Q, F> {}
+
 impl<'w, Q, F> Fetch<'w> for Filtered<Q, F>
 where
     Q: Fetch<'w>,
@@ -105,6 +126,8 @@ where
             fetch: self.fetch.prepare(data)?,
             filter: self.filter.prepare(data)?,
             include_components: self.include_components,
+            include_disabled: self.include_disabled,
+            include_static: self.include_static,
         })
     }
 
@@ -113,6 +136,8 @@ where
         self.fetch.filter_arch(data)
             && self.filter.filter_arch(data)
             && (!data.arch.has(component_info().key()) || self.include_components)
+            && (!data.arch.has(disabled().key()) || self.include_disabled)
+            && (!data.arch.has(is_static().key()) || self.include_static)
     }
 
     #[inline]
@@ -132,6 +157,15 @@ where
     fn searcher(&self, searcher: &mut ArchetypeSearcher) {
         self.fetch.searcher(searcher);
         self.filter.searcher(searcher);
+        if !self.include_components {
+            searcher.add_excluded(component_info().key());
+        }
+        if !self.include_disabled {
+            searcher.add_excluded(disabled().key());
+        }
+        if !self.include_static {
+            searcher.add_excluded(is_static().key());
+        }
     }
 }
 
@@ -151,13 +185,21 @@ where
 
     type Chunk = Q::Chunk;
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.fetch.create_chunk(slots)
     }
 
     unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
         Q::fetch_next(chunk)
     }
+
+    fn prefetch(&self, slots: Slice) {
+        self.fetch.prefetch(slots);
+        self.filter.prefetch(slots);
+    }
 }
 
 gen_bitops! {
@@ -165,13 +207,17 @@ gen_bitops! {
     And[A,B];
     BatchSize[];
     ChangeFilter[T];
+    Unchanged[T];
     Nothing[];
     Or[T];
-    WithTarget[];
+    Xor[L, R];
+    ExactlyOneOf[T];
     WithRelation[];
     With[];
     WithoutRelation[];
     Without[];
+    WithKind[];
+    ArchFilter[F];
     Cmp[A,B];
 }
 
@@ -245,6 +291,8 @@ impl<'q> FetchItem<'q> for With {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for With {}
+
 impl<'a> Fetch<'a> for With {
     const MUTABLE: bool = false;
 
@@ -268,6 +316,10 @@ impl<'a> Fetch<'a> for With {
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+
+    fn searcher(&self, searcher: &mut ArchetypeSearcher) {
+        searcher.add_required(self.component)
+    }
 }
 
 impl StaticFilter for With {
@@ -287,6 +339,8 @@ impl<'q> FetchItem<'q> for Without {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for Without {}
+
 impl<'w> Fetch<'w> for Without {
     const MUTABLE: bool = false;
 
@@ -310,6 +364,10 @@ impl<'w> Fetch<'w> for Without {
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+
+    fn searcher(&self, searcher: &mut ArchetypeSearcher) {
+        searcher.add_excluded(self.component)
+    }
 }
 
 impl StaticFilter for Without {
@@ -320,17 +378,19 @@ impl StaticFilter for Without {
 
 #[derive(Debug, Clone)]
 /// Yields all entities with the relation of the specified kind
-pub(crate) struct WithTarget {
-    pub(crate) target: Entity,
+pub struct WithRelation {
+    pub(crate) relation: Entity,
+    pub(crate) name: &'static str,
 }
 
-impl<'q> FetchItem<'q> for WithTarget {
+impl<'q> FetchItem<'q> for WithRelation {
     type Item = ();
 }
 
-impl<'w> Fetch<'w> for WithTarget {
-    const MUTABLE: bool = false;
+unsafe impl TrustedAccess for WithRelation {}
 
+impl<'w> Fetch<'w> for WithRelation {
+    const MUTABLE: bool = false;
     type Prepared = All;
 
     fn prepare(&self, _: FetchPrepareData) -> Option<Self::Prepared> {
@@ -342,133 +402,194 @@ impl<'w> Fetch<'w> for WithTarget {
     }
 
     fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "with (*)({})", self.target)
+        write!(f, "with {}(*)", self.name)
     }
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
 }
 
-impl StaticFilter for WithTarget {
+impl StaticFilter for WithRelation {
     fn filter_static(&self, arch: &Archetype) -> bool {
-        arch.components().keys().any(|v| {
-            if let Some(v) = v.target {
-                if v == self.target {
-                    return true;
-                }
-            }
-
-            false
-        })
+        arch.relations_like(self.relation).next().is_some()
     }
 }
 
-pub(crate) struct ArchetypeFilter<F>(pub(crate) F);
-
-impl<F> core::fmt::Debug for ArchetypeFilter<F> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_tuple("ArchetypeFilter")
-            .field(&type_name::<F>())
-            .finish()
-    }
+#[derive(Debug, Clone)]
+/// Opposite of [crate::filter::Without]
+pub struct WithoutRelation {
+    pub(crate) relation: Entity,
+    pub(crate) name: &'static str,
 }
 
-impl<'q, F> FetchItem<'q> for ArchetypeFilter<F> {
+impl<'q> FetchItem<'q> for WithoutRelation {
     type Item = ();
 }
 
-impl<'w, F: Fn(&Archetype) -> bool> Fetch<'w> for ArchetypeFilter<F> {
+unsafe impl TrustedAccess for WithoutRelation {}
+
+impl<'a> Fetch<'a> for WithoutRelation {
     const MUTABLE: bool = false;
+
     type Prepared = All;
 
-    fn prepare(&'w self, _: FetchPrepareData) -> Option<Self::Prepared> {
+    fn prepare(&self, _: FetchPrepareData) -> Option<Self::Prepared> {
         Some(All)
     }
 
     fn filter_arch(&self, data: FetchAccessData) -> bool {
-        (self.0)(data.arch)
+        self.filter_static(data.arch)
     }
 
     fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "archetype_filter {}", &type_name::<F>())
+        write!(f, "without {}(*)", self.name)
     }
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
 }
 
+impl StaticFilter for WithoutRelation {
+    fn filter_static(&self, arch: &Archetype) -> bool {
+        arch.relations_like(self.relation).next().is_none()
+    }
+}
+
+/// Yields entities whose kind contains all bits of `kind`.
+///
+/// See [`World::spawn_kind`](crate::World::spawn_kind)
+pub fn kind_filter(kind: EntityKind) -> WithKind {
+    WithKind { kind }
+}
+
 #[derive(Debug, Clone)]
-/// Yields all entities with the relation of the specified kind
-pub struct WithRelation {
-    pub(crate) relation: Entity,
-    pub(crate) name: &'static str,
+/// Yields entities of the specified kind.
+///
+/// The kind is only known per entity, so the entities are filtered per slot.
+///
+/// See [`kind_filter`]
+pub struct WithKind {
+    kind: EntityKind,
 }
 
-impl<'q> FetchItem<'q> for WithRelation {
+impl<'q> FetchItem<'q> for WithKind {
     type Item = ();
 }
 
-impl<'w> Fetch<'w> for WithRelation {
+unsafe impl TrustedAccess for WithKind {}
+
+impl<'w> Fetch<'w> for WithKind {
     const MUTABLE: bool = false;
-    type Prepared = All;
 
-    fn prepare(&self, _: FetchPrepareData) -> Option<Self::Prepared> {
-        Some(All)
-    }
+    type Prepared = PreparedWithKind<'w>;
 
-    fn filter_arch(&self, data: FetchAccessData) -> bool {
-        self.filter_static(data.arch)
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        Some(PreparedWithKind {
+            entities: data.arch.entities(),
+            kind: self.kind,
+        })
     }
 
-    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "with {}(*)", self.name)
+    #[inline]
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        true
     }
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "kind {:?}", self.kind)
+    }
 }
 
-impl StaticFilter for WithRelation {
-    fn filter_static(&self, arch: &Archetype) -> bool {
-        arch.relations_like(self.relation).next().is_some()
+#[doc(hidden)]
+pub struct PreparedWithKind<'a> {
+    entities: &'a [Entity],
+    kind: EntityKind,
+}
+
+impl<'w, 'q> PreparedFetch<'q> for PreparedWithKind<'w> {
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let matches = |slot: Slot| self.entities[slot].kind().contains(self.kind);
+
+        let first = slots.iter().position(matches).unwrap_or(slots.len());
+
+        let count = slots
+            .iter()
+            .skip(first)
+            .take_while(|&slot| matches(slot))
+            .count();
+
+        Slice::new(slots.start + first, slots.start + first + count)
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
     }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
 }
 
-#[derive(Debug, Clone)]
-/// Opposite of [crate::filter::Without]
-pub struct WithoutRelation {
-    pub(crate) relation: Entity,
-    pub(crate) name: &'static str,
+/// Filters archetypes by a custom predicate.
+///
+/// See [`Query::filter_arch`](crate::Query::filter_arch)
+#[derive(Clone)]
+pub struct ArchFilter<F> {
+    func: F,
 }
 
-impl<'q> FetchItem<'q> for WithoutRelation {
+impl<F> ArchFilter<F>
+where
+    F: Fn(ArchInfo) -> bool,
+{
+    /// Creates a new filter which yields the entities of the archetypes matching `func`
+    pub fn new(func: F) -> Self {
+        Self { func }
+    }
+}
+
+impl<'q, F> FetchItem<'q> for ArchFilter<F> {
     type Item = ();
 }
 
-impl<'a> Fetch<'a> for WithoutRelation {
+impl<'w, F> Fetch<'w> for ArchFilter<F>
+where
+    F: Fn(ArchInfo) -> bool,
+{
     const MUTABLE: bool = false;
 
     type Prepared = All;
 
-    fn prepare(&self, _: FetchPrepareData) -> Option<Self::Prepared> {
-        Some(All)
-    }
-
-    fn filter_arch(&self, data: FetchAccessData) -> bool {
-        self.filter_static(data.arch)
+    fn prepare(&self, data: FetchPrepareData) -> Option<Self::Prepared> {
+        if (self.func)(ArchInfo::new(data.arch_id, data.arch)) {
+            Some(All)
+        } else {
+            None
+        }
     }
 
-    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "without {}(*)", self.name)
+    // The predicate may depend on the entities of the archetype, and is as such evaluated when
+    // the archetype is prepared rather than cached with the matched archetypes.
+    #[inline]
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        true
     }
 
     #[inline]
     fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
-}
 
-impl StaticFilter for WithoutRelation {
-    fn filter_static(&self, arch: &Archetype) -> bool {
-        arch.relations_like(self.relation).next().is_none()
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("filter_arch")
     }
 }
 
@@ -490,6 +611,8 @@ where
     type Item = F::Item;
 }
 
+unsafe impl<'a, F: TrustedAccess> TrustedAccess for RefFetch<'a, F> {}
+
 impl<'a, 'w, F> Fetch<'w> for RefFetch<'a, F>
 where
     F: Fetch<'w>,
@@ -524,13 +647,15 @@ where
     }
 }
 
-impl<'a, 'q, F> FetchItem<'q> for &'a F
+impl<'q, F> FetchItem<'q> for &F
 where
     F: FetchItem<'q>,
 {
     type Item = F::Item;
 }
 
+unsafe impl<F: TrustedAccess> TrustedAccess for &F {}
+
 impl<'a, 'w, F> Fetch<'w> for &'a F
 where
     'a: 'w,
@@ -580,7 +705,11 @@ impl<'q> PreparedFetch<'q> for BatchSize {
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -590,6 +719,8 @@ impl<'q> FetchItem<'q> for BatchSize {
     type Item = ();
 }
 
+unsafe impl TrustedAccess for BatchSize {}
+
 impl<'w> Fetch<'w> for BatchSize {
     const MUTABLE: bool = false;
 
@@ -638,7 +769,6 @@ mod tests {
 
     use crate::{
         archetype::{ArchetypeId, Change, ChangeKind, ChangeList},
-        component,
         filter::change::ChangeFetch,
         World,
     };
@@ -780,6 +910,7 @@ mod tests {
                     arch_id: ArchetypeId::MAX,
                     old_tick: 0,
                     new_tick: 1,
+                    exclusive: false,
                 })
                 .unwrap(),
         )
@@ -1,6 +1,8 @@
 use crate::{
-    archetype::{Archetype, Slice},
-    fetch::{FetchAccessData, FetchPrepareData, FmtQuery, PreparedFetch, UnionFilter},
+    archetype::{Archetype, Slice, Slot},
+    fetch::{
+        FetchAccessData, FetchPrepareData, FmtQuery, PreparedFetch, TrustedAccess, UnionFilter,
+    },
     filter::StaticFilter,
     system::Access,
     Fetch, FetchItem,
@@ -28,6 +30,8 @@ where
     type Item = (L::Item, R::Item);
 }
 
+unsafe impl<L: TrustedAccess, R: TrustedAccess> TrustedAccess for And<L, R> {}
+
 impl<'w, L, R> Fetch<'w> for And<L, R>
 where
     L: Fetch<'w>,
@@ -82,7 +86,10 @@ where
         self.1.filter_slots(l)
     }
 
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         (self.0.create_chunk(slots), self.1.create_chunk(slots))
     }
 
@@ -104,6 +111,8 @@ impl<'q, T> FetchItem<'q> for Not<T> {
     type Item = ();
 }
 
+unsafe impl<T: TrustedAccess> TrustedAccess for Not<T> {}
+
 impl<'w, T> Fetch<'w> for Not<T>
 where
     T: Fetch<'w>,
@@ -117,7 +126,9 @@ where
     }
 
     fn filter_arch(&self, data: FetchAccessData) -> bool {
-        !self.0.filter_arch(data)
+        // A slot level filter may reject only some entities of a matched archetype, which are then
+        // yielded by the negation
+        !self.0.filter_arch(data) || <T::Prepared as PreparedFetch<'w>>::HAS_FILTER
     }
 
     #[inline]
@@ -144,14 +155,24 @@ where
         if let Some(fetch) = &mut self.0 {
             let v = fetch.filter_slots(slots);
 
-            slots.difference(v).unwrap()
+            if v.start > slots.start {
+                // Yield the slots before the first match
+                Slice::new(slots.start, v.start)
+            } else {
+                // Skip the matched slots, as later slots may match again
+                Slice::new(v.end, v.end)
+            }
         } else {
             slots
         }
     }
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, _: Slice) -> Self::Chunk {}
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
 
     #[inline]
     unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
@@ -202,6 +223,8 @@ where
     type Item = T::Item;
 }
 
+unsafe impl<T: TrustedAccess> TrustedAccess for Union<T> {}
+
 impl<'w, T> Fetch<'w> for Union<T>
 where
     T: Fetch<'w>,
@@ -254,7 +277,10 @@ where
     type Chunk = T::Chunk;
 
     #[inline]
-    unsafe fn create_chunk(&'q mut self, slots: Slice) -> Self::Chunk {
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
         self.0.create_chunk(slots)
     }
 
@@ -264,6 +290,102 @@ where
     }
 }
 
+/// Exclusive or combinator.
+///
+/// Matches the slots where exactly one of the two filters pass.
+///
+/// See [`ExactlyOneOf`] for more than two filters.
+#[derive(Debug, Clone)]
+pub struct Xor<L, R>(pub L, pub R);
+
+impl<'q, L, R> FetchItem<'q> for Xor<L, R> {
+    type Item = ();
+}
+
+unsafe impl<L: TrustedAccess, R: TrustedAccess> TrustedAccess for Xor<L, R> {}
+
+impl<'w, L, R> Fetch<'w> for Xor<L, R>
+where
+    L: Fetch<'w>,
+    R: Fetch<'w>,
+{
+    const MUTABLE: bool = L::MUTABLE || R::MUTABLE;
+
+    type Prepared = ExactlyOneOf<(Option<L::Prepared>, Option<R::Prepared>)>;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        Some(ExactlyOneOf((self.0.prepare(data), self.1.prepare(data))))
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        self.0.filter_arch(data) || self.1.filter_arch(data)
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        self.0.access(data, dst);
+        self.1.access(data, dst);
+    }
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.describe(f)?;
+        f.write_str(" ^ ")?;
+        self.1.describe(f)?;
+
+        Ok(())
+    }
+}
+
+impl<L: StaticFilter, R: StaticFilter> StaticFilter for Xor<L, R> {
+    fn filter_static(&self, arch: &Archetype) -> bool {
+        self.0.filter_static(arch) != self.1.filter_static(arch)
+    }
+}
+
+/// Matches the slots where exactly one of the filters in the tuple pass.
+///
+/// This is useful for validating that an entity is in exactly one of several mutually exclusive
+/// states.
+#[derive(Debug, Clone)]
+pub struct ExactlyOneOf<T>(pub T);
+
+/// Returns the first run of slots in `slots` where exactly one filter passes.
+///
+/// `filter` returns the first passing run of each filter for the given slots, or `None` if the
+/// filter does not match the archetype.
+fn exactly_one<const N: usize>(
+    slots: Slice,
+    mut filter: impl FnMut(Slice) -> [Option<Slice>; N],
+) -> Slice {
+    let mut start: Slot = slots.start;
+    while start < slots.end {
+        let runs = filter(Slice::new(start, slots.end));
+        let passing = || runs.iter().flatten().filter(|v| !v.is_empty());
+
+        let Some(first) = passing().map(|v| v.start).min() else {
+            break;
+        };
+
+        if passing().filter(|v| v.start == first).count() == 1 {
+            // The run ends where the filter stops passing, or where any other filter starts to
+            let end = passing()
+                .map(|v| if v.start == first { v.end } else { v.start })
+                .min()
+                .unwrap();
+
+            return Slice::new(first, end);
+        }
+
+        // Skip past the overlap
+        start = passing()
+            .filter(|v| v.start == first)
+            .map(|v| v.end)
+            .min()
+            .unwrap();
+    }
+
+    Slice::new(slots.end, slots.end)
+}
+
 macro_rules! tuple_impl {
     ($($idx: tt => $ty: ident),*) => {
         // Or
@@ -271,6 +393,8 @@ macro_rules! tuple_impl {
             type Item = ();
         }
 
+        unsafe impl<$($ty: TrustedAccess, )*> TrustedAccess for Or<($($ty,)*)> {}
+
         impl<'w, $($ty, )*> Fetch<'w> for Or<($($ty,)*)>
         where $($ty: Fetch<'w>,)*
         {
@@ -334,8 +458,85 @@ macro_rules! tuple_impl {
             unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
 
             #[inline]
-            unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk {}
+            unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+            where
+                Self: 'q,
+            {
+            }
+
+        }
+
+        // ExactlyOneOf
+        impl<'q, $($ty, )*> FetchItem<'q> for ExactlyOneOf<($($ty,)*)> {
+            type Item = ();
+        }
+
+        unsafe impl<$($ty: TrustedAccess, )*> TrustedAccess for ExactlyOneOf<($($ty,)*)> {}
+
+        impl<'w, $($ty, )*> Fetch<'w> for ExactlyOneOf<($($ty,)*)>
+        where $($ty: Fetch<'w>,)*
+        {
+            const MUTABLE: bool =  $($ty::MUTABLE )||*;
+            type Prepared       = ExactlyOneOf<($(Option<$ty::Prepared>,)*)>;
+
+            fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+                let inner = &self.0;
+                Some( ExactlyOneOf(($(inner.$idx.prepare(data),)*)) )
+            }
+
+            fn filter_arch(&self, data: FetchAccessData) -> bool {
+                let inner = &self.0;
+                $(inner.$idx.filter_arch(data))||*
+            }
+
+            fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+                 $(self.0.$idx.access(data, dst);)*
+            }
+
+            fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut s = f.debug_tuple("ExactlyOneOf");
+                    let inner = &self.0;
+                $(
+                    s.field(&FmtQuery(&inner.$idx));
+                )*
+                s.finish()
+            }
+        }
+
+        impl<$($ty: StaticFilter, )*> StaticFilter for ExactlyOneOf<($($ty,)*)> {
+            fn filter_static(&self, arch: &Archetype) -> bool {
+                let inner = &self.0;
+                [$(inner.$idx.filter_static(arch)),*].into_iter().filter(|&v| v).count() == 1
+            }
+        }
+
+        impl<'q, $($ty, )*> PreparedFetch<'q> for ExactlyOneOf<($(Option<$ty>,)*)>
+        where $($ty: PreparedFetch<'q>,)*
+        {
+            type Item = ();
+            type Chunk = ();
+
+            const HAS_FILTER: bool = true;
+
+            unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+                let inner = &mut self.0;
+
+                exactly_one(slots, |slots| {
+                    [
+                        $( inner.$idx.as_mut().map(|v| v.filter_slots(slots))),*
+                    ]
+                })
+            }
+
+            #[inline]
+            unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
 
+            #[inline]
+            unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+            where
+                Self: 'q,
+            {
+            }
         }
 
         impl<'q, $($ty, )*> UnionFilter for Or<($(Option<$ty>,)*)>
@@ -392,4 +593,27 @@ mod tests {
             [Slice::new(0, 2), Slice::new(3, 10), Slice::new(10, 16)]
         );
     }
+
+    #[test]
+    fn exactly_one_of() {
+        let fetch = ExactlyOneOf((
+            Some(Slice::new(0, 4)),
+            Some(Slice::new(2, 8)),
+            None::<Slice>,
+            Some(Slice::new(12, 14)),
+            Some(Slice::new(13, 20)),
+        ));
+
+        let fetch = FilterIter::new(Slice::new(0, 100), fetch);
+
+        assert_eq!(
+            fetch.collect_vec(),
+            [
+                Slice::new(0, 2),
+                Slice::new(4, 8),
+                Slice::new(12, 13),
+                Slice::new(14, 20)
+            ]
+        );
+    }
 }
@@ -1,10 +1,14 @@
-use core::fmt::{self, Debug, Formatter};
+use core::{
+    cell::RefCell,
+    fmt::{self, Debug, Display, Formatter},
+};
 
 use crate::{
     archetype::{Archetype, Slot},
     component::ComponentKey,
+    components::name,
     metadata::debuggable,
-    Entity, Fetch, Query, World,
+    Entity, Fetch, FetchItem, Query, QueryBorrow, World,
 };
 
 /// Debug formats the world with the given filter.
@@ -23,9 +27,10 @@ where
 
         let mut query = Query::new(())
             .with_components()
+            .include_disabled()
             .filter(self.filter.by_ref());
 
-        let mut query = query.borrow(self.world);
+        let mut query = query.borrow_unchecked(self.world);
 
         for batch in query.iter_batched() {
             let arch = batch.arch();
@@ -109,6 +114,88 @@ impl<'a> Debug for EntityFormatter<'a> {
     }
 }
 
+/// Formats the entities matched by a query along with their fetched items.
+///
+/// Created using [`QueryBorrow::format`]
+pub struct QueryFormatter<'a, 'w, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+{
+    pub(crate) world: &'w World,
+    pub(crate) borrow: RefCell<&'a mut QueryBorrow<'w, Q, F>>,
+}
+
+impl<'a, 'w, Q, F> QueryFormatter<'a, 'w, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    for<'x> <Q as FetchItem<'x>>::Item: Debug,
+{
+    fn for_each(&self, mut f: impl FnMut(EntityLabel, &dyn Debug) -> fmt::Result) -> fmt::Result {
+        let world = self.world;
+        let mut borrow = self.borrow.borrow_mut();
+
+        for mut chunk in borrow.iter_batched() {
+            while let Some((id, item)) = chunk.next_with_id() {
+                f(EntityLabel { world, id }, &item)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, 'w, Q, F> Debug for QueryFormatter<'a, 'w, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    for<'x> <Q as FetchItem<'x>>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        self.for_each(|label, item| {
+            map.entry(&label, item);
+            Ok(())
+        })?;
+
+        map.finish()
+    }
+}
+
+impl<'a, 'w, Q, F> Display for QueryFormatter<'a, 'w, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    for<'x> <Q as FetchItem<'x>>::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.for_each(|label, item| writeln!(f, "{label:?}: {item:?}"))
+    }
+}
+
+/// Formats an entity by its name, if any, and id
+struct EntityLabel<'a> {
+    world: &'a World,
+    id: Entity,
+}
+
+impl<'a> Debug for EntityLabel<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // The name may be mutably borrowed by the query itself
+        let name = self
+            .world
+            .location(self.id)
+            .ok()
+            .and_then(|loc| self.world.try_get_at(loc, name()).ok().flatten());
+
+        match name {
+            Some(name) => write!(f, "{} {}", *name, self.id),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
 pub(crate) struct MissingDebug;
 
 impl Debug for MissingDebug {
@@ -124,22 +211,14 @@ pub(crate) struct RowValueFormatter<'a> {
     pub slot: Slot,
 }
 
-struct ComponentName {
-    base_name: &'static str,
-    id: ComponentKey,
-}
-
-impl Debug for ComponentName {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.base_name, self.id)
-    }
-}
-
 impl<'a> Debug for RowValueFormatter<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut map = f.debug_map();
         for data in self.arch.try_borrow_all().flatten() {
             let desc = data.storage.desc();
+            if self.world.is_hidden(desc.key()) {
+                continue;
+            }
 
             if let Ok(visitor) = self.world.get(desc.key().id, debuggable()) {
                 map.entry(&desc, (visitor.debug_storage)(&data.storage, self.slot));
@@ -227,6 +306,23 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn disabled_entities() {
+        use alloc::format;
+
+        use crate::components::disabled;
+
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(name(), "hidden".into())
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        let s = format!("{:?}", world.format_debug(name().with()));
+        assert!(s.contains(&format!("{id}")), "{s}");
+        assert!(s.contains("hidden"), "{s}");
+    }
+
     #[test]
     fn tree_formatter() {
         let mut world = World::new();
@@ -248,4 +344,73 @@ mod tests {
         #[cfg(feature = "std")]
         println!("{}", s)
     }
+
+    #[test]
+    fn hidden_components() {
+        crate::component! {
+            bookkeeping: u32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(name(), "a".into())
+            .set(bookkeeping(), 5)
+            .spawn(&mut world);
+
+        world.hide_component(bookkeeping());
+
+        let s = alloc::format!("{:?}", world.format_entities(&[id]));
+        assert!(s.contains("name"));
+        assert!(!s.contains("bookkeeping"));
+
+        let entity = world.entity(id).unwrap();
+        assert_eq!(
+            entity
+                .components()
+                .map(|v| v.key())
+                .collect::<alloc::vec::Vec<_>>(),
+            [name().key()]
+        );
+        assert_eq!(entity.components_with_hidden().count(), 2);
+        assert_eq!(*entity.get(bookkeeping()).unwrap(), 5);
+
+        world.show_component(bookkeeping());
+        let s = alloc::format!("{:?}", world.format_entities(&[id]));
+        assert!(s.contains("bookkeeping"));
+    }
+
+    #[test]
+    fn query_formatter() {
+        use alloc::{format, vec::Vec};
+
+        crate::component! {
+            health: f32,
+        }
+
+        let mut world = World::new();
+        let a = Entity::builder()
+            .set(name(), "a".into())
+            .set(health(), 1.0)
+            .spawn(&mut world);
+        let b = Entity::builder().set(health(), 2.0).spawn(&mut world);
+
+        let mut query = Query::new(health());
+        let mut borrow = query.borrow(&world).unwrap();
+
+        let s = format!("{}", borrow.format());
+        let mut lines = s.lines().collect::<Vec<_>>();
+        lines.sort();
+        let mut expected = [format!("a {a}: 1.0"), format!("{b}: 2.0")];
+        expected.sort();
+        assert_eq!(lines, expected);
+
+        let s = format!("{:?}", borrow.format());
+        assert!(s.contains(&format!("a {a}: 1.0")));
+        drop(borrow);
+
+        // The name can not be read while mutably borrowed by the query
+        let mut query = Query::new((name().as_mut(), health()));
+        let s = format!("{}", query.borrow(&world).unwrap().format());
+        assert_eq!(s, format!("{a}: (\"a\", 1.0)\n"));
+    }
 }
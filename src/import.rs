@@ -0,0 +1,148 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+
+use crate::{component::ComponentValue, error::Result, BatchSpawn, Component, Entity, World};
+
+type Converter = Box<dyn Fn(&hecs::Archetype, &mut BatchSpawn) -> Result<()>>;
+
+/// Bulk imports entities from a [`hecs`] world, allowing an existing codebase to be migrated
+/// incrementally.
+///
+/// Only the registered components are imported. Each entity is spawned into the flax world, even
+/// if it has none of the registered components, and the mapping from the `hecs` entities to the
+/// spawned entities is returned to remap references between entities.
+///
+/// Each `hecs` archetype is converted and spawned as a single batch.
+///
+/// ```rust
+/// # use flax::{*, import::HecsImport};
+/// component! {
+///     health: f32,
+/// }
+///
+/// let mut src = hecs::World::new();
+/// let a = src.spawn((5.0_f32,));
+///
+/// let mut world = World::new();
+/// let map = HecsImport::new()
+///     .component(health())
+///     .import(&src, &mut world)
+///     .unwrap();
+///
+/// assert_eq!(world.get(map[&a], health()).as_deref(), Ok(&5.0));
+/// ```
+#[derive(Default)]
+pub struct HecsImport {
+    converters: Vec<Converter>,
+}
+
+impl HecsImport {
+    /// Creates a new importer without any registered components
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Imports the `hecs` component of the same type as `component`
+    pub fn component<T: ComponentValue + Clone>(self, component: Component<T>) -> Self {
+        self.component_with(component, T::clone)
+    }
+
+    /// Imports the `hecs` component of type `U`, converting it into the value of `component`
+    pub fn component_with<T, U>(
+        mut self,
+        component: Component<T>,
+        convert: impl Fn(&U) -> T + 'static,
+    ) -> Self
+    where
+        T: ComponentValue,
+        U: hecs::Component,
+    {
+        self.converters.push(Box::new(move |arch, batch| {
+            if let Some(column) = arch.get::<&U>() {
+                batch.set(component, column.iter().map(&convert))?;
+            }
+
+            Ok(())
+        }));
+        self
+    }
+
+    /// Spawns all entities of `src` into `dst`.
+    ///
+    /// Returns the flax entity of each `hecs` entity.
+    ///
+    /// All archetypes are converted before spawning, so nothing is spawned into `dst` if the
+    /// import fails.
+    pub fn import(
+        &self,
+        src: &hecs::World,
+        dst: &mut World,
+    ) -> Result<BTreeMap<hecs::Entity, Entity>> {
+        // Archetypes only store the entity index
+        let entities = src
+            .iter()
+            .map(|entity| (entity.entity().id(), entity.entity()))
+            .collect::<BTreeMap<_, _>>();
+
+        let batches = src
+            .archetypes()
+            .filter(|arch| !arch.ids().is_empty())
+            .map(|arch| {
+                let mut batch = BatchSpawn::new(arch.ids().len());
+                for converter in &self.converters {
+                    converter(arch, &mut batch)?;
+                }
+
+                Ok((arch.ids(), batch))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut map = BTreeMap::new();
+        for (ids, mut batch) in batches {
+            let spawned = batch.spawn(dst);
+            map.extend(ids.iter().map(|id| entities[id]).zip(spawned));
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+
+    use super::*;
+
+    component! {
+        name: String,
+        position: (f32, f32),
+    }
+
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn import_hecs() {
+        let mut src = hecs::World::new();
+        let a = src.spawn((String::from("a"), Position { x: 1.0, y: 2.0 }));
+        let b = src.spawn((Position { x: 3.0, y: 4.0 },));
+        let c = src.spawn((5_u32,));
+
+        let mut world = World::new();
+        let map = HecsImport::new()
+            .component(name())
+            .component_with(position(), |v: &Position| (v.x, v.y))
+            .import(&src, &mut world)
+            .unwrap();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(world.get(map[&a], name()).as_deref(), Ok(&"a".into()));
+        assert_eq!(world.get(map[&a], position()).as_deref(), Ok(&(1.0, 2.0)));
+        assert_eq!(world.get(map[&b], position()).as_deref(), Ok(&(3.0, 4.0)));
+        assert!(!world.has(map[&b], name()));
+
+        // Entities without any imported components are still spawned
+        assert!(world.is_alive(map[&c]));
+    }
+}
@@ -0,0 +1,505 @@
+use core::{
+    any::TypeId,
+    fmt::{self, Debug, Formatter},
+};
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use atomic_refcell::{AtomicRefCell, AtomicRefMut};
+
+use crate::{
+    archetype::{Slice, Slot},
+    component::ComponentValue,
+    entity_ids,
+    events::{Event, EventSubscriber},
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch},
+    system::{Access, AccessKind},
+    Component, Entity, Fetch, FetchItem, Query, World,
+};
+
+/// Maps the values of a component to the entities which have them.
+///
+/// Change events are buffered and applied when the index is next read.
+pub(crate) struct ValueIndex<T> {
+    component: Component<T>,
+    state: AtomicRefCell<IndexState<T>>,
+    rx: flume::Receiver<Event>,
+}
+
+struct IndexState<T> {
+    values: BTreeMap<T, BTreeSet<Entity>>,
+    entities: BTreeMap<Entity, T>,
+    /// Entities which could not be read as the component was borrowed
+    dirty: BTreeSet<Entity>,
+}
+
+impl<T: ComponentValue + Ord + Clone> ValueIndex<T> {
+    pub(crate) fn new(world: &mut World, component: Component<T>) -> Self {
+        let (tx, rx) = flume::unbounded();
+        world.subscribe(tx.filter_components([component.key()]));
+
+        let mut state = IndexState {
+            values: BTreeMap::new(),
+            entities: BTreeMap::new(),
+            dirty: BTreeSet::new(),
+        };
+
+        let mut query = Query::new((entity_ids(), component)).include_disabled();
+        for (id, value) in &mut query.borrow_unchecked(world) {
+            state.insert(id, value.clone());
+        }
+
+        Self {
+            component,
+            state: AtomicRefCell::new(state),
+            rx,
+        }
+    }
+
+    /// Returns the sorted entities which currently have `value`
+    pub(crate) fn lookup(&self, world: &World, value: &T) -> Vec<Entity> {
+        let mut state = self.state.borrow_mut();
+        state.dirty.extend(self.rx.drain().map(|v| v.id));
+        state.refresh(world, self.component);
+
+        state
+            .values
+            .get(value)
+            .map(|v| v.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl<T: ComponentValue + Ord + Clone> IndexState<T> {
+    fn refresh(&mut self, world: &World, component: Component<T>) {
+        for (id, value) in read_dirty(&mut self.dirty, world, component) {
+            match value {
+                Some(value) => self.insert(id, value),
+                None => self.remove(id),
+            }
+        }
+    }
+
+    fn insert(&mut self, id: Entity, value: T) {
+        if let Some(old) = self.entities.get(&id) {
+            if *old == value {
+                return;
+            }
+
+            self.remove(id);
+        }
+
+        self.values.entry(value.clone()).or_default().insert(id);
+        self.entities.insert(id, value);
+    }
+
+    fn remove(&mut self, id: Entity) {
+        if let Some(old) = self.entities.remove(&id) {
+            if let Some(ids) = self.values.get_mut(&old) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    self.values.remove(&old);
+                }
+            }
+        }
+    }
+}
+
+/// Reads the current value of `component` for each dirty entity, or `None` if it no longer has
+/// the component.
+///
+/// Entities whose component is currently borrowed are kept dirty.
+pub(crate) fn read_dirty<T: ComponentValue + Clone>(
+    dirty: &mut BTreeSet<Entity>,
+    world: &World,
+    component: Component<T>,
+) -> Vec<(Entity, Option<T>)> {
+    let mut values = Vec::new();
+    for id in core::mem::take(dirty) {
+        let Ok(loc) = world.location(id) else {
+            values.push((id, None));
+            continue;
+        };
+
+        match world.try_get_at(loc, component) {
+            Ok(value) => values.push((id, value.map(|v| v.clone()))),
+            Err(_) => {
+                dirty.insert(id);
+            }
+        }
+    }
+
+    values
+}
+
+/// Decides which entity a label resolves to when several entities share it.
+///
+/// See [`LabelMap::with_policy`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LabelPolicy {
+    /// Resolve to the entity which has held the label the longest
+    #[default]
+    First,
+    /// Resolve to the entity which most recently acquired the label
+    Last,
+    /// Panic when resolving a label held by more than one entity
+    Panic,
+}
+
+/// Maps the values of a key component, such as [`name`](crate::components::name), to the entity
+/// which has them.
+///
+/// Created by [`World::label_map`]. The map is kept up to date through change events, which are
+/// applied when the map is next read.
+pub struct LabelMap<T> {
+    component: Component<T>,
+    policy: LabelPolicy,
+    state: AtomicRefCell<LabelState<T>>,
+    rx: flume::Receiver<Event>,
+}
+
+struct LabelState<T> {
+    /// The entities with each label, in the order they acquired it
+    labels: BTreeMap<T, Vec<Entity>>,
+    entities: BTreeMap<Entity, T>,
+    dirty: BTreeSet<Entity>,
+}
+
+impl<T: ComponentValue + Ord + Clone> LabelMap<T> {
+    pub(crate) fn new(world: &mut World, component: Component<T>) -> Self {
+        let (tx, rx) = flume::unbounded();
+        world.subscribe(tx.filter_components([component.key()]));
+
+        let mut state = LabelState {
+            labels: BTreeMap::new(),
+            entities: BTreeMap::new(),
+            dirty: BTreeSet::new(),
+        };
+
+        let mut query = Query::new((entity_ids(), component)).include_disabled();
+        for (id, label) in &mut query.borrow_unchecked(world) {
+            state.insert(id, label.clone());
+        }
+
+        Self {
+            component,
+            policy: LabelPolicy::default(),
+            state: AtomicRefCell::new(state),
+            rx,
+        }
+    }
+
+    /// Sets how labels shared by several entities are resolved
+    pub fn with_policy(mut self, policy: LabelPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the entity with `label`, resolved according to the [`LabelPolicy`].
+    ///
+    /// # Panics
+    /// If the policy is [`LabelPolicy::Panic`] and several entities have `label`
+    pub fn get(&self, world: &World, label: &T) -> Option<Entity>
+    where
+        T: Debug,
+    {
+        let state = self.refresh(world);
+        let ids = state.labels.get(label)?;
+
+        match self.policy {
+            LabelPolicy::First => ids.first().copied(),
+            LabelPolicy::Last => ids.last().copied(),
+            LabelPolicy::Panic => {
+                assert!(
+                    ids.len() == 1,
+                    "Label {label:?} of {} is shared by {ids:?}",
+                    self.component.name()
+                );
+
+                ids.first().copied()
+            }
+        }
+    }
+
+    /// Returns all entities with `label`, in the order they acquired it
+    pub fn get_all(&self, world: &World, label: &T) -> Vec<Entity> {
+        self.refresh(world)
+            .labels
+            .get(label)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the label of `id`
+    pub fn label(&self, world: &World, id: Entity) -> Option<T> {
+        self.refresh(world).entities.get(&id).cloned()
+    }
+
+    /// Returns the labels which are shared by more than one entity
+    pub fn collisions(&self, world: &World) -> Vec<T> {
+        self.refresh(world)
+            .labels
+            .iter()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(label, _)| label.clone())
+            .collect()
+    }
+
+    /// Returns the number of distinct labels
+    pub fn len(&self, world: &World) -> usize {
+        self.refresh(world).labels.len()
+    }
+
+    /// Returns true if no entity has a label
+    pub fn is_empty(&self, world: &World) -> bool {
+        self.refresh(world).labels.is_empty()
+    }
+
+    fn refresh(&self, world: &World) -> AtomicRefMut<'_, LabelState<T>> {
+        let mut state = self.state.borrow_mut();
+        state.dirty.extend(self.rx.drain().map(|v| v.id));
+
+        let state_ref = &mut *state;
+        for (id, label) in read_dirty(&mut state_ref.dirty, world, self.component) {
+            match label {
+                Some(label) => state_ref.insert(id, label),
+                None => state_ref.remove(id),
+            }
+        }
+
+        state
+    }
+}
+
+impl<T> Debug for LabelMap<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabelMap")
+            .field("component", &self.component)
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Ord + Clone> LabelState<T> {
+    fn insert(&mut self, id: Entity, label: T) {
+        if let Some(old) = self.entities.get(&id) {
+            if *old == label {
+                return;
+            }
+
+            self.remove(id);
+        }
+
+        self.labels.entry(label.clone()).or_default().push(id);
+        self.entities.insert(id, label);
+    }
+
+    fn remove(&mut self, id: Entity) {
+        if let Some(old) = self.entities.remove(&id) {
+            if let Some(ids) = self.labels.get_mut(&old) {
+                ids.retain(|&v| v != id);
+                if ids.is_empty() {
+                    self.labels.remove(&old);
+                }
+            }
+        }
+    }
+}
+
+/// Filter for entities whose indexed component equals a value.
+///
+/// See [`equals_indexed`]
+pub struct EqualsIndexed<T> {
+    component: Component<T>,
+    value: T,
+}
+
+/// Filter entities whose `component` equals `value`, using the index created by
+/// [`World::create_index`] rather than comparing each entity.
+///
+/// Matches nothing if no index exists for `component`.
+///
+/// The filter does not borrow `component`, which allows it to be used in the same query as
+/// `component.as_mut()`. Values modified during the same iteration are not reflected until the
+/// next time the query is prepared.
+pub fn equals_indexed<T: ComponentValue + Ord + Clone>(
+    component: Component<T>,
+    value: T,
+) -> EqualsIndexed<T> {
+    EqualsIndexed { component, value }
+}
+
+impl<'q, T: ComponentValue> FetchItem<'q> for EqualsIndexed<T> {
+    type Item = ();
+}
+
+impl<'w, T: ComponentValue + Ord + Clone + Debug> Fetch<'w> for EqualsIndexed<T> {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedEqualsIndexed;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let slots = data
+            .world
+            .lookup(self.component, &self.value)?
+            .into_iter()
+            .filter_map(|id| data.world.location(id).ok())
+            .filter(|loc| loc.arch_id == data.arch_id)
+            .map(|loc| loc.slot)
+            .collect::<BTreeSet<_>>();
+
+        if slots.is_empty() {
+            return None;
+        }
+
+        Some(PreparedEqualsIndexed {
+            slots: slots.into_iter().collect(),
+        })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        data.arch.has(self.component.key())
+    }
+
+    fn access(&self, _: FetchAccessData, dst: &mut Vec<Access>) {
+        // Reading the index applies pending events
+        dst.push(Access {
+            kind: AccessKind::External(TypeId::of::<ValueIndex<T>>()),
+            mutable: true,
+        })
+    }
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} == {:?} (indexed)", self.component.name(), self.value)
+    }
+
+    fn searcher(&self, searcher: &mut crate::ArchetypeSearcher) {
+        searcher.add_required(self.component.key())
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedEqualsIndexed {
+    /// Sorted slots of the archetype with the value
+    slots: Vec<Slot>,
+}
+
+impl<'q> PreparedFetch<'q> for PreparedEqualsIndexed {
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let first = self.slots.partition_point(|&v| v < slots.start);
+
+        let Some(&start) = self.slots.get(first) else {
+            return slots.empty_tail();
+        };
+
+        if start >= slots.end {
+            return slots.empty_tail();
+        }
+
+        // Extend over the contiguous run of slots
+        let count = self.slots[first..]
+            .iter()
+            .zip(start..slots.end)
+            .take_while(|&(&a, b)| a == b)
+            .count();
+
+        Slice::new(start, start + count)
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use itertools::Itertools;
+
+    use crate::components::name;
+
+    use super::*;
+
+    component! {
+        player_id: u32,
+    }
+
+    #[test]
+    fn value_index() {
+        let mut world = World::new();
+
+        let ids = (0..8)
+            .map(|i| Entity::builder().set(player_id(), i % 4).spawn(&mut world))
+            .collect_vec();
+
+        world.create_index(player_id());
+
+        assert_eq!(world.lookup(player_id(), &1), Some(vec![ids[1], ids[5]]));
+        assert_eq!(world.lookup(player_id(), &9), Some(Vec::new()));
+
+        *world.get_mut(ids[1], player_id()).unwrap() = 9;
+        world.despawn(ids[5]).unwrap();
+        world.set(ids[2], player_id(), 1).unwrap();
+
+        assert_eq!(world.lookup(player_id(), &1), Some(vec![ids[2]]));
+        assert_eq!(world.lookup(player_id(), &9), Some(vec![ids[1]]));
+
+        let mut query =
+            Query::new((entity_ids(), player_id().as_mut())).filter(equals_indexed(player_id(), 3));
+
+        for (_, id) in &mut query.borrow(&world).unwrap() {
+            *id = 4;
+        }
+
+        assert_eq!(world.lookup(player_id(), &3), Some(Vec::new()));
+        assert_eq!(world.lookup(player_id(), &4), Some(vec![ids[3], ids[7]]));
+
+        component! {
+            not_indexed: u32,
+        }
+
+        assert_eq!(world.lookup(not_indexed(), &1), None);
+    }
+
+    #[test]
+    fn label_map() {
+        let mut world = World::new();
+
+        let ids =
+            ["a", "b", "c"].map(|v| Entity::builder().set(name(), v.into()).spawn(&mut world));
+
+        let labels = world.label_map(name());
+        assert_eq!(labels.get(&world, &"b".into()), Some(ids[1]));
+        assert_eq!(labels.label(&world, ids[2]), Some("c".into()));
+        assert_eq!(labels.len(&world), 3);
+
+        world.set(ids[2], name(), "b".into()).unwrap();
+        assert_eq!(labels.get(&world, &"b".into()), Some(ids[1]));
+        assert_eq!(labels.get_all(&world, &"b".into()), [ids[1], ids[2]]);
+        assert_eq!(labels.collisions(&world), ["b"]);
+        assert_eq!(labels.get(&world, &"c".into()), None);
+
+        let labels = labels.with_policy(LabelPolicy::Last);
+        assert_eq!(labels.get(&world, &"b".into()), Some(ids[2]));
+
+        world.despawn(ids[1]).unwrap();
+        world.remove(ids[0], name()).unwrap();
+        assert_eq!(labels.get(&world, &"b".into()), Some(ids[2]));
+        assert_eq!(labels.get(&world, &"a".into()), None);
+        assert!(labels.collisions(&world).is_empty());
+        assert_eq!(labels.len(&world), 1);
+    }
+}
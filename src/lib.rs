@@ -55,7 +55,7 @@
 //!   let mut query = Query::new((health().as_mut(), regen()));
 //!
 //!   // Apply health regeneration for all matched entites
-//!   for (health, regen) in &mut query.borrow(&world) {
+//!   for (health, regen) in &mut query.borrow(&world).unwrap() {
 //!       *health = (*health + regen).min(100.0);
 //!   }
 //!
@@ -203,6 +203,8 @@ mod macros;
 
 /// Structured component storage
 pub mod archetype;
+/// Variable length component values
+mod blob;
 /// Provides a buffer for holding multiple types simultaneously
 pub mod buffer;
 /// Contains a commandbuffer
@@ -217,11 +219,14 @@ pub mod filter;
 pub mod system;
 /// Contains the main ecs world
 pub mod world;
+mod world_cell;
 
 /// Provides a debug visitor
 // mod cascade;
 mod archetypes;
 pub mod components;
+pub mod debug;
+pub mod dirty;
 mod entity_ref;
 mod entry;
 /// Defines the single error type and result alias
@@ -236,6 +241,8 @@ pub mod format;
 pub mod metadata;
 /// Query the world
 pub mod query;
+/// Runtime access to component fields through property paths
+pub mod reflect;
 /// Low level relation construction
 pub mod relation;
 /// System execution
@@ -246,8 +253,27 @@ pub mod schedule;
 /// entities therein
 pub mod serialize;
 
+#[cfg(feature = "hecs")]
+/// Imports entities from other ECS libraries
+pub mod import;
+#[cfg(feature = "flume")]
+/// Indexes from component values to entities
+pub mod index;
+#[cfg(feature = "flume")]
+/// Mirrors component values to and from an external store, such as the state of a UI
+mod mirror;
 /// Provides a sink trait for sending events
 pub mod sink;
+pub mod snapshot;
+/// Spatial indexing of entity positions
+#[cfg(feature = "spatial")]
+pub mod spatial;
+/// Finite state machines represented as exclusive relations
+pub mod state;
+/// Utilities for writing tests against a world
+pub mod testing;
+/// Interpolation of component values over time
+pub mod tween;
 /// Provides tuple utilities like `cloned`
 mod util;
 /// vtable implementation for dynamic dispatching
@@ -256,26 +282,31 @@ mod writer;
 
 // Required due to macro
 pub use archetype::{BatchSpawn, RefMut};
+pub use blob::Blob;
 pub use commands::CommandBuffer;
 pub use component::Component;
-pub use entity::{entity_ids, Entity, EntityBuilder};
+pub use entity::{entity_ids, EditBuilder, Entity, EntityBuilder, EntityTree};
 pub use entity_ref::{EntityRef, EntityRefMut};
 pub use entry::{Entry, OccupiedEntry, VacantEntry};
 pub use error::Error;
 pub use fetch::{
-    relations_like, EntityIds, Fetch, FetchExt, FetchItem, Mutable, Opt, OptOr, Relations,
+    relations_like, relations_like_mut, EntityIds, Fetch, FetchExt, FetchItem, Mutable, Opt, OptOr,
+    Relations, RelationsMut,
 };
 
-pub use metadata::{Debuggable, Exclusive};
+pub use metadata::{Debuggable, Exclusive, Immutable, NoLock, Transient, Unique};
+#[cfg(feature = "flume")]
+pub use mirror::{Mirror, MirrorStore};
 
 pub use query::{
-    Children, Dfs, DfsBorrow, DfsIter, EntityBorrow, EntityQuery, Planar, Query, QueryBorrow,
-    QueryIter, Topo,
+    Children, Combinations, Dfs, DfsBorrow, DfsIter, EntityBorrow, EntityQuery, Planar, Query,
+    QueryBorrow, QueryCursor, QueryIter, Topo, ZipDisjoint,
 };
 pub use relation::RelationExt;
 pub use schedule::{Schedule, ScheduleBuilder, SystemInfo};
 pub use system::{BoxedSystem, SharedResource, System, SystemBuilder};
 pub use world::World;
+pub use world_cell::{CellAccess, WorldCell};
 
 pub(crate) use query::ArchetypeSearcher;
 pub(crate) use vtable::ComponentVTable;
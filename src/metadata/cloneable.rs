@@ -0,0 +1,116 @@
+use core::fmt;
+
+use crate::{
+    archetype::{Archetype, Slot},
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+    relation::RelationExt,
+    Entity,
+};
+
+use super::Metadata;
+
+component! {
+    /// Allows cloning the component without knowing its type, attached by [`Cloneable`]
+    pub cloneable: CloneVTable,
+}
+
+/// Type erased cloning of a component
+#[derive(Clone, Copy)]
+pub struct CloneVTable {
+    from_arch: fn(&Archetype, ComponentDesc, Slot, Option<Entity>, &mut ComponentBuffer) -> bool,
+    from_buffer: fn(&ComponentBuffer, ComponentDesc, Option<Entity>, &mut ComponentBuffer) -> bool,
+}
+
+impl fmt::Debug for CloneVTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CloneVTable").finish_non_exhaustive()
+    }
+}
+
+impl CloneVTable {
+    /// Creates a vtable for a component of type `T`
+    pub fn new<T: ComponentValue + Clone>() -> Self {
+        fn from_arch<T: ComponentValue + Clone>(
+            arch: &Archetype,
+            desc: ComponentDesc,
+            slot: Slot,
+            target: Option<Entity>,
+            dst: &mut ComponentBuffer,
+        ) -> bool {
+            let Some(storage) = arch.borrow::<T>(desc.key()) else {
+                return false;
+            };
+
+            let Some(value) = storage.get().get(slot) else {
+                return false;
+            };
+
+            dst.set(retarget(desc, target), value.clone());
+            true
+        }
+
+        fn from_buffer<T: ComponentValue + Clone>(
+            src: &ComponentBuffer,
+            desc: ComponentDesc,
+            target: Option<Entity>,
+            dst: &mut ComponentBuffer,
+        ) -> bool {
+            let Some(value) = src.get(desc.downcast::<T>()) else {
+                return false;
+            };
+
+            dst.set(retarget(desc, target), value.clone());
+            true
+        }
+
+        Self {
+            from_arch: from_arch::<T>,
+            from_buffer: from_buffer::<T>,
+        }
+    }
+
+    /// Clones the component at `slot` into `dst`, optionally replacing the relation target.
+    pub(crate) fn clone_from_arch(
+        &self,
+        arch: &Archetype,
+        desc: ComponentDesc,
+        slot: Slot,
+        target: Option<Entity>,
+        dst: &mut ComponentBuffer,
+    ) -> bool {
+        (self.from_arch)(arch, desc, slot, target, dst)
+    }
+
+    /// Clones the component in `src` into `dst`, optionally replacing the relation target.
+    pub(crate) fn clone_from_buffer(
+        &self,
+        src: &ComponentBuffer,
+        desc: ComponentDesc,
+        target: Option<Entity>,
+        dst: &mut ComponentBuffer,
+    ) -> bool {
+        (self.from_buffer)(src, desc, target, dst)
+    }
+}
+
+fn retarget<T: ComponentValue>(desc: ComponentDesc, target: Option<Entity>) -> crate::Component<T> {
+    let component = desc.downcast::<T>();
+    match target {
+        Some(target) => component.of(target),
+        None => component,
+    }
+}
+
+/// Allows the component to be cloned into other entities or worlds, such as through
+/// [`World::clone_entities_into`](crate::World::clone_entities_into).
+pub struct Cloneable;
+
+impl<T> Metadata<T> for Cloneable
+where
+    T: ComponentValue + Clone,
+{
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(cloneable(), CloneVTable::new::<T>());
+    }
+}
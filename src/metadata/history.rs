@@ -0,0 +1,102 @@
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+};
+
+use super::Metadata;
+
+component! {
+    /// The number of changes retained for each entity, see [`History`].
+    pub change_history: usize,
+}
+
+/// Retains the last `N` changes of the component for each entity.
+///
+/// The changes are retrieved using [`World::change_history`](crate::World::change_history), and
+/// can be correlated with the system which ran at the recorded change tick to find out when, and
+/// by whom, a component was last touched.
+///
+/// The history of an entity is discarded when the component is removed.
+///
+/// ```rust
+/// # use flax::*;
+/// # use flax::metadata::History;
+/// component! {
+///     health: f32 => [ History<4> ],
+/// }
+///
+/// let mut world = World::new();
+/// let id = Entity::builder().set(health(), 100.0).spawn(&mut world);
+///
+/// *world.get_mut(id, health()).unwrap() -= 10.0;
+///
+/// let history = world.change_history(id, health()).unwrap();
+/// assert_eq!(history.len(), 2);
+/// ```
+pub struct History<const N: usize>;
+
+impl<T: ComponentValue, const N: usize> Metadata<T> for History<N> {
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(change_history(), N);
+    }
+}
+
+pub(crate) fn history_capacity(desc: &ComponentDesc) -> Option<usize> {
+    desc.meta_ref().get(change_history()).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{archetype::ChangeKind, Entity, Query, World};
+
+    use super::*;
+
+    component! {
+        health: f32 => [ History<2> ],
+        armor: f32,
+    }
+
+    #[test]
+    fn history() {
+        let mut world = World::new();
+        let id = Entity::builder().set(health(), 100.0).spawn(&mut world);
+
+        let kinds = |world: &World| {
+            world
+                .change_history(id, health())
+                .unwrap()
+                .iter()
+                .map(|v| v.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(kinds(&world), [ChangeKind::Added]);
+
+        Query::new(health().as_mut())
+            .borrow(&world)
+            .unwrap()
+            .for_each(|v| *v -= 10.0);
+
+        let tick = world.change_tick();
+        let history = world.change_history(id, health()).unwrap();
+        assert_eq!(history.last().unwrap().tick, tick);
+        assert_eq!(kinds(&world), [ChangeKind::Added, ChangeKind::Modified]);
+
+        // The history is retained when the entity moves to another archetype
+        world.set(id, armor(), 5.0).unwrap();
+        *world.get_mut(id, health()).unwrap() -= 10.0;
+
+        assert_eq!(kinds(&world), [ChangeKind::Modified, ChangeKind::Modified]);
+
+        // Changes are not retained for other components
+        assert_eq!(world.change_history(id, armor()).unwrap(), []);
+
+        world.remove(id, health()).unwrap();
+        assert!(world.change_history(id, health()).is_err());
+
+        world.set(id, health(), 50.0).unwrap();
+        assert_eq!(kinds(&world), [ChangeKind::Added]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+};
+
+use super::Metadata;
+
+component! {
+    /// The component can not be modified once inserted, see [`Immutable`].
+    pub immutable: (),
+}
+
+/// Declares a component which can only be written when it is inserted, such as through spawning
+/// or [`World::set`](crate::World::set).
+///
+/// Use for id-like components which external indexes depend on, as the value can never change
+/// without the component being replaced, which is observable through change detection and
+/// subscribers.
+///
+/// Accessing the component mutably through [`World::get_mut`](crate::World::get_mut) or
+/// [`World::update`](crate::World::update) fails with
+/// [`Error::ImmutableComponent`](crate::Error::ImmutableComponent), and creating a mutable fetch
+/// through [`Component::as_mut`](crate::Component::as_mut) panics.
+pub struct Immutable;
+
+impl<T: ComponentValue> Metadata<T> for Immutable {
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(immutable(), ());
+    }
+}
+
+pub(crate) fn is_immutable(desc: &ComponentDesc) -> bool {
+    desc.meta_ref().has(immutable())
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::String;
+
+    use crate::{error::Error, Entity, FetchExt, Query, World};
+
+    use super::*;
+
+    component! {
+        external_id: String => [ Immutable ],
+    }
+
+    #[test]
+    fn immutable() {
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(external_id(), "a".into())
+            .spawn(&mut world);
+
+        assert_eq!(
+            world.get_mut(id, external_id()).err(),
+            Some(Error::ImmutableComponent(external_id().desc()))
+        );
+        assert_eq!(
+            world.update(id, external_id(), |v| v.push('!')),
+            Err(Error::ImmutableComponent(external_id().desc()))
+        );
+
+        // The value can be replaced
+        world.set(id, external_id(), "b".into()).unwrap();
+        assert_eq!(*world.get(id, external_id()).unwrap(), "b");
+
+        let values = Query::new(external_id().cloned())
+            .borrow(&world)
+            .unwrap()
+            .iter()
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(values, ["b"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is declared `Immutable`")]
+    fn immutable_query() {
+        Query::new(external_id().as_mut());
+    }
+}
@@ -4,11 +4,30 @@ use crate::{
     components::name,
 };
 
+mod cloneable;
 mod debuggable;
+mod history;
+mod immutable;
+mod no_lock;
 mod relation;
+mod requires;
+mod transient;
+mod unique;
 
+pub use cloneable::*;
 pub use debuggable::*;
+pub(crate) use history::history_capacity;
+pub use history::{change_history, History};
+pub(crate) use immutable::is_immutable;
+pub use immutable::{immutable, Immutable};
+pub(crate) use no_lock::is_no_lock;
+pub use no_lock::{no_lock, NoLock};
 pub use relation::*;
+pub use requires::*;
+pub(crate) use transient::is_transient;
+pub use transient::{transient, Transient};
+pub(crate) use unique::is_unique;
+pub use unique::{unique, Unique};
 
 /// Additional data that can attach itself to a component
 ///
@@ -36,8 +55,6 @@ where
 mod test {
     use alloc::string::String;
 
-    use crate::component;
-
     use super::*;
 
     #[test]
@@ -0,0 +1,141 @@
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+};
+
+use super::Metadata;
+
+component! {
+    /// The component storage is not locked when read by queries, see [`NoLock`].
+    pub no_lock: (),
+}
+
+/// Declares a component which provides its own synchronization, such as a `Mutex<T>` or an atomic.
+///
+/// Queries read the component without locking the storage, which removes the overhead of locking
+/// twice when the value is already synchronized.
+///
+/// In return, the component is never writable through a shared `&World`. Borrowing the
+/// component mutably, such as through `as_mut()`, [`World::get_mut`](crate::World::get_mut) or
+/// [`World::update_dedup`](crate::World::update_dedup), panics rather than aliasing a concurrent
+/// read. The value can still be replaced using [`World::set`](crate::World::set), which requires
+/// exclusive access to the world.
+///
+/// ```rust
+/// # use flax::*;
+/// # use core::sync::atomic::{AtomicU32, Ordering};
+/// component! {
+///     hits: AtomicU32 => [ NoLock ],
+/// }
+///
+/// let mut world = World::new();
+/// let id = Entity::builder().set(hits(), AtomicU32::new(0)).spawn(&mut world);
+///
+/// let mut query = Query::new(hits());
+/// for v in &mut query.borrow(&world).unwrap() {
+///     v.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// assert_eq!(world.get(id, hits()).unwrap().load(Ordering::Relaxed), 1);
+/// ```
+pub struct NoLock;
+
+impl<T: ComponentValue> Metadata<T> for NoLock {
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(no_lock(), ());
+    }
+}
+
+pub(crate) fn is_no_lock(desc: &ComponentDesc) -> bool {
+    desc.meta_ref().has(no_lock())
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::{Entity, Query, World};
+
+    use super::*;
+
+    component! {
+        hits: AtomicU32 => [ NoLock ],
+        total: u32 => [ NoLock ],
+    }
+
+    #[test]
+    fn no_lock() {
+        let mut world = World::new();
+        let ids = (0..4)
+            .map(|i| {
+                Entity::builder()
+                    .set(hits(), AtomicU32::new(i))
+                    .spawn(&mut world)
+            })
+            .collect::<alloc::vec::Vec<_>>();
+
+        let mut query = Query::new(hits());
+        let mut query2 = Query::new(hits());
+
+        let mut borrow = query.borrow(&world).unwrap();
+        let mut borrow2 = query2.borrow(&world).unwrap();
+
+        for (a, b) in borrow.iter().zip(borrow2.iter()) {
+            a.fetch_add(1, Ordering::Relaxed);
+            b.fetch_add(1, Ordering::Relaxed);
+        }
+
+        drop((borrow, borrow2));
+
+        // Replacing the value requires exclusive access to the world
+        world.set(ids[0], hits(), AtomicU32::new(10)).unwrap();
+
+        let values = Query::new(hits())
+            .borrow(&world)
+            .unwrap()
+            .iter()
+            .map(|v| v.load(Ordering::Relaxed))
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(values, [10, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be borrowed mutably")]
+    fn no_lock_mut() {
+        let mut world = World::new();
+        Entity::builder()
+            .set(hits(), AtomicU32::new(0))
+            .spawn(&mut world);
+
+        let mut query = Query::new(hits().as_mut());
+        query
+            .borrow(&world)
+            .unwrap()
+            .for_each(|v| *v.get_mut() += 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be borrowed mutably")]
+    fn no_lock_get_mut() {
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(hits(), AtomicU32::new(0))
+            .spawn(&mut world);
+
+        let value = world.get(id, hits()).unwrap();
+        let _ = world.get_mut(id, hits());
+        drop(value);
+    }
+
+    #[test]
+    #[should_panic(expected = "can not be borrowed mutably")]
+    fn no_lock_update_dedup() {
+        let mut world = World::new();
+        let id = Entity::builder().set(total(), 0).spawn(&mut world);
+
+        let value = world.get(id, total()).unwrap();
+        let _ = world.update_dedup(id, total(), 1);
+        assert_eq!(*value, 0);
+    }
+}
@@ -1,4 +1,10 @@
-use crate::component::{ComponentDesc, ComponentValue};
+use core::marker::PhantomData;
+
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+    Component, Entity,
+};
 
 use super::Metadata;
 
@@ -13,6 +19,10 @@ component! {
     ///// This creates a bidirectional graph.
     //pub symmetric: Symmetric,
 
+    /// Creates the value of a relation for a specific object.
+    ///
+    /// See [`ObjectDefault`]
+    pub(crate) object_default: ObjectDefaultFn,
 }
 
 /// Mutually exclusive relation.
@@ -37,9 +47,100 @@ impl<T: ComponentValue> Metadata<T> for Exclusive {
 //     }
 // }
 
+/// Provides the default value of a relation to a specific object.
+///
+/// See [`ObjectDefault`]
+pub trait DefaultForObject<T> {
+    /// Returns the default value of the relation to `object`
+    fn default_for(object: Entity) -> T;
+}
+
+/// Registers `D` as the factory of default values for each object of a relation.
+///
+/// The factory is used when a relation is inserted through
+/// [`Entry::or_default`](crate::entry::Entry::or_default), and when the relation is a
+/// [`Requirement`](super::Requirement) of another component, without a default of its own.
+///
+/// ```rust
+/// # use flax::{*, metadata::{DefaultForObject, ObjectDefault}};
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Edge {
+///     to: Option<Entity>,
+/// }
+///
+/// struct EdgeDefault;
+///
+/// impl DefaultForObject<Edge> for EdgeDefault {
+///     fn default_for(object: Entity) -> Edge {
+///         Edge { to: Some(object) }
+///     }
+/// }
+///
+/// component! {
+///     edge(object): Edge => [ ObjectDefault<EdgeDefault> ],
+/// }
+///
+/// let mut world = World::new();
+/// let a = world.spawn();
+/// let b = world.spawn();
+///
+/// assert_eq!(*world.entry(a, edge(b)).unwrap().or_default(), Edge { to: Some(b) });
+/// ```
+pub struct ObjectDefault<D>(PhantomData<D>);
+
+impl<T, D> Metadata<T> for ObjectDefault<D>
+where
+    T: ComponentValue,
+    D: DefaultForObject<T>,
+{
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(object_default(), ObjectDefaultFn(insert_default::<T, D>));
+    }
+}
+
+/// Type erased [`DefaultForObject`]
+#[derive(Clone, Copy)]
+pub(crate) struct ObjectDefaultFn(fn(ComponentDesc, &mut ComponentBuffer) -> bool);
+
+fn insert_default<T: ComponentValue, D: DefaultForObject<T>>(
+    desc: ComponentDesc,
+    buffer: &mut ComponentBuffer,
+) -> bool {
+    let Some(object) = desc.key().target() else {
+        return false;
+    };
+
+    buffer.set(desc.downcast::<T>(), D::default_for(object));
+    true
+}
+
+/// Inserts the default value of the relation `desc` into `buffer` using the registered
+/// [`ObjectDefault`], if any.
+pub(crate) fn insert_object_default(desc: ComponentDesc, buffer: &mut ComponentBuffer) -> bool {
+    match desc.meta_ref().get(object_default()) {
+        Some(f) => (f.0)(desc, buffer),
+        None => false,
+    }
+}
+
+/// Returns the default value of the relation from the registered [`ObjectDefault`], if any.
+pub(crate) fn object_default_of<T: ComponentValue>(component: Component<T>) -> Option<T> {
+    let mut buffer = ComponentBuffer::new();
+    if insert_object_default(component.desc(), &mut buffer) {
+        buffer.remove(component)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use alloc::sync::Arc;
+    use alloc::{sync::Arc, vec, vec::Vec};
+
+    use crate::{
+        metadata::{Requirement, Requirements, Requires},
+        Entity,
+    };
 
     use super::*;
 
@@ -47,6 +148,74 @@ mod test {
         a(id): Arc<()> => [ Exclusive ],
     }
 
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct Weight(Option<Entity>, f32);
+
+    struct WeightDefault;
+
+    impl DefaultForObject<Weight> for WeightDefault {
+        fn default_for(object: Entity) -> Weight {
+            Weight(Some(object), 1.0)
+        }
+    }
+
+    struct NodeRequirements;
+
+    impl Requirements for NodeRequirements {
+        fn requirements() -> Vec<Requirement> {
+            vec![Requirement::new(weight(root()))]
+        }
+    }
+
+    component! {
+        weight(object): Weight => [ ObjectDefault<WeightDefault> ],
+        count(object): u32 => [ ObjectDefault<CountDefault> ],
+        node: () => [ Requires<NodeRequirements> ],
+        root,
+    }
+
+    struct CountDefault;
+
+    impl DefaultForObject<u32> for CountDefault {
+        fn default_for(object: Entity) -> u32 {
+            object.index() + 100
+        }
+    }
+
+    #[test]
+    fn object_default() {
+        use crate::World;
+
+        let mut world = World::new();
+        let id1 = world.spawn();
+        let id2 = world.spawn();
+
+        assert_eq!(
+            *world.entry(id1, weight(id2)).unwrap().or_default(),
+            Weight(Some(id2), 1.0)
+        );
+
+        *world.entry(id1, count(id2)).unwrap().or_default() += 1;
+        assert_eq!(
+            world.get(id1, count(id2)).as_deref(),
+            Ok(&(id2.index() + 101))
+        );
+
+        // Existing values are kept
+        world.set(id2, weight(id1), Weight(Some(id1), 5.0)).unwrap();
+        assert_eq!(
+            *world.entry(id2, weight(id1)).unwrap().or_default(),
+            Weight(Some(id1), 5.0)
+        );
+
+        // Required relations
+        let id3 = Entity::builder().set(node(), ()).spawn(&mut world);
+        assert_eq!(
+            world.get(id3, weight(root())).as_deref(),
+            Ok(&Weight(Some(root()), 1.0))
+        );
+    }
+
     #[test]
     #[cfg(feature = "flume")]
     fn exclusive_set() {
@@ -94,7 +263,7 @@ mod test {
         let mut query = Query::new((entity_ids(), relations_like(a)));
 
         ensure(
-            query.borrow(&world),
+            query.borrow(&world).unwrap(),
             &[(id1, vec![]), (id2, vec![]), (id3, vec![(id1, &shared)])],
         );
 
@@ -119,7 +288,7 @@ mod test {
         world.set(id3, a(id2), shared.clone()).unwrap();
 
         ensure(
-            query.borrow(&world),
+            query.borrow(&world).unwrap(),
             &[
                 (id1, vec![(id2, &shared)]),
                 (id2, vec![]),
@@ -156,7 +325,7 @@ mod test {
         );
 
         ensure(
-            query.borrow(&world),
+            query.borrow(&world).unwrap(),
             &[
                 (id1, vec![(id3, &shared)]),
                 (id2, vec![]),
@@ -174,7 +343,7 @@ mod test {
             .unwrap();
 
         ensure(
-            query.borrow(&world),
+            query.borrow(&world).unwrap(),
             &[
                 (id1, vec![(id1, &shared)]),
                 (id2, vec![]),
@@ -0,0 +1,341 @@
+use core::{fmt, marker::PhantomData};
+
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+    Component,
+};
+
+use super::Metadata;
+
+component! {
+    /// The components which must be present alongside a component.
+    ///
+    /// See [`Requires`]
+    pub required_components: Vec<Requirement>,
+}
+
+type DefaultFn = Arc<dyn Fn(&mut ComponentBuffer) + Send + Sync>;
+
+/// A component which is required to be present alongside another component
+#[derive(Clone)]
+pub struct Requirement {
+    desc: ComponentDesc,
+    default: Option<DefaultFn>,
+}
+
+impl Requirement {
+    /// The component must be present, and inserting a component requiring it on an entity which
+    /// does not have it fails.
+    ///
+    /// Relations with an [`ObjectDefault`](super::ObjectDefault) are inserted automatically.
+    pub fn new<T: ComponentValue>(component: Component<T>) -> Self {
+        Self {
+            desc: component.desc(),
+            default: None,
+        }
+    }
+
+    /// The component is inserted with its default value if it is not present
+    pub fn with_default<T: ComponentValue + Default>(component: Component<T>) -> Self {
+        Self {
+            desc: component.desc(),
+            default: Some(Arc::new(move |buffer| {
+                buffer.set(component, T::default());
+            })),
+        }
+    }
+
+    /// The component is inserted with a clone of `value` if it is not present
+    pub fn with_value<T: ComponentValue + Clone>(component: Component<T>, value: T) -> Self {
+        Self {
+            desc: component.desc(),
+            default: Some(Arc::new(move |buffer| {
+                buffer.set(component, value.clone());
+            })),
+        }
+    }
+
+    /// Returns the required component
+    pub fn desc(&self) -> ComponentDesc {
+        self.desc
+    }
+
+    /// Returns true if the component is inserted automatically
+    pub fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+
+    pub(crate) fn insert_default(&self, buffer: &mut ComponentBuffer) -> bool {
+        match &self.default {
+            Some(f) => {
+                f(buffer);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl fmt::Debug for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Requirement")
+            .field("desc", &self.desc)
+            .field("has_default", &self.has_default())
+            .finish()
+    }
+}
+
+/// Declares a set of components which are required by another component.
+///
+/// See [`Requires`]
+pub trait Requirements {
+    /// Returns the required components
+    fn requirements() -> Vec<Requirement>;
+}
+
+/// Ensures the components declared by `R` are present whenever this component is inserted.
+///
+/// Components with a default are inserted automatically, in the same archetype transition as the
+/// component requiring them, otherwise insertion fails with
+/// [`Error::MissingComponent`](crate::Error::MissingComponent).
+///
+/// Removing a required component through [`World::remove`](crate::World::remove) fails with
+/// [`Error::RequiredComponent`](crate::Error::RequiredComponent) while a component requiring it
+/// is present. Bulk removals, such as [`World::clear`](crate::World::clear), removing
+/// [`Transient`](super::Transient) components, or [`World::edit`](crate::World::edit), are not
+/// checked.
+///
+/// ```rust
+/// # use flax::{*, metadata::{Requires, Requirement, Requirements}};
+/// # #[derive(Debug, Default, Clone, PartialEq)]
+/// # struct Transform;
+/// struct SpriteRequirements;
+///
+/// impl Requirements for SpriteRequirements {
+///     fn requirements() -> Vec<Requirement> {
+///         vec![Requirement::with_default(transform())]
+///     }
+/// }
+///
+/// component! {
+///     transform: Transform,
+///     sprite: &'static str => [ Requires<SpriteRequirements> ],
+/// }
+///
+/// let mut world = World::new();
+/// let id = Entity::builder().set(sprite(), "player.png").spawn(&mut world);
+///
+/// assert!(world.has(id, transform()));
+/// ```
+pub struct Requires<R>(PhantomData<R>);
+
+impl<T, R> Metadata<T> for Requires<R>
+where
+    T: ComponentValue,
+    R: Requirements,
+{
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        let requirements = R::requirements();
+        match buffer.get_mut(required_components()) {
+            Some(v) => v.extend(requirements),
+            None => {
+                buffer.set(required_components(), requirements);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec};
+
+    use crate::{
+        archetype::ChangeKind, component::dummy, components::child_of, entity_ids,
+        error::MissingComponent, CommandBuffer, Entity, Error, Query, World,
+    };
+
+    use super::*;
+
+    struct SpriteRequirements;
+
+    impl Requirements for SpriteRequirements {
+        fn requirements() -> Vec<Requirement> {
+            vec![
+                Requirement::with_default(transform()),
+                Requirement::with_value(layer(), 1),
+            ]
+        }
+    }
+
+    struct TransformRequirements;
+
+    impl Requirements for TransformRequirements {
+        fn requirements() -> Vec<Requirement> {
+            vec![Requirement::with_default(visible())]
+        }
+    }
+
+    struct ColliderRequirements;
+
+    impl Requirements for ColliderRequirements {
+        fn requirements() -> Vec<Requirement> {
+            vec![Requirement::new(shape())]
+        }
+    }
+
+    component! {
+        transform: [f32; 3] => [ Requires<TransformRequirements> ],
+        visible: bool,
+        layer: u32,
+        sprite: String => [ Requires<SpriteRequirements> ],
+        shape: f32,
+        collider: () => [ Requires<ColliderRequirements> ],
+    }
+
+    #[test]
+    fn requires() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(sprite(), "player".into())
+            .set(layer(), 5)
+            .spawn(&mut world);
+
+        assert_eq!(world.get(id, transform()).as_deref(), Ok(&[0.0; 3]));
+        // Transitive requirements
+        assert_eq!(world.get(id, visible()).as_deref(), Ok(&false));
+        // Existing values are kept
+        assert_eq!(world.get(id, layer()).as_deref(), Ok(&5));
+
+        let id = world.spawn();
+        world.set(id, layer(), 3).unwrap();
+        world.set(id, sprite(), "enemy".into()).unwrap();
+
+        assert!(world.has(id, transform()));
+        assert_eq!(world.get(id, layer()).as_deref(), Ok(&3));
+
+        let id = world.spawn();
+        assert_eq!(
+            world.set(id, collider(), ()),
+            Err(Error::MissingComponent(MissingComponent {
+                id,
+                desc: shape().desc()
+            }))
+        );
+        assert!(!world.has(id, collider()));
+
+        world.set(id, shape(), 1.0).unwrap();
+        world.set(id, collider(), ()).unwrap();
+
+        let mut cmd = CommandBuffer::new();
+        let id = world.spawn();
+        cmd.set(id, sprite(), "deferred".into());
+        cmd.apply(&mut world).unwrap();
+
+        assert!(world.has(id, transform()));
+        assert!(world.has(id, visible()));
+    }
+
+    #[test]
+    fn single_transition() {
+        let mut world = World::new();
+
+        // The components are added in the same transition, and as such at the same change tick
+        let added = |world: &World, id, components: &[ComponentDesc]| {
+            let loc = world.location(id).unwrap();
+            components
+                .iter()
+                .map(|v| {
+                    world
+                        .changes(loc.arch_id, v.key(), ChangeKind::Added)
+                        .find(|(slots, _)| slots.contains(loc.slot))
+                        .unwrap()
+                        .1
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let id = world.spawn();
+        world.set(id, sprite(), "player".into()).unwrap();
+        let ticks = added(
+            &world,
+            id,
+            &[sprite().desc(), transform().desc(), visible().desc()],
+        );
+        assert!(ticks.iter().all(|&v| v == ticks[0]), "{ticks:?}");
+
+        // Type erased
+        let id = world.spawn();
+        let mut cmd = CommandBuffer::new();
+        cmd.set(id, transform(), [1.0; 3]);
+        cmd.apply(&mut world).unwrap();
+
+        let ticks = added(&world, id, &[transform().desc(), visible().desc()]);
+        assert!(ticks.iter().all(|&v| v == ticks[0]), "{ticks:?}");
+    }
+
+    #[test]
+    fn remove_required() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(sprite(), "player".into())
+            .spawn(&mut world);
+
+        assert_eq!(
+            world.remove(id, transform()),
+            Err(Error::RequiredComponent(
+                id,
+                transform().desc(),
+                sprite().desc()
+            ))
+        );
+        assert!(world.has(id, transform()));
+
+        world.remove(id, sprite()).unwrap();
+        world.remove(id, layer()).unwrap();
+        world.remove(id, transform()).unwrap();
+        world.remove(id, visible()).unwrap();
+    }
+
+    #[test]
+    fn try_spawn_missing() {
+        let mut world = World::new();
+
+        let mut builder = Entity::builder();
+        builder
+            .set(collider(), ())
+            .attach(child_of, Entity::builder().set(sprite(), "child".into()));
+
+        assert_eq!(
+            builder.try_spawn(&mut world),
+            Err(Error::MissingComponent(MissingComponent {
+                id: dummy(),
+                desc: shape().desc()
+            }))
+        );
+
+        // Nothing was spawned, and the builder is left unchanged
+        assert_eq!(Query::new(sprite()).borrow(&world).unwrap().count(), 0);
+
+        let id = builder.set(shape(), 1.0).try_spawn(&mut world).unwrap();
+        assert!(world.has(id, collider()));
+
+        let children = Query::new((entity_ids(), transform()))
+            .with_relation(child_of(id))
+            .borrow(&world)
+            .unwrap()
+            .count();
+        assert_eq!(children, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to spawn entity")]
+    fn spawn_missing() {
+        let mut world = World::new();
+        Entity::builder().set(collider(), ()).spawn(&mut world);
+    }
+}
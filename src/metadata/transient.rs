@@ -0,0 +1,83 @@
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+};
+
+use super::Metadata;
+
+component! {
+    /// The component is removed from all entities at the next [`World::maintain`](crate::World::maintain).
+    pub transient: (),
+}
+
+/// Marks a component as only living until the end of the frame.
+///
+/// Use for one-frame events, such as `damaged_this_frame`, instead of removing them from each
+/// entity manually. The removal is performed for whole archetypes at a time.
+pub struct Transient;
+
+impl<T: ComponentValue> Metadata<T> for Transient {
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(transient(), ());
+    }
+}
+
+pub(crate) fn is_transient(desc: &ComponentDesc) -> bool {
+    desc.meta_ref().has(transient())
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{entity_ids, error::Error, Entity, Query, World};
+
+    use super::*;
+
+    component! {
+        health: f32,
+        damaged: f32 => [ Transient ],
+    }
+
+    #[test]
+    fn transient() {
+        let mut world = World::new();
+
+        let a = Entity::builder()
+            .set(health(), 1.0)
+            .set(damaged(), 0.5)
+            .spawn(&mut world);
+
+        let b = Entity::builder().set(health(), 1.0).spawn(&mut world);
+        let c = Entity::builder().set(damaged(), 0.2).spawn(&mut world);
+
+        world.maintain().unwrap();
+
+        assert_eq!(world.get(a, health()).as_deref(), Ok(&1.0));
+        assert_eq!(world.get(b, health()).as_deref(), Ok(&1.0));
+        assert!(world.is_alive(c));
+
+        for id in [a, c] {
+            assert!(matches!(
+                world.get(id, damaged()).as_deref(),
+                Err(Error::MissingComponent(_))
+            ));
+        }
+
+        let mut ids = Query::new(entity_ids())
+            .with(health())
+            .borrow(&world)
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        ids.sort();
+
+        let mut expected = [a, b];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        // The component can be re-added for the next frame
+        world.set(a, damaged(), 0.1).unwrap();
+        assert_eq!(world.get(a, damaged()).as_deref(), Ok(&0.1));
+    }
+}
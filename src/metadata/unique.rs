@@ -0,0 +1,84 @@
+use crate::{
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentValue},
+};
+
+use super::Metadata;
+
+component! {
+    /// The component is the only component of its type, and can be looked up by type through
+    /// [`World::unique_component`](crate::World::unique_component).
+    pub unique: (),
+}
+
+/// Guarantees that the component is the only component declared with its type.
+///
+/// Allows generic code to access the component by type alone, without the component handle being
+/// passed through every call.
+///
+/// The component can only be looked up once it has been registered in the world, either by being
+/// added to an entity or through [`World::register`](crate::World::register).
+pub struct Unique;
+
+impl<T: ComponentValue> Metadata<T> for Unique {
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(unique(), ());
+    }
+}
+
+pub(crate) fn is_unique(desc: &ComponentDesc) -> bool {
+    desc.meta_ref().has(unique())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{error::Error, Entity, World};
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Health(f32);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Position(f32, f32);
+
+    component! {
+        health: Health => [ Unique ],
+        position: Position,
+    }
+
+    fn damage(world: &World, id: Entity, amount: f32) -> crate::error::Result<()> {
+        world.get_unique_mut::<Health>(id)?.0 -= amount;
+        Ok(())
+    }
+
+    #[test]
+    fn unique() {
+        let mut world = World::new();
+
+        // Not yet registered
+        assert_eq!(world.unique_component::<Health>(), None);
+
+        let id = Entity::builder()
+            .set(health(), Health(1.0))
+            .set(position(), Position(0.0, 0.0))
+            .spawn(&mut world);
+
+        assert_eq!(world.unique_component::<Health>(), Some(health()));
+        assert_eq!(world.unique_component::<Position>(), None);
+
+        damage(&world, id, 0.25).unwrap();
+        assert_eq!(world.get_unique::<Health>(id).as_deref(), Ok(&Health(0.75)));
+
+        assert!(matches!(
+            world.get_unique::<Position>(id).as_deref(),
+            Err(Error::NoUniqueComponent(_))
+        ));
+
+        let other = world.spawn();
+        assert!(matches!(
+            world.get_unique::<Health>(other).as_deref(),
+            Err(Error::MissingComponent(_))
+        ));
+    }
+}
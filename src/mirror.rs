@@ -0,0 +1,188 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use crate::{
+    component::ComponentValue,
+    entity_ids,
+    events::{Event, EventSubscriber},
+    CommandBuffer, Component, Entity, Query, World,
+};
+
+/// A user provided store, such as the state of a UI framework, which mirrors the values of a
+/// component.
+pub trait MirrorStore<T> {
+    /// The component was added to or modified on the entity
+    fn update(&mut self, id: Entity, value: &T);
+    /// The component was removed from the entity, or the entity was despawned
+    fn remove(&mut self, id: Entity);
+    /// Appends the values which were edited in the store since the last call
+    fn drain_edits(&mut self, edits: &mut Vec<(Entity, T)>);
+}
+
+/// Keeps a [`MirrorStore`] in sync with the values of a component.
+///
+/// Changes in the world are mirrored into the store by [`Self::sync_to_store`], and edits made in
+/// the store are written back through a [`CommandBuffer`] by [`Self::sync_from_store`].
+///
+/// Changes are buffered through a subscriber, and each entity is mirrored at most once per sync
+/// with the value it has at the time of syncing.
+///
+/// **Note**: applying the edits of the store will cause them to be mirrored back into the store on
+/// the next sync.
+pub struct Mirror<T> {
+    component: Component<T>,
+    rx: flume::Receiver<Event>,
+    /// Entities which have not yet been mirrored
+    dirty: BTreeSet<Entity>,
+}
+
+impl<T: ComponentValue + Clone> Mirror<T> {
+    /// Creates a new mirror for `component`.
+    ///
+    /// The entities which currently have the component are mirrored on the first sync.
+    pub fn new(world: &mut World, component: Component<T>) -> Self {
+        let (tx, rx) = flume::unbounded();
+        world.subscribe(tx.filter_components([component.key()]));
+
+        // Disabled entities still send events, and are as such mirrored as well
+        let mut query = Query::new(entity_ids()).with(component).include_disabled();
+        let dirty = query.borrow_unchecked(world).iter().collect();
+
+        Self {
+            component,
+            rx,
+            dirty,
+        }
+    }
+
+    /// Returns the mirrored component
+    pub fn component(&self) -> Component<T> {
+        self.component
+    }
+
+    /// Mirrors the changes since the last sync into `store`.
+    ///
+    /// Entities whose component is currently borrowed are mirrored on the next sync.
+    pub fn sync_to_store(&mut self, world: &World, store: &mut impl MirrorStore<T>) {
+        self.dirty.extend(self.rx.drain().map(|v| v.id));
+
+        let dirty = core::mem::take(&mut self.dirty);
+        for id in dirty {
+            let Ok(loc) = world.location(id) else {
+                store.remove(id);
+                continue;
+            };
+
+            match world.try_get_at(loc, self.component) {
+                Ok(Some(value)) => store.update(id, &value),
+                Ok(None) => store.remove(id),
+                Err(_) => {
+                    self.dirty.insert(id);
+                }
+            }
+        }
+    }
+
+    /// Records the edits made in `store` into `cmd`, to be applied to the world.
+    pub fn sync_from_store(&self, store: &mut impl MirrorStore<T>, cmd: &mut CommandBuffer) {
+        let mut edits = Vec::new();
+        store.drain_edits(&mut edits);
+
+        for (id, value) in edits {
+            cmd.set(id, self.component, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, string::String, vec};
+
+    use super::*;
+
+    component! {
+        label: String,
+    }
+
+    #[derive(Default)]
+    struct Store {
+        values: BTreeMap<Entity, String>,
+        edits: Vec<(Entity, String)>,
+    }
+
+    impl MirrorStore<String> for Store {
+        fn update(&mut self, id: Entity, value: &String) {
+            self.values.insert(id, value.clone());
+        }
+
+        fn remove(&mut self, id: Entity) {
+            self.values.remove(&id);
+        }
+
+        fn drain_edits(&mut self, edits: &mut Vec<(Entity, String)>) {
+            edits.append(&mut self.edits)
+        }
+    }
+
+    #[test]
+    fn mirror() {
+        let mut world = World::new();
+        let mut store = Store::default();
+
+        let a = Entity::builder().set(label(), "a".into()).spawn(&mut world);
+
+        let mut mirror = Mirror::new(&mut world, label());
+
+        let b = Entity::builder().set(label(), "b".into()).spawn(&mut world);
+
+        mirror.sync_to_store(&world, &mut store);
+        assert_eq!(
+            store.values,
+            BTreeMap::from([(a, "a".into()), (b, "b".into())])
+        );
+
+        world.get_mut(a, label()).unwrap().push('!');
+        world.despawn(b).unwrap();
+
+        mirror.sync_to_store(&world, &mut store);
+        assert_eq!(store.values, BTreeMap::from([(a, "a!".into())]));
+
+        // Edits in the store are applied back to the world
+        store.edits = vec![(a, "edited".into())];
+
+        let mut cmd = CommandBuffer::new();
+        mirror.sync_from_store(&mut store, &mut cmd);
+        cmd.apply(&mut world).unwrap();
+
+        assert_eq!(*world.get(a, label()).unwrap(), "edited");
+
+        mirror.sync_to_store(&world, &mut store);
+        assert_eq!(store.values, BTreeMap::from([(a, "edited".into())]));
+    }
+
+    #[test]
+    fn mirror_disabled() {
+        use crate::components::disabled;
+
+        let mut world = World::new();
+        let mut store = Store::default();
+
+        let a = Entity::builder()
+            .set(label(), "a".into())
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        let mut mirror = Mirror::new(&mut world, label());
+
+        // Mirrored the same as a disabled entity spawned after the mirror
+        let b = Entity::builder()
+            .set(label(), "b".into())
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        mirror.sync_to_store(&world, &mut store);
+        assert_eq!(
+            store.values,
+            BTreeMap::from([(a, "a".into()), (b, "b".into())])
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Slice},
     fetch::{FetchPrepareData, PreparedFetch},
-    filter::Filtered,
-    Entity, Fetch, World,
+    filter::{next_slice, Filtered},
+    Fetch, World,
 };
 
 use super::{ArchetypeChunks, Chunk};
@@ -14,33 +14,52 @@ pub(crate) struct PreparedArchetype<'w, Q, F> {
 }
 
 impl<'w, Q, F> PreparedArchetype<'w, Q, F> {
+    /// Creates a chunk for the matched subset of `slots`.
+    ///
+    /// The archetype is only borrowed for the duration of the call, see [`PreparedFetch`].
+    ///
+    /// # Safety
+    ///
+    /// - `slots` must be disjoint to all other chunks of this archetype which are alive.
+    /// - `self` must outlive `'q`, and must not be moved while the chunk is alive.
     #[inline]
-    pub unsafe fn create_chunk<'q>(&'q mut self, slots: Slice) -> Option<Chunk<'q, Q>>
+    pub unsafe fn create_chunk<'q>(&mut self, slots: Slice) -> Option<Chunk<'q, Q>>
     where
-        Q: PreparedFetch<'q>,
-        F: PreparedFetch<'q>,
+        Q: 'q + PreparedFetch<'q>,
+        F: 'q + PreparedFetch<'q>,
+        'w: 'q,
     {
         let slots = unsafe { self.fetch.filter_slots(slots) };
         if slots.is_empty() {
             return None;
         }
 
-        // Fetch will never change and all calls are disjoint
-        let fetch = unsafe { &mut *(&mut self.fetch as *mut Filtered<Q, F>) };
+        let chunk = unsafe { self.fetch.create_chunk(slots) };
 
-        let chunk = unsafe { fetch.create_chunk(slots) };
+        Some(Chunk::new(self.arch, chunk, slots))
+    }
+
+    /// Splits the next matched chunk off of `slots`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::create_chunk`]
+    #[inline]
+    pub(crate) unsafe fn next_chunk<'q>(&mut self, slots: &mut Slice) -> Option<Chunk<'q, Q>>
+    where
+        Q: 'q + PreparedFetch<'q>,
+        F: 'q + PreparedFetch<'q>,
+        'w: 'q,
+    {
+        let slots = next_slice(slots, &mut self.fetch)?;
+        let chunk = unsafe { self.fetch.create_chunk(slots) };
 
-        let chunk = Chunk::new(self.arch, chunk, slots);
-        Some(chunk)
+        Some(Chunk::new(self.arch, chunk, slots))
     }
 
     #[inline]
-    pub fn chunks(&mut self) -> ArchetypeChunks<Q, F> {
-        ArchetypeChunks {
-            fetch: &mut self.fetch as *mut _,
-            slots: self.arch.slots(),
-            arch: self.arch,
-        }
+    pub fn chunks(&mut self) -> ArchetypeChunks<'_, Q, F> {
+        ArchetypeChunks::new(self.arch, &mut self.fetch)
     }
 }
 
@@ -50,6 +69,8 @@ pub struct QueryBorrowState<'w, Q, F> {
     pub(crate) fetch: &'w Filtered<Q, F>,
     pub(crate) old_tick: u32,
     pub(crate) new_tick: u32,
+    /// See [`FetchPrepareData::exclusive`]
+    pub(crate) exclusive: bool,
 }
 
 impl<'w, Q, F> QueryBorrowState<'w, Q, F>
@@ -69,6 +90,7 @@ where
             world: self.world,
             old_tick: self.old_tick,
             new_tick: self.new_tick,
+            exclusive: self.exclusive,
         };
 
         Some(PreparedArchetype {
@@ -78,29 +100,3 @@ where
         })
     }
 }
-
-struct BatchesWithId<'q, Q: PreparedFetch<'q>, F> {
-    chunks: ArchetypeChunks<'q, Q, F>,
-    // The current batch
-    current: Option<Chunk<'q, Q>>,
-}
-
-impl<'q, Q, F> Iterator for BatchesWithId<'q, Q, F>
-where
-    Q: 'q + PreparedFetch<'q>,
-    F: 'q + PreparedFetch<'q>,
-{
-    type Item = (Entity, Q::Item);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if let Some(current) = self.current.as_mut() {
-                if let item @ Some(_) = current.next_with_id() {
-                    return item;
-                }
-            }
-
-            self.current = Some(self.chunks.next()?);
-        }
-    }
-}
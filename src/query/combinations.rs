@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use crate::{
+    archetype::{Slice, Slot},
+    fetch::PreparedFetch,
+    filter::{next_slice, Filtered},
+    Fetch,
+};
+
+use super::PreparedArchetype;
+
+/// Yields every unique combination of `K` distinct items matched by a query.
+///
+/// Each combination is yielded once, regardless of order, and never contains the same entity
+/// twice. As such, the items of a combination can be mutated simultaneously.
+///
+/// Since two combinations may share an entity, the items of a combination borrow the iterator and
+/// must be dropped before advancing. This makes it a *lending* iterator, which is used through
+/// [`Self::fetch_next`] rather than [`Iterator`].
+///
+/// See [`QueryBorrow::iter_combinations`](crate::QueryBorrow::iter_combinations)
+pub struct Combinations<'w, 'q, Q, F, const K: usize>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+{
+    prepared: &'q mut [PreparedArchetype<'w, Q::Prepared, F::Prepared>],
+    /// The prepared archetype and slot of each matched entity
+    matched: Vec<(usize, Slot)>,
+    indices: [usize; K],
+    started: bool,
+}
+
+impl<'w, 'q, Q, F, const K: usize> Combinations<'w, 'q, Q, F, K>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+{
+    pub(super) fn new(prepared: &'q mut [PreparedArchetype<'w, Q::Prepared, F::Prepared>]) -> Self {
+        let mut matched = Vec::new();
+        for (idx, p) in prepared.iter_mut().enumerate() {
+            let mut slots = p.arch.slots();
+            while let Some(chunk) = next_slice(&mut slots, &mut p.fetch) {
+                matched.extend(chunk.iter().map(|slot| (idx, slot)));
+            }
+        }
+
+        Self {
+            prepared,
+            matched,
+            indices: core::array::from_fn(|i| i),
+            started: false,
+        }
+    }
+
+    /// Returns the next combination of items.
+    pub fn fetch_next(&mut self) -> Option<[<Q::Prepared as PreparedFetch<'_>>::Item; K]> {
+        if !self.advance() {
+            return None;
+        }
+
+        let prepared = &mut *self.prepared;
+        let matched = &self.matched;
+        let indices = self.indices;
+
+        Some(core::array::from_fn(|i| {
+            let (idx, slot) = matched[indices[i]];
+
+            // Safety: the indices of a combination are distinct, so each item accesses a disjoint
+            // slot. The items borrow `self`, so no other combination is alive.
+            unsafe {
+                let mut chunk = prepared[idx].fetch.create_chunk(Slice::single(slot));
+                <Filtered<Q::Prepared, F::Prepared> as PreparedFetch>::fetch_next(&mut chunk)
+            }
+        }))
+    }
+
+    /// Moves `indices` to the next combination in lexicographic order
+    fn advance(&mut self) -> bool {
+        let n = self.matched.len();
+        if K == 0 || K > n {
+            return false;
+        }
+
+        if !self.started {
+            self.started = true;
+            return true;
+        }
+
+        // Find the rightmost index which can still be incremented
+        let Some(i) = (0..K).rev().find(|&i| self.indices[i] < n - K + i) else {
+            return false;
+        };
+
+        self.indices[i] += 1;
+        for j in i + 1..K {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+
+        true
+    }
+
+    /// Returns the total number of combinations, including those already yielded
+    pub fn total(&self) -> usize {
+        let n = self.matched.len();
+        if K > n {
+            return 0;
+        }
+
+        // n choose K
+        (0..K).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+}
@@ -30,6 +30,7 @@ where
 {
     world: AtomicRef<'a, World>,
     query: &'a mut Query<Q, F, S>,
+    last_run: Option<u32>,
 }
 
 impl<'a, Q, F, S> SystemData<'a> for Query<Q, F, S>
@@ -43,7 +44,25 @@ where
     fn acquire(&'a mut self, ctx: &'a SystemContext<'_, '_, '_>) -> Self::Value {
         let world = ctx.world();
 
-        QueryData { world, query: self }
+        QueryData {
+            world,
+            query: self,
+            last_run: None,
+        }
+    }
+
+    fn acquire_since(
+        &'a mut self,
+        ctx: &'a SystemContext<'_, '_, '_>,
+        last_run: u32,
+    ) -> Self::Value {
+        let world = ctx.world();
+
+        QueryData {
+            world,
+            query: self,
+            last_run: Some(last_run),
+        }
     }
 
     fn describe(&self, f: &mut alloc::fmt::Formatter<'_>) -> alloc::fmt::Result {
@@ -68,8 +87,18 @@ where
     ///
     /// The same query can be prepared multiple times, though not
     /// simultaneously.
-    pub fn borrow(&mut self) -> <S as QueryStrategy<Q, F>>::Borrow {
-        self.query.borrow(&self.world)
+    ///
+    /// When executed as part of a system, change filters yield the changes made since the
+    /// previous run of the system.
+    pub fn borrow(&mut self) -> <S as QueryStrategy<'_, Q, F>>::Borrow {
+        // Access is checked by the schedule
+        match self
+            .query
+            .borrow_inner(&self.world, self.last_run, false, false)
+        {
+            Ok(v) => v,
+            Err(_) => unreachable!("Unchecked borrows do not fail"),
+        }
     }
 }
 
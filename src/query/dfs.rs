@@ -311,13 +311,13 @@ where
         };
 
         let dfs = &self.dfs;
-        let prepared = (&mut self.prepared[..]) as *mut [_] as *mut PreparedArchetype<_, _>;
+        let prepared = &mut self.prepared[..];
         let arch_index = *dfs.state.archetypes_index.get(&loc.arch_id).unwrap();
 
-        // Fetch will never change and all calls are disjoint as the graph is acyclic
-        let p = unsafe { &mut *prepared.add(arch_index) };
-
-        if let Some(mut chunk) = unsafe { p.create_chunk(Slice::single(loc.slot)) } {
+        // Safety: all chunks are disjoint as the graph is acyclic
+        if let Some(mut chunk) =
+            unsafe { prepared[arch_index].create_chunk(Slice::single(loc.slot)) }
+        {
             Self::traverse_batch(
                 self.query_state.world,
                 dfs,
@@ -337,11 +337,11 @@ where
         Visit: for<'q> FnMut(<Q as FetchItem<'q>>::Item, Option<&T>, &V) -> V,
     {
         let dfs = &self.dfs;
-        let prepared = (&mut self.prepared[..]) as *mut [_] as *mut PreparedArchetype<_, _>;
+        let prepared = &mut self.prepared[..];
         for &arch_index in dfs.state.roots.iter() {
-            // Fetch will never change and all calls are disjoint
-            let p = unsafe { &mut *prepared.add(arch_index) };
-            for mut chunk in p.chunks() {
+            let mut slots = prepared[arch_index].arch.slots();
+            // Safety: all chunks are disjoint as the graph is acyclic
+            while let Some(mut chunk) = unsafe { prepared[arch_index].next_chunk(&mut slots) } {
                 Self::traverse_batch(
                     self.query_state.world,
                     dfs,
@@ -358,9 +358,7 @@ where
     fn traverse_batch<V, Visit>(
         world: &World,
         dfs: &Dfs<T>,
-        // Uses a raw pointer to be able to recurse inside the loop
-        // Alternative: release all borrows and borrow/prepare each fetch inside the loop
-        prepared: *mut PreparedArchetype<Q::Prepared, F::Prepared>,
+        prepared: &mut [PreparedArchetype<'w, Q::Prepared, F::Prepared>],
         chunk: &mut Chunk<Q::Prepared>,
         edge: Option<&[T]>,
         value: &V,
@@ -380,9 +378,9 @@ where
 
                 let edge = arch.borrow::<T>(ComponentKey::new(dfs.relation, Some(id)));
 
-                let p = unsafe { &mut *prepared.add(arch_index) };
-
-                for mut chunk in p.chunks() {
+                let mut slots = prepared[arch_index].arch.slots();
+                // Safety: all chunks are disjoint as the graph is acyclic
+                while let Some(mut chunk) = unsafe { prepared[arch_index].next_chunk(&mut slots) } {
                     Self::traverse_batch(
                         world,
                         dfs,
@@ -422,18 +420,18 @@ where
     /// The arch_index must not be pushed twice or appear later in the stack as a result of
     /// the hierarchy
     unsafe fn push_to_stack(&mut self, arch_index: usize) {
-        let arch = &mut self.prepared[arch_index];
-        // Fetch will never change and all calls are disjoint
-        let p = unsafe { &mut *(arch as *mut PreparedArchetype<_, _>) };
-        self.stack.extend(p.chunks())
+        let p = &mut self.prepared[arch_index];
+        let mut slots = p.arch.slots();
+        // Safety: each arch_index is only pushed once, see above
+        while let Some(chunk) = p.next_chunk(&mut slots) {
+            self.stack.push(chunk)
+        }
     }
 
     /// See: [`Self::push_to_stack`]
     unsafe fn push_slice_to_stack(&mut self, arch_index: usize, slice: Slice) {
-        let arch = &mut self.prepared[arch_index];
-        // Fetch will never change and all calls are disjoint
-        let p = unsafe { &mut *(arch as *mut PreparedArchetype<_, _>) };
-        if let Some(chunk) = p.create_chunk(slice) {
+        // Safety: see `push_to_stack`
+        if let Some(chunk) = self.prepared[arch_index].create_chunk(slice) {
             self.stack.push(chunk)
         }
     }
@@ -453,15 +451,9 @@ where
             if let Some((id, item)) = chunk.next_with_id() {
                 // Add the children
                 for &arch_index in self.adj.get(&id).into_iter().flatten() {
-                    let p = &mut self.prepared[arch_index];
-
-                    // Promote the borrow of the fetch to 'q
-                    // This is safe because each borrow is disjoint
-                    let p = unsafe { &mut *(p as *mut PreparedArchetype<_, _>) };
-
-                    let chunks = p.chunks();
-
-                    self.stack.extend(chunks);
+                    // Safety: the hierarchy is acyclic, so each child archetype chunk is
+                    // disjoint to the chunks already on the stack
+                    unsafe { self.push_to_stack(arch_index) }
                 }
 
                 return Some(item);
@@ -509,10 +501,13 @@ mod test {
         world.set(c, child_of(b), ()).unwrap();
 
         let mut query = Query::new(entity_ids()).with_strategy(Dfs::new(child_of));
-        assert_eq!(query.borrow(&world).iter().collect_vec(), [a, b, c]);
+        assert_eq!(
+            query.borrow(&world).unwrap().iter().collect_vec(),
+            [a, b, c]
+        );
 
         world.set(a, child_of(c), ()).unwrap();
-        assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+        assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
     }
 
     #[test]
@@ -573,16 +568,16 @@ mod test {
 
         let mut query = Query::new((entity_ids(), name())).with_strategy(Dfs::new(child_of));
 
-        assert_dfs(query.borrow(&world).iter(), &edges, &all);
+        assert_dfs(query.borrow(&world).unwrap().iter(), &edges, &all);
 
         world.set(b, child_of(h), ()).unwrap();
 
         edges.insert(b, h);
 
-        assert_dfs(query.borrow(&world).iter(), &edges, &all);
+        assert_dfs(query.borrow(&world).unwrap().iter(), &edges, &all);
 
         assert_dfs(
-            query.borrow(&world).iter_from(c),
+            query.borrow(&world).unwrap().iter_from(c),
             &edges,
             &[c, d, e].into_iter().collect(),
         );
@@ -635,6 +630,7 @@ mod test {
 
         let items = Query::new((name().cloned(), a().modified().copied()))
             .borrow(&world)
+            .unwrap()
             .iter()
             .sorted()
             .collect_vec();
@@ -652,13 +648,14 @@ mod test {
         // let mut query = crate::Query::new((name().cloned(), a().copied()));
         let mut query = Query::new((entity_ids(), a().copied())).with_strategy(Dfs::new(child_of));
 
-        assert_dfs(query.borrow(&world).iter(), &edges, &all);
+        assert_dfs(query.borrow(&world).unwrap().iter(), &edges, &all);
 
         let mut cmd = CommandBuffer::new();
 
         Query::new((entity_ids(), name()))
             .with_strategy(Dfs::new(child_of))
             .borrow(&world)
+            .unwrap()
             .traverse(&Vec::new(), |(id, name), _, prefix| {
                 let mut p = prefix.clone();
                 p.push(name.clone());
@@ -669,7 +666,7 @@ mod test {
 
         cmd.apply(&mut world).unwrap();
 
-        assert_dfs(query.borrow(&world).iter(), &edges, &all);
+        assert_dfs(query.borrow(&world).unwrap().iter(), &edges, &all);
         // assert_eq!(
         //     items,
         //     [
@@ -683,6 +680,7 @@ mod test {
 
         let paths = Query::new(path().cloned())
             .borrow(&world)
+            .unwrap()
             .iter()
             .sorted()
             .collect_vec();
@@ -694,7 +692,7 @@ mod test {
         let mut query = Query::new((name().cloned(), a().modified().copied()))
             .with_strategy(Dfs::new(child_of));
 
-        let items = query.borrow(&world).iter().sorted().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().sorted().collect_vec();
 
         assert_eq!(
             items,
@@ -707,33 +705,35 @@ mod test {
             ]
         );
 
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, []);
         *world.get_mut(ids[0], a()).unwrap() -= 1;
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, [("a".to_string(), -1)]);
 
         Query::new((child_of(ids[0]), a().as_mut()))
             .borrow(&world)
+            .unwrap()
             .for_each(|(_, a)| {
                 *a *= -1;
             });
 
         // No changes, since the root is not modified
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, []);
 
         Query::new((name(), a().as_mut()))
             .filter(child_of(ids[0]).with() | name().eq("a".to_string()))
             .borrow(&world)
+            .unwrap()
             .for_each(|(_, a)| {
                 *a *= -10;
             });
 
-        let items = query.borrow(&world).iter().sorted().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().sorted().collect_vec();
         assert_eq!(
             items,
             [
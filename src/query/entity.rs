@@ -133,7 +133,7 @@ mod test {
 
     use glam::{vec3, Vec3};
 
-    use crate::{component, components::name, filter::Or, FetchExt, Query, System, World};
+    use crate::{components::name, filter::Or, FetchExt, Query, System, World};
 
     use super::*;
 
@@ -153,18 +153,21 @@ mod test {
 
         let mut query = Query::new((name(), a().opt())).entity(id);
         {
-            let mut borrow = query.borrow(&world);
+            let mut borrow = query.borrow(&world).unwrap();
             assert_eq!(borrow.get(), Ok((&"Foo".into(), Some(&5))));
             assert_eq!(borrow.get(), Ok((&"Foo".into(), Some(&5))));
         }
 
         world.remove(id, a()).unwrap();
 
-        assert_eq!(query.borrow(&world).get(), Ok((&"Foo".into(), None)));
+        assert_eq!(
+            query.borrow(&world).unwrap().get(),
+            Ok((&"Foo".into(), None))
+        );
 
         world.remove(id, name()).unwrap();
         assert_eq!(
-            query.borrow(&world).get(),
+            query.borrow(&world).unwrap().get(),
             Err(Error::MissingComponent(MissingComponent {
                 id,
                 desc: name().desc()
@@ -172,12 +175,15 @@ mod test {
         );
         world.set(id, name(), "Bar".into()).unwrap();
         {
-            let mut borrow = query.borrow(&world);
+            let mut borrow = query.borrow(&world).unwrap();
             assert_eq!(borrow.get(), Ok((&"Bar".into(), None)));
             assert_eq!(borrow.get(), Ok((&"Bar".into(), None)));
         }
         world.despawn(id).unwrap();
-        assert_eq!(query.borrow(&world).get(), Err(Error::NoSuchEntity(id)));
+        assert_eq!(
+            query.borrow(&world).unwrap().get(),
+            Err(Error::NoSuchEntity(id))
+        );
     }
 
     #[test]
@@ -198,12 +204,12 @@ mod test {
             .spawn(&mut world);
 
         let mut query = Query::new((name(), position().as_mut())).entity(id);
-        assert!(query.borrow(&world).get().is_err());
+        assert!(query.borrow(&world).unwrap().get().is_err());
 
         world.set(id, position(), vec3(4.8, 4.2, 9.1)).unwrap();
 
         {
-            let mut borrow = query.borrow(&world);
+            let mut borrow = query.borrow(&world).unwrap();
             assert_eq!(borrow.get(), Ok((&"Bar".into(), &mut vec3(4.8, 4.2, 9.1))));
 
             *borrow.get().unwrap().1 = Vec3::X;
@@ -242,16 +248,16 @@ mod test {
             .spawn(&mut world);
 
         let mut query = Query::new((name(), position().as_mut()));
-        assert!(query.borrow(&world).get(id2).is_err());
+        assert!(query.borrow(&world).unwrap().get(id2).is_err());
         assert_eq!(
-            query.borrow(&world).get(id),
+            query.borrow(&world).unwrap().get(id),
             Ok((&"Foo".into(), &mut vec3(1.4, 6.4, 1.7)))
         );
 
         world.set(id2, position(), vec3(4.8, 4.2, 9.1)).unwrap();
 
         {
-            let mut borrow = query.borrow(&world);
+            let mut borrow = query.borrow(&world).unwrap();
             assert_eq!(
                 borrow.get(id2),
                 Ok((&"Bar".into(), &mut vec3(4.8, 4.2, 9.1)))
@@ -299,10 +305,16 @@ mod test {
             )))
             .entity(resources());
 
-        assert_eq!(query.borrow(&world).get(), Ok((&800.0, &600.0, &false)));
+        assert_eq!(
+            query.borrow(&world).unwrap().get(),
+            Ok((&800.0, &600.0, &false))
+        );
         world.set(resources(), allow_vsync(), true).unwrap();
 
-        assert_eq!(query.borrow(&world).get(), Ok((&800.0, &600.0, &true)));
-        assert!(query.borrow(&world).get().is_err());
+        assert_eq!(
+            query.borrow(&world).unwrap().get(),
+            Ok((&800.0, &600.0, &true))
+        );
+        assert!(query.borrow(&world).unwrap().get().is_err());
     }
 }
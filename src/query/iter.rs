@@ -1,6 +1,6 @@
 use crate::{
     archetype::{Archetype, Slice, Slot},
-    fetch::PreparedFetch,
+    fetch::{PreparedFetch, ReadComponent},
     filter::{next_slice, Filtered},
     Entity,
 };
@@ -55,6 +55,14 @@ impl<'q, Q: PreparedFetch<'q>> Chunk<'q, Q> {
     }
 }
 
+impl<'w, 'q, T: 'q> Chunk<'q, ReadComponent<'w, T>> {
+    /// Returns the remaining items of the chunk as a contiguous slice
+    pub(crate) fn as_slice(&self) -> &'q [T] {
+        // Safety: the chunk points to `len` contiguous values which are borrowed for `'q`
+        unsafe { core::slice::from_raw_parts(self.fetch.as_ptr(), self.len()) }
+    }
+}
+
 impl<'q, Q> Iterator for Chunk<'q, Q>
 where
     Q: PreparedFetch<'q>,
@@ -65,7 +73,6 @@ where
         if self.pos == self.end {
             None
         } else {
-            // let fetch = unsafe { &mut *(self.fetch as *mut Q::Batch) };
             let item = unsafe { Q::fetch_next(&mut self.fetch) };
             self.pos += 1;
             Some(item)
@@ -105,14 +112,26 @@ where
 /// An iterator over a single archetype which returns chunks.
 /// The chunk size is determined by the largest continuous matched entities for
 /// filters.
+///
+/// The iterator holds an exclusive borrow of the fetch for `'q`, and splits chunks off of it which
+/// each access a disjoint slice of the archetype. The chunks do not borrow the fetch itself, see
+/// [`PreparedFetch`], and as the slices never overlap and are never revisited, the chunks may
+/// outlive the call to `next` which created them.
 pub struct ArchetypeChunks<'q, Q, F> {
-    pub(crate) arch: &'q Archetype,
-    pub(crate) fetch: *mut Filtered<Q, F>,
-    pub(crate) slots: Slice,
+    arch: &'q Archetype,
+    fetch: &'q mut Filtered<Q, F>,
+    slots: Slice,
 }
 
-unsafe impl<'q, Q: 'q, F: 'q> Sync for ArchetypeChunks<'q, Q, F> where &'q mut Filtered<Q, F>: Sync {}
-unsafe impl<'q, Q: 'q, F: 'q> Send for ArchetypeChunks<'q, Q, F> where &'q mut Filtered<Q, F>: Send {}
+impl<'q, Q, F> ArchetypeChunks<'q, Q, F> {
+    pub(crate) fn new(arch: &'q Archetype, fetch: &'q mut Filtered<Q, F>) -> Self {
+        Self {
+            arch,
+            fetch,
+            slots: arch.slots(),
+        }
+    }
+}
 
 impl<'q, Q, F> Iterator for ArchetypeChunks<'q, Q, F>
 where
@@ -123,14 +142,18 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        // Fetch will never change and all calls are disjoint
-        let fetch = unsafe { &mut *self.fetch };
-
         // Get the next chunk
-        let slots = next_slice(&mut self.slots, fetch)?;
+        let slots = next_slice(&mut self.slots, self.fetch)?;
+
+        // Start loading the slots following this chunk while it is being visited
+        #[cfg(feature = "prefetch")]
+        if !self.slots.is_empty() {
+            self.fetch.prefetch(self.slots);
+        }
 
-        // Safety: Disjoint chunk
-        let chunk = unsafe { fetch.create_chunk(slots) };
+        // Safety: `self.slots` only shrinks from the left, so the chunk is disjoint to all
+        // previous chunks, and the fetch is borrowed for `'q`
+        let chunk = unsafe { self.fetch.create_chunk(slots) };
         let chunk = Chunk::new(self.arch, chunk, slots);
 
         Some(chunk)
@@ -1,4 +1,5 @@
 mod borrow;
+mod combinations;
 mod data;
 mod dfs;
 mod difference;
@@ -9,18 +10,19 @@ mod planar;
 mod searcher;
 mod topo;
 mod walk;
-use itertools::Itertools;
+mod zip;
 pub use walk::{Children, DfsIter, GraphBorrow, GraphQuery, Node};
 
 use core::fmt::Debug;
 
 use crate::{
-    archetype::Slot,
+    archetype::{ArchInfo, Slot},
     component::ComponentValue,
-    fetch::FmtQuery,
-    filter::{All, BatchSize, Filtered, With, WithRelation, Without, WithoutRelation},
+    error::AccessConflict,
+    fetch::{FmtQuery, TrustedAccess},
+    filter::{All, ArchFilter, BatchSize, Filtered, With, WithRelation, Without, WithoutRelation},
     relation::RelationExt,
-    system::Access,
+    system::{Access, AccessKind},
     util::TuplePush,
     Component, Entity, Fetch, FetchItem, World,
 };
@@ -28,6 +30,7 @@ use alloc::vec::Vec;
 
 use self::borrow::QueryBorrowState;
 pub(crate) use borrow::*;
+pub use combinations::Combinations;
 pub use data::*;
 pub use dfs::*;
 pub use entity::EntityBorrow;
@@ -36,6 +39,7 @@ pub use one::QueryOne;
 pub use planar::*;
 pub use searcher::ArchetypeSearcher;
 pub use topo::{Topo, TopoBorrow, TopoIter};
+pub use zip::ZipDisjoint;
 
 /// Similar to [`Query`], except optimized to only fetch a single entity.
 ///
@@ -62,6 +66,33 @@ pub trait QueryStrategy<'w, Q, F> {
     fn access(&self, world: &'w World, fetch: &'w Filtered<Q, F>, dst: &mut Vec<Access>);
 }
 
+/// Attempts to borrow the cell of each archetype access, failing on the first one which is
+/// already borrowed in a conflicting way.
+///
+/// The cells are released again, so this only detects the borrows which are alive at the time of
+/// the call. The query acquires its borrows as it visits each archetype.
+fn check_accesses(world: &World, accesses: &[Access]) -> Result<(), AccessConflict> {
+    for access in accesses {
+        let AccessKind::Archetype { id, component } = access.kind else {
+            continue;
+        };
+
+        let Some(cell) = world.archetypes.get(id).cell(component) else {
+            continue;
+        };
+
+        if !cell.can_borrow(access.mutable) {
+            return Err(AccessConflict {
+                arch_id: id,
+                desc: cell.desc(),
+                mutable: access.mutable,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Represents a query and state for a given world.
 /// The archetypes to visit is cached in the query which means it is more
 /// performant to reuse the query than creating a new one.
@@ -75,6 +106,11 @@ pub struct Query<Q, F = All, S = Planar> {
 
     change_tick: u32,
     archetype_gen: u32,
+    /// Number of items collected by the last execution
+    last_len: usize,
+    /// The accesses checked by [`Self::borrow`], valid for the archetype generation `access_gen`
+    accesses: Vec<Access>,
+    access_gen: u32,
 
     strategy: S,
 }
@@ -96,7 +132,7 @@ where
 
 impl<Q> Query<Q, All, Planar> {
     /// Construct a new query which will fetch all items in the given query.
-
+    ///
     /// The query can be either a singular component, a tuple of components, or
     /// any other type which implements [crate::Fetch].
     ///
@@ -106,7 +142,7 @@ impl<Q> Query<Q, All, Planar> {
     ///
     /// A fetch may also contain filters
     /// Construct a new query which will fetch all items in the given query.
-
+    ///
     /// The query can be either a singular component, a tuple of components, or
     /// any other type which implements [crate::Fetch].
     ///
@@ -116,7 +152,7 @@ impl<Q> Query<Q, All, Planar> {
     ///
     /// A fetch may also contain filters
     /// Construct a new query which will fetch all items in the given query.
-
+    ///
     /// The query can be either a singular component, a tuple of components, or
     /// any other type which implements [crate::Fetch].
     ///
@@ -130,10 +166,13 @@ impl<Q> Query<Q, All, Planar> {
         Q: for<'x> Fetch<'x>,
     {
         Self {
-            fetch: Filtered::new(fetch, All, false),
+            fetch: Filtered::new(fetch, All, false, false, true),
             change_tick: 0,
             strategy: Planar::new(),
             archetype_gen: 0,
+            last_len: 0,
+            accesses: Vec::new(),
+            access_gen: 0,
         }
     }
 
@@ -143,6 +182,22 @@ impl<Q> Query<Q, All, Planar> {
     pub fn with_components(mut self) -> Self {
         self.fetch.include_components = true;
         self.archetype_gen = 0;
+        self.access_gen = 0;
+        self
+    }
+
+    /// Include reserved entities which are not yet spawned with any components.
+    ///
+    /// By default, ids acquired through [`World::reserve`] are not visited by queries until they
+    /// are given a component. This yields them as empty entities instead, given that the query
+    /// does not require any components.
+    ///
+    /// **Note**: Reserved ids are only observable once flushed, see [`World::flush_reserved`].
+    /// Only relevant for the `planar` strategy
+    pub fn include_reserved(mut self) -> Self {
+        self.strategy.include_reserved = true;
+        self.archetype_gen = 0;
+        self.access_gen = 0;
         self
     }
 }
@@ -163,6 +218,9 @@ where
             fetch: self.fetch,
             change_tick: self.change_tick,
             archetype_gen: 0,
+            last_len: 0,
+            accesses: Vec::new(),
+            access_gen: 0,
             strategy,
         }
     }
@@ -183,24 +241,62 @@ where
         self.with_strategy(Topo::new(relation))
     }
 
-    /// Collect all elements in the query into a vector
-    pub fn collect_vec<'w, T>(&'w mut self, world: &'w World) -> Vec<T>
+    /// Collect all elements in the query into a vector.
+    ///
+    /// The vector is preallocated using the number of elements of the previous collection, see
+    /// [`Self::expected_len`].
+    pub fn collect_vec<T>(&mut self, world: &World) -> Vec<T>
     where
         T: 'static,
         Q: for<'q> FetchItem<'q, Item = T>,
     {
-        let mut borrow = self.borrow(world);
-        borrow.iter().collect()
+        let mut items = Vec::with_capacity(self.last_len);
+        items.extend(self.borrow_unchecked(world).iter());
+
+        self.last_len = items.len();
+        items
     }
 
     /// Collect all elements in the query into a sorted vector
-    pub fn collect_sorted_vec<'w, T>(&'w mut self, world: &'w World) -> Vec<T>
+    pub fn collect_sorted_vec<T>(&mut self, world: &World) -> Vec<T>
     where
         T: 'static + Ord,
         Q: for<'q> FetchItem<'q, Item = T>,
     {
-        let mut borrow = self.borrow(world);
-        borrow.iter().sorted().collect()
+        let mut items = self.collect_vec(world);
+        items.sort();
+        items
+    }
+
+    /// Borrow this and another query at the same time, allowing both entity populations to be
+    /// processed in one pass.
+    ///
+    /// Fails with [`Error::ConflictingAccess`](crate::Error::ConflictingAccess) if the queries can
+    /// access the same component of the same archetype where either access is mutable, such as
+    /// when the queries are not made disjoint through their filters. Conflicting accesses to
+    /// anything other than a component, such as an index, fail with
+    /// [`Error::ConflictingAccessKind`](crate::Error::ConflictingAccessKind).
+    pub fn zip_disjoint<'w, Q2, F2>(
+        &'w mut self,
+        other: &'w mut Query<Q2, F2>,
+        world: &'w World,
+    ) -> crate::error::Result<ZipDisjoint<'w, Q, F, Q2, F2>>
+    where
+        Q2: for<'x> Fetch<'x>,
+        F2: for<'x> Fetch<'x>,
+    {
+        let mut left = Vec::new();
+        self.strategy.access(world, &self.fetch, &mut left);
+
+        let mut right = Vec::new();
+        other.strategy.access(world, &other.fetch, &mut right);
+
+        zip::check_disjoint(world, &left, &right)?;
+
+        Ok(ZipDisjoint::new(
+            self.borrow_unchecked(world),
+            other.borrow_unchecked(world),
+        ))
     }
 }
 
@@ -209,6 +305,56 @@ where
     Q: for<'x> Fetch<'x>,
     F: for<'x> Fetch<'x>,
 {
+    /// True if either the fetch or the filter may modify components.
+    ///
+    /// Read-only queries only acquire shared borrows of the archetype cells and do not advance
+    /// the world change tick.
+    pub const MUTABLE: bool = <Q as Fetch<'static>>::MUTABLE || <F as Fetch<'static>>::MUTABLE;
+
+    /// Include entities which are [`disabled`](crate::components::disabled).
+    ///
+    /// By default, archetypes with the `disabled` marker are skipped by all queries.
+    pub fn include_disabled(mut self) -> Self {
+        self.fetch.include_disabled = true;
+        self.archetype_gen = 0;
+        self.access_gen = 0;
+        self
+    }
+
+    /// Skip the metadata entities of components.
+    ///
+    /// This is the default, and restores it after [`Query::with_components`].
+    pub fn exclude_components(mut self) -> Self {
+        self.fetch.include_components = false;
+        self.archetype_gen = 0;
+        self.access_gen = 0;
+        self
+    }
+
+    /// Skip static entities, such as resource entities and the metadata entities of components
+    /// declared using [`component!`](crate::component!).
+    ///
+    /// Useful for world-wide maintenance queries, which would otherwise visit and modify the
+    /// statically declared entities.
+    pub fn exclude_static(mut self) -> Self {
+        self.fetch.include_static = false;
+        self.archetype_gen = 0;
+        self.access_gen = 0;
+        self
+    }
+
+    /// Skips the archetypes for which `func` returns false, before the entities are filtered.
+    ///
+    /// The predicate is evaluated for each matched archetype every time the query is borrowed,
+    /// and may as such depend on the number of entities in the archetype.
+    pub fn filter_arch<G>(self, func: G) -> Query<Q, F::PushRight, S>
+    where
+        G: Fn(ArchInfo) -> bool,
+        F: TuplePush<ArchFilter<G>>,
+    {
+        self.filter(ArchFilter::new(func))
+    }
+
     /// Adds a new filter to the query.
     /// This filter is and:ed with the existing filters.
     pub fn filter<G>(self, filter: G) -> Query<Q, F::PushRight, S>
@@ -220,9 +366,14 @@ where
                 self.fetch.fetch,
                 self.fetch.filter.push_right(filter),
                 self.fetch.include_components,
+                self.fetch.include_disabled,
+                self.fetch.include_static,
             ),
             change_tick: self.change_tick,
             archetype_gen: 0,
+            last_len: 0,
+            accesses: Vec::new(),
+            access_gen: 0,
             strategy: self.strategy,
         }
     }
@@ -273,11 +424,39 @@ where
         self.filter(component.with())
     }
 
+    /// Returns the number of items collected by the last execution of the query.
+    ///
+    /// This can be used to size scratch buffers in per-frame passes, avoiding repeated
+    /// reallocation as the number of matched entities usually changes little between frames.
+    pub fn expected_len(&self) -> usize {
+        self.last_len
+    }
+
+    /// Records the number of items yielded by an execution of the query, which is returned by
+    /// [`Self::expected_len`].
+    ///
+    /// This is done automatically by [`Query::collect_vec`].
+    pub fn set_expected_len(&mut self, len: usize) {
+        self.last_len = len;
+    }
+
+    /// Returns an [`ArchetypeSearcher`] seeded with the components required and excluded by the
+    /// query.
+    ///
+    /// This can be used to find the candidate archetypes of the query outside of the query
+    /// machinery. The archetypes are a superset of those matched by the query, as not all filters
+    /// can be expressed as search terms.
+    pub fn searcher(&self) -> ArchetypeSearcher {
+        let mut searcher = ArchetypeSearcher::new();
+        self.fetch.searcher(&mut searcher);
+        searcher
+    }
+
     /// Prepare the next change tick and return the old one for the last time
     /// the query ran
-    fn prepare_tick(&mut self, world: &World) -> (u32, u32) {
+    fn prepare_tick(change_tick: &mut u32, world: &World, since: Option<u32>) -> (u32, u32) {
         // The tick of the last iteration
-        let mut old_tick = self.change_tick;
+        let mut old_tick = since.unwrap_or(*change_tick);
 
         // Set the change_tick for self to that of the query, to make all
         // changes before this invocation too old
@@ -285,7 +464,7 @@ where
         // It is only necessary to acquire a new change tick if the query will
         // change anything
 
-        let new_tick = if Q::MUTABLE {
+        let new_tick = if Self::MUTABLE {
             world.advance_change_tick();
             world.change_tick()
         } else {
@@ -296,7 +475,7 @@ where
             old_tick = 0;
         }
 
-        self.change_tick = new_tick;
+        *change_tick = new_tick;
         (old_tick, new_tick)
     }
 
@@ -311,26 +490,141 @@ where
     ///
     /// It is safe to use the same prepared query for both iteration and random
     /// access, Rust's borrow rules will ensure aliasing rules.
-    pub fn borrow<'w>(&'w mut self, world: &'w World) -> S::Borrow
+    ///
+    /// If the query is read-only, see [`Self::MUTABLE`], the world change tick is left as is.
+    ///
+    /// Fails if a component accessed by the query is already borrowed in a conflicting way at the
+    /// time of the call, such as by another live [`QueryBorrow`] or a component reference held
+    /// by a callback. The query is left as is, and changes are still reported by the next
+    /// successful borrow. The accessed components are cached until the archetypes of the world
+    /// change.
+    ///
+    /// **Note**: The components are not held borrowed by this check, but are borrowed as each
+    /// archetype is visited. A conflicting borrow acquired after this returns, such as by
+    /// another query borrowed later but iterated first, is not reported and still panics while
+    /// iterating.
+    ///
+    /// See [`Self::borrow_unchecked`] to skip the check.
+    pub fn borrow<'w>(&'w mut self, world: &'w World) -> Result<S::Borrow, AccessConflict>
+    where
+        S: QueryStrategy<'w, Q, F>,
+    {
+        profile_function!();
+        self.borrow_inner(world, None, true, false)
+    }
+
+    /// Borrow data in the world for the query through exclusive access to the world.
+    ///
+    /// As the world can not be accessed elsewhere while the borrow is alive, read-only queries
+    /// read their components without acquiring the guards of the storage, which removes the
+    /// synchronization cost of borrowing each archetype. Queries which access anything mutably,
+    /// including through [`EntityRef`](crate::EntityRef) or [`MaybeMut`](crate::fetch::MaybeMut),
+    /// are borrowed as usual.
+    ///
+    /// The unguarded reads rely on [`Fetch::access`] to describe everything the query touches,
+    /// and is thus only available for fetches and filters which implement [`TrustedAccess`].
+    pub fn borrow_exclusive<'w>(&'w mut self, world: &'w mut World) -> S::Borrow
+    where
+        S: QueryStrategy<'w, Q, F>,
+        Q: TrustedAccess,
+        F: TrustedAccess,
+    {
+        profile_function!();
+        match self.borrow_inner(world, None, false, true) {
+            Ok(v) => v,
+            Err(_) => unreachable!("Unchecked borrows do not fail"),
+        }
+    }
+
+    /// Borrow data in the world for the query, without checking for conflicting borrows up front.
+    ///
+    /// # Panics
+    /// While iterating, if a component accessed by the query is already borrowed in a
+    /// conflicting way.
+    pub fn borrow_unchecked<'w>(&'w mut self, world: &'w World) -> S::Borrow
+    where
+        S: QueryStrategy<'w, Q, F>,
+    {
+        profile_function!();
+        match self.borrow_inner(world, None, false, false) {
+            Ok(v) => v,
+            Err(_) => unreachable!("Unchecked borrows do not fail"),
+        }
+    }
+
+    /// Borrow data in the world for the query, where change filters yield changes made after
+    /// `old_tick` rather than since the query was last borrowed.
+    ///
+    /// This allows several independent users, such as systems, to share the same query while
+    /// keeping their own change baseline. An `old_tick` which is ahead of the world, such as after
+    /// the change tick wrapped around, is treated as if nothing has been seen yet.
+    ///
+    /// Fails on conflicting borrows, which are only checked when called, in the same way as
+    /// [`Self::borrow`].
+    pub fn borrow_since<'w>(
+        &'w mut self,
+        world: &'w World,
+        old_tick: u32,
+    ) -> Result<S::Borrow, AccessConflict>
     where
         S: QueryStrategy<'w, Q, F>,
     {
         profile_function!();
-        let (old_tick, new_tick) = self.prepare_tick(world);
+        self.borrow_inner(world, Some(old_tick), true, false)
+    }
+
+    pub(super) fn borrow_inner<'w>(
+        &'w mut self,
+        world: &'w World,
+        since: Option<u32>,
+        check: bool,
+        exclusive: bool,
+    ) -> Result<S::Borrow, AccessConflict>
+    where
+        S: QueryStrategy<'w, Q, F>,
+    {
+        let archetype_gen = world.archetype_gen();
+        let dirty = archetype_gen > self.archetype_gen;
+
+        let Self {
+            fetch,
+            change_tick,
+            archetype_gen: prev_archetype_gen,
+            accesses,
+            access_gen,
+            strategy,
+            ..
+        } = self;
+
+        let fetch = &*fetch;
+
+        if (check || exclusive) && *access_gen != archetype_gen {
+            accesses.clear();
+            strategy.access(world, fetch, accesses);
+            *access_gen = archetype_gen;
+        }
+
+        // Check before any state is updated, so that a failed borrow does not skip any changes
+        if check {
+            check_accesses(world, accesses)?;
+        }
+
+        // Anything accessed mutably may alias the unguarded reads
+        let exclusive = exclusive && accesses.iter().all(|v| !v.mutable);
+
+        let (old_tick, new_tick) = Self::prepare_tick(change_tick, world, since);
 
         let borrow_state = QueryBorrowState {
             old_tick,
             new_tick,
             world,
-            fetch: &self.fetch,
+            fetch,
+            exclusive,
         };
 
-        let archetype_gen = world.archetype_gen();
-        let dirty = archetype_gen > self.archetype_gen;
-
-        self.archetype_gen = archetype_gen;
+        *prev_archetype_gen = archetype_gen;
 
-        self.strategy.borrow(borrow_state, dirty)
+        Ok(strategy.borrow(borrow_state, dirty))
     }
 }
 
@@ -371,16 +665,200 @@ mod test {
         )));
 
         assert_eq!(
-            query.borrow(&world).get(resources()),
+            query.borrow(&world).unwrap().get(resources()),
             Ok((&800.0, &600.0, &false))
         );
         world.set(resources(), allow_vsync(), true).unwrap();
 
         assert_eq!(
-            query.borrow(&world).get(resources()),
+            query.borrow(&world).unwrap().get(resources()),
             Ok((&800.0, &600.0, &true))
         );
-        assert!(query.borrow(&world).get(resources()).is_err());
+        assert!(query.borrow(&world).unwrap().get(resources()).is_err());
+    }
+
+    #[test]
+    fn reserved() {
+        use crate::{entity::EntityKind, entity_ids};
+
+        let mut world = World::new();
+        let id = world.spawn();
+        let reserved = world.reserve_one(EntityKind::empty());
+
+        assert!(world.entity(reserved).is_err());
+
+        let mut query = Query::new(entity_ids());
+        let mut reserved_query = Query::new(entity_ids()).include_reserved();
+
+        assert_eq!(query.collect_vec(&world), [id]);
+        assert_eq!(reserved_query.collect_vec(&world), [id]);
+
+        world.flush_reserved();
+
+        assert!(world.entity(reserved).unwrap().is_empty());
+        assert_eq!(query.collect_vec(&world), [id]);
+        assert_eq!(reserved_query.collect_sorted_vec(&world), [id, reserved]);
+    }
+
+    #[test]
+    fn read_only_tick() {
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder().set(a(), 5).spawn(&mut world);
+
+        let mut query = Query::new(a());
+        const { assert!(!Query::<Component<i32>>::MUTABLE) };
+
+        let tick = world.change_tick();
+        assert_eq!(query.borrow(&world).unwrap().get(id), Ok(&5));
+        assert_eq!(world.change_tick(), tick);
+
+        let mut query = Query::new(a().as_mut());
+        assert_eq!(*query.borrow(&world).unwrap().get(id).unwrap(), 5);
+        assert!(world.change_tick() > tick);
+    }
+
+    #[test]
+    fn borrow_exclusive() {
+        use crate::FetchExt;
+        use itertools::Itertools;
+
+        component! {
+            a: i32,
+            b: f32,
+        }
+
+        let mut world = World::new();
+        let ids = (0..4)
+            .map(|i| {
+                Entity::builder()
+                    .set(a(), i)
+                    .set(b(), 1.0)
+                    .spawn(&mut world)
+            })
+            .collect_vec();
+
+        let mut query = Query::new((a().copied(), b()));
+        let mut changed = Query::new(a().copied()).filter(a().modified());
+
+        assert_eq!(changed.borrow_exclusive(&mut world).iter().count(), 4);
+
+        let tick = world.change_tick();
+        assert_eq!(
+            query.borrow_exclusive(&mut world).iter().collect_vec(),
+            [(0, &1.0), (1, &1.0), (2, &1.0), (3, &1.0)]
+        );
+        assert_eq!(world.change_tick(), tick);
+
+        *world.get_mut(ids[2], a()).unwrap() = 5;
+
+        assert_eq!(
+            changed.borrow_exclusive(&mut world).iter().collect_vec(),
+            [5]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "already immutably borrowed")]
+    fn borrow_exclusive_guarded() {
+        use crate::fetch::entity_refs;
+
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        Entity::builder().set(a(), 1).spawn(&mut world);
+
+        // Mutable access through the world is still guarded
+        let mut query = Query::new((a(), entity_refs()));
+        for (_, entity) in &mut query.borrow_exclusive(&mut world) {
+            *entity.get_mut(a()).unwrap() += 1;
+        }
+    }
+
+    #[test]
+    fn borrow_since() {
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder().set(a(), 1).spawn(&mut world);
+
+        let mut query = Query::new(a().modified());
+        let start = world.change_tick();
+
+        assert_eq!(query.borrow(&world).unwrap().iter().count(), 1);
+        assert_eq!(query.borrow(&world).unwrap().iter().count(), 0);
+
+        // Another user with its own baseline still sees the change
+        assert_eq!(query.borrow_since(&world, 0).unwrap().iter().count(), 1);
+
+        *world.get_mut(id, a()).unwrap() = 2;
+        let tick = world.change_tick();
+
+        assert_eq!(query.borrow_since(&world, start).unwrap().iter().count(), 1);
+        assert_eq!(query.borrow_since(&world, tick).unwrap().iter().count(), 0);
+
+        // A tick ahead of the world is treated as having seen nothing
+        assert_eq!(
+            query.borrow_since(&world, u32::MAX).unwrap().iter().count(),
+            1
+        );
+    }
+
+    #[test]
+    fn searcher() {
+        component! {
+            a: i32,
+            b: i32,
+            c: i32,
+        }
+
+        let mut world = World::new();
+        let id1 = Entity::builder().set(a(), 1).spawn(&mut world);
+        let id2 = Entity::builder().set(a(), 2).set(b(), 2).spawn(&mut world);
+        let id3 = Entity::builder().set(a(), 3).set(c(), 3).spawn(&mut world);
+        let id4 = Entity::builder()
+            .set(a(), 4)
+            .set(b(), 4)
+            .set(c(), 4)
+            .spawn(&mut world);
+
+        let arch = |id| world.location(id).unwrap().arch_id;
+
+        let mut found = ArchetypeSearcher::new()
+            .with_required(a().key())
+            .with_excluded(b().key())
+            .find(&world);
+        found.sort();
+
+        let mut expected = [arch(id1), arch(id3)];
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let mut found = Query::new(a()).without(c()).searcher().find(&world);
+        found.sort();
+
+        let mut expected = [arch(id1), arch(id2)];
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let found = ArchetypeSearcher::new()
+            .with_required(b().key())
+            .with_required(c().key())
+            .find(&world);
+        assert_eq!(found, [arch(id4)]);
+
+        let found = ArchetypeSearcher::new()
+            .with_required(b().key())
+            .with_excluded(b().key())
+            .find(&world);
+        assert_eq!(found, []);
     }
 
     #[test]
@@ -406,11 +884,11 @@ mod test {
 
         let mut query = Query::new((a().modified(), b(), c().opt()));
 
-        let borrow = query.borrow(&world);
+        let borrow = query.borrow(&world).unwrap();
 
         drop(borrow);
 
-        let mut borrow = query.borrow(&world);
+        let mut borrow = query.borrow(&world).unwrap();
 
         assert_eq!(
             borrow.get(id4),
@@ -440,14 +918,183 @@ mod test {
 
         let mut query = Query::new(name());
 
-        assert_eq!(query.borrow(&world).get(id), Ok(&"id".into()));
-        assert_eq!(query.borrow(&world).get(id2), Ok(&"id2".into()));
+        assert_eq!(query.borrow(&world).unwrap().get(id), Ok(&"id".into()));
+        assert_eq!(query.borrow(&world).unwrap().get(id2), Ok(&"id2".into()));
         assert_eq!(
-            query.borrow(&world).get(a().id()),
+            query.borrow(&world).unwrap().get(a().id()),
             Err(Error::DoesNotMatch(a().id()))
         );
 
         let mut query = query.with_components();
-        assert_eq!(query.borrow(&world).get(a().id()), Ok(&"a".into()));
+        assert_eq!(query.borrow(&world).unwrap().get(a().id()), Ok(&"a".into()));
+    }
+
+    #[test]
+    fn expected_len() {
+        use crate::entity_ids;
+
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        let mut query = Query::new(entity_ids()).with(a());
+        assert_eq!(query.expected_len(), 0);
+
+        let ids = (0..10)
+            .map(|i| Entity::builder().set(a(), i).spawn(&mut world))
+            .collect::<Vec<_>>();
+
+        let items = query.collect_vec(&world);
+        assert_eq!(items, ids);
+        assert_eq!(query.expected_len(), 10);
+
+        world.despawn(ids[0]).unwrap();
+        assert_eq!(query.collect_vec(&world).capacity(), 10);
+        assert_eq!(query.expected_len(), 9);
+    }
+
+    #[test]
+    fn exclude_static() {
+        use crate::{components::component_info, entity_ids};
+
+        component! {
+            a: i32,
+            resources,
+        }
+
+        let mut world = World::new();
+        world.set(resources(), a(), 1).unwrap();
+        let id = Entity::builder().set(a(), 2).spawn(&mut world);
+
+        let mut expected = [id, resources()];
+        expected.sort();
+
+        let mut query = Query::new(entity_ids()).with(a());
+        assert_eq!(query.collect_sorted_vec(&world), expected);
+
+        let mut query = Query::new(entity_ids()).with(a()).exclude_static();
+        assert_eq!(query.collect_vec(&world), [id]);
+
+        let mut query = Query::new(entity_ids())
+            .with_components()
+            .with(component_info());
+        assert!(query.collect_vec(&world).contains(&a().id()));
+
+        let mut query = Query::new(entity_ids())
+            .with_components()
+            .exclude_components()
+            .with(component_info());
+        assert_eq!(query.collect_vec(&world), []);
+
+        // Components declared through `component!` are static
+        let mut query = Query::new(entity_ids())
+            .with_components()
+            .exclude_static()
+            .with(component_info());
+        assert!(!query.collect_vec(&world).contains(&a().id()));
+    }
+
+    #[test]
+    fn borrow_conflict() {
+        use crate::error::AccessConflict;
+        use itertools::Itertools;
+
+        component! {
+            a: i32,
+            b: f32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(a(), 1)
+            .set(b(), 1.0)
+            .spawn(&mut world);
+        let arch_id = world.location(id).unwrap().arch_id;
+
+        let mut writer = Query::new(a().as_mut());
+        let mut reader = Query::new((a(), b()));
+        let mut modified = Query::new(b().as_mut()).filter(a().modified());
+
+        assert_eq!(modified.borrow(&world).unwrap().count(), 1);
+
+        let mut borrow = writer.borrow(&world).unwrap();
+        let values = borrow.iter().collect_vec();
+
+        assert_eq!(
+            reader.borrow(&world).err(),
+            Some(AccessConflict {
+                arch_id,
+                desc: a().desc(),
+                mutable: false,
+            })
+        );
+
+        assert!(modified.borrow(&world).is_err());
+        assert!(modified.borrow_since(&world, 0).is_err());
+
+        for v in values {
+            *v += 1;
+        }
+
+        drop(borrow);
+
+        assert_eq!(reader.borrow(&world).unwrap().get(id), Ok((&2, &1.0)));
+
+        // The modification is not lost by the failed borrow
+        assert_eq!(modified.borrow(&world).unwrap().count(), 1);
+        assert_eq!(modified.borrow(&world).unwrap().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "already mutably borrowed")]
+    fn borrow_conflict_after_check() {
+        use itertools::Itertools;
+
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        Entity::builder().set(a(), 1).spawn(&mut world);
+
+        let mut reader = Query::new(a());
+        let mut writer = Query::new(a().as_mut());
+
+        // Nothing is borrowed yet when either borrow is checked
+        let mut reader = reader.borrow(&world).unwrap();
+        let mut writer = writer.borrow(&world).unwrap();
+
+        let _values = writer.iter().collect_vec();
+        reader.iter().for_each(drop);
+    }
+
+    #[test]
+    fn filter_arch() {
+        use crate::entity_ids;
+
+        component! {
+            a: i32,
+            b: (),
+        }
+
+        let mut world = World::new();
+        let id1 = Entity::builder().set(a(), 1).spawn(&mut world);
+        let id2 = Entity::builder().set(a(), 2).spawn(&mut world);
+        let id3 = Entity::builder().set(a(), 3).set(b(), ()).spawn(&mut world);
+
+        let mut query = Query::new(entity_ids())
+            .with(a())
+            .filter_arch(|arch| arch.len() > 1);
+        assert_eq!(query.collect_sorted_vec(&world), [id1, id2]);
+
+        // The predicate is evaluated for each borrow
+        let id4 = Entity::builder().set(a(), 4).set(b(), ()).spawn(&mut world);
+        assert_eq!(query.collect_sorted_vec(&world), [id1, id2, id3, id4]);
+
+        let mut query = Query::new(entity_ids())
+            .with(a())
+            .filter_arch(|arch| !arch.has(b().key()));
+        assert_eq!(query.collect_sorted_vec(&world), [id1, id2]);
     }
 }
@@ -24,6 +24,7 @@ impl<'w, Q: Fetch<'w>> QueryOne<'w, Q> {
             arch_id: loc.arch_id,
             old_tick: 0,
             new_tick: world.advance_change_tick(),
+            exclusive: false,
         });
 
         Self { prepared, loc }
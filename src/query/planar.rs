@@ -1,26 +1,29 @@
 use alloc::vec::Vec;
-use core::{iter::Flatten, slice::IterMut};
+use core::{cell::RefCell, fmt::Debug, iter::Flatten, slice::IterMut};
 use smallvec::SmallVec;
 
 use crate::{
-    archetype::{ArchetypeId, Slice},
+    archetype::{ArchetypeId, Slice, Slot},
+    component::ComponentValue,
     entity::EntityLocation,
     error::{MissingComponent, Result},
     fetch::{FetchAccessData, PreparedFetch},
-    filter::{All, Filtered},
+    filter::{next_slice, All, Filtered},
+    format::QueryFormatter,
     system::{Access, AccessKind},
-    Entity, Error, Fetch, FetchItem, World,
+    Component, Entity, Error, Fetch, FetchItem, World,
 };
 
 use super::{
     borrow::QueryBorrowState, difference::find_missing_components, ArchetypeChunks,
-    ArchetypeSearcher, Chunk, PreparedArchetype, QueryStrategy,
+    ArchetypeSearcher, Chunk, Combinations, PreparedArchetype, QueryStrategy,
 };
 
 /// The default linear iteration strategy
 #[derive(Clone)]
 pub struct Planar {
     pub(super) archetypes: Vec<ArchetypeId>,
+    pub(super) include_reserved: bool,
 }
 
 impl core::fmt::Debug for Planar {
@@ -33,6 +36,7 @@ impl Planar {
     pub(super) fn new() -> Self {
         Self {
             archetypes: Vec::new(),
+            include_reserved: false,
         }
     }
 }
@@ -42,11 +46,26 @@ impl Planar {
     fn update_state<'w, Q: Fetch<'w>, F: Fetch<'w>>(
         world: &crate::World,
         fetch: &Filtered<Q, F>,
+        include_reserved: bool,
         result: &mut Vec<ArchetypeId>,
     ) {
         let mut searcher = ArchetypeSearcher::default();
         fetch.searcher(&mut searcher);
 
+        // Reserved entities have no components, and are as such only matched by queries which
+        // do not require any
+        if include_reserved && searcher.required.is_empty() {
+            let arch_id = world.archetypes.reserved;
+            let arch = world.archetypes.get(arch_id);
+            if fetch.filter_arch(FetchAccessData {
+                world,
+                arch,
+                arch_id,
+            }) {
+                result.push(arch_id)
+            }
+        }
+
         searcher.find_archetypes(&world.archetypes, |arch_id, arch| {
             if !fetch.filter_arch(FetchAccessData {
                 world,
@@ -72,11 +91,17 @@ where
         // Make sure the archetypes to visit are up to date
         if dirty {
             self.archetypes.clear();
-            Self::update_state(state.world, state.fetch, &mut self.archetypes);
+            Self::update_state(
+                state.world,
+                state.fetch,
+                self.include_reserved,
+                &mut self.archetypes,
+            );
         }
 
         QueryBorrow {
             prepared: SmallVec::new(),
+            prepared_all: false,
             archetypes: &self.archetypes,
             state,
         }
@@ -84,7 +109,7 @@ where
 
     fn access(&self, world: &World, fetch: &Filtered<Q, F>, dst: &mut Vec<Access>) {
         let mut result = Vec::new();
-        Self::update_state(world, fetch, &mut result);
+        Self::update_state(world, fetch, self.include_reserved, &mut result);
 
         result.iter().for_each(|&arch_id| {
             let arch = world.archetypes.get(arch_id);
@@ -104,6 +129,65 @@ where
     }
 }
 
+/// The position of a partially completed iteration, used to continue it later.
+///
+/// See [`QueryBorrow::for_each_resumable`]
+#[derive(Debug, Clone)]
+pub struct QueryCursor {
+    arch_id: Option<ArchetypeId>,
+    slot: Slot,
+    batch_size: usize,
+}
+
+impl QueryCursor {
+    /// Creates a new cursor at the start of the query, which visits at most `batch_size` slots
+    /// between each check of the stop condition.
+    pub fn new(batch_size: usize) -> Self {
+        assert!(batch_size > 0, "Batch size of 0 will never yield");
+
+        Self {
+            arch_id: None,
+            slot: 0,
+            batch_size,
+        }
+    }
+
+    /// Returns the number of slots visited between each check of the stop condition
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns true if the cursor is at the start of the query
+    pub fn is_start(&self) -> bool {
+        self.arch_id.is_none()
+    }
+
+    /// Moves the cursor back to the start of the query
+    pub fn reset(&mut self) {
+        self.arch_id = None;
+        self.slot = 0;
+    }
+}
+
+impl Default for QueryCursor {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+/// The number of entities matched by a query in an archetype.
+///
+/// See [`QueryBorrow::archetype_counts`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchetypeCount {
+    /// The matched archetype
+    pub arch_id: ArchetypeId,
+    /// The number of entities in the archetype
+    pub entities: usize,
+    /// The number of entities which passed the filters
+    pub matched: usize,
+}
+
 /// A lazily prepared query which borrows and hands out chunk iterators for
 /// each archetype matched.
 ///
@@ -116,6 +200,11 @@ where
     F: Fetch<'w>,
 {
     prepared: SmallVec<[PreparedArchetype<'w, Q::Prepared, F::Prepared>; 8]>,
+    /// All non-empty archetypes are prepared.
+    ///
+    /// Empty archetypes are never prepared, so this can not be inferred from the number of
+    /// prepared archetypes.
+    prepared_all: bool,
     archetypes: &'w [ArchetypeId],
     state: QueryBorrowState<'w, Q, F>,
 }
@@ -156,15 +245,97 @@ where
         self.iter().next()
     }
 
+    /// Returns a formatter which prints each matched entity, by name and id, along with its
+    /// fetched item.
+    ///
+    /// Useful for inspecting the input of a system. Formatting iterates the query, and as such
+    /// marks mutably fetched components as modified.
+    pub fn format<'q>(&'q mut self) -> QueryFormatter<'q, 'w, Q, F>
+    where
+        for<'x> <Q as FetchItem<'x>>::Item: Debug,
+    {
+        QueryFormatter {
+            world: self.state.world,
+            borrow: RefCell::new(self),
+        }
+    }
+
     /// Iterate all items matched by query and filter.
     pub fn iter_batched<'q>(&'q mut self) -> BatchedIter<'w, 'q, Q, F>
     where
         'w: 'q,
     {
-        // Prepare all archetypes only if it is not already done
+        self.prepare_all();
+
+        BatchedIter {
+            archetypes: self.prepared.iter_mut(),
+            current: None,
+        }
+    }
+
+    /// Iterate every `n`th matched entity of each archetype, starting at `offset`.
+    ///
+    /// Entities are selected by their slot in the archetype, which allows amortizing work over
+    /// several frames by incrementing `offset` each frame, visiting every entity exactly once
+    /// every `n` frames as long as no entities are added or removed.
+    ///
+    /// Skipped entities are neither borrowed nor marked as modified.
+    pub fn iter_step<'q>(&'q mut self, n: usize, offset: usize) -> StepIter<'w, 'q, Q, F>
+    where
+        'w: 'q,
+    {
+        assert!(n > 0, "Step of 0 will never yield");
+        self.prepare_all();
+
+        StepIter {
+            archetypes: self.prepared.iter_mut(),
+            current: None,
+            n,
+            offset: offset % n,
+        }
+    }
+
+    /// Iterate all unique combinations of `K` distinct items matched by the query.
+    ///
+    /// The items of each combination can be mutated at the same time, which is useful for
+    /// interactions between entities, such as collision resolution.
+    ///
+    /// ```rust
+    /// # use flax::*;
+    /// component! {
+    ///     position: f32,
+    ///     velocity: f32,
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// for i in 0..4 {
+    ///     Entity::builder()
+    ///         .set(position(), i as f32)
+    ///         .set(velocity(), 0.0)
+    ///         .spawn(&mut world);
+    /// }
+    ///
+    /// let mut query = Query::new((position(), velocity().as_mut()));
+    /// let mut borrow = query.borrow(&world).unwrap();
+    /// let mut combinations = borrow.iter_combinations::<2>();
+    ///
+    /// while let Some([(a_pos, a_vel), (b_pos, b_vel)]) = combinations.fetch_next() {
+    ///     let force = 1.0 / (b_pos - a_pos);
+    ///     *a_vel -= force;
+    ///     *b_vel += force;
+    /// }
+    /// ```
+    pub fn iter_combinations<const K: usize>(&mut self) -> Combinations<'w, '_, Q, F, K> {
+        self.prepare_all();
+        Combinations::new(&mut self.prepared)
+    }
+
+    /// Prepare all archetypes only if it is not already done
+    fn prepare_all(&mut self) {
         // Clear previous borrows
-        if self.prepared.len() != self.archetypes.len() {
+        if !self.prepared_all {
             self.clear_borrows();
+            self.prepared_all = true;
             self.prepared = self
                 .archetypes
                 .iter()
@@ -178,11 +349,6 @@ where
                 })
                 .collect();
         }
-
-        BatchedIter {
-            archetypes: self.prepared.iter_mut(),
-            current: None,
-        }
     }
 
     /// Execute a closure for each item in the iterator.
@@ -229,10 +395,105 @@ where
             .for_each(|batch| batch.for_each(&func))
     }
 
+    /// Returns the number of entities matched by the query and filters in each archetype.
+    ///
+    /// Useful for profiling which archetypes dominate the workload of a query, and whether
+    /// filters, such as change filters, reduce it. The matched entities are neither borrowed nor
+    /// marked as modified.
+    pub fn archetype_counts(&mut self) -> Vec<ArchetypeCount> {
+        self.prepare_all();
+
+        self.prepared
+            .iter_mut()
+            .map(|p| {
+                let mut slots = p.arch.slots();
+                let mut matched = 0;
+                while let Some(chunk) = next_slice(&mut slots, &mut p.fetch) {
+                    matched += chunk.len();
+                }
+
+                ArchetypeCount {
+                    arch_id: p.arch_id,
+                    entities: p.arch.len(),
+                    matched,
+                }
+            })
+            .collect()
+    }
+
+    /// Execute a closure for each item, starting where the previous call with the same `cursor`
+    /// stopped.
+    ///
+    /// The items are visited in batches of at most [`QueryCursor::batch_size`], and `should_stop`
+    /// is checked after each batch. Returns true if all remaining items were visited, in which
+    /// case the cursor is reset to start from the beginning on the next call.
+    ///
+    /// Entities which are moved to another archetype between calls may be skipped or visited
+    /// twice during the same pass.
+    pub fn for_each_resumable(
+        &mut self,
+        cursor: &mut QueryCursor,
+        mut should_stop: impl FnMut() -> bool,
+        mut func: impl FnMut(<Q as FetchItem<'_>>::Item),
+    ) -> bool {
+        self.clear_borrows();
+
+        // Start over if the archetype was removed since the previous call
+        let (start, mut slot) = match cursor
+            .arch_id
+            .and_then(|id| self.archetypes.iter().position(|&v| v == id))
+        {
+            Some(idx) => (idx, cursor.slot),
+            None => (0, 0),
+        };
+
+        for (i, &arch_id) in self.archetypes.iter().enumerate().skip(start) {
+            let arch = self.state.world.archetypes.get(arch_id);
+            let end = arch.len();
+
+            if let Some(mut p) = self.state.prepare_fetch(arch_id, arch) {
+                while slot < end {
+                    let batch_end = (slot + cursor.batch_size).min(end);
+                    let mut slots = Slice::new(slot, batch_end);
+
+                    while let Some(matched) = next_slice(&mut slots, &mut p.fetch) {
+                        // Safety: the chunks are consumed before the next is created
+                        if let Some(chunk) = unsafe { p.create_chunk(matched) } {
+                            chunk.for_each(&mut func);
+                        }
+                    }
+
+                    slot = batch_end;
+                    if should_stop() {
+                        if slot < end {
+                            cursor.arch_id = Some(arch_id);
+                            cursor.slot = slot;
+                        } else if let Some(&next) = self.archetypes.get(i + 1) {
+                            // Don't resume at the end of an exhausted archetype
+                            cursor.arch_id = Some(next);
+                            cursor.slot = 0;
+                        } else {
+                            cursor.reset();
+                            return true;
+                        }
+
+                        return false;
+                    }
+                }
+            }
+
+            slot = 0;
+        }
+
+        cursor.reset();
+        true
+    }
+
     /// Release all borrowed archetypes
     #[inline]
     pub fn clear_borrows(&mut self) {
-        self.prepared.clear()
+        self.prepared.clear();
+        self.prepared_all = false;
     }
 
     /// Consumes the iterator and returns the number of entities visited.
@@ -271,7 +532,7 @@ where
     }
 
     /// Get the fetch items for an entity.
-    pub fn get(&mut self, id: Entity) -> Result<<Q::Prepared as PreparedFetch>::Item> {
+    pub fn get(&mut self, id: Entity) -> Result<<Q::Prepared as PreparedFetch<'_>>::Item> {
         let EntityLocation { arch_id, slot } = self.state.world.location(id)?;
 
         let idx =
@@ -299,6 +560,59 @@ where
     }
 }
 
+impl<'w, T, F> QueryBorrow<'w, Component<T>, F>
+where
+    T: ComponentValue,
+    F: Fetch<'w>,
+{
+    /// Folds the matched values of the component one contiguous slice at a time.
+    ///
+    /// Operating on slices rather than on each item allows the compiler to vectorize the
+    /// reduction.
+    pub fn fold_chunks<B>(&mut self, init: B, mut f: impl FnMut(B, &[T]) -> B) -> B {
+        self.iter_batched()
+            .fold(init, |acc, chunk| f(acc, chunk.as_slice()))
+    }
+
+    /// Returns the smallest matched value of the component, or `None` if nothing matched
+    pub fn min(&mut self) -> Option<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.fold_chunks(None, |acc, values| {
+            values.iter().fold(acc, |acc, &v| match acc {
+                Some(cur) if v < cur => Some(v),
+                None => Some(v),
+                acc => acc,
+            })
+        })
+    }
+
+    /// Returns the largest matched value of the component, or `None` if nothing matched
+    pub fn max(&mut self) -> Option<T>
+    where
+        T: PartialOrd + Copy,
+    {
+        self.fold_chunks(None, |acc, values| {
+            values.iter().fold(acc, |acc, &v| match acc {
+                Some(cur) if v > cur => Some(v),
+                None => Some(v),
+                acc => acc,
+            })
+        })
+    }
+
+    /// Returns the sum of the matched values of the component
+    pub fn sum(&mut self) -> T
+    where
+        T: Copy + Default + core::ops::Add<Output = T>,
+    {
+        self.fold_chunks(T::default(), |acc, values| {
+            values.iter().fold(acc, |acc, &v| acc + v)
+        })
+    }
+}
+
 /// The query iterator
 pub struct QueryIter<'w, 'q, Q, F>
 where
@@ -322,6 +636,60 @@ where
     }
 }
 
+type Prepared<'w, Q, F> =
+    PreparedArchetype<'w, <Q as Fetch<'w>>::Prepared, <F as Fetch<'w>>::Prepared>;
+
+/// Iterates every `n`th entity of each archetype.
+///
+/// See [`QueryBorrow::iter_step`]
+pub struct StepIter<'w, 'q, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+{
+    archetypes: IterMut<'q, Prepared<'w, Q, F>>,
+    /// The current archetype and remaining slots
+    current: Option<(&'q mut Prepared<'w, Q, F>, Slice)>,
+    n: usize,
+    offset: usize,
+}
+
+impl<'w, 'q, Q, F> Iterator for StepIter<'w, 'q, Q, F>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    'w: 'q,
+{
+    type Item = <Q::Prepared as PreparedFetch<'q>>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                let p = self.archetypes.next()?;
+                let slots = p.arch.slots();
+                self.current = Some((p, slots));
+            }
+
+            let (p, slots) = self.current.as_mut().unwrap();
+
+            // The first slot at or after the start which is selected
+            let slot = slots.start + (self.offset + self.n - slots.start % self.n) % self.n;
+            if slot >= slots.end {
+                self.current = None;
+                continue;
+            }
+
+            *slots = Slice::new(slot + 1, slots.end);
+
+            // Safety: the slots are visited in increasing order, so each chunk is disjoint to all
+            // previous chunks of the archetype
+            if let Some(mut chunk) = unsafe { p.create_chunk(Slice::single(slot)) } {
+                return chunk.next();
+            }
+        }
+    }
+}
+
 // struct SlicePtrIter<T> {
 //     ptr: *mut T,
 //     count: usize,
@@ -399,10 +767,14 @@ where
                 }
             }
 
-            let p = unsafe {
-                &mut *(self.archetypes.next()?
-                    as *mut PreparedArchetype<'w, Q::Prepared, F::Prepared>)
-            };
+            // The archetypes are borrowed for `'q` and each is only visited once
+            let p = self.archetypes.next()?;
+
+            // Start loading the next archetype while this one is being visited
+            #[cfg(feature = "prefetch")]
+            if let Some(next) = self.archetypes.as_slice().first() {
+                next.fetch.prefetch(next.arch.slots());
+            }
 
             self.current = Some(p.chunks());
         }
@@ -5,20 +5,67 @@ use crate::{
     archetype::{Archetype, ArchetypeId},
     archetypes::Archetypes,
     component::ComponentKey,
+    World,
 };
 
 #[derive(Default, Debug, Clone)]
-/// Declares search terms for a queries archetypes
+/// Declares search terms for a queries archetypes.
+///
+/// This can also be used directly to find the archetypes which contain a set of components
+/// while not containing another set, for use in custom iteration strategies.
 pub struct ArchetypeSearcher {
     pub(crate) required: Vec<ComponentKey>,
+    pub(crate) excluded: Vec<ComponentKey>,
 }
 
 impl ArchetypeSearcher {
+    /// Creates a new searcher which matches all archetypes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Add a required component
     pub fn add_required(&mut self, component: ComponentKey) {
         self.required.push(component)
     }
 
+    /// Add a component which matched archetypes must not have
+    pub fn add_excluded(&mut self, component: ComponentKey) {
+        self.excluded.push(component)
+    }
+
+    /// Add a required component
+    pub fn with_required(mut self, component: ComponentKey) -> Self {
+        self.add_required(component);
+        self
+    }
+
+    /// Add a component which matched archetypes must not have
+    pub fn with_excluded(mut self, component: ComponentKey) -> Self {
+        self.add_excluded(component);
+        self
+    }
+
+    /// Returns the required components
+    pub fn required(&self) -> &[ComponentKey] {
+        &self.required
+    }
+
+    /// Returns the excluded components
+    pub fn excluded(&self) -> &[ComponentKey] {
+        &self.excluded
+    }
+
+    /// Returns the ids of all archetypes in `world` matching the search terms.
+    ///
+    /// The returned list is valid until the archetypes of the world change, which can be detected
+    /// through [`World::archetype_gen`].
+    pub fn find(&mut self, world: &World) -> Vec<ArchetypeId> {
+        let mut result = Vec::new();
+        self.find_archetypes(&world.archetypes, |arch_id, _| result.push(arch_id));
+        result
+    }
+
     #[inline]
     pub(crate) fn find_archetypes<'a>(
         &mut self,
@@ -27,8 +74,25 @@ impl ArchetypeSearcher {
     ) {
         self.required.sort();
         self.required.dedup();
+        self.excluded.sort();
+        self.excluded.dedup();
+
+        // An archetype can not both have and not have a component
+        if self
+            .required
+            .iter()
+            .any(|v| self.excluded.binary_search(v).is_ok())
+        {
+            return;
+        }
 
-        traverse_archetypes(archetypes, archetypes.root(), &self.required, &mut result);
+        traverse_archetypes(
+            archetypes,
+            archetypes.root(),
+            &self.required,
+            &self.excluded,
+            &mut result,
+        );
     }
 }
 
@@ -37,31 +101,38 @@ pub(crate) fn traverse_archetypes<'a>(
     archetypes: &'a Archetypes,
     cur: ArchetypeId,
     required: &[ComponentKey],
+    excluded: &[ComponentKey],
     result: &mut impl FnMut(ArchetypeId, &'a Archetype),
 ) {
     let arch = archetypes.get(cur);
+    // Every archetype below an edge contains the edge's component
+    let children = arch
+        .children
+        .iter()
+        .filter(|(component, _)| excluded.binary_search(component).is_err());
+
     match required {
         // All components are found, every archetype from now on is matched
         [] => {
             // This matches
             result(cur, arch);
 
-            for &arch_id in arch.children.values() {
-                traverse_archetypes(archetypes, arch_id, required, result);
+            for (_, &arch_id) in children {
+                traverse_archetypes(archetypes, arch_id, required, excluded, result);
             }
         }
         [head, tail @ ..] => {
             // Since the components in the trie are in order, a value greater than head means the
             // current component will never occur
-            for (&component, &arch_id) in &arch.children {
+            for (&component, &arch_id) in children {
                 match component.cmp(head) {
                     cmp::Ordering::Less => {
                         // Not quite, keep looking
-                        traverse_archetypes(archetypes, arch_id, required, result);
+                        traverse_archetypes(archetypes, arch_id, required, excluded, result);
                     }
                     cmp::Ordering::Equal => {
                         // One more component has been found, continue to search for the remaining ones
-                        traverse_archetypes(archetypes, arch_id, tail, result);
+                        traverse_archetypes(archetypes, arch_id, tail, excluded, result);
                     }
                     cmp::Ordering::Greater => {
                         // We won't find anything of interest further down the tree
@@ -24,6 +24,8 @@ use super::{
 ///
 /// Cycles are not visited.
 ///
+/// Parents are always yielded before their children, including across disjoint trees.
+///
 /// Links where the fetch is not satisfied, e.g; missing components, will "fall-through" and
 /// affect the ordering, but not be returned by the iteration.
 pub struct Topo {
@@ -43,8 +45,6 @@ impl State {
         self.clear();
         let mut searcher = ArchetypeSearcher::default();
         fetch.searcher(&mut searcher);
-        // Maps each entity to all archetypes of its children
-        let mut deps: BTreeMap<_, _> = BTreeMap::new();
 
         searcher.find_archetypes(&world.archetypes, |arch_id, arch| {
             if !fetch.filter_arch(FetchAccessData {
@@ -60,36 +60,32 @@ impl State {
 
             let existing = self.archetypes_index.insert(arch_id, idx);
             debug_assert_eq!(existing, None, "duplicate archetype");
-            // Find dependencies
-            let arch_deps: Vec<_> = arch
-                .relations_like(relation)
-                .map(|(key, _)| {
-                    assert_eq!(key.id, relation);
-                    let target = key.target.unwrap();
-                    let loc = world.location(target).unwrap();
-                    loc.arch_id
-                })
-                .collect();
-
-            if !arch_deps.is_empty() {
-                deps.insert(arch_id, arch_deps);
-            }
         });
 
         fn sort(
+            world: &World,
+            relation: Entity,
             order: &mut Vec<usize>,
             visited: &mut BTreeSet<ArchetypeId>,
             index: &BTreeMap<ArchetypeId, usize>,
-            deps: &BTreeMap<ArchetypeId, Vec<ArchetypeId>>,
             arch_id: ArchetypeId,
         ) {
             if !visited.insert(arch_id) {
                 return;
             }
 
-            // Make sure all dependencies i.e; parents, are visited first
-            for &dep in deps.get(&arch_id).into_iter().flatten() {
-                sort(order, visited, index, deps, dep);
+            // Make sure all dependencies i.e; parents, are visited first.
+            //
+            // This includes the parents of archetypes not matched by the query, so that the
+            // ordering falls through to their ancestors
+            let arch = world.archetypes.get(arch_id);
+            for (key, _) in arch.relations_like(relation) {
+                debug_assert_eq!(key.id, relation);
+                let Some(dep) = key.target.and_then(|v| world.location(v).ok()) else {
+                    continue;
+                };
+
+                sort(world, relation, order, visited, index, dep.arch_id);
             }
 
             if let Some(&arch_index) = index.get(&arch_id) {
@@ -100,10 +96,11 @@ impl State {
         let mut visited = BTreeSet::new();
         for &arch_id in self.archetypes.iter() {
             sort(
+                world,
+                relation,
                 &mut self.order,
                 &mut visited,
                 &self.archetypes_index,
-                &deps,
                 arch_id,
             )
         }
@@ -213,6 +210,9 @@ where
                 .flat_map(|&idx| {
                     let arch_id = self.topo.archetypes[idx];
                     let arch = self.state.world.archetypes.get(arch_id);
+                    if arch.is_empty() {
+                        return None;
+                    }
 
                     self.state.prepare_fetch(arch_id, arch)
                 })
@@ -257,7 +257,7 @@ mod test {
 
     use crate::{
         components::{component_info, name},
-        Debuggable, FetchExt, Query, World,
+        entity_ids, Debuggable, FetchExt, Query, World,
     };
     use alloc::string::ToString;
 
@@ -382,7 +382,7 @@ mod test {
             .without(component_info())
             .with(tree());
 
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, ["a", "d", "b", "c", "f", "e", "g"]);
 
@@ -397,15 +397,54 @@ mod test {
         //   g
         world.detach(b);
 
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, ["a", "d", "c", "f", "b", "e", "g"]);
 
         // Removing the `tree` from `e` is equivalent to removing the dependency
         world.remove(e, tree()).unwrap();
 
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, ["a", "d", "c", "f", "b", "g"]);
     }
+
+    #[test]
+    fn topo_fall_through() {
+        component! {
+            transform: i32,
+            child_of(parent): (),
+        }
+
+        let mut world = World::new();
+
+        // Spawn in reverse order to make sure the ordering does not follow the spawn order
+        let c = Entity::builder().set(transform(), 3).spawn(&mut world);
+        let b = world.spawn();
+        let a = Entity::builder().set(transform(), 1).spawn(&mut world);
+
+        // `b` does not have a transform, but still orders `a` before `c`
+        world.set(b, child_of(a), ()).unwrap();
+        world.set(c, child_of(b), ()).unwrap();
+
+        // A disjoint tree
+        let d = Entity::builder().set(transform(), 4).spawn(&mut world);
+        let e = Entity::builder()
+            .set(transform(), 5)
+            .set(child_of(d), ())
+            .spawn(&mut world);
+
+        let items = Query::new((entity_ids(), transform().copied()))
+            .topo(child_of)
+            .borrow(&world)
+            .unwrap()
+            .iter()
+            .collect_vec();
+
+        let pos = |id| items.iter().position(|v| v.0 == id).unwrap();
+
+        assert_eq!(items.len(), 4);
+        assert!(pos(a) < pos(c));
+        assert!(pos(d) < pos(e));
+    }
 }
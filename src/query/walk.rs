@@ -34,7 +34,7 @@ impl<Q> GraphQuery<Q, All> {
     {
         Self {
             relation: relation.id(),
-            fetch: Filtered::new(fetch, All, false),
+            fetch: Filtered::new(fetch, All, false, false, true),
             change_tick: 0,
             archetype_gen: 0,
             state: Default::default(),
@@ -55,6 +55,8 @@ where
                 self.fetch.fetch,
                 And(self.fetch.filter, filter),
                 self.fetch.include_components,
+                self.fetch.include_disabled,
+                self.fetch.include_static,
             ),
             relation: self.relation,
             change_tick: 0,
@@ -64,7 +66,7 @@ where
     }
 
     /// Prepares the query upon the world.
-    pub fn borrow<'w>(&'w mut self, world: &'w World) -> GraphBorrow<Q, F> {
+    pub fn borrow<'w>(&'w mut self, world: &'w World) -> GraphBorrow<'w, Q, F> {
         // The tick of the last iteration
         let mut old_tick = self.change_tick;
 
@@ -86,6 +88,7 @@ where
             new_tick,
             world,
             fetch: &self.fetch,
+            exclusive: false,
         };
 
         let archetype_gen = world.archetype_gen();
@@ -0,0 +1,93 @@
+use core::iter::Zip;
+
+use crate::{
+    error::{Error, Result},
+    system::{Access, AccessKind},
+    Fetch, World,
+};
+
+use super::{QueryBorrow, QueryIter};
+
+/// Borrows two queries with disjoint accesses at the same time.
+///
+/// See [`Query::zip_disjoint`](crate::Query::zip_disjoint)
+pub struct ZipDisjoint<'w, Q, F, Q2, F2>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    Q2: Fetch<'w>,
+    F2: Fetch<'w>,
+{
+    left: QueryBorrow<'w, Q, F>,
+    right: QueryBorrow<'w, Q2, F2>,
+}
+
+impl<'w, Q, F, Q2, F2> ZipDisjoint<'w, Q, F, Q2, F2>
+where
+    Q: Fetch<'w>,
+    F: Fetch<'w>,
+    Q2: Fetch<'w>,
+    F2: Fetch<'w>,
+{
+    pub(crate) fn new(left: QueryBorrow<'w, Q, F>, right: QueryBorrow<'w, Q2, F2>) -> Self {
+        Self { left, right }
+    }
+
+    /// Iterate the items of both queries in lockstep.
+    ///
+    /// The iteration stops when either query is exhausted.
+    pub fn iter<'q>(&'q mut self) -> Zip<QueryIter<'w, 'q, Q, F>, QueryIter<'w, 'q, Q2, F2>>
+    where
+        'w: 'q,
+    {
+        self.left.iter().zip(self.right.iter())
+    }
+
+    /// Returns the borrow of the first query
+    pub fn left(&mut self) -> &mut QueryBorrow<'w, Q, F> {
+        &mut self.left
+    }
+
+    /// Returns the borrow of the second query
+    pub fn right(&mut self) -> &mut QueryBorrow<'w, Q2, F2> {
+        &mut self.right
+    }
+
+    /// Returns both borrows, which can be used at the same time
+    pub fn split(&mut self) -> (&mut QueryBorrow<'w, Q, F>, &mut QueryBorrow<'w, Q2, F2>) {
+        (&mut self.left, &mut self.right)
+    }
+}
+
+/// Returns an error if any access of `left` can alias an access of `right`
+pub(crate) fn check_disjoint(world: &World, left: &[Access], right: &[Access]) -> Result<()> {
+    for a in left {
+        for b in right {
+            if a.is_compatible_with(b) {
+                continue;
+            }
+
+            // Prefer reporting the component if either side borrows one
+            let kind = if b.kind.is_archetype() {
+                b.kind
+            } else {
+                a.kind
+            };
+
+            if let AccessKind::Archetype { id, component } = kind {
+                let desc = world
+                    .archetypes
+                    .get(id)
+                    .cell(component)
+                    .expect("Archetype has the accessed component")
+                    .desc();
+
+                return Err(Error::ConflictingAccess(desc));
+            }
+
+            return Err(Error::ConflictingAccessKind(kind));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,477 @@
+//! A component is made accessible by implementing [`Reflect`](crate::reflect::Reflect) for its type
+//! and attaching the [`Reflectable`](crate::reflect::Reflectable) metadata. Fields can then be read
+//! and written through paths such as `"transform.translation.x"` using [`World::get_path`] and
+//! [`World::set_path`], where the first segment is the component name.
+//!
+//! This allows consoles, scripts, and animation systems to drive arbitrary fields without
+//! knowing the concrete types.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt::{self, Display};
+
+use crate::{
+    archetype::{Archetype, Slice, Slot},
+    buffer::ComponentBuffer,
+    component::{ComponentDesc, ComponentKey, ComponentValue},
+    error::{Error, Result},
+    metadata::Metadata,
+    Entity,
+};
+
+#[cfg(doc)]
+use crate::World;
+
+/// A dynamically typed field value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A boolean
+    Bool(bool),
+    /// Any integer
+    Int(i64),
+    /// Any floating point number
+    Float(f64),
+    /// A string
+    String(String),
+}
+
+impl Value {
+    /// Returns the value as a float, converting integers
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Int(v) => Some(v as f64),
+            Value::Float(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an integer, truncating floats
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Value::Int(v) => Some(v),
+            Value::Float(v) => Some(v as i64),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Value::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a string
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(v) => v.fmt(f),
+            Value::Int(v) => v.fmt(f),
+            Value::Float(v) => v.fmt(f),
+            Value::String(v) => v.fmt(f),
+        }
+    }
+}
+
+/// Access to the fields of a value through a path of field names.
+///
+/// An empty path refers to the value itself.
+pub trait Reflect {
+    /// Returns the value at `path`
+    fn get_path(&self, path: &[&str]) -> Option<Value>;
+    /// Sets the value at `path`.
+    ///
+    /// Returns false if the path does not exist or the value has the wrong type.
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool;
+}
+
+macro_rules! reflect_num {
+    ($variant: ident, $conv: ident, $($ty: ty),*) => {
+        $(
+            impl Reflect for $ty {
+                fn get_path(&self, path: &[&str]) -> Option<Value> {
+                    if !path.is_empty() {
+                        return None;
+                    }
+
+                    Some(Value::$variant((*self).try_into().ok()?))
+                }
+
+                fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+                    if !path.is_empty() {
+                        return false;
+                    }
+
+                    match value.$conv().and_then(|v| v.try_into().ok()) {
+                        Some(v) => {
+                            *self = v;
+                            true
+                        }
+                        None => false,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+reflect_num!(Int, as_i64, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+impl Reflect for f32 {
+    fn get_path(&self, path: &[&str]) -> Option<Value> {
+        path.is_empty().then_some(Value::Float(*self as f64))
+    }
+
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+        match value.as_f64() {
+            Some(v) if path.is_empty() => {
+                *self = v as f32;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Reflect for f64 {
+    fn get_path(&self, path: &[&str]) -> Option<Value> {
+        path.is_empty().then_some(Value::Float(*self))
+    }
+
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+        match value.as_f64() {
+            Some(v) if path.is_empty() => {
+                *self = v;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Reflect for bool {
+    fn get_path(&self, path: &[&str]) -> Option<Value> {
+        path.is_empty().then_some(Value::Bool(*self))
+    }
+
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+        match value {
+            Value::Bool(v) if path.is_empty() => {
+                *self = v;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Reflect for String {
+    fn get_path(&self, path: &[&str]) -> Option<Value> {
+        path.is_empty().then(|| Value::String(self.clone()))
+    }
+
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+        match value {
+            Value::String(v) if path.is_empty() => {
+                *self = v;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Arrays are indexed by position, or by `x`, `y`, `z`, `w` for vector like arrays.
+impl<T: Reflect, const N: usize> Reflect for [T; N] {
+    fn get_path(&self, path: &[&str]) -> Option<Value> {
+        let (head, tail) = path.split_first()?;
+        self.get(array_index(head)?)?.get_path(tail)
+    }
+
+    fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+        let Some((head, tail)) = path.split_first() else {
+            return false;
+        };
+
+        match array_index(head).and_then(|i| self.get_mut(i)) {
+            Some(v) => v.set_path(tail, value),
+            None => false,
+        }
+    }
+}
+
+fn array_index(segment: &str) -> Option<usize> {
+    match segment {
+        "x" => Some(0),
+        "y" => Some(1),
+        "z" => Some(2),
+        "w" => Some(3),
+        v => v.parse().ok(),
+    }
+}
+
+/// Type erased accessors for a [`Reflect`] component
+#[derive(Clone, Copy)]
+pub struct ReflectVTable {
+    get: fn(&Archetype, ComponentKey, Slot, &[&str]) -> Option<Value>,
+    set: fn(&Archetype, ComponentKey, Entity, Slot, u32, &[&str], Value) -> bool,
+}
+
+impl fmt::Debug for ReflectVTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectVTable").finish_non_exhaustive()
+    }
+}
+
+impl ReflectVTable {
+    /// Creates accessors for a component of type `T`
+    pub fn new<T: ComponentValue + Reflect>() -> Self {
+        fn get<T: ComponentValue + Reflect>(
+            arch: &Archetype,
+            key: ComponentKey,
+            slot: Slot,
+            path: &[&str],
+        ) -> Option<Value> {
+            arch.borrow::<T>(key)?.get().get(slot)?.get_path(path)
+        }
+
+        fn set<T: ComponentValue + Reflect>(
+            arch: &Archetype,
+            key: ComponentKey,
+            id: Entity,
+            slot: Slot,
+            tick: u32,
+            path: &[&str],
+            value: Value,
+        ) -> bool {
+            let Some(mut storage) = arch.borrow_mut::<T>(key) else {
+                return false;
+            };
+
+            let written = match storage.get_mut().get_mut(slot) {
+                Some(v) => v.set_path(path, value),
+                None => false,
+            };
+
+            if written {
+                storage.set_modified(&[id], Slice::single(slot), tick);
+            }
+
+            written
+        }
+
+        Self {
+            get: get::<T>,
+            set: set::<T>,
+        }
+    }
+}
+
+component! {
+    /// Field accessors of a component, attached by [`Reflectable`]
+    pub reflect: ReflectVTable,
+}
+
+/// Makes the fields of a component accessible through [`World::get_path`] and
+/// [`World::set_path`]
+pub struct Reflectable;
+
+impl<T> Metadata<T> for Reflectable
+where
+    T: ComponentValue + Reflect,
+{
+    fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+        buffer.set(reflect(), ReflectVTable::new::<T>());
+    }
+}
+
+/// Splits a path into the component and its field path, finding the component in `arch`
+fn resolve(arch: &Archetype, path: &str) -> Result<(ComponentDesc, ReflectVTable)> {
+    let name = path.split('.').next().unwrap_or_default();
+
+    let mut matches = arch.components_desc().filter(|v| v.name() == name);
+
+    let desc = matches
+        .next()
+        .ok_or_else(|| Error::InvalidPath(format!("No component named {name:?}")))?;
+
+    // Relations, or components declared in different modules, can share a name
+    if matches.next().is_some() {
+        return Err(Error::InvalidPath(format!(
+            "Component name {name:?} is ambiguous"
+        )));
+    }
+
+    let vtable = desc
+        .meta_ref()
+        .get(reflect())
+        .copied()
+        .ok_or_else(|| Error::InvalidPath(format!("Component {name:?} is not reflectable")))?;
+
+    Ok((desc, vtable))
+}
+
+pub(crate) fn get_path(arch: &Archetype, slot: Slot, path: &str) -> Result<Value> {
+    let (desc, vtable) = resolve(arch, path)?;
+    let fields = path.split('.').skip(1).collect::<alloc::vec::Vec<_>>();
+
+    (vtable.get)(arch, desc.key(), slot, &fields)
+        .ok_or_else(|| Error::InvalidPath(path.to_string()))
+}
+
+pub(crate) fn set_path(
+    arch: &Archetype,
+    id: Entity,
+    slot: Slot,
+    tick: u32,
+    path: &str,
+    value: Value,
+) -> Result<()> {
+    let (desc, vtable) = resolve(arch, path)?;
+    let fields = path.split('.').skip(1).collect::<alloc::vec::Vec<_>>();
+
+    if (vtable.set)(arch, desc.key(), id, slot, tick, &fields, value) {
+        Ok(())
+    } else {
+        Err(Error::InvalidPath(path.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        archetype::ChangeKind, components::name, entity_ids, filter::ChangeFilter, Query, World,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Transform {
+        translation: [f32; 3],
+        scale: f32,
+    }
+
+    impl Reflect for Transform {
+        fn get_path(&self, path: &[&str]) -> Option<Value> {
+            match path.split_first()? {
+                (&"translation", tail) => self.translation.get_path(tail),
+                (&"scale", tail) => self.scale.get_path(tail),
+                _ => None,
+            }
+        }
+
+        fn set_path(&mut self, path: &[&str], value: Value) -> bool {
+            match path.split_first() {
+                Some((&"translation", tail)) => self.translation.set_path(tail, value),
+                Some((&"scale", tail)) => self.scale.set_path(tail, value),
+                _ => false,
+            }
+        }
+    }
+
+    component! {
+        transform: Transform => [Reflectable],
+        health: i32 => [Reflectable],
+    }
+
+    #[test]
+    fn property_paths() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(
+                transform(),
+                Transform {
+                    translation: [1.0, 2.0, 3.0],
+                    scale: 1.0,
+                },
+            )
+            .set(health(), 50)
+            .set(name(), "a".into())
+            .spawn(&mut world);
+
+        assert_eq!(
+            world.get_path(id, "transform.translation.y"),
+            Ok(Value::Float(2.0))
+        );
+        assert_eq!(world.get_path(id, "health"), Ok(Value::Int(50)));
+
+        assert!(world.get_path(id, "transform.rotation").is_err());
+        assert!(world.get_path(id, "velocity").is_err());
+        // Not reflectable
+        assert!(world.get_path(id, "name").is_err());
+
+        let mut changed =
+            Query::new(entity_ids()).filter(ChangeFilter::new(transform(), ChangeKind::Modified));
+        assert_eq!(
+            changed.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            [id]
+        );
+
+        world
+            .set_path(id, "transform.translation.x", Value::Float(5.0))
+            .unwrap();
+        world
+            .set_path(id, "transform.scale", Value::Int(2))
+            .unwrap();
+        world.set_path(id, "health", Value::Float(25.0)).unwrap();
+
+        assert!(world
+            .set_path(id, "transform.scale", Value::Bool(true))
+            .is_err());
+
+        assert_eq!(
+            *world.get(id, transform()).unwrap(),
+            Transform {
+                translation: [5.0, 2.0, 3.0],
+                scale: 2.0,
+            }
+        );
+        assert_eq!(*world.get(id, health()).unwrap(), 25);
+
+        assert_eq!(
+            changed.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            [id]
+        );
+        assert_eq!(
+            changed.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            []
+        );
+    }
+
+    #[test]
+    fn ambiguous_path() {
+        mod other {
+            use crate::reflect::Reflectable;
+
+            component! {
+                pub health: i32 => [Reflectable],
+            }
+        }
+
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(health(), 50)
+            .set(other::health(), 10)
+            .spawn(&mut world);
+
+        assert!(world.get_path(id, "health").is_err());
+        assert!(world.set_path(id, "health", Value::Int(5)).is_err());
+        assert_eq!(*world.get(id, health()).unwrap(), 50);
+        assert_eq!(*world.get(id, other::health()).unwrap(), 10);
+    }
+}
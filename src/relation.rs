@@ -4,7 +4,7 @@ use core::{
     sync::atomic::AtomicU32,
 };
 
-use alloc::collections::btree_map::Range;
+use alloc::{collections::btree_map::Range, vec::Vec};
 use atomic_refcell::AtomicRef;
 
 use crate::{
@@ -12,7 +12,7 @@ use crate::{
     component::{dummy, ComponentKey, ComponentValue},
     entity::EntityKind,
     fetch::{nth_relation, NthRelation},
-    filter::{WithRelation, WithoutRelation},
+    filter::{ModifiedRelation, RelationValue, WithRelation, WithoutRelation},
     vtable::{ComponentVTable, UntypedVTable},
     Component, Entity,
 };
@@ -62,6 +62,31 @@ where
     {
         nth_relation(self, 0)
     }
+
+    /// Construct a new filter yielding entities which have any relation of this kind whose
+    /// value satisfies `func`.
+    ///
+    /// The predicate is evaluated over contiguous slots, which preserves batching.
+    fn filter_value<F>(self, func: F) -> RelationValue<T, F>
+    where
+        Self: Sized,
+        F: Fn(&T) -> bool,
+    {
+        RelationValue::new(self, func)
+    }
+
+    /// Construct a new filter yielding entities where any relation of this kind was added or
+    /// modified, without knowing the targets up front.
+    ///
+    /// **Note**: removed relations are not tracked, as removal moves the entity out of the
+    /// archetype rather than recording a change.
+    fn modified_relation(self) -> ModifiedRelation
+    where
+        Self: Sized,
+    {
+        let relation = self.as_relation();
+        ModifiedRelation::new(relation.id(), relation.name())
+    }
 }
 
 impl<T, F> RelationExt<T> for F
@@ -284,3 +309,109 @@ where
         ))
     }
 }
+
+/// Identifies a single edge of a [`Multi`] relation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeIndex(u32);
+
+/// Relation value allowing an entity to have several distinct edges to the same target.
+///
+/// Each edge is identified by an [`EdgeIndex`] which is unique for the pair of entities, and
+/// is not reused while the relation exists.
+///
+/// Use [`World::add_edge`](crate::World::add_edge) and
+/// [`World::remove_edge`](crate::World::remove_edge) to manage the edges, which removes the
+/// relation entirely once the last edge is removed. Indices of a relation which is added again
+/// start over, and may as such equal those of the removed edges.
+///
+/// ```rust
+/// # use flax::{*, relation::Multi};
+/// component! {
+///     buff_from(caster): Multi<f32>,
+/// }
+///
+/// let mut world = World::new();
+/// let caster = world.spawn();
+/// let target = world.spawn();
+///
+/// let a = world.add_edge(target, buff_from(caster), 1.5).unwrap();
+/// let b = world.add_edge(target, buff_from(caster), 2.0).unwrap();
+///
+/// world.remove_edge(target, buff_from(caster), a).unwrap();
+///
+/// let buffs = world.get(target, buff_from(caster)).unwrap();
+/// assert_eq!(buffs.iter().collect::<Vec<_>>(), [(b, &2.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Multi<T> {
+    edges: Vec<(EdgeIndex, T)>,
+    next: u32,
+}
+
+impl<T> Default for Multi<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Multi<T> {
+    /// Creates a relation value without any edges
+    pub fn new() -> Self {
+        Self {
+            edges: Vec::new(),
+            next: 0,
+        }
+    }
+
+    /// Adds a new edge
+    pub fn insert(&mut self, value: T) -> EdgeIndex {
+        let index = EdgeIndex(self.next);
+        self.next += 1;
+        self.edges.push((index, value));
+        index
+    }
+
+    /// Removes an edge, returning its value
+    pub fn remove(&mut self, index: EdgeIndex) -> Option<T> {
+        let i = self.edges.iter().position(|v| v.0 == index)?;
+        Some(self.edges.remove(i).1)
+    }
+
+    /// Returns the value of an edge
+    pub fn get(&self, index: EdgeIndex) -> Option<&T> {
+        self.edges.iter().find(|v| v.0 == index).map(|v| &v.1)
+    }
+
+    /// Returns the value of an edge mutably
+    pub fn get_mut(&mut self, index: EdgeIndex) -> Option<&mut T> {
+        self.edges
+            .iter_mut()
+            .find(|v| v.0 == index)
+            .map(|v| &mut v.1)
+    }
+
+    /// Retains only the edges specified by the predicate
+    pub fn retain(&mut self, mut f: impl FnMut(EdgeIndex, &mut T) -> bool) {
+        self.edges.retain_mut(|(index, value)| f(*index, value))
+    }
+
+    /// Iterates the edges in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (EdgeIndex, &T)> {
+        self.edges.iter().map(|(index, value)| (*index, value))
+    }
+
+    /// Iterates the edges mutably in insertion order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (EdgeIndex, &mut T)> {
+        self.edges.iter_mut().map(|(index, value)| (*index, value))
+    }
+
+    /// Returns the number of edges
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns true if there are no edges
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
@@ -0,0 +1,204 @@
+use core::fmt::Write;
+
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use itertools::Itertools;
+
+use crate::{
+    system::{Access, AccessKind},
+    World,
+};
+
+/// A system and the resources it accesses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemNode {
+    name: String,
+    batch: usize,
+    reads: BTreeSet<String>,
+    writes: BTreeSet<String>,
+}
+
+impl SystemNode {
+    /// Returns the system name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the index of the batch the system executes in
+    pub fn batch(&self) -> usize {
+        self.batch
+    }
+
+    /// Returns the names of the resources accessed immutably
+    pub fn reads(&self) -> &BTreeSet<String> {
+        &self.reads
+    }
+
+    /// Returns the names of the resources accessed mutably
+    pub fn writes(&self) -> &BTreeSet<String> {
+        &self.writes
+    }
+}
+
+/// Two systems which can not execute in parallel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessConflict {
+    before: usize,
+    after: usize,
+    resources: BTreeSet<String>,
+}
+
+impl AccessConflict {
+    /// Index of the system which executes first
+    pub fn before(&self) -> usize {
+        self.before
+    }
+
+    /// Index of the system which has to wait for [`Self::before`]
+    pub fn after(&self) -> usize {
+        self.after
+    }
+
+    /// The resources both systems access, where at least one of the accesses is mutable
+    pub fn resources(&self) -> &BTreeSet<String> {
+        &self.resources
+    }
+}
+
+/// Describes which systems access which resources, and the conflicts which force systems into
+/// separate batches.
+///
+/// See [`Schedule::access_graph`](crate::Schedule::access_graph)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessGraph {
+    systems: Vec<SystemNode>,
+    conflicts: Vec<AccessConflict>,
+}
+
+impl AccessGraph {
+    pub(super) fn new(world: &World, batches: &[Vec<(String, Vec<Access>)>]) -> Self {
+        let systems = batches
+            .iter()
+            .enumerate()
+            .flat_map(|(batch, systems)| systems.iter().map(move |v| (batch, v)))
+            .collect_vec();
+
+        let nodes = systems
+            .iter()
+            .map(|&(batch, (name, accesses))| {
+                let (writes, reads): (Vec<_>, Vec<_>) = accesses.iter().partition(|v| v.mutable);
+
+                SystemNode {
+                    name: name.clone(),
+                    batch,
+                    reads: reads
+                        .iter()
+                        .map(|v| resource_name(world, &v.kind))
+                        .collect(),
+                    writes: writes
+                        .iter()
+                        .map(|v| resource_name(world, &v.kind))
+                        .collect(),
+                }
+            })
+            .collect_vec();
+
+        let mut conflicts = Vec::new();
+        for (after, (_, (_, dst))) in systems.iter().enumerate() {
+            for (before, (_, (_, src))) in systems.iter().take(after).enumerate() {
+                let resources: BTreeSet<_> = src
+                    .iter()
+                    .cartesian_product(dst.iter())
+                    .filter(|(a, b)| !a.is_compatible_with(b))
+                    .map(|(a, _)| resource_name(world, &a.kind))
+                    .collect();
+
+                if !resources.is_empty() {
+                    conflicts.push(AccessConflict {
+                        before,
+                        after,
+                        resources,
+                    });
+                }
+            }
+        }
+
+        Self {
+            systems: nodes,
+            conflicts,
+        }
+    }
+
+    /// Returns the systems in execution order
+    pub fn systems(&self) -> &[SystemNode] {
+        &self.systems
+    }
+
+    /// Returns the conflicting pairs of systems
+    pub fn conflicts(&self) -> &[AccessConflict] {
+        &self.conflicts
+    }
+
+    /// Formats the graph in the graphviz dot language.
+    ///
+    /// Systems are grouped by batch, and each conflict is an edge labeled with the contended
+    /// resources.
+    pub fn to_dot(&self) -> String {
+        let mut s = String::new();
+        writeln!(s, "digraph schedule {{").unwrap();
+        writeln!(s, "    node [shape=box];").unwrap();
+
+        for (batch, systems) in &self.systems.iter().enumerate().group_by(|v| v.1.batch) {
+            writeln!(s, "    subgraph cluster_{batch} {{").unwrap();
+            writeln!(s, "        label=\"batch {batch}\";").unwrap();
+            for (i, system) in systems {
+                writeln!(
+                    s,
+                    "        s{i} [label=\"{}\\nreads: {}\\nwrites: {}\"];",
+                    escape(&system.name),
+                    escape(&system.reads.iter().join(", ")),
+                    escape(&system.writes.iter().join(", ")),
+                )
+                .unwrap();
+            }
+            writeln!(s, "    }}").unwrap();
+        }
+
+        for conflict in &self.conflicts {
+            writeln!(
+                s,
+                "    s{} -> s{} [label=\"{}\"];",
+                conflict.before,
+                conflict.after,
+                escape(&conflict.resources.iter().join(", ")),
+            )
+            .unwrap();
+        }
+
+        writeln!(s, "}}").unwrap();
+        s
+    }
+}
+
+fn resource_name(world: &World, kind: &AccessKind) -> String {
+    match kind {
+        AccessKind::Archetype { id, component } => world
+            .archetypes
+            .get(*id)
+            .component(*component)
+            .map(|v| v.name().to_string())
+            .unwrap_or_else(|| component.to_string()),
+        AccessKind::External(ty) => format!("external {ty:?}"),
+        AccessKind::World => "world".into(),
+        AccessKind::CommandBuffer => "commandbuffer".into(),
+        AccessKind::Input(ty) => format!("input {ty:?}"),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('"', "\\\"")
+}
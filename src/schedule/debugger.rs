@@ -0,0 +1,169 @@
+use alloc::{string::String, vec::Vec};
+
+use anyhow::Context;
+
+use crate::{
+    archetype::{ArchetypeId, ChangeKind, Slice},
+    component::ComponentDesc,
+    system::{access_info, AccessInfo, IntoInput, SystemContext},
+    CommandBuffer, World,
+};
+
+use super::Schedule;
+
+/// A change made by a system to a slice of an archetype
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemChange {
+    /// The changed archetype
+    pub arch_id: ArchetypeId,
+    /// The changed component
+    pub component: ComponentDesc,
+    /// The kind of change
+    pub kind: ChangeKind,
+    /// The changed slots in the archetype
+    pub slice: Slice,
+}
+
+/// Describes the execution of a single system
+#[derive(Debug, Clone)]
+pub struct StepInfo {
+    name: String,
+    changes: Vec<SystemChange>,
+}
+
+impl StepInfo {
+    /// Returns the name of the executed system
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the archetype slices changed by the system
+    pub fn changes(&self) -> &[SystemChange] {
+        &self.changes
+    }
+}
+
+/// Executes a schedule one system at a time.
+///
+/// Between each step the next system, its accesses, and the pending commands can be inspected,
+/// which allows building frame inspectors for editors.
+///
+/// The systems are executed sequentially in the same order as [`Schedule::execute_seq`].
+pub struct ScheduleDebugger<'a> {
+    schedule: &'a mut Schedule,
+    cursor: usize,
+}
+
+impl<'a> ScheduleDebugger<'a> {
+    pub(crate) fn new(schedule: &'a mut Schedule) -> Self {
+        Self {
+            schedule,
+            cursor: 0,
+        }
+    }
+
+    /// Returns the name of the system which will be executed by the next step
+    pub fn next_system(&self) -> Option<&str> {
+        self.schedule
+            .systems
+            .iter()
+            .flatten()
+            .nth(self.cursor)
+            .map(|v| v.name())
+    }
+
+    /// Returns the accesses of the system which will be executed by the next step
+    pub fn next_access(&self, world: &World) -> Option<AccessInfo> {
+        let system = self.schedule.systems.iter().flatten().nth(self.cursor)?;
+
+        let mut access = Vec::new();
+        system.access(world, &mut access);
+        Some(access_info(&access, world))
+    }
+
+    /// Returns the commands recorded by the systems executed so far, which are applied when the
+    /// schedule finishes
+    pub fn pending_commands(&self) -> &CommandBuffer {
+        &self.schedule.cmd
+    }
+
+    /// Returns true if all systems have been executed and the next step applies the pending
+    /// commands
+    pub fn is_finished(&self) -> bool {
+        self.next_system().is_none()
+    }
+
+    /// Executes the next system and returns the changes it made to the world.
+    ///
+    /// When all systems have been executed the pending commands are applied, `None` is returned, and
+    /// the debugger restarts from the first system.
+    pub fn step(&mut self, world: &mut World) -> anyhow::Result<Option<StepInfo>> {
+        let Some(system) = self.schedule.systems.iter_mut().flatten().nth(self.cursor) else {
+            self.cursor = 0;
+            self.schedule
+                .cmd
+                .apply(world)
+                .context("Failed to apply commandbuffer")?;
+
+            return Ok(None);
+        };
+
+        self.cursor += 1;
+
+        // Capture modifications for all components, not only the ones being queried
+        for (_, arch) in world.archetypes.iter() {
+            for cell in arch.cells() {
+                cell.data.borrow().changes.set_track_modified();
+            }
+        }
+
+        let since = world.change_tick();
+        let name = system.name().into();
+
+        let mut unit = ();
+        let input = (&mut unit).into_input();
+        let ctx = SystemContext::new(world, &mut self.schedule.cmd, &input);
+        system.execute(&ctx)?;
+        drop(ctx);
+
+        Ok(Some(StepInfo {
+            name,
+            changes: changes_since(world, since),
+        }))
+    }
+
+    /// Executes all remaining systems and applies the pending commands
+    pub fn run_to_end(&mut self, world: &mut World) -> anyhow::Result<Vec<StepInfo>> {
+        let mut steps = Vec::new();
+        while let Some(step) = self.step(world)? {
+            steps.push(step);
+        }
+
+        Ok(steps)
+    }
+}
+
+fn changes_since(world: &World, since: u32) -> Vec<SystemChange> {
+    let mut result = Vec::new();
+    for (arch_id, arch) in world.archetypes.iter() {
+        for cell in arch.cells() {
+            let data = cell.data.borrow();
+            for kind in [ChangeKind::Added, ChangeKind::Modified, ChangeKind::Removed] {
+                result.extend(
+                    data.changes
+                        .get(kind)
+                        .iter()
+                        .filter(|v| v.tick > since)
+                        .map(|v| SystemChange {
+                            arch_id,
+                            component: cell.desc(),
+                            kind,
+                            slice: v.slice,
+                        }),
+                );
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,62 @@
+use core::{
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use alloc::vec::Vec;
+
+use crate::{system::SystemFuture, CommandBuffer};
+
+enum JoinState {
+    Pending(SystemFuture),
+    Done(anyhow::Result<CommandBuffer>),
+}
+
+/// Awaits the futures of a batch of asynchronous systems concurrently.
+///
+/// Resolves to the output of each future, in order.
+pub(crate) struct JoinAll {
+    futures: Vec<JoinState>,
+}
+
+impl JoinAll {
+    pub(crate) fn new(futures: Vec<SystemFuture>) -> Self {
+        Self {
+            futures: futures.into_iter().map(JoinState::Pending).collect(),
+        }
+    }
+}
+
+impl Future for JoinAll {
+    type Output = Vec<anyhow::Result<CommandBuffer>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let futures = &mut self.get_mut().futures;
+
+        let mut done = true;
+        for state in futures.iter_mut() {
+            if let JoinState::Pending(future) = state {
+                match Pin::new(future).poll(cx) {
+                    Poll::Ready(output) => *state = JoinState::Done(output),
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+
+        if !done {
+            return Poll::Pending;
+        }
+
+        let outputs = mem::take(futures)
+            .into_iter()
+            .map(|state| match state {
+                JoinState::Done(output) => output,
+                JoinState::Pending(_) => unreachable!(),
+            })
+            .collect();
+
+        Poll::Ready(outputs)
+    }
+}
@@ -1,3 +1,14 @@
+mod access_graph;
+mod debugger;
+mod join;
+#[cfg(feature = "std")]
+mod timeline;
+
+pub use access_graph::{AccessConflict, AccessGraph, SystemNode};
+pub use debugger::{ScheduleDebugger, StepInfo, SystemChange};
+#[cfg(feature = "std")]
+pub use timeline::{Timeline, TimelineEntry};
+
 use core::{mem, ops::Deref};
 
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
@@ -5,6 +16,7 @@ use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use anyhow::Context;
 use itertools::Itertools;
 
+use self::join::JoinAll;
 use crate::{
     system::{access_info, AccessInfo, IntoInput, SystemContext},
     util::Verbatim,
@@ -86,6 +98,8 @@ pub struct Schedule {
     cmd: CommandBuffer,
 
     archetype_gen: u32,
+    #[cfg(feature = "std")]
+    timeline: Option<Timeline>,
 }
 
 /// Holds information regarding a schedule's batches
@@ -184,6 +198,8 @@ impl Schedule {
             systems: alloc::vec![systems.into()],
             archetype_gen: 0,
             cmd: CommandBuffer::new(),
+            #[cfg(feature = "std")]
+            timeline: None,
         }
     }
 
@@ -241,6 +257,36 @@ impl Schedule {
         BatchInfos(batches)
     }
 
+    /// Returns which systems access which components, and the conflicts between them which
+    /// prevent the systems from executing in parallel.
+    ///
+    /// Use [`AccessGraph::to_dot`] to visualize the schedule.
+    pub fn access_graph(&mut self, world: &World) -> AccessGraph {
+        self.systems = Self::build_dependencies(mem::take(&mut self.systems), world);
+
+        let batches = self
+            .systems
+            .iter()
+            .map(|batch| {
+                batch
+                    .iter()
+                    .map(|system| {
+                        let mut access = Vec::new();
+                        system.access(world, &mut access);
+                        (String::from(system.name()), access)
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        AccessGraph::new(world, &batches)
+    }
+
+    /// Returns a debugger which executes the schedule one system at a time
+    pub fn debugger(&mut self) -> ScheduleDebugger<'_> {
+        ScheduleDebugger::new(self)
+    }
+
     /// Same as [`Self::execute_seq`] but allows supplying short lived input available to the systems
     ///
     /// The data can be a mutable reference type, or a tuple of mutable references
@@ -257,12 +303,21 @@ impl Schedule {
         let _span = tracing::info_span!("execute_seq").entered();
 
         for system in self.systems.iter_mut().flatten() {
-            system.execute(&ctx)?
+            #[cfg(feature = "std")]
+            let span = self
+                .timeline
+                .as_ref()
+                .map(|v| v.begin(ctx.world().change_tick()));
+
+            system.execute(&ctx)?;
+
+            #[cfg(feature = "std")]
+            if let (Some(timeline), Some(span)) = (&mut self.timeline, span) {
+                timeline.end(system.name(), span, ctx.world().change_tick());
+            }
         }
 
-        self.cmd
-            .apply(world)
-            .context("Failed to apply commandbuffer")
+        self.apply_commands(world)
     }
 
     #[cfg(feature = "rayon")]
@@ -291,36 +346,179 @@ impl Schedule {
         let mut batches = self.systems.iter_mut();
 
         for batch in &mut batches {
+            #[cfg(feature = "std")]
+            let span = self
+                .timeline
+                .as_ref()
+                .map(|v| v.begin(ctx.world().change_tick()));
+
             batch
                 .par_iter_mut()
                 .try_for_each(|system| system.execute(&ctx))?;
 
+            // Changes made by the batch can not be attributed to a single system
+            #[cfg(feature = "std")]
+            if let (Some(timeline), Some(span)) = (&mut self.timeline, span) {
+                timeline.end_batch(
+                    batch.iter().map(|v| v.name()),
+                    span,
+                    ctx.world().change_tick(),
+                );
+            }
+
             // If the archetype generation changed the batches are invalidated
             //
             // Execute sequentially, and rebuild the schedule next time around
             if self.archetype_gen != ctx.world.get_mut().archetype_gen() {
-                return Self::bail_seq(batches, &mut ctx);
+                return Self::bail_seq(
+                    batches,
+                    &mut ctx,
+                    #[cfg(feature = "std")]
+                    &mut self.timeline,
+                );
+            }
+        }
+
+        self.apply_commands(world)
+    }
+
+    /// Executes the schedule as a future, driven by any executor.
+    ///
+    /// Systems built with [`SystemBuilder::build_async`](crate::SystemBuilder::build_async) are
+    /// awaited concurrently with the other asynchronous systems of the same batch. The world is
+    /// only borrowed while the systems are invoked and not across await points, and the commands
+    /// returned by each future are applied before the next batch runs.
+    ///
+    /// Synchronous systems run to completion when invoked, in the same order as
+    /// [`Self::execute_seq`].
+    pub async fn execute_async(&mut self, world: &mut World) -> anyhow::Result<()> {
+        profile_function!();
+
+        let w_gen = world.archetype_gen();
+        if self.archetype_gen != w_gen {
+            self.archetype_gen = w_gen;
+            self.systems = Self::build_dependencies(mem::take(&mut self.systems), world);
+        }
+
+        for i in 0..self.systems.len() {
+            let mut names = Vec::new();
+            let mut futures = Vec::new();
+            {
+                let ctx = SystemContext::new_async(world, &mut self.cmd, &());
+
+                for system in &mut self.systems[i] {
+                    #[cfg(feature = "std")]
+                    let span = self
+                        .timeline
+                        .as_ref()
+                        .map(|v| v.begin(ctx.world().change_tick()));
+
+                    system.execute(&ctx)?;
+
+                    #[cfg(feature = "std")]
+                    if let (Some(timeline), Some(span)) = (&mut self.timeline, span) {
+                        timeline.end(system.name(), span, ctx.world().change_tick());
+                    }
+
+                    for future in ctx.take_futures() {
+                        names.push(String::from(system.name()));
+                        futures.push(future);
+                    }
+                }
+            }
+
+            for (name, cmd) in names.into_iter().zip(JoinAll::new(futures).await) {
+                #[cfg(feature = "std")]
+                let span = self.timeline.as_ref().map(|v| v.begin(world.change_tick()));
+
+                cmd?.apply(world)
+                    .with_context(|| alloc::format!("Failed to apply commandbuffer of {name}"))?;
+
+                #[cfg(feature = "std")]
+                if let (Some(timeline), Some(span)) = (&mut self.timeline, span) {
+                    timeline.end(name, span, world.change_tick());
+                }
             }
         }
 
+        self.apply_commands(world)
+    }
+
+    /// Applies the commands recorded by the systems
+    fn apply_commands(&mut self, world: &mut World) -> anyhow::Result<()> {
+        #[cfg(feature = "std")]
+        let span = self.timeline.as_ref().map(|v| v.begin(world.change_tick()));
+
         self.cmd
             .apply(world)
-            .context("Failed to apply commandbuffer")
+            .context("Failed to apply commandbuffer")?;
+
+        #[cfg(feature = "std")]
+        if let (Some(timeline), Some(span)) = (&mut self.timeline, span) {
+            timeline.end("apply commands", span, world.change_tick());
+        }
+
+        Ok(())
+    }
+
+    /// Starts recording which system runs at each change tick, keeping at most the `capacity`
+    /// latest system executions.
+    ///
+    /// This allows a change tick to be translated into the system which made the change and when,
+    /// see [`Timeline`]. Replaces any previously recorded timeline.
+    #[cfg(feature = "std")]
+    pub fn record_timeline(&mut self, capacity: usize) {
+        self.timeline = Some(Timeline::new(capacity));
+    }
+
+    /// Stops recording the timeline, returning it
+    #[cfg(feature = "std")]
+    pub fn stop_timeline(&mut self) -> Option<Timeline> {
+        self.timeline.take()
+    }
+
+    /// Returns the timeline being recorded, if any
+    #[cfg(feature = "std")]
+    pub fn timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
     }
 
     #[cfg(feature = "rayon")]
     fn bail_seq(
         batches: core::slice::IterMut<Vec<BoxedSystem>>,
         ctx: &mut SystemContext<'_, '_, '_>,
+        #[cfg(feature = "std")] timeline: &mut Option<Timeline>,
     ) -> anyhow::Result<()> {
         for system in batches.flatten() {
+            #[cfg(feature = "std")]
+            let span = timeline
+                .as_ref()
+                .map(|v| v.begin(ctx.world().change_tick()));
+
             system.execute(ctx)?;
+
+            #[cfg(feature = "std")]
+            if let (Some(timeline), Some(span)) = (&mut *timeline, span) {
+                timeline.end(system.name(), span, ctx.world().change_tick());
+            }
         }
 
+        let world = ctx.world.get_mut();
+
+        #[cfg(feature = "std")]
+        let span = timeline.as_ref().map(|v| v.begin(world.change_tick()));
+
         ctx.cmd
             .get_mut()
-            .apply(ctx.world.get_mut())
-            .context("Failed to apply commandbuffer")
+            .apply(world)
+            .context("Failed to apply commandbuffer")?;
+
+        #[cfg(feature = "std")]
+        if let (Some(timeline), Some(span)) = (timeline, span) {
+            timeline.end("apply commands", span, world.change_tick());
+        }
+
+        Ok(())
     }
 
     fn build_dependencies(systems: Vec<Vec<BoxedSystem>>, world: &World) -> Vec<Vec<BoxedSystem>> {
@@ -0,0 +1,158 @@
+use core::fmt::Write;
+use std::time::{Duration, Instant};
+
+use alloc::{collections::VecDeque, string::String};
+
+/// The change ticks and time spanned by a single system execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEntry {
+    system: String,
+    start_tick: u32,
+    end_tick: u32,
+    start: Duration,
+    duration: Duration,
+}
+
+impl TimelineEntry {
+    /// Returns the name of the system
+    pub fn system(&self) -> &str {
+        &self.system
+    }
+
+    /// Returns the world change tick before the system ran.
+    ///
+    /// Changes made by the system are after this tick.
+    pub fn start_tick(&self) -> u32 {
+        self.start_tick
+    }
+
+    /// Returns the world change tick after the system ran.
+    ///
+    /// Changes made by the system are at or before this tick.
+    pub fn end_tick(&self) -> u32 {
+        self.end_tick
+    }
+
+    /// Returns true if a change at `tick` could have been made by the system
+    pub fn contains_tick(&self, tick: u32) -> bool {
+        self.start_tick < tick && tick <= self.end_tick
+    }
+
+    /// Returns when the system started, relative to when the timeline started recording
+    pub fn start(&self) -> Duration {
+        self.start
+    }
+
+    /// Returns how long the system ran for
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Records which system was running at each change tick, and when.
+///
+/// This allows a change tick, such as from [`World::change_tick`](crate::World::change_tick) or a
+/// change record, to be attributed to the system which made it. Systems of the same parallel
+/// batch share the ticks of the whole batch.
+///
+/// See [`Schedule::record_timeline`](crate::Schedule::record_timeline)
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    epoch: Instant,
+    capacity: usize,
+    entries: VecDeque<TimelineEntry>,
+}
+
+impl Timeline {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            epoch: Instant::now(),
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn begin(&self, tick: u32) -> SpanStart {
+        SpanStart {
+            tick,
+            time: Instant::now(),
+        }
+    }
+
+    pub(crate) fn end(&mut self, system: impl Into<String>, start: SpanStart, end_tick: u32) {
+        self.end_batch([system], start, end_tick)
+    }
+
+    /// Records a span shared by several systems which ran in parallel
+    pub(crate) fn end_batch<S: Into<String>>(
+        &mut self,
+        systems: impl IntoIterator<Item = S>,
+        start: SpanStart,
+        end_tick: u32,
+    ) {
+        let offset = start.time.duration_since(self.epoch);
+        let duration = start.time.elapsed();
+
+        for system in systems {
+            if self.capacity == 0 {
+                return;
+            }
+
+            if self.entries.len() == self.capacity {
+                self.entries.pop_front();
+            }
+
+            self.entries.push_back(TimelineEntry {
+                system: system.into(),
+                start_tick: start.tick,
+                end_tick,
+                start: offset,
+                duration,
+            });
+        }
+    }
+
+    /// Returns the recorded executions, oldest first
+    pub fn entries(&self) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the executions which could have made a change at `tick`.
+    ///
+    /// Yields more than one entry if the system ran in a parallel batch.
+    pub fn lookup(&self, tick: u32) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries.iter().filter(move |v| v.contains_tick(tick))
+    }
+
+    /// Removes all recorded entries
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+
+    /// Exports the timeline as comma separated values, with a header row.
+    ///
+    /// Each row contains the system name, tick range, and the start and duration in seconds.
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from("system,start_tick,end_tick,start,duration\n");
+        for entry in &self.entries {
+            writeln!(
+                s,
+                "\"{}\",{},{},{},{}",
+                entry.system.replace('"', "\"\""),
+                entry.start_tick,
+                entry.end_tick,
+                entry.start.as_secs_f64(),
+                entry.duration.as_secs_f64()
+            )
+            .unwrap();
+        }
+
+        s
+    }
+}
+
+/// The change tick and time at which a span of the timeline started
+pub(crate) struct SpanStart {
+    tick: u32,
+    time: Instant,
+}
@@ -12,7 +12,7 @@ use crate::{
     Component, Entity, EntityBuilder, World,
 };
 
-use super::{RowFields, SerializeFormat, WorldFields};
+use super::{serialize_aliases, serialized_name, RowFields, SerializeFormat, WorldFields};
 
 #[derive(Clone)]
 struct Slot {
@@ -55,6 +55,7 @@ impl<'a, 'de> DeserializeSeed<'de> for DeserializeStorage<'a> {
 /// Incrementally construct a [crate::serialize::DeserializeContext]
 pub struct DeserializeBuilder {
     slots: BTreeMap<String, Slot>,
+    aliases: BTreeMap<String, String>,
 }
 
 impl DeserializeBuilder {
@@ -63,14 +64,33 @@ impl DeserializeBuilder {
         Default::default()
     }
 
-    /// Register a component using the component's name
+    /// Register a component using the component's name, or
+    /// [`serialize_name`](super::serialize_name) if present.
+    ///
+    /// Any [`serialize_aliases`] of the component are registered as well.
     ///
     /// See [`Self::with_name`]
     pub fn with<T>(&mut self, component: Component<T>) -> &mut Self
     where
         T: ComponentValue + for<'x> Deserialize<'x>,
     {
-        self.with_name(component.name(), component)
+        let key = serialized_name(component.desc());
+
+        if let Some(aliases) = component.desc().meta_ref().get(serialize_aliases()) {
+            for alias in aliases {
+                self.with_alias(alias.clone(), key.clone());
+            }
+        }
+
+        self.with_name(key, component)
+    }
+
+    /// Accept `alias` as another name for the component registered as `key`.
+    ///
+    /// This allows reading data written before a component was renamed.
+    pub fn with_alias(&mut self, alias: impl Into<String>, key: impl Into<String>) -> &mut Self {
+        self.aliases.insert(alias.into(), key.into());
+        self
     }
 
     /// Register a new component to be deserialized
@@ -117,6 +137,7 @@ impl DeserializeBuilder {
     pub fn build(&mut self) -> DeserializeContext {
         DeserializeContext {
             slots: self.slots.clone(),
+            aliases: self.aliases.clone(),
         }
     }
 }
@@ -124,6 +145,7 @@ impl DeserializeBuilder {
 /// Describes how to deserialize the world from the described components.
 pub struct DeserializeContext {
     slots: BTreeMap<String, Slot>,
+    aliases: BTreeMap<String, String>,
 }
 
 impl DeserializeContext {
@@ -137,9 +159,30 @@ impl DeserializeContext {
         deserializer.deserialize_enum("World", &["row", "col"], WorldVisitor { context: self })
     }
 
+    /// Deserializes the components of a single entity into a builder.
+    ///
+    /// See [`SerializeContext::serialize_entity`](super::SerializeContext::serialize_entity)
+    pub fn deserialize_entity<'de, D>(
+        &self,
+        deserializer: D,
+    ) -> core::result::Result<EntityBuilder, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut builder = EntityBuilder::new();
+        DeserializeEntityData {
+            context: self,
+            builder: &mut builder,
+        }
+        .deserialize(deserializer)?;
+
+        Ok(builder)
+    }
+
     fn get(&self, key: &str) -> Result<&Slot, String> {
         self.slots
             .get(key)
+            .or_else(|| self.slots.get(self.aliases.get(key)?))
             .ok_or_else(|| format!("Unknown component key: {key:?}"))
     }
 }
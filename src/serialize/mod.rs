@@ -1,22 +1,38 @@
 mod de;
 mod ser;
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 pub use de::*;
 pub use ser::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    component::{ComponentKey, ComponentValue},
+    component::{ComponentDesc, ComponentValue},
     filter::And,
     filter::{All, StaticFilter},
-    Component,
+    Component, Debuggable,
 };
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ComponentSerKey {
-    key: String,
-    id: ComponentKey,
+component! {
+    /// Stable name of a component in serialized data.
+    ///
+    /// Used by [`SerializeBuilder::with`] and [`DeserializeBuilder::with`] instead of the
+    /// component's name, so that renaming the component does not break existing data.
+    ///
+    /// Attach through a [`Metadata`](crate::metadata::Metadata) implementation.
+    pub serialize_name: String => [ Debuggable ],
+    /// Previous serialized names of a component which are accepted when deserializing.
+    ///
+    /// Attach through a [`Metadata`](crate::metadata::Metadata) implementation.
+    pub serialize_aliases: Vec<String> => [ Debuggable ],
+}
+
+/// Returns the name a component is serialized as
+fn serialized_name(desc: ComponentDesc) -> String {
+    desc.meta_ref()
+        .get(serialize_name())
+        .cloned()
+        .unwrap_or_else(|| desc.name().into())
 }
 
 #[derive(serde::Deserialize)]
@@ -74,14 +90,18 @@ impl<F> SerdeBuilder<F>
 where
     F: StaticFilter + 'static + Clone,
 {
-    /// Register a component using the component name.
+    /// Register a component using the component name, or [`serialize_name`] if present.
+    ///
+    /// Any [`serialize_aliases`] are accepted when deserializing.
     ///
     /// See [`Self::with_name`]
     pub fn with<T>(&mut self, component: Component<T>) -> &mut Self
     where
         T: ComponentValue + Serialize + for<'de> Deserialize<'de>,
     {
-        self.with_name(component.name(), component)
+        self.ser.with(component);
+        self.de.with(component);
+        self
     }
 
     /// Register a component for both serialization and deserialiaztion
@@ -95,6 +115,14 @@ where
         self
     }
 
+    /// Accept `alias` as another name for the component registered as `key` when deserializing.
+    ///
+    /// See [`DeserializeBuilder::with_alias`]
+    pub fn with_alias(&mut self, alias: impl Into<String>, key: impl Into<String>) -> &mut Self {
+        self.de.with_alias(alias, key);
+        self
+    }
+
     /// Add a new filter to specify which entities will be serialized.
     pub fn with_filter<G>(self, filter: G) -> SerdeBuilder<And<F, G>> {
         SerdeBuilder {
@@ -120,7 +148,7 @@ mod test {
         Rng, SeedableRng,
     };
 
-    use crate::{archetype::BatchSpawn, component, components::name, Entity, World};
+    use crate::{archetype::BatchSpawn, components::name, Entity, EntityBuilder, World};
 
     use super::*;
 
@@ -246,4 +274,125 @@ mod test {
 
         test_eq(&world, &new_world);
     }
+
+    #[test]
+    fn aliases() {
+        use crate::{buffer::ComponentBuffer, metadata::Metadata};
+
+        struct PositionNames;
+
+        impl Metadata<(f32, f32)> for PositionNames {
+            fn attach(_: ComponentDesc, buffer: &mut ComponentBuffer) {
+                buffer.set(serialize_name(), "pos".into());
+                buffer.set(serialize_aliases(), vec!["old_pos".into()]);
+            }
+        }
+
+        component! {
+            old_position: (f32, f32),
+            position: (f32, f32) => [ PositionNames ],
+            health: f32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(old_position(), (1.0, 2.0))
+            .set(health(), 5.0)
+            .spawn(&mut world);
+
+        // Data written before the rename
+        let serializer = SerializeBuilder::new()
+            .with_name("old_pos", old_position())
+            .with_name("hp", health())
+            .build();
+
+        let deserializer = DeserializeBuilder::new()
+            .with(position())
+            .with_name("health", health())
+            .with_alias("hp", "health")
+            .build();
+
+        for format in [SerializeFormat::RowMajor, SerializeFormat::ColumnMajor] {
+            let json = serde_json::to_string(&serializer.serialize(&world, format)).unwrap();
+
+            let new_world = deserializer
+                .deserialize(&mut serde_json::Deserializer::from_str(&json[..]))
+                .unwrap();
+
+            assert_eq!(new_world.get(id, position()).as_deref(), Ok(&(1.0, 2.0)));
+            assert_eq!(new_world.get(id, health()).as_deref(), Ok(&5.0));
+        }
+
+        // The stable name is used when writing
+        let (serializer, _) = SerdeBuilder::new().with(position()).build();
+        let mut world = World::new();
+        Entity::builder()
+            .set(position(), (1.0, 2.0))
+            .spawn(&mut world);
+
+        let json = serde_json::to_string(&serializer.serialize(&world, SerializeFormat::RowMajor))
+            .unwrap();
+        assert!(json.contains("\"pos\""));
+        assert!(!json.contains("position"));
+    }
+
+    #[test]
+    fn single_entity() {
+        component! {
+            health: f32,
+            items: Vec<String>,
+            transient: u32,
+        }
+
+        let mut world = World::new();
+        let id = Entity::builder()
+            .set(name(), "Player".into())
+            .set(health(), 67.8)
+            .set(items(), vec!["Dagger".into()])
+            .set(transient(), 5)
+            .spawn(&mut world);
+
+        let (serializer, deserializer) = SerdeBuilder::new()
+            .with(name())
+            .with(health())
+            .with(items())
+            .build();
+
+        let entity = world.entity(id).unwrap();
+
+        let mut json = Vec::new();
+        entity
+            .serialize(&mut serde_json::Serializer::new(&mut json), &serializer)
+            .unwrap();
+
+        let encoded = ron::to_string(&serializer.serialize_entity(&entity)).unwrap();
+
+        let mut new_world = World::new();
+
+        let mut builder = EntityBuilder::from_serialized(
+            &mut serde_json::Deserializer::from_slice(&json),
+            &deserializer,
+        )
+        .unwrap();
+        let a = builder.spawn(&mut new_world);
+
+        let b = deserializer
+            .deserialize_entity(&mut ron::Deserializer::from_str(&encoded).unwrap())
+            .unwrap()
+            .spawn(&mut new_world);
+
+        for id in [a, b] {
+            let entity = new_world.entity(id).unwrap();
+            assert_eq!(entity.get(name()).as_deref(), Ok(&"Player".into()));
+            assert_eq!(entity.get(health()).as_deref(), Ok(&67.8));
+            assert_eq!(entity.get(items()).as_deref(), Ok(&vec!["Dagger".into()]));
+            assert!(!entity.has(transient()));
+        }
+
+        assert!(EntityBuilder::from_serialized(
+            &mut serde_json::Deserializer::from_str(r#"{ "unknown": 1 }"#),
+            &deserializer
+        )
+        .is_err());
+    }
 }
@@ -3,7 +3,7 @@ use crate::{
     component::{ComponentKey, ComponentValue},
     components::component_info,
     filter::{All, And, StaticFilter},
-    Component, Entity, World,
+    Component, Entity, EntityRef, World,
 };
 
 use alloc::{boxed::Box, collections::BTreeMap, string::String};
@@ -12,7 +12,7 @@ use serde::{
     Serialize, Serializer,
 };
 
-use super::SerializeFormat;
+use super::{serialized_name, SerializeFormat};
 
 #[derive(Clone)]
 struct Slot {
@@ -48,14 +48,15 @@ impl<F> SerializeBuilder<F>
 where
     F: StaticFilter + 'static + Clone,
 {
-    /// Register a component using the component name.
+    /// Register a component using the component name, or
+    /// [`serialize_name`](super::serialize_name) if present.
     ///
     /// See u`Self::with_name`u
     pub fn with<T>(&mut self, component: Component<T>) -> &mut Self
     where
         T: ComponentValue + Serialize,
     {
-        self.with_name(component.name(), component)
+        self.with_name(serialized_name(component.desc()), component)
     }
 
     /// Register a new component to be serialized if encountered.
@@ -127,6 +128,19 @@ impl SerializeContext {
         }
     }
 
+    /// Serialize the registered components of a single entity as a map.
+    ///
+    /// The entity id is not included, and the filter of the context is not applied.
+    ///
+    /// See [`DeserializeContext::deserialize_entity`](super::DeserializeContext::deserialize_entity)
+    pub fn serialize_entity<'a>(&'a self, entity: &EntityRef<'a>) -> EntitySerializer<'a> {
+        EntitySerializer(SerializeEntityData {
+            slot: entity.loc.slot,
+            arch: entity.arch,
+            context: self,
+        })
+    }
+
     fn archetypes<'a>(
         &'a self,
         world: &'a World,
@@ -182,6 +196,18 @@ impl<'a> Serialize for WorldSerializer<'a> {
     }
 }
 
+/// Serializes the components of a single entity
+pub struct EntitySerializer<'a>(SerializeEntityData<'a>);
+
+impl<'a> Serialize for EntitySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
 struct SerializeEntities<'a> {
     world: &'a World,
     context: &'a SerializeContext,
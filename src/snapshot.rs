@@ -0,0 +1,373 @@
+//! Read only copies of component values which can be read from other threads without blocking,
+//! or being blocked by, accesses to the world.
+//!
+//! Snapshots are created through [`World::create_snapshot`] and republished at each
+//! [`World::maintain`].
+use core::{
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering::SeqCst},
+};
+
+use alloc::{boxed::Box, collections::BTreeMap, sync::Arc, vec::Vec};
+use atomic_refcell::AtomicRefCell;
+
+use crate::{component::ComponentValue, Component, Entity, World};
+
+/// The values of a component in a single archetype at the time of a snapshot
+pub struct SnapshotColumn<T> {
+    ids: Vec<Entity>,
+    values: Vec<T>,
+}
+
+impl<T> SnapshotColumn<T> {
+    /// Returns the entities of the column
+    pub fn ids(&self) -> &[Entity] {
+        &self.ids
+    }
+
+    /// Returns the values of the column, in the same order as [`Self::ids`]
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Iterate the entities and their values
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.ids.iter().copied().zip(&self.values)
+    }
+}
+
+/// The values of a component for all entities at a point in time
+pub struct Snapshot<T> {
+    tick: u32,
+    columns: Vec<SnapshotColumn<T>>,
+    /// Column and row of each entity
+    index: BTreeMap<Entity, (usize, usize)>,
+}
+
+impl<T: ComponentValue + Clone> Snapshot<T> {
+    fn capture(world: &World, component: Component<T>) -> Self {
+        let mut columns = Vec::new();
+        let mut index = BTreeMap::new();
+
+        for (_, arch) in world.archetypes.iter() {
+            if arch.is_empty() {
+                continue;
+            }
+
+            let Some(values) = arch.borrow::<T>(component.key()) else {
+                continue;
+            };
+
+            let col = columns.len();
+            index.extend(
+                arch.entities()
+                    .iter()
+                    .enumerate()
+                    .map(|(row, &id)| (id, (col, row))),
+            );
+
+            columns.push(SnapshotColumn {
+                ids: arch.entities().to_vec(),
+                values: values.get().to_vec(),
+            });
+        }
+
+        Self {
+            tick: world.change_tick(),
+            columns,
+            index,
+        }
+    }
+}
+
+impl<T> Snapshot<T> {
+    /// Returns the change tick of the world when the snapshot was taken
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    /// Returns the value of the component for `id`
+    pub fn get(&self, id: Entity) -> Option<&T> {
+        let &(col, row) = self.index.get(&id)?;
+        Some(&self.columns[col].values[row])
+    }
+
+    /// Returns the columns of the snapshot, one for each archetype with the component
+    pub fn columns(&self) -> &[SnapshotColumn<T>] {
+        &self.columns
+    }
+
+    /// Iterate the entities and their values
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.columns.iter().flat_map(|v| v.iter())
+    }
+
+    /// Returns the number of entities in the snapshot
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Returns true if the snapshot contains no entities
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+impl<T: Debug> Debug for Snapshot<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+struct Epoch<T> {
+    snapshot: Snapshot<T>,
+    /// Number of guards currently reading the epoch
+    readers: AtomicUsize,
+}
+
+struct Shared<T> {
+    current: AtomicPtr<Epoch<T>>,
+    /// Number of readers which have loaded `current` but not yet registered with the epoch.
+    ///
+    /// Retired epochs are only reclaimed while this is zero.
+    pending: AtomicUsize,
+    /// Replaced epochs which may still be read. Only accessed by the writer.
+    retired: AtomicRefCell<Vec<*mut Epoch<T>>>,
+}
+
+// Safety: the epochs are only accessed immutably by readers, and reclaimed by the writer once no
+// reader can access them.
+unsafe impl<T: Send + Sync> Send for Shared<T> {}
+unsafe impl<T: Send + Sync> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    fn new(snapshot: Snapshot<T>) -> Self {
+        Self {
+            current: AtomicPtr::new(Epoch::alloc(snapshot)),
+            pending: AtomicUsize::new(0),
+            retired: AtomicRefCell::new(Vec::new()),
+        }
+    }
+
+    fn publish(&self, snapshot: Snapshot<T>) {
+        let old = self.current.swap(Epoch::alloc(snapshot), SeqCst);
+
+        let mut retired = self.retired.borrow_mut();
+        retired.push(old);
+
+        // A pending reader may have loaded any of the retired epochs without being counted yet.
+        // Readers arriving after this point are guaranteed to observe the new epoch.
+        if self.pending.load(SeqCst) != 0 {
+            return;
+        }
+
+        retired.retain(|&epoch| {
+            // Safety: retired epochs are only freed here
+            if unsafe { (*epoch).readers.load(SeqCst) } != 0 {
+                return true;
+            }
+
+            drop(unsafe { Box::from_raw(epoch) });
+            false
+        });
+    }
+}
+
+impl<T> Epoch<T> {
+    fn alloc(snapshot: Snapshot<T>) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            snapshot,
+            readers: AtomicUsize::new(0),
+        }))
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // Safety: no readers or writer remain
+        let current = *self.current.get_mut();
+        for &epoch in self.retired.get_mut().iter().chain([&current]) {
+            drop(unsafe { Box::from_raw(epoch) });
+        }
+    }
+}
+
+/// Reads the latest published [`Snapshot`] of a component.
+///
+/// Created by [`World::create_snapshot`]. Reading is wait-free, and can be done concurrently with
+/// any access to the world.
+pub struct SnapshotReader<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for SnapshotReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> SnapshotReader<T> {
+    /// Returns the latest published snapshot.
+    ///
+    /// The snapshot is kept alive for as long as the guard is held, even if a newer snapshot is
+    /// published in the meantime.
+    pub fn load(&self) -> SnapshotGuard<'_, T> {
+        let shared = &*self.shared;
+        shared.pending.fetch_add(1, SeqCst);
+        let epoch = shared.current.load(SeqCst);
+        // Safety: epochs are not reclaimed while `pending` is non-zero
+        let epoch = unsafe { &*epoch };
+        epoch.readers.fetch_add(1, SeqCst);
+        shared.pending.fetch_sub(1, SeqCst);
+
+        SnapshotGuard { epoch }
+    }
+}
+
+impl<T> Debug for SnapshotReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotReader").finish_non_exhaustive()
+    }
+}
+
+/// Keeps a [`Snapshot`] alive while it is being read
+pub struct SnapshotGuard<'a, T> {
+    epoch: &'a Epoch<T>,
+}
+
+impl<'a, T> Deref for SnapshotGuard<'a, T> {
+    type Target = Snapshot<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.epoch.snapshot
+    }
+}
+
+impl<'a, T> Drop for SnapshotGuard<'a, T> {
+    fn drop(&mut self) {
+        self.epoch.readers.fetch_sub(1, SeqCst);
+    }
+}
+
+impl<'a, T: Debug> Debug for SnapshotGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Publishes snapshots of a component from the world
+pub(crate) trait SnapshotSource: Send + Sync {
+    fn publish(&self, world: &World);
+    /// Returns true if all readers have been dropped
+    fn is_orphaned(&self) -> bool;
+}
+
+struct SnapshotWriter<T> {
+    component: Component<T>,
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: ComponentValue + Clone> SnapshotSource for SnapshotWriter<T> {
+    fn publish(&self, world: &World) {
+        self.shared
+            .publish(Snapshot::capture(world, self.component))
+    }
+
+    fn is_orphaned(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+}
+
+/// Captures the current values of `component` and returns the writer and reader of its snapshots
+pub(crate) fn snapshot_channel<T: ComponentValue + Clone>(
+    world: &World,
+    component: Component<T>,
+) -> (Box<dyn SnapshotSource>, SnapshotReader<T>) {
+    let shared = Arc::new(Shared::new(Snapshot::capture(world, component)));
+    let writer = SnapshotWriter {
+        component,
+        shared: shared.clone(),
+    };
+
+    (Box::new(writer), SnapshotReader { shared })
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::{components::name, Entity, World};
+
+    component! {
+        position: f32,
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut world = World::new();
+
+        let ids = (0..4)
+            .map(|i| {
+                let mut builder = Entity::builder();
+                builder.set(position(), i as f32);
+                if i % 2 == 0 {
+                    builder.set(name(), "even".into());
+                }
+                builder.spawn(&mut world)
+            })
+            .collect::<Vec<_>>();
+
+        let reader = world.create_snapshot(position());
+
+        let snapshot = reader.load();
+        assert_eq!(snapshot.len(), 4);
+        assert_eq!(snapshot.columns().len(), 2);
+        assert_eq!(snapshot.get(ids[2]), Some(&2.0));
+
+        *world.get_mut(ids[2], position()).unwrap() = 5.0;
+        world.despawn(ids[3]).unwrap();
+        world.maintain().unwrap();
+
+        // The held snapshot is unaffected
+        assert_eq!(snapshot.get(ids[2]), Some(&2.0));
+        assert_eq!(snapshot.len(), 4);
+        drop(snapshot);
+
+        let snapshot = reader.load();
+        assert_eq!(snapshot.get(ids[2]), Some(&5.0));
+        assert_eq!(snapshot.get(ids[3]), None);
+
+        let mut values = snapshot.iter().map(|(id, &v)| (id, v)).collect::<Vec<_>>();
+        values.sort_by_key(|v| v.0);
+        assert_eq!(values, [(ids[0], 0.0), (ids[1], 1.0), (ids[2], 5.0)]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn snapshot_threaded() {
+        let mut world = World::new();
+        let id = Entity::builder().set(position(), 0.0).spawn(&mut world);
+
+        let reader = world.create_snapshot(position());
+
+        let thread = std::thread::spawn(move || {
+            let mut last = 0.0;
+            while last < 100.0 {
+                let snapshot = reader.load();
+                let value = *snapshot.get(id).unwrap();
+                assert!(value >= last);
+                last = value;
+            }
+        });
+
+        for i in 1..=100 {
+            *world.get_mut(id, position()).unwrap() = i as f32;
+            world.maintain().unwrap();
+        }
+
+        thread.join().unwrap();
+    }
+}
@@ -0,0 +1,502 @@
+use core::{
+    any::TypeId,
+    fmt::{self, Debug, Formatter},
+};
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
+use atomic_refcell::{AtomicRefCell, AtomicRefMut};
+
+use crate::{
+    archetype::{Slice, Slot},
+    entity_ids,
+    events::{Event, EventSubscriber},
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch},
+    index::read_dirty,
+    system::{Access, AccessKind},
+    Debuggable, Entity, Fetch, FetchItem, Query, World,
+};
+
+component! {
+    /// The world space position used by the [`SpatialIndex`]
+    pub position: [f32; 3] => [ Debuggable ],
+}
+
+/// An axis aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// The lower corner
+    pub min: [f32; 3],
+    /// The upper corner
+    pub max: [f32; 3],
+}
+
+impl Aabb {
+    /// Creates a new bounding box from two corners
+    pub fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns true if `point` is inside the box, inclusive of the boundary
+    pub fn contains(&self, point: [f32; 3]) -> bool {
+        (0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+    }
+}
+
+type CellKey = [i32; 3];
+
+/// A uniform grid over the [`position`] component of all entities in a world.
+///
+/// The index is kept up to date by subscribing to the world's change events, which are applied
+/// during [`SpatialIndex::update`] and whenever a [`WithinBounds`] filter is prepared. Use
+/// [`SpatialIndex::within_bounds`] to restrict a query to the entities inside a region without
+/// scanning the whole world.
+pub struct SpatialIndex {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    cell_size: f32,
+    state: AtomicRefCell<GridState>,
+    rx: flume::Receiver<Event>,
+}
+
+struct GridState {
+    cells: BTreeMap<CellKey, BTreeSet<Entity>>,
+    locations: BTreeMap<Entity, ([f32; 3], CellKey)>,
+    /// Entities which could not be read as the position was borrowed
+    dirty: BTreeSet<Entity>,
+    /// Incremented whenever an indexed position changes
+    version: u64,
+}
+
+impl SpatialIndex {
+    /// Creates a new index over all entities with a [`position`] in `world`.
+    ///
+    /// `cell_size` is the side length of each grid cell, and should be in the order of the most
+    /// common query extent.
+    pub fn new(world: &mut World, cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "cell_size must be positive");
+
+        let (tx, rx) = flume::unbounded();
+        world.subscribe(tx.filter_components([position().key()]));
+
+        let mut state = GridState {
+            cells: BTreeMap::new(),
+            locations: BTreeMap::new(),
+            dirty: BTreeSet::new(),
+            version: 0,
+        };
+
+        // Disabled entities still send events, and are as such indexed as well
+        let mut query = Query::new((entity_ids(), position())).include_disabled();
+        for (id, &pos) in &mut query.borrow_unchecked(world) {
+            state.insert(cell_size, id, pos);
+        }
+
+        Self {
+            shared: Arc::new(Shared {
+                cell_size,
+                state: AtomicRefCell::new(state),
+                rx,
+            }),
+        }
+    }
+
+    /// Applies all pending change events from `world` to the index
+    pub fn update(&mut self, world: &World) {
+        self.shared.refresh(world);
+    }
+
+    /// Returns the number of indexed entities
+    pub fn len(&self) -> usize {
+        self.shared.state.borrow().locations.len()
+    }
+
+    /// Returns true if no entities are indexed
+    pub fn is_empty(&self) -> bool {
+        self.shared.state.borrow().locations.is_empty()
+    }
+
+    /// Returns the entities in the grid cells overlapping `bounds`.
+    ///
+    /// This is a conservative set, and may contain entities just outside of `bounds`.
+    pub fn candidates(&self, bounds: Aabb) -> BTreeSet<Entity> {
+        self.shared
+            .state
+            .borrow()
+            .candidates(self.shared.cell_size, bounds)
+    }
+
+    /// Returns the entities whose indexed position is inside `bounds`
+    pub fn within(&self, bounds: Aabb) -> BTreeSet<Entity> {
+        self.shared
+            .state
+            .borrow()
+            .within(self.shared.cell_size, bounds)
+    }
+
+    /// Returns a filter which only yields entities whose [`position`] is inside `bounds`.
+    ///
+    /// The filter shares the index, and applies pending change events each time it is prepared.
+    /// Positions which are borrowed while preparing, such as by `position().as_mut()` in the same
+    /// query, are not reflected until the next time the query is prepared.
+    ///
+    /// The filter does not borrow the [`position`] component, which allows it to be used in the
+    /// same query as `position().as_mut()`.
+    pub fn within_bounds(&self, bounds: Aabb) -> WithinBounds {
+        WithinBounds {
+            index: self.shared.clone(),
+            bounds,
+            cache: AtomicRefCell::new(None),
+        }
+    }
+}
+
+impl Shared {
+    fn refresh(&self, world: &World) -> AtomicRefMut<'_, GridState> {
+        let mut state = self.state.borrow_mut();
+        state.dirty.extend(self.rx.drain().map(|v| v.id));
+
+        for (id, pos) in read_dirty(&mut state.dirty, world, position()) {
+            match pos {
+                Some(pos) => state.insert(self.cell_size, id, pos),
+                None => state.remove(id),
+            }
+        }
+
+        state
+    }
+}
+
+fn cell_key(cell_size: f32, pos: [f32; 3]) -> CellKey {
+    pos.map(|v| {
+        let v = (v / cell_size).floor();
+        // Saturates on overflow, and maps NaN to 0
+        v as i32
+    })
+}
+
+impl GridState {
+    fn candidates(&self, cell_size: f32, bounds: Aabb) -> BTreeSet<Entity> {
+        let min = cell_key(cell_size, bounds.min);
+        let max = cell_key(cell_size, bounds.max);
+
+        let in_range = |key: &CellKey| (0..3).all(|i| key[i] >= min[i] && key[i] <= max[i]);
+
+        let cell_count = (0..3)
+            .map(|i| (max[i] as i64 - min[i] as i64 + 1).max(0) as u64)
+            .fold(1u64, |acc, v| acc.saturating_mul(v));
+
+        let mut result = BTreeSet::new();
+        if cell_count > self.cells.len() as u64 {
+            // Scan the occupied cells rather than visiting every cell in a large region
+            for (_, ids) in self.cells.iter().filter(|(key, _)| in_range(key)) {
+                result.extend(ids.iter().copied());
+            }
+        } else {
+            for x in min[0]..=max[0] {
+                for y in min[1]..=max[1] {
+                    for z in min[2]..=max[2] {
+                        if let Some(ids) = self.cells.get(&[x, y, z]) {
+                            result.extend(ids.iter().copied());
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn within(&self, cell_size: f32, bounds: Aabb) -> BTreeSet<Entity> {
+        let mut result = self.candidates(cell_size, bounds);
+        result.retain(|id| bounds.contains(self.locations[id].0));
+        result
+    }
+
+    fn insert(&mut self, cell_size: f32, id: Entity, pos: [f32; 3]) {
+        let key = cell_key(cell_size, pos);
+        if let Some((old_pos, old)) = self.locations.insert(id, (pos, key)) {
+            if old_pos != pos {
+                self.version += 1;
+            }
+
+            if old == key {
+                return;
+            }
+
+            self.remove_from_cell(old, id);
+        } else {
+            self.version += 1;
+        }
+
+        self.cells.entry(key).or_default().insert(id);
+    }
+
+    fn remove(&mut self, id: Entity) {
+        if let Some((_, old)) = self.locations.remove(&id) {
+            self.version += 1;
+            self.remove_from_cell(old, id);
+        }
+    }
+
+    fn remove_from_cell(&mut self, key: CellKey, id: Entity) {
+        if let Some(ids) = self.cells.get_mut(&key) {
+            ids.remove(&id);
+            if ids.is_empty() {
+                self.cells.remove(&key);
+            }
+        }
+    }
+}
+
+/// Filter for entities inside a bounding box.
+///
+/// See [`SpatialIndex::within_bounds`]
+pub struct WithinBounds {
+    index: Arc<Shared>,
+    bounds: Aabb,
+    /// The sorted entities inside the bounds, and the index version they were computed at
+    cache: AtomicRefCell<Option<(u64, Vec<Entity>)>>,
+}
+
+impl Clone for WithinBounds {
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index.clone(),
+            bounds: self.bounds,
+            cache: AtomicRefCell::new(self.cache.borrow().clone()),
+        }
+    }
+}
+
+impl Debug for WithinBounds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithinBounds")
+            .field("bounds", &self.bounds)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'q> FetchItem<'q> for WithinBounds {
+    type Item = ();
+}
+
+impl<'w> Fetch<'w> for WithinBounds {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedWithinBounds;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let state = self.index.refresh(data.world);
+
+        let mut cache = self.cache.borrow_mut();
+        let (_, entities) = match &mut *cache {
+            Some(cache) if cache.0 == state.version => cache,
+            cache => cache.insert((
+                state.version,
+                state
+                    .within(self.index.cell_size, self.bounds)
+                    .into_iter()
+                    .collect(),
+            )),
+        };
+
+        drop(state);
+
+        // Visit whichever of the matching entities or the archetype's entities is smaller
+        let mut slots = if entities.len() <= data.arch.len() {
+            entities
+                .iter()
+                .filter_map(|&id| data.world.location(id).ok())
+                .filter(|loc| loc.arch_id == data.arch_id)
+                .map(|loc| loc.slot)
+                .collect::<Vec<_>>()
+        } else {
+            data.arch
+                .entities()
+                .iter()
+                .enumerate()
+                .filter(|(_, id)| entities.binary_search(id).is_ok())
+                .map(|(slot, _)| slot)
+                .collect::<Vec<_>>()
+        };
+
+        if slots.is_empty() {
+            return None;
+        }
+
+        slots.sort_unstable();
+
+        Some(PreparedWithinBounds { slots })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        data.arch.has(position().key())
+    }
+
+    fn access(&self, _: FetchAccessData, dst: &mut Vec<Access>) {
+        // Reading the index applies pending events
+        dst.push(Access {
+            kind: AccessKind::External(TypeId::of::<SpatialIndex>()),
+            mutable: true,
+        })
+    }
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "within_bounds({:?}, {:?})",
+            self.bounds.min, self.bounds.max
+        )
+    }
+
+    fn searcher(&self, searcher: &mut crate::ArchetypeSearcher) {
+        searcher.add_required(position().key())
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedWithinBounds {
+    /// Sorted slots of the archetype inside the bounds
+    slots: Vec<Slot>,
+}
+
+impl<'q> PreparedFetch<'q> for PreparedWithinBounds {
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let first = self.slots.partition_point(|&v| v < slots.start);
+
+        let Some(&start) = self.slots.get(first) else {
+            return slots.empty_tail();
+        };
+
+        if start >= slots.end {
+            return slots.empty_tail();
+        }
+
+        // Extend over the contiguous run of slots
+        let count = self.slots[first..]
+            .iter()
+            .zip(start..slots.end)
+            .take_while(|&(&a, b)| a == b)
+            .count();
+
+        Slice::new(start, start + count)
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use itertools::Itertools;
+
+    use crate::components::name;
+
+    use super::*;
+
+    #[test]
+    fn within_bounds() {
+        let mut world = World::new();
+
+        let ids = (0..10)
+            .map(|i| {
+                Entity::builder()
+                    .set(position(), [i as f32, 0.0, 0.0])
+                    .spawn(&mut world)
+            })
+            .collect_vec();
+
+        // Not part of the index
+        Entity::builder()
+            .set(name(), "unpositioned".into())
+            .spawn(&mut world);
+
+        let mut index = SpatialIndex::new(&mut world, 2.0);
+        assert_eq!(index.len(), 10);
+
+        let bounds = Aabb::new([2.5, -1.0, -1.0], [6.0, 1.0, 1.0]);
+
+        let mut query = Query::new(entity_ids()).filter(index.within_bounds(bounds));
+        assert_eq!(
+            query.borrow(&world).unwrap().iter().collect_vec(),
+            ids[3..=6].to_vec()
+        );
+
+        // Move entities through a query, which is picked up through change events
+        Query::new(position().as_mut())
+            .borrow(&world)
+            .unwrap()
+            .for_each(|pos| pos[0] += 10.0);
+
+        world.despawn(ids[9]).unwrap();
+
+        // The filter applies the pending events without an explicit update
+        assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
+
+        index.update(&world);
+
+        assert_eq!(index.len(), 9);
+        assert_eq!(index.within(bounds), Default::default());
+
+        let bounds = Aabb::new([12.5, -1.0, -1.0], [20.0, 1.0, 1.0]);
+        let mut query =
+            Query::new((entity_ids(), position().as_mut())).filter(index.within_bounds(bounds));
+
+        let found = query
+            .borrow(&world)
+            .unwrap()
+            .iter()
+            .map(|(id, pos)| {
+                pos[1] = 1.0;
+                id
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, ids[3..9].to_vec());
+    }
+
+    #[test]
+    fn disabled_entities() {
+        use crate::components::disabled;
+
+        let mut world = World::new();
+
+        let a = Entity::builder()
+            .set(position(), [0.0; 3])
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        let mut index = SpatialIndex::new(&mut world, 2.0);
+
+        // Indexed the same as a disabled entity spawned after the index
+        let b = Entity::builder()
+            .set(position(), [1.0, 0.0, 0.0])
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        index.update(&world);
+        assert_eq!(index.len(), 2);
+
+        let found = index.within(Aabb::new([-1.0; 3], [2.0; 3]));
+        assert_eq!(found.into_iter().collect_vec(), [a, b]);
+    }
+}
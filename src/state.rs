@@ -0,0 +1,332 @@
+//! Each state is an entity, and an entity is *in* a state when it has the [`state`] relation
+//! targeting it. As the relation is [`Exclusive`], an entity can only be in a single state at a
+//! time, and entering a new state removes the previous one.
+//!
+//! Transitions are observed using the [`entered_state`](crate::state::entered_state) and
+//! [`exited_state`](crate::state::exited_state) filters, which yield the entities which entered or
+//! left a state since the query last ran.
+
+use core::{
+    fmt::{self, Formatter},
+    mem,
+};
+
+use alloc::{
+    collections::BTreeMap,
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use atomic_refcell::AtomicRefCell;
+
+use crate::{
+    archetype::{ArchetypeId, ChangeKind, Slice, Slot, Storage},
+    component::{ComponentDesc, ComponentKey},
+    error::Result,
+    events::{EventData, EventSubscriber},
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch, TrustedAccess},
+    filter::ChangeFilter,
+    system::Access,
+    Debuggable, Entity, Exclusive, Fetch, FetchItem, World,
+};
+
+component! {
+    /// The current state of an entity
+    pub state(state): () => [ Debuggable, Exclusive ],
+}
+
+/// Returns the current state of `id`, if any
+pub fn current_state(world: &World, id: Entity) -> Result<Option<Entity>> {
+    Ok(world.entity(id)?.relations(state).next().map(|(s, _)| s))
+}
+
+/// Transitions `id` into `new_state`, exiting the previous state.
+///
+/// Returns the previous state. Entering the current state again is a no-op.
+pub fn set_state(world: &mut World, id: Entity, new_state: Entity) -> Result<Option<Entity>> {
+    let prev = current_state(world, id)?;
+    if prev == Some(new_state) {
+        return Ok(prev);
+    }
+
+    world.set(id, state(new_state), ())?;
+
+    Ok(prev)
+}
+
+/// Filter for entities which entered `state` since the query last ran
+pub fn entered_state(s: Entity) -> ChangeFilter<()> {
+    ChangeFilter::new(state(s), ChangeKind::Added)
+}
+
+/// Filter for entities which exited `state` since the query last ran.
+///
+/// The exits are recorded from the removal events of the [`state`] relation in `world`, which
+/// includes both transitions and removing the relation directly. Despawned entities are not
+/// yielded, as they no longer exist.
+///
+/// As the exits are consumed by the filter, each filter should only be used in a single query.
+pub fn exited_state(world: &mut World, s: Entity) -> ExitedState {
+    let exits = Arc::new(AtomicRefCell::new(Vec::new()));
+
+    world.subscribe(ExitSubscriber {
+        key: state(s).key(),
+        exits: Arc::downgrade(&exits),
+    });
+
+    ExitedState {
+        state: s,
+        exits,
+        run: AtomicRefCell::new(ExitRun {
+            tick: None,
+            slots: BTreeMap::new(),
+        }),
+    }
+}
+
+/// Records the entities which had the state relation removed
+struct ExitSubscriber {
+    key: ComponentKey,
+    exits: Weak<AtomicRefCell<Vec<Entity>>>,
+}
+
+impl EventSubscriber for ExitSubscriber {
+    fn on_added(&self, _: &Storage, _: &EventData) {}
+
+    fn on_modified(&self, _: &EventData) {}
+
+    fn on_removed(&self, _: &Storage, event: &EventData) {
+        if let Some(exits) = self.exits.upgrade() {
+            exits.borrow_mut().extend_from_slice(event.ids);
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.exits.strong_count() > 0
+    }
+
+    fn matches_component(&self, desc: ComponentDesc) -> bool {
+        desc.key() == self.key
+    }
+}
+
+/// Filter for entities which exited a state.
+///
+/// See [`exited_state`]
+pub struct ExitedState {
+    state: Entity,
+    exits: Arc<AtomicRefCell<Vec<Entity>>>,
+    run: AtomicRefCell<ExitRun>,
+}
+
+/// The exits yielded by the current run of the query
+struct ExitRun {
+    tick: Option<u32>,
+    /// Sorted slots of each archetype
+    slots: BTreeMap<ArchetypeId, Vec<Slot>>,
+}
+
+impl fmt::Debug for ExitedState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExitedState")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'q> FetchItem<'q> for ExitedState {
+    type Item = ();
+}
+
+unsafe impl TrustedAccess for ExitedState {}
+
+impl<'w> Fetch<'w> for ExitedState {
+    // Acquires a new change tick for each run, which separates the exits of one run from the next
+    const MUTABLE: bool = true;
+
+    type Prepared = PreparedExitedState;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        let mut run = self.run.borrow_mut();
+
+        if run.tick != Some(data.new_tick) {
+            run.tick = Some(data.new_tick);
+            run.slots.clear();
+
+            for id in mem::take(&mut *self.exits.borrow_mut()) {
+                if let Ok(loc) = data.world.location(id) {
+                    run.slots.entry(loc.arch_id).or_default().push(loc.slot);
+                }
+            }
+
+            for slots in run.slots.values_mut() {
+                slots.sort_unstable();
+                slots.dedup();
+            }
+        }
+
+        let slots = run.slots.get(&data.arch_id)?.clone();
+
+        Some(PreparedExitedState { slots })
+    }
+
+    fn filter_arch(&self, _: FetchAccessData) -> bool {
+        // The entity is no longer in the state, but may be in any other archetype
+        true
+    }
+
+    #[inline]
+    fn access(&self, _: FetchAccessData, _: &mut Vec<Access>) {}
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "exited_state({})", self.state)
+    }
+}
+
+#[doc(hidden)]
+pub struct PreparedExitedState {
+    /// Sorted slots of the archetype which exited the state
+    slots: Vec<Slot>,
+}
+
+impl<'q> PreparedFetch<'q> for PreparedExitedState {
+    type Item = ();
+    type Chunk = ();
+
+    const HAS_FILTER: bool = true;
+
+    unsafe fn filter_slots(&mut self, slots: Slice) -> Slice {
+        let first = self.slots.partition_point(|&v| v < slots.start);
+
+        let Some(&start) = self.slots.get(first) else {
+            return slots.empty_tail();
+        };
+
+        if start >= slots.end {
+            return slots.empty_tail();
+        }
+
+        // Extend over the contiguous run of slots
+        let count = self.slots[first..]
+            .iter()
+            .zip(start..slots.end)
+            .take_while(|&(&a, b)| a == b)
+            .count();
+
+        Slice::new(start, start + count)
+    }
+
+    #[inline]
+    unsafe fn create_chunk(&mut self, _: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+    }
+
+    #[inline]
+    unsafe fn fetch_next(_: &mut Self::Chunk) -> Self::Item {}
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{entity_ids, Query};
+
+    use super::*;
+
+    #[test]
+    fn transitions() {
+        let mut world = World::new();
+
+        let idle = world.spawn();
+        let running = world.spawn();
+
+        let a = world.spawn();
+        let b = world.spawn();
+
+        let mut entered = Query::new(entity_ids()).filter(entered_state(running));
+        let mut exited = Query::new(entity_ids()).filter(exited_state(&mut world, idle));
+
+        assert_eq!(set_state(&mut world, a, idle), Ok(None));
+        assert_eq!(set_state(&mut world, b, idle), Ok(None));
+
+        assert_eq!(
+            entered.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            []
+        );
+        assert_eq!(
+            exited.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            []
+        );
+
+        assert_eq!(set_state(&mut world, a, running), Ok(Some(idle)));
+        assert_eq!(current_state(&world, a), Ok(Some(running)));
+        assert_eq!(current_state(&world, b), Ok(Some(idle)));
+
+        assert_eq!(
+            entered.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            [a]
+        );
+        assert_eq!(
+            exited.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            [a]
+        );
+
+        assert_eq!(
+            entered.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            []
+        );
+        assert_eq!(
+            exited.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+            []
+        );
+
+        // Leave and re-enter
+        set_state(&mut world, a, idle).unwrap();
+        set_state(&mut world, b, running).unwrap();
+        set_state(&mut world, a, running).unwrap();
+
+        let mut found = entered.borrow(&world).unwrap().iter().collect::<Vec<_>>();
+        found.sort();
+        assert_eq!(found, [a, b]);
+
+        let mut found = exited.borrow(&world).unwrap().iter().collect::<Vec<_>>();
+        found.sort();
+        assert_eq!(found, [a, b]);
+
+        // Removing the state directly is also an exit
+        let mut exited_running =
+            Query::new(entity_ids()).filter(exited_state(&mut world, running));
+        assert_eq!(
+            exited_running
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            []
+        );
+
+        world.remove(a, state(running)).unwrap();
+        world.despawn(b).unwrap();
+
+        assert_eq!(current_state(&world, a), Ok(None));
+        assert_eq!(
+            exited_running
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            [a]
+        );
+
+        // The exits are only yielded once, even if no change tick was acquired in between
+        assert_eq!(
+            exited_running
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            []
+        );
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     CommandBuffer, World,
 };
 
-use super::{input::ExtractDyn, SystemAccess, SystemData};
+use super::{input::ExtractDyn, SystemAccess, SystemData, SystemFuture};
 
 /// A resource that can be shared between systems
 /// The difference between this and an `Arc<Mutex<_>>` is that this will be
@@ -73,6 +73,8 @@ pub struct SystemContext<'w, 'b, 'input> {
     pub(crate) cmd: AtomicRefCell<&'w mut CommandBuffer>,
     /// External input
     input: &'b dyn ExtractDyn<'b, 'input>,
+    /// Futures of the asynchronous systems, if executed asynchronously
+    futures: Option<AtomicRefCell<Vec<SystemFuture>>>,
 }
 
 impl<'a, 'b, 'input> SystemContext<'a, 'b, 'input> {
@@ -86,47 +88,86 @@ impl<'a, 'b, 'input> SystemContext<'a, 'b, 'input> {
             world: AtomicRefCell::new(world),
             cmd: AtomicRefCell::new(cmd),
             input,
+            futures: None,
         }
     }
 
+    /// Creates a context which collects the futures of asynchronous systems
+    pub(crate) fn new_async(
+        world: &'a mut World,
+        cmd: &'a mut CommandBuffer,
+        input: &'b dyn ExtractDyn<'b, 'input>,
+    ) -> Self {
+        Self {
+            futures: Some(AtomicRefCell::new(Vec::new())),
+            ..Self::new(world, cmd, input)
+        }
+    }
+
+    /// Adds the future of an asynchronous system to be awaited by the executor
+    pub(crate) fn push_future(&self, future: SystemFuture) -> anyhow::Result<()> {
+        match &self.futures {
+            Some(futures) => {
+                futures.borrow_mut().push(future);
+                Ok(())
+            }
+            None => Err(anyhow::anyhow!(
+                "Asynchronous systems must be executed through `Schedule::execute_async`"
+            )),
+        }
+    }
+
+    /// Takes the futures of the asynchronous systems executed so far
+    pub(crate) fn take_futures(&self) -> Vec<SystemFuture> {
+        self.futures
+            .as_ref()
+            .map(|v| core::mem::take(&mut *v.borrow_mut()))
+            .unwrap_or_default()
+    }
+
     /// Access the world
     #[inline]
-    pub fn world(&self) -> AtomicRef<World> {
+    pub fn world(&self) -> AtomicRef<'_, World> {
         let borrow = self.world.borrow();
         AtomicRef::map(borrow, |v| *v)
     }
 
     /// Access the world mutably
     #[inline]
-    pub fn world_mut(&self) -> AtomicRefMut<World> {
+    pub fn world_mut(&self) -> AtomicRefMut<'_, World> {
         let borrow = self.world.borrow_mut();
         AtomicRefMut::map(borrow, |v| *v)
     }
 
+    /// Returns the current change tick of the world, if it is not mutably borrowed
+    pub(crate) fn change_tick(&self) -> Option<u32> {
+        self.world.try_borrow().ok().map(|v| v.change_tick())
+    }
+
     /// Access the commandbuffer
     #[inline]
-    pub fn cmd(&self) -> AtomicRef<CommandBuffer> {
+    pub fn cmd(&self) -> AtomicRef<'_, CommandBuffer> {
         let borrow = self.cmd.borrow();
         AtomicRef::map(borrow, |v| *v)
     }
 
     /// Access the commandbuffer mutably
     #[inline]
-    pub fn cmd_mut(&self) -> AtomicRefMut<CommandBuffer> {
+    pub fn cmd_mut(&self) -> AtomicRefMut<'_, CommandBuffer> {
         let borrow = self.cmd.borrow_mut();
         AtomicRefMut::map(borrow, |v| *v)
     }
 
     /// Access user provided input data
     #[inline]
-    pub fn input<T: 'static>(&self) -> Option<AtomicRef<T>> {
+    pub fn input<T: 'static>(&self) -> Option<AtomicRef<'_, T>> {
         let cell = unsafe { self.input.extract_dyn(TypeId::of::<T>()) };
         cell.map(|v| AtomicRef::map(v.borrow(), unsafe { |v| v.cast().as_ref() }))
     }
 
     /// Access user provided input data
     #[inline]
-    pub fn input_mut<T: 'static>(&self) -> Option<AtomicRefMut<T>> {
+    pub fn input_mut<T: 'static>(&self) -> Option<AtomicRefMut<'_, T>> {
         let cell = unsafe { self.input.extract_dyn(TypeId::of::<T>()) };
         cell.map(|v| AtomicRefMut::map(v.borrow_mut(), unsafe { |v| v.cast().as_mut() }))
     }
@@ -19,7 +19,10 @@ use alloc::{
 use core::{
     any::{type_name, TypeId},
     fmt::{self, Formatter},
+    future::Future,
     marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
 };
 
 pub use context::*;
@@ -72,6 +75,33 @@ where
     }
 }
 
+/// Execute a function for each item in the query within a time budget
+#[cfg(feature = "std")]
+pub struct ForEachBudgeted<Func> {
+    func: Func,
+    budget: std::time::Duration,
+    cursor: crate::query::QueryCursor,
+}
+
+#[cfg(feature = "std")]
+impl<'a, Func, Q, F> SystemFn<'a, (QueryData<'a, Q, F>,), ()> for ForEachBudgeted<Func>
+where
+    for<'x> Q: Fetch<'x>,
+    for<'x> F: Fetch<'x>,
+    for<'x> Func: FnMut(<Q as FetchItem<'x>>::Item),
+{
+    fn execute(&mut self, mut data: (QueryData<Q, F>,)) {
+        let start = std::time::Instant::now();
+        let budget = self.budget;
+
+        data.0.borrow().for_each_resumable(
+            &mut self.cursor,
+            || start.elapsed() >= budget,
+            &mut self.func,
+        );
+    }
+}
+
 /// Execute a function for each item in the query in parallel batches
 #[cfg(feature = "rayon")]
 pub struct ParForEach<F> {
@@ -96,6 +126,53 @@ where
     }
 }
 
+/// The future of an asynchronous system, resolving to the commands to apply to the world.
+///
+/// See [`SystemBuilder::build_async`]
+pub struct SystemFuture(Pin<Box<dyn Future<Output = anyhow::Result<CommandBuffer>> + Send>>);
+
+// Safety: the inner future is only accessible through a mutable reference
+unsafe impl Sync for SystemFuture {}
+
+impl SystemFuture {
+    /// Wraps a future resolving to the commands to apply to the world
+    pub fn new(
+        future: impl Future<Output = anyhow::Result<CommandBuffer>> + Send + 'static,
+    ) -> Self {
+        Self(Box::pin(future))
+    }
+}
+
+impl Future for SystemFuture {
+    type Output = anyhow::Result<CommandBuffer>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+impl fmt::Debug for SystemFuture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemFuture").finish_non_exhaustive()
+    }
+}
+
+#[doc(hidden)]
+pub struct AsyncFn<Func, Fut> {
+    func: Func,
+    _marker: PhantomData<fn() -> Fut>,
+}
+
+impl<'a, Func, Args, Fut> SystemFn<'a, Args, SystemFuture> for AsyncFn<Func, Fut>
+where
+    Func: SystemFn<'a, Args, Fut>,
+    Fut: Future<Output = anyhow::Result<CommandBuffer>> + Send + 'static,
+{
+    fn execute(&'a mut self, args: Args) -> SystemFuture {
+        SystemFuture::new(self.func.execute(args))
+    }
+}
+
 impl<Q, F> SystemBuilder<(Query<Q, F>,)>
 where
     for<'x> Q: Fetch<'x> + 'static,
@@ -114,6 +191,38 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<Q, F> SystemBuilder<(Query<Q, F>,)>
+where
+    for<'x> Q: Fetch<'x> + 'static,
+    for<'x> F: Fetch<'x> + 'static,
+{
+    /// Execute a function for each item in the query, suspending the remaining items until the
+    /// next execution once `budget` is exceeded.
+    ///
+    /// The elapsed time is checked after each batch of `batch_size` slots, and the position is
+    /// stored in a [`QueryCursor`](crate::query::QueryCursor) owned by the system.
+    pub fn for_each_budgeted<Func>(
+        self,
+        budget: std::time::Duration,
+        batch_size: usize,
+        func: Func,
+    ) -> System<ForEachBudgeted<Func>, (Query<Q, F>,), ()>
+    where
+        for<'x> Func: FnMut(<Q as FetchItem<'x>>::Item),
+    {
+        System::new(
+            self.name.unwrap_or_else(|| type_name::<Func>().to_string()),
+            ForEachBudgeted {
+                func,
+                budget,
+                cursor: crate::query::QueryCursor::new(batch_size),
+            },
+            self.args,
+        )
+    }
+}
+
 #[cfg(feature = "rayon")]
 impl<Q, F> SystemBuilder<(Query<Q, F>,)>
 where
@@ -239,6 +348,36 @@ impl<Args> SystemBuilder<Args> {
         )
     }
 
+    /// Build an asynchronous system.
+    ///
+    /// The function is invoked with the system's arguments and returns a future, which is awaited
+    /// alongside the other systems of the same batch by [`Schedule::execute_async`]. As the
+    /// future may not borrow the arguments, any data needed across await points is copied out
+    /// beforehand, and changes are returned as a [`CommandBuffer`] which is applied once the
+    /// future completes and before the next batch runs.
+    ///
+    /// Asynchronous systems fail when executed through the synchronous executors.
+    ///
+    /// [`Schedule::execute_async`]: crate::Schedule::execute_async
+    pub fn build_async<Func, Fut>(
+        self,
+        func: Func,
+    ) -> System<AsyncFn<Func, Fut>, Args, SystemFuture>
+    where
+        Args: for<'a> SystemData<'a> + 'static,
+        Func: for<'this, 'a> SystemFn<'this, <Args as SystemData<'a>>::Value, Fut>,
+        Fut: Future<Output = anyhow::Result<CommandBuffer>> + Send + 'static,
+    {
+        System::new(
+            self.name.unwrap_or_else(|| type_name::<Func>().to_string()),
+            AsyncFn {
+                func,
+                _marker: PhantomData,
+            },
+            self.args,
+        )
+    }
+
     /// Add a new generic argument to the system
     fn with<S>(self, other: S) -> SystemBuilder<Args::PushRight>
     where
@@ -257,22 +396,11 @@ pub struct System<F, Args, Ret> {
     name: String,
     data: Args,
     func: F,
+    /// The world change tick when the data of the previous execution was acquired
+    last_run: Option<u32>,
     _marker: PhantomData<Ret>,
 }
 
-struct FormatWith<F> {
-    func: F,
-}
-
-impl<F> fmt::Debug for FormatWith<F>
-where
-    F: Fn(&mut Formatter<'_>) -> fmt::Result,
-{
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        (self.func)(f)
-    }
-}
-
 /// Abstraction over a system with any kind of arguments and fallibility
 #[doc(hidden)]
 pub trait DynSystem {
@@ -280,6 +408,10 @@ pub trait DynSystem {
     fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result;
     fn execute(&mut self, ctx: &SystemContext<'_, '_, '_>) -> anyhow::Result<()>;
     fn access(&self, world: &World, dst: &mut Vec<Access>);
+    /// Returns the change tick of the last execution, if tracked
+    fn last_run(&self) -> Option<u32> {
+        None
+    }
 }
 
 impl<F, Args, Err> DynSystem for System<F, Args, Result<(), Err>>
@@ -294,9 +426,10 @@ where
         #[cfg(feature = "tracing")]
         let _span = tracing::info_span!("system", name = self.name).entered();
 
-        let data = self.data.acquire(ctx);
+        let data = acquire_data(&mut self.data, &mut self.last_run, ctx);
 
         let res: anyhow::Result<()> = self.func.execute(data).map_err(Into::into);
+
         if let Err(err) = res {
             return Err(err.context(format!("Failed to execute system: {:?}", self)));
         }
@@ -321,6 +454,10 @@ where
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn last_run(&self) -> Option<u32> {
+        self.last_run
+    }
 }
 
 impl<F, Args> DynSystem for System<F, Args, ()>
@@ -336,7 +473,7 @@ where
 
         let data = {
             profile_scope!("acquire_data");
-            self.data.acquire(ctx)
+            acquire_data(&mut self.data, &mut self.last_run, ctx)
         };
 
         {
@@ -362,6 +499,83 @@ where
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn last_run(&self) -> Option<u32> {
+        self.last_run
+    }
+}
+
+impl<F, Args> DynSystem for System<F, Args, SystemFuture>
+where
+    Args: for<'x> SystemData<'x>,
+    F: for<'x> SystemFn<'x, <Args as SystemData<'x>>::Value, SystemFuture>,
+{
+    fn execute(&mut self, ctx: &SystemContext<'_, '_, '_>) -> anyhow::Result<()> {
+        profile_function!(self.name());
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("system", name = self.name).entered();
+
+        let data = acquire_data(&mut self.data, &mut self.last_run, ctx);
+
+        let future = self.func.execute(data);
+
+        if let Err(err) = ctx.push_future(future) {
+            return Err(err.context(format!("Failed to execute system: {:?}", self)));
+        }
+
+        Ok(())
+    }
+
+    fn describe(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("async fn ")?;
+        f.write_str(&self.name)?;
+        self.data.describe(f)?;
+
+        Ok(())
+    }
+
+    fn access(&self, world: &World, dst: &mut Vec<Access>) {
+        self.data.access(world, dst)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn last_run(&self) -> Option<u32> {
+        self.last_run
+    }
+}
+
+/// Acquires the system data, observing the changes made since the previous run.
+///
+/// The world change tick is recorded as the last run before the system executes, so that changes
+/// made while it executes, such as by other systems of the same parallel batch, are observed by
+/// the next run.
+fn acquire_data<'a, Args: SystemData<'a>>(
+    data: &'a mut Args,
+    last_run: &mut Option<u32>,
+    ctx: &'a SystemContext<'_, '_, '_>,
+) -> Args::Value {
+    let tick = ctx.change_tick();
+
+    let since = changes_since(*last_run, tick);
+    *last_run = tick.or(*last_run);
+
+    match since {
+        Some(since) => data.acquire_since(ctx, since),
+        None => data.acquire(ctx),
+    }
+}
+
+/// Returns the tick to observe changes since, given the previous run and the current world change
+/// tick.
+///
+/// If the change tick wrapped around since the previous run, the changes can no longer be ordered
+/// against it, and all changes are observed as if the system never ran.
+fn changes_since(last_run: Option<u32>, tick: Option<u32>) -> Option<u32> {
+    last_run.filter(|&last_run| tick.is_none_or(|tick| last_run <= tick))
 }
 
 impl<F, Args, Ret> fmt::Debug for System<F, Args, Ret>
@@ -379,6 +593,7 @@ impl<F, Args, Ret> System<F, Args, Ret> {
             name,
             data,
             func,
+            last_run: None,
             _marker: PhantomData,
         }
     }
@@ -429,9 +644,10 @@ impl<F, Args, Ret> System<F, Args, Ret> {
         let input = input.into_input();
         let ctx = SystemContext::new(world, &mut cmd, &input);
 
-        let data = self.data.acquire(&ctx);
+        let data = acquire_data(&mut self.data, &mut self.last_run, &ctx);
 
         let ret = self.func.execute(data);
+
         ctx.cmd_mut()
             .apply(&mut ctx.world.borrow_mut())
             .expect("Failed to apply commandbuffer");
@@ -486,22 +702,39 @@ impl AccessKind {
 }
 
 /// An access for a component in an archetype
-#[derive(Default, Debug, Clone)]
-#[allow(dead_code)]
+#[derive(Default, Clone)]
 struct ArchetypeAccess {
     arch: ArchetypeInfo,
     components: Vec<ComponentAccessInfo>,
-    change_events: Vec<ComponentAccessInfo>,
 }
 
-#[derive(Debug, Clone)]
-#[allow(dead_code)]
+// The accesses are only ever inspected through `Debug`, which a derive does not count as a read
+impl fmt::Debug for ArchetypeAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchetypeAccess")
+            .field("arch", &self.arch)
+            .field("components", &self.components)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
 struct ComponentAccessInfo {
     mutable: bool,
     name: &'static str,
     id: ComponentKey,
 }
 
+impl fmt::Debug for ComponentAccessInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentAccessInfo")
+            .field("mutable", &self.mutable)
+            .field("name", &self.name)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
 /// Human friendly system access
 #[derive(Default, Debug, Clone)]
 pub struct AccessInfo {
@@ -634,6 +867,13 @@ impl BoxedSystem {
     pub fn name(&self) -> &str {
         self.inner.name()
     }
+
+    /// Returns the world change tick when the previous execution of the system acquired its data.
+    ///
+    /// Change filters of the system's queries yield changes made after this tick.
+    pub fn last_run(&self) -> Option<u32> {
+        self.inner.last_run()
+    }
 }
 
 impl<T> From<T> for BoxedSystem
@@ -649,7 +889,7 @@ where
 #[cfg(feature = "std")]
 mod test {
 
-    use crate::{component, CommandBuffer, Component, EntityBuilder, Query, QueryBorrow, World};
+    use crate::{CommandBuffer, Component, EntityBuilder, Query, QueryBorrow, World};
 
     use super::*;
 
@@ -702,6 +942,95 @@ mod test {
         let _ = res.unwrap_err();
     }
 
+    #[test]
+    fn system_last_run() {
+        use crate::{entity_ids, EntityIds, FetchExt};
+
+        component! {
+            a: i32,
+        }
+
+        let mut world = World::new();
+        let id = EntityBuilder::new().set(a(), 1).spawn(&mut world);
+
+        let seen = SharedResource::new(Vec::new());
+        let mut system = System::builder()
+            .with_query(Query::new(entity_ids()).filter(a().modified()))
+            .with_resource(seen.clone())
+            .build(|mut q: QueryBorrow<EntityIds, _>, seen: &mut Vec<usize>| {
+                seen.push(q.iter().count())
+            })
+            .boxed();
+
+        assert_eq!(system.last_run(), None);
+        system.run(&mut world).unwrap();
+
+        let tick = system.last_run().unwrap();
+        system.run(&mut world).unwrap();
+
+        *world.get_mut(id, a()).unwrap() = 2;
+        system.run(&mut world).unwrap();
+
+        assert!(system.last_run().unwrap() > tick);
+        assert_eq!(*seen.borrow(), [1, 0, 1]);
+    }
+
+    #[test]
+    fn system_last_run_wrapping() {
+        assert_eq!(changes_since(None, Some(5)), None);
+        assert_eq!(changes_since(Some(3), Some(5)), Some(3));
+        assert_eq!(changes_since(Some(3), None), Some(3));
+
+        // The change tick wrapped around
+        assert_eq!(changes_since(Some(u32::MAX - 1), Some(2)), None);
+    }
+
+    #[test]
+    fn for_each_budgeted() {
+        use std::time::Duration;
+
+        component! {
+            a: i32,
+            b: (),
+        }
+
+        let mut world = World::new();
+        for i in 0..6 {
+            let mut builder = EntityBuilder::new();
+            builder.set(a(), i);
+            if i % 2 == 0 {
+                builder.tag(b());
+            }
+            builder.spawn(&mut world);
+        }
+
+        let seen = SharedResource::new(Vec::new());
+        let mut system = System::builder()
+            .with_query(Query::new(a()))
+            // An exhausted budget suspends the system after each batch
+            .for_each_budgeted(Duration::ZERO, 2, {
+                let seen = seen.clone();
+                move |&v| seen.borrow_mut().push(v)
+            });
+
+        // Each archetype contains 3 entities, visited in batches of 2 and 1
+        system.run(&mut world);
+        assert_eq!(seen.borrow().len(), 2);
+
+        system.run(&mut world);
+        system.run(&mut world);
+        assert_eq!(seen.borrow().len(), 5);
+
+        system.run(&mut world);
+        let mut all = seen.borrow().clone();
+        all.sort();
+        assert_eq!(all, [0, 1, 2, 3, 4, 5]);
+
+        // The next pass starts from the beginning
+        system.run(&mut world);
+        assert_eq!(seen.borrow().len(), 8);
+    }
+
     #[test]
     fn system_builder_empty() {
         let mut a = 5;
@@ -714,4 +1043,104 @@ mod test {
 
         assert_eq!(a, 6);
     }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn system_last_run_par() {
+        use std::sync::{Arc, Barrier, Mutex};
+
+        use crate::{entity_ids, EntityIds, FetchExt, Schedule};
+
+        component! {
+            value: i32,
+            pending: (),
+        }
+
+        /// Writes to the pending entities once the reader has acquired its data
+        struct Writer {
+            query: Query<crate::Mutable<i32>, (crate::filter::All, crate::filter::With)>,
+            barrier: Arc<Barrier>,
+        }
+
+        impl DynSystem for Writer {
+            fn name(&self) -> &str {
+                "writer"
+            }
+
+            fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str("writer")
+            }
+
+            fn execute(&mut self, ctx: &SystemContext<'_, '_, '_>) -> anyhow::Result<()> {
+                self.barrier.wait();
+                self.query
+                    .borrow(&ctx.world())
+                    .unwrap()
+                    .for_each(|v| *v += 1);
+                self.barrier.wait();
+                Ok(())
+            }
+
+            fn access(&self, world: &World, dst: &mut Vec<Access>) {
+                self.query.access(world, dst)
+            }
+        }
+
+        let mut world = World::new();
+        let id = EntityBuilder::new()
+            .set(value(), 0)
+            .tag(pending())
+            .spawn(&mut world);
+
+        // Modifications are only recorded once observed, which the reader does not do for the
+        // pending entities
+        Query::new(entity_ids())
+            .filter(value().modified())
+            .borrow(&world)
+            .unwrap()
+            .for_each(|_| {});
+
+        let barrier = Arc::new(Barrier::new(2));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let reader = System::builder()
+            .with_query(
+                Query::new(entity_ids())
+                    .filter(value().modified())
+                    .without(pending()),
+            )
+            .build({
+                let barrier = barrier.clone();
+                let seen = seen.clone();
+                move |mut q: QueryBorrow<EntityIds, _>| {
+                    let ids = q.iter().collect::<Vec<_>>();
+                    // Let the writer execute
+                    barrier.wait();
+                    barrier.wait();
+                    seen.lock().unwrap().push(ids);
+                }
+            });
+
+        let writer = Writer {
+            query: Query::new(value().as_mut()).with(pending()),
+            barrier,
+        };
+
+        let mut schedule = Schedule::from([reader.boxed(), writer.into()]);
+        assert_eq!(schedule.batch_info(&world).len(), 1);
+
+        // Run the systems concurrently regardless of the number of cores
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+
+        pool.install(|| schedule.execute_par(&mut world)).unwrap();
+
+        // The modification made while the reader executed is observed by its next run
+        world.remove(id, pending()).unwrap();
+        pool.install(|| schedule.execute_par(&mut world)).unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), [vec![], vec![id]]);
+    }
 }
@@ -54,6 +54,20 @@ pub trait SystemData<'a>: SystemAccess {
 
     /// Get the data from the system context
     fn acquire(&'a mut self, ctx: &'a SystemContext<'_, '_, '_>) -> Self::Value;
+
+    /// Get the data from the system context, where `last_run` is the change tick when the previous
+    /// execution of the system acquired its data.
+    ///
+    /// Data which tracks changes, such as queries, should use `last_run` as their baseline.
+    fn acquire_since(
+        &'a mut self,
+        ctx: &'a SystemContext<'_, '_, '_>,
+        last_run: u32,
+    ) -> Self::Value {
+        let _ = last_run;
+        self.acquire(ctx)
+    }
+
     /// Human friendly debug description
     fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result;
 }
@@ -103,6 +117,15 @@ macro_rules! tuple_impl {
                 ($((self.$idx).acquire(_ctx),)*)
             }
 
+            #[allow(clippy::unused_unit)]
+            fn acquire_since(
+                &'a mut self,
+                _ctx: &'a SystemContext<'_, '_, '_>,
+                _last_run: u32,
+            ) -> Self::Value {
+                ($((self.$idx).acquire_since(_ctx, _last_run),)*)
+            }
+
             fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
                 core::fmt::Debug::fmt(&($(
                     FmtSystemData(&self.$idx),
@@ -283,8 +306,8 @@ mod test {
     use itertools::Itertools;
 
     use crate::{
-        component, components::name, filter::All, query::QueryData, system::SystemContext,
-        CommandBuffer, Component, Entity, Query, QueryBorrow, World,
+        components::name, filter::All, query::QueryData, system::SystemContext, CommandBuffer,
+        Component, Entity, Query, QueryBorrow, World,
     };
 
     use super::{SystemData, SystemFn, WithWorldMut};
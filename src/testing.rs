@@ -0,0 +1,120 @@
+use alloc::vec::Vec;
+
+use crate::{entity_ids, Entity, EntityBuilder, Fetch, FetchItem, Query, World};
+
+/// Asserts that an entity has the given component values.
+///
+/// Components are given by the name of their component function, and are compared using
+/// `PartialEq` with the provided value.
+///
+/// ```rust
+/// use flax::{assert_entity, component, Entity, World};
+///
+/// component! {
+///     health: f32,
+///     name: String,
+/// }
+///
+/// let mut world = World::new();
+/// let id = Entity::builder()
+///     .set(health(), 100.0)
+///     .set(name(), "Player".into())
+///     .spawn(&mut world);
+///
+/// assert_entity!(world, id, { health: 100.0, name: "Player" });
+/// ```
+#[macro_export]
+macro_rules! assert_entity {
+    ($world: expr, $id: expr, { $($component: ident: $value: expr),* $(,)? }) => {{
+        let world: &$crate::World = &$world;
+        let id: $crate::Entity = $id;
+        $(
+            match world.get(id, $component()) {
+                Ok(value) => {
+                    let expected = $value;
+                    assert!(
+                        *value == expected,
+                        "component `{}` of {} does not match: {:?} != {:?}",
+                        stringify!($component),
+                        id,
+                        &*value,
+                        expected,
+                    )
+                }
+                Err(err) => panic!("{err}"),
+            }
+        )*
+    }};
+}
+
+/// Returns the items of the query for all matched entities, ordered by entity id.
+///
+/// Useful for comparing the state of the world against an expected snapshot.
+pub fn query_snapshot<Q, T>(world: &World, fetch: Q) -> Vec<(Entity, T)>
+where
+    Q: for<'x> Fetch<'x> + for<'x> FetchItem<'x, Item = T>,
+    T: 'static,
+{
+    let mut items = Query::new((entity_ids(), fetch)).collect_vec(world);
+    items.sort_by_key(|v| v.0);
+    items
+}
+
+/// Asserts that the items of the query match `expected`, see [`query_snapshot`]
+#[track_caller]
+pub fn assert_query_snapshot<Q, T>(world: &World, fetch: Q, expected: &[(Entity, T)])
+where
+    Q: for<'x> Fetch<'x> + for<'x> FetchItem<'x, Item = T>,
+    T: 'static + PartialEq + core::fmt::Debug,
+{
+    assert_eq!(query_snapshot(world, fetch), expected);
+}
+
+/// Spawns the given entities into a new world.
+///
+/// The entities are spawned in order, and as such the returned ids are deterministic for the
+/// same input.
+pub fn world_from<I>(entities: I) -> (World, Vec<Entity>)
+where
+    I: IntoIterator,
+    I::Item: Into<EntityBuilder>,
+{
+    let mut world = World::new();
+    let ids = entities
+        .into_iter()
+        .map(|v| Into::<EntityBuilder>::into(v).spawn(&mut world))
+        .collect();
+
+    (world, ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+
+    use crate::FetchExt;
+
+    use super::*;
+
+    component! {
+        a: i32,
+        b: String,
+    }
+
+    #[test]
+    fn snapshot() {
+        let mut builder = Entity::builder();
+        builder.set(a(), 1).set(b(), "Foo".into());
+
+        let (world, ids) = world_from([builder, Entity::builder().set(a(), 2).into()]);
+
+        assert_entity!(world, ids[0], { a: 1, b: "Foo" });
+        assert_entity!(world, ids[1], { a: 2 });
+
+        assert_query_snapshot(&world, a().copied(), &[(ids[0], 1), (ids[1], 2)]);
+        assert_eq!(
+            query_snapshot(&world, b().cloned()),
+            vec![(ids[0], String::from("Foo"))]
+        );
+    }
+}
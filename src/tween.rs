@@ -0,0 +1,361 @@
+//! A tween is started using [`World::tween`], and is advanced by
+//! [`update_tweens`](crate::tween::update_tweens) or the system returned by
+//! [`tween_system`](crate::tween::tween_system).
+//!
+//! A tween is cancelled if the component is written by anything else while it is running, which is
+//! detected through the component's change ticks. This allows gameplay code to take over a value
+//! without having to know about the tween animating it. Starting a new tween for the same entity
+//! and component replaces the previous one.
+//!
+//! [`World::tween_field`] interpolates a single field of a component, leaving the rest of the
+//! value untouched. Components declared [`Immutable`](crate::Immutable) or
+//! [`NoLock`](crate::NoLock) can not be tweened, as tweens write through a shared world.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    archetype::{ChangeKind, Slice},
+    component::{ComponentKey, ComponentValue},
+    error::Result,
+    BoxedSystem, Component, Entity, System, World,
+};
+
+/// A value which can be linearly interpolated
+pub trait Lerp {
+    /// Interpolates between `self` and `other`, where `t = 0` is `self` and `t = 1` is `other`
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
+impl<T: Lerp, const N: usize> Lerp for [T; N] {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        core::array::from_fn(|i| self[i].lerp(&other[i], t))
+    }
+}
+
+/// Easing function applied to the progress of a tween
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Accelerates from zero velocity
+    QuadIn,
+    /// Decelerates to zero velocity
+    QuadOut,
+    /// Accelerates until halfway, then decelerates
+    QuadInOut,
+    /// Accelerates from zero velocity
+    CubicIn,
+    /// Decelerates to zero velocity
+    CubicOut,
+    /// Accelerates until halfway, then decelerates
+    CubicInOut,
+    /// A user supplied function mapping `0..=1` to `0..=1`
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Maps the linear progress `t` in `0..=1` to the eased progress
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Easing::QuadInOut => 1.0 - 2.0 * (1.0 - t) * (1.0 - t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::CubicInOut if t < 0.5 => 4.0 * t * t * t,
+            Easing::CubicInOut => 1.0 - 4.0 * (1.0 - t) * (1.0 - t) * (1.0 - t),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+trait AnyTween: Send + Sync {
+    fn target(&self) -> (Entity, ComponentKey);
+    /// Returns false when the tween is finished or cancelled
+    fn advance(&mut self, world: &World, dt: f32) -> bool;
+}
+
+struct TweenOf<T, F> {
+    id: Entity,
+    component: Component<T>,
+    /// Selects the interpolated field of the component
+    field: fn(&mut T) -> &mut F,
+    from: F,
+    to: F,
+    elapsed: f32,
+    duration: f32,
+    easing: Easing,
+    /// Change tick of the last write made by this tween
+    since: u32,
+}
+
+impl<T, F> AnyTween for TweenOf<T, F>
+where
+    T: ComponentValue,
+    F: 'static + Send + Sync + Lerp,
+{
+    fn target(&self) -> (Entity, ComponentKey) {
+        (self.id, self.component.key())
+    }
+
+    fn advance(&mut self, world: &World, dt: f32) -> bool {
+        let Ok(loc) = world.location(self.id) else {
+            return false;
+        };
+
+        let arch = world.archetypes.get(loc.arch_id);
+        let key = self.component.key();
+
+        // Someone else wrote to the component
+        let Some(storage) = arch.borrow::<T>(key) else {
+            return false;
+        };
+
+        // The entity may have moved to an archetype which is not yet tracking modifications
+        storage.changes().set_track_modified();
+
+        let modified = storage
+            .changes()
+            .get(ChangeKind::Modified)
+            .iter()
+            .find(|v| v.slice.contains(loc.slot))
+            .map(|v| v.tick);
+
+        drop(storage);
+
+        if modified.is_some_and(|tick| tick > self.since) {
+            return false;
+        }
+
+        self.elapsed += dt;
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let value = self.from.lerp(&self.to, self.easing.apply(t));
+
+        let Some(mut storage) = arch.borrow_mut::<T>(key) else {
+            return false;
+        };
+
+        *(self.field)(&mut storage.get_mut()[loc.slot]) = value;
+        storage.set_modified(
+            &[self.id],
+            Slice::single(loc.slot),
+            world.advance_change_tick(),
+        );
+
+        // Marks the tick as observed so that subsequent writes receive a later tick
+        self.since = world.change_tick();
+
+        t < 1.0
+    }
+}
+
+/// The running tweens of a world
+#[derive(Default)]
+pub struct Tweens {
+    inner: Vec<Box<dyn AnyTween>>,
+}
+
+impl Tweens {
+    /// Returns the number of running tweens
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns true if there are no running tweens
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns true if there is a running tween for the component of `id`
+    pub fn is_tweening(&self, id: Entity, component: ComponentKey) -> bool {
+        self.inner.iter().any(|v| v.target() == (id, component))
+    }
+
+    /// Stops the tween of the component of `id`, leaving the value as is.
+    ///
+    /// Returns true if a tween was running.
+    pub fn cancel(&mut self, id: Entity, component: ComponentKey) -> bool {
+        let len = self.inner.len();
+        self.inner.retain(|v| v.target() != (id, component));
+        self.inner.len() != len
+    }
+}
+
+component! {
+    /// The running tweens, stored on the [`tween_registry`] entity
+    pub tweens: Tweens,
+
+    /// Static entity holding the running [`tweens`] of a world
+    pub tween_registry,
+}
+
+pub(crate) fn start<T: ComponentValue + Clone, F: 'static + Send + Sync + Lerp + Clone>(
+    world: &mut World,
+    id: Entity,
+    component: Component<T>,
+    field: fn(&mut T) -> &mut F,
+    target: F,
+    duration: f32,
+    easing: Easing,
+) -> Result<()> {
+    let mut value = world.get(id, component)?.clone();
+    let from = field(&mut value).clone();
+
+    // Writes by others are detected through the modification changes
+    let loc = world.location(id)?;
+    if let Some(storage) = world
+        .archetypes
+        .get(loc.arch_id)
+        .borrow::<T>(component.key())
+    {
+        storage.changes().set_track_modified();
+    }
+
+    let tween = TweenOf {
+        id,
+        component,
+        field,
+        from,
+        to: target,
+        elapsed: 0.0,
+        duration,
+        easing,
+        since: world.change_tick(),
+    };
+
+    let mut tweens = world.entry(tween_registry(), tweens())?.or_default();
+    tweens.cancel(id, component.key());
+    tweens.inner.push(Box::new(tween));
+
+    Ok(())
+}
+
+/// Advances all running tweens by `dt` seconds, removing finished and cancelled ones
+pub fn update_tweens(world: &World, dt: f32) {
+    let Ok(mut tweens) = world.get_mut(tween_registry(), tweens()) else {
+        return;
+    };
+
+    if tweens.is_empty() {
+        return;
+    }
+
+    tweens.inner.retain_mut(|v| v.advance(world, dt));
+}
+
+/// Returns a system which advances all tweens by a fixed `dt` each run
+pub fn tween_system(dt: f32) -> BoxedSystem {
+    System::builder()
+        .with_name("update_tweens")
+        .with_world()
+        .build(move |world: &World| update_tweens(world, dt))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Error;
+
+    use super::*;
+
+    component! {
+        position: [f32; 2],
+        scale: f32,
+        frozen: f32 => [ crate::Immutable ],
+        unlocked: f32 => [ crate::NoLock ],
+    }
+
+    #[test]
+    fn tween() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(position(), [0.0, 0.0])
+            .set(scale(), 1.0)
+            .spawn(&mut world);
+
+        world
+            .tween(id, position(), [4.0, 8.0], 1.0, Easing::Linear)
+            .unwrap();
+        world.tween(id, scale(), 3.0, 1.0, Easing::QuadIn).unwrap();
+
+        update_tweens(&world, 0.25);
+        assert_eq!(*world.get(id, position()).unwrap(), [1.0, 2.0]);
+        assert_eq!(*world.get(id, scale()).unwrap(), 1.125);
+
+        // Take over the scale
+        *world.get_mut(id, scale()).unwrap() = 0.5;
+
+        let mut system = tween_system(0.5);
+        system.run(&mut world).unwrap();
+
+        assert_eq!(*world.get(id, position()).unwrap(), [3.0, 6.0]);
+        assert_eq!(*world.get(id, scale()).unwrap(), 0.5);
+        assert_eq!(world.get(tween_registry(), tweens()).unwrap().len(), 1);
+
+        system.run(&mut world).unwrap();
+        assert_eq!(*world.get(id, position()).unwrap(), [4.0, 8.0]);
+        assert!(world.get(tween_registry(), tweens()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn tween_field() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(position(), [0.0, 2.0])
+            .spawn(&mut world);
+
+        world
+            .tween_field(id, position(), |v| &mut v[0], 4.0, 1.0, Easing::Linear)
+            .unwrap();
+
+        update_tweens(&world, 0.5);
+        assert_eq!(*world.get(id, position()).unwrap(), [2.0, 2.0]);
+
+        update_tweens(&world, 0.5);
+        assert_eq!(*world.get(id, position()).unwrap(), [4.0, 2.0]);
+        assert!(world.get(tween_registry(), tweens()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reject_unwritable() {
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(frozen(), 0.0)
+            .set(unlocked(), 0.0)
+            .spawn(&mut world);
+
+        assert_eq!(
+            world.tween(id, frozen(), 1.0, 1.0, Easing::Linear),
+            Err(Error::ImmutableComponent(frozen().desc()))
+        );
+        assert_eq!(
+            world.tween(id, unlocked(), 1.0, 1.0, Easing::Linear),
+            Err(Error::NoLockComponent(unlocked().desc()))
+        );
+
+        assert!(world.get(tween_registry(), tweens()).is_err());
+    }
+}
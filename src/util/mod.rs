@@ -95,6 +95,22 @@ impl<T> TuplePush<T> for All {
     }
 }
 
+/// Hints the cpu to load the cache line containing `ptr`.
+///
+/// Does nothing unless the `prefetch` feature is enabled on a supported target.
+#[inline(always)]
+pub(crate) fn prefetch<T>(ptr: *const T) {
+    #[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+    // Safety: prefetching is a hint which never faults, regardless of the address
+    unsafe {
+        use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+        _mm_prefetch::<_MM_HINT_T0>(ptr.cast())
+    }
+
+    #[cfg(not(all(feature = "prefetch", target_arch = "x86_64")))]
+    let _ = ptr;
+}
+
 #[doc(hidden)]
 /// A lifetime annotated covariant pointer
 pub struct Ptr<'a, T> {
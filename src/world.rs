@@ -1,36 +1,67 @@
-use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
 use core::{
+    any::TypeId,
     fmt,
     fmt::Formatter,
     mem::{self, MaybeUninit},
+    ptr,
     sync::atomic::{AtomicBool, AtomicU32, Ordering, Ordering::Relaxed},
 };
 use once_cell::unsync::OnceCell;
 use smallvec::SmallVec;
 
+use anyhow::Context;
 use atomic_refcell::{AtomicRef, BorrowError, BorrowMutError};
 use itertools::Itertools;
 
 use crate::{
-    archetype::{Archetype, ArchetypeId, ArchetypeInfo, Slot},
+    archetype::{
+        Archetype, ArchetypeId, ArchetypeInfo, ChangeKind, ChangeRecord, Slice, Slot,
+        StoragePoolStats,
+    },
     archetypes::Archetypes,
     buffer::ComponentBuffer,
     component::{dummy, ComponentDesc, ComponentKey, ComponentValue},
     components::{self, component_info, is_static, name},
-    entity::{entity_ids, Entity, EntityIndex, EntityKind, EntityLocation, EntityStore},
+    entity::{
+        entity_ids, EditBuilder, Entity, EntityIndex, EntityKind, EntityLocation, EntityStore,
+        EntityTree,
+    },
     entity_ref::{EntityRef, EntityRefMut},
     entry::{Entry, OccupiedEntry, VacantEntry},
     error::{MissingComponent, Result},
-    events::EventSubscriber,
-    filter::StaticFilter,
+    events::{EntityEventKind, EventSubscriber},
+    fetch::{EntityLoc, FetchAccessData, FetchPrepareData, PreparedFetch, QueryItemHandle},
+    filter::{next_slice, All, Filtered, StaticFilter},
     format::{EntitiesFormatter, HierarchyFormatter, WorldFormatter},
-    relation::{Relation, RelationExt},
+    metadata::{
+        cloneable, insert_object_default, is_immutable, is_no_lock, is_transient, is_unique,
+        required_components,
+    },
+    reflect::{self, Value},
+    relation::{EdgeIndex, Multi, Relation, RelationExt},
+    snapshot::{snapshot_channel, SnapshotReader, SnapshotSource},
+    tween::{self, Easing, Lerp},
+    world_cell::{CellAccess, WorldCell},
     writer::{
         self, EntityWriter, FnWriter, Replace, ReplaceDyn, SingleComponentWriter, WriteDedup,
     },
-    BatchSpawn, Component, ComponentVTable, Error, Fetch, Query, RefMut,
+    BatchSpawn, Blob, CommandBuffer, Component, ComponentVTable, Error, Fetch, FetchExt, FetchItem,
+    Query, RefMut,
 };
 
+#[cfg(feature = "flume")]
+use crate::index::{LabelMap, ValueIndex};
+
+#[cfg(feature = "leak-detection")]
+use crate::archetype::ComponentCounts;
+
 #[derive(Debug, Default)]
 struct EntityStores {
     inner: BTreeMap<EntityKind, EntityStore>,
@@ -54,6 +85,66 @@ impl EntityStores {
     }
 }
 
+#[track_caller]
+fn assert_user_kind(kind: EntityKind) {
+    assert!(
+        EntityKind::USER.contains(kind),
+        "Only user defined kinds can be used when spawning entities, found: {kind:?}"
+    );
+}
+
+/// Fails if the component is declared [`Immutable`](crate::Immutable) or stores [`Blob`]s, which
+/// can not be accessed mutably
+fn ensure_mutable<T: ComponentValue>(component: Component<T>) -> Result<()> {
+    let desc = component.desc();
+    if is_immutable(&desc) || desc.is::<Blob>() {
+        Err(Error::ImmutableComponent(component.desc()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Fails if the component is declared [`NoLock`](crate::NoLock), and can as such not be written
+/// through a shared world
+fn ensure_locked<T: ComponentValue>(component: Component<T>) -> Result<()> {
+    if is_no_lock(&component.desc()) {
+        Err(Error::NoLockComponent(component.desc()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns a component of `arch` which [requires](crate::metadata::Requires) `component`, if any
+fn find_dependent(arch: &Archetype, component: ComponentKey) -> Option<ComponentDesc> {
+    arch.components_desc().find(|v| {
+        v.meta_ref()
+            .get(required_components())
+            .is_some_and(|v| v.iter().any(|v| v.desc().key() == component))
+    })
+}
+
+/// Records `count` entities moving from `src` to `dst`
+fn record_migration(
+    migrations: &mut BTreeMap<ComponentKey, MigrationStats>,
+    src: &Archetype,
+    dst: &Archetype,
+    count: u64,
+) {
+    for desc in dst.components_desc().filter(|v| !src.has(v.key())) {
+        migrations
+            .entry(desc.key())
+            .or_insert_with(|| MigrationStats::new(desc))
+            .added += count;
+    }
+
+    for desc in src.components_desc().filter(|v| !dst.has(v.key())) {
+        migrations
+            .entry(desc.key())
+            .or_insert_with(|| MigrationStats::new(desc))
+            .removed += count;
+    }
+}
+
 pub(crate) fn update_entity_loc(
     world: &mut World,
     id: Entity,
@@ -85,6 +176,26 @@ pub struct World {
     entities: EntityStores,
     pub(crate) archetypes: Archetypes,
     change_tick: AtomicU32,
+    /// Fixed change tick used while inside [`World::batch_scope`]
+    batch_tick: Option<u32>,
+    /// Value indexes created through [`World::create_index`]
+    #[cfg(feature = "flume")]
+    indexes: BTreeMap<ComponentKey, Arc<dyn core::any::Any + Send + Sync>>,
+    /// Number of archetype migrations caused by each component
+    migrations: BTreeMap<ComponentKey, MigrationStats>,
+    /// Validators added through [`World::add_spawn_validator`]
+    spawn_validators: Vec<Box<SpawnValidator>>,
+    /// Applied at the next [`World::maintain`]
+    commands: CommandBuffer,
+    maintain_policy: MaintainPolicy,
+    /// The change tick at which events started being deferred through [`World::defer_events`]
+    deferred_since: Option<u32>,
+    /// Components marked as [`Unique`](crate::metadata::Unique), by type
+    unique_components: BTreeMap<TypeId, ComponentDesc>,
+    /// Components excluded from inspection through [`World::hide_component`]
+    hidden_components: BTreeSet<Entity>,
+    /// Snapshots created through [`World::create_snapshot`]
+    snapshots: Vec<Box<dyn SnapshotSource>>,
 
     has_reserved: AtomicBool,
 }
@@ -96,6 +207,17 @@ impl World {
             entities: EntityStores::new(),
             archetypes: Archetypes::new(),
             change_tick: AtomicU32::new(0b11),
+            batch_tick: None,
+            #[cfg(feature = "flume")]
+            indexes: BTreeMap::new(),
+            migrations: BTreeMap::new(),
+            spawn_validators: Vec::new(),
+            commands: CommandBuffer::new(),
+            maintain_policy: MaintainPolicy::default(),
+            deferred_since: None,
+            unique_components: BTreeMap::new(),
+            hidden_components: BTreeSet::new(),
+            snapshots: Vec::new(),
             has_reserved: AtomicBool::new(false),
         }
     }
@@ -113,7 +235,7 @@ impl World {
     /// The returned entity ids can be used directly by functions such as [ `set` ]( World::set ) and
     /// [ `spawn_at` ]( World::spawn_at ), but will not be yielded by queries until properly spawned by
     /// by adding a component or using spawn_at.
-    pub fn reserve(&self, kind: EntityKind, count: usize) -> ReservedEntityIter {
+    pub fn reserve(&self, kind: EntityKind, count: usize) -> ReservedEntityIter<'_> {
         self.has_reserved.store(true, Relaxed);
         let iter = self.entities.get(kind).unwrap().reserve(count);
         ReservedEntityIter(iter)
@@ -132,8 +254,20 @@ impl World {
             .0
     }
 
+    /// Spawn a new empty entity with the user defined `kind`.
+    ///
+    /// The kind is part of the entity id, and can be used to cheaply partition entities without
+    /// additional components using [`kind_filter`](crate::filter::kind_filter).
+    ///
+    /// Panics if `kind` contains any non-user bits.
+    pub fn spawn_kind(&mut self, kind: EntityKind) -> Entity {
+        assert_user_kind(kind);
+
+        self.spawn_inner(self.archetypes.root, kind).0
+    }
+
     /// Spawn a new empty entity and acquire an entity reference.
-    pub fn spawn_ref(&mut self) -> EntityRefMut {
+    pub fn spawn_ref(&mut self) -> EntityRefMut<'_> {
         profile_function!();
         let (id, loc, _) = self.spawn_inner(self.archetypes.root, EntityKind::empty());
         EntityRefMut {
@@ -145,7 +279,16 @@ impl World {
 
     /// Efficiently spawn many entities with the same components at once.
     pub fn spawn_batch(&mut self, chunk: &mut BatchSpawn) -> Vec<Entity> {
+        self.spawn_batch_kind(chunk, EntityKind::empty())
+    }
+
+    pub(crate) fn spawn_batch_kind(
+        &mut self,
+        chunk: &mut BatchSpawn,
+        kind: EntityKind,
+    ) -> Vec<Entity> {
         profile_function!();
+        assert_user_kind(kind);
         self.flush_reserved();
 
         for component in chunk.components() {
@@ -157,7 +300,7 @@ impl World {
         let (arch_id, arch) = self.archetypes.find_create(chunk.components());
 
         let base = arch.len();
-        let store = self.entities.init(EntityKind::empty());
+        let store = self.entities.init(kind);
 
         let ids = (0..chunk.len())
             .map(|idx| {
@@ -170,10 +313,11 @@ impl World {
 
         let _ = arch.allocate_n(&ids);
 
-        for (_, mut storage) in chunk.take_all() {
-            unsafe {
-                arch.extend(&mut storage, change_tick);
-            }
+        unsafe { arch.extend_all(chunk.take_all().map(|(_, v)| v), change_tick) }
+
+        for &id in &ids {
+            self.archetypes
+                .entity_event(id, arch_id, EntityEventKind::Spawned);
         }
 
         ids
@@ -211,8 +355,12 @@ impl World {
         let loc = store.spawn_at(id.index, id.gen, EntityLocation { slot: 0, arch_id })?;
 
         loc.slot = arch.allocate(id);
+        let loc = *loc;
 
-        Ok((*loc, arch))
+        self.archetypes
+            .entity_event(id, arch_id, EntityEventKind::Spawned);
+
+        Ok((loc, self.archetypes.get_mut(arch_id)))
     }
 
     pub(crate) fn spawn_at_with(
@@ -220,6 +368,8 @@ impl World {
         id: Entity,
         buffer: &mut ComponentBuffer,
     ) -> Result<(Entity, EntityLocation)> {
+        self.fill_required(id, buffer)?;
+
         let change_tick = self.advance_change_tick();
 
         for &component in buffer.components() {
@@ -229,9 +379,7 @@ impl World {
         let (arch_id, _) = self.archetypes.find_create(buffer.components().copied());
         let (loc, arch) = self.spawn_at_inner(id, arch_id)?;
 
-        for (desc, src) in buffer.drain() {
-            unsafe { arch.push(desc.key(), src, change_tick) }
-        }
+        unsafe { arch.push_all(buffer.drain(), change_tick) }
 
         Ok((id, loc))
     }
@@ -239,7 +387,16 @@ impl World {
     /// Spawn an entity with the given components.
     ///
     /// For increased ergonomics, prefer [crate::EntityBuilder]
-    pub(crate) fn spawn_with(&mut self, buffer: &mut ComponentBuffer) -> Entity {
+    ///
+    /// Panics if a required component is missing and has no default, which callers are expected
+    /// to check beforehand through [`Self::check_required`].
+    pub(crate) fn spawn_with(&mut self, buffer: &mut ComponentBuffer, kind: EntityKind) -> Entity {
+        assert_user_kind(kind);
+
+        if let Err(err) = self.fill_required(dummy(), buffer) {
+            panic!("Failed to spawn entity: {err}");
+        }
+
         for component in buffer.components() {
             self.init_component(*component);
         }
@@ -247,13 +404,9 @@ impl World {
         let change_tick = self.advance_change_tick();
         let (arch_id, _) = self.archetypes.find_create(buffer.components().copied());
 
-        let (id, _, arch) = self.spawn_inner(arch_id, EntityKind::empty());
+        let (id, _, arch) = self.spawn_inner(arch_id, kind);
 
-        for (desc, src) in buffer.drain() {
-            unsafe {
-                arch.push(desc.key, src, change_tick);
-            }
-        }
+        unsafe { arch.push_all(buffer.drain(), change_tick) }
 
         id
     }
@@ -267,6 +420,8 @@ impl World {
             .get_disjoint(arch_id, self.archetypes.root)
             .unwrap();
 
+        record_migration(&mut self.migrations, src, dst, 1);
+
         let (dst_slot, swapped) = unsafe { src.move_to(dst, slot, |c, p| c.drop(p)) };
 
         if let Some((swapped, slot)) = swapped {
@@ -288,9 +443,345 @@ impl World {
         Ok(())
     }
 
+    /// Splits the world into cells which each access a disjoint set of components.
+    ///
+    /// The cells can be used simultaneously, such as from the jobs of an external job system,
+    /// without going through a [`Schedule`](crate::Schedule).
+    ///
+    /// Fails if a component is written by one cell and read or written by another.
+    pub fn split<const N: usize>(
+        &mut self,
+        accesses: [CellAccess; N],
+    ) -> Result<[WorldCell<'_>; N]> {
+        WorldCell::split(self, accesses)
+    }
+
+    /// Adds a validator which is invoked with the components of each entity spawned or appended to
+    /// through an [`EntityBuilder`](crate::EntityBuilder), before the world is modified.
+    ///
+    /// When appending, the components already present on the entity are included. Components
+    /// added through [`Requires`](crate::metadata::Requires) are not yet present, nor are the
+    /// relations to the parent of attached children.
+    ///
+    /// Returning an error rejects the entity, which causes
+    /// [`EntityBuilder::spawn`](crate::EntityBuilder::spawn) to panic, and the fallible methods
+    /// to return [`Error::SpawnRejected`].
+    pub fn add_spawn_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&[ComponentDesc]) -> core::result::Result<(), String> + Send + Sync + 'static,
+    {
+        self.spawn_validators.push(Box::new(validator))
+    }
+
+    /// Runs the spawn validators for `buffer`, including the existing components of `id`.
+    pub(crate) fn validate_spawn(
+        &self,
+        id: Option<Entity>,
+        buffer: &ComponentBuffer,
+    ) -> Result<()> {
+        if self.spawn_validators.is_empty() {
+            return Ok(());
+        }
+
+        let mut components = buffer.components().copied().collect_vec();
+        if let Some(id) = id {
+            let loc = self.location(id)?;
+            components.extend(self.archetypes.get(loc.arch_id).components_desc());
+            components.sort();
+            components.dedup();
+        }
+
+        for validator in &self.spawn_validators {
+            validator(&components).map_err(Error::SpawnRejected)?;
+        }
+
+        Ok(())
+    }
+
     /// Prune empty archetypes, returning the number of archetypes removed
     pub fn prune_archetypes(&mut self) -> usize {
-        self.archetypes.prune_all()
+        self.archetypes.prune(PrunePolicy::Aggressive)
+    }
+
+    /// Prune empty archetypes according to `policy`, returning the number of archetypes removed.
+    ///
+    /// [`Self::maintain`] prunes automatically using the policy set through
+    /// [`Self::set_maintain_policy`], which defaults to never pruning. Pruning too eagerly can
+    /// cause thrashing when entities repeatedly leave and re-enter a rarely populated archetype,
+    /// in which case a more conservative policy keeps those archetypes around.
+    pub fn prune_archetypes_with(&mut self, policy: PrunePolicy) -> usize {
+        self.archetypes.prune(policy)
+    }
+
+    /// Returns statistics of the pool which recycles the storage allocations of pruned
+    /// archetypes.
+    ///
+    /// Allocations are reused when archetypes are created or grow, which avoids going through
+    /// the global allocator when archetypes are repeatedly pruned and recreated, such as during
+    /// level transitions.
+    pub fn storage_pool_stats(&self) -> StoragePoolStats {
+        self.archetypes.pool.stats()
+    }
+
+    /// Frees the allocations held by the storage pool, returning the number of bytes freed.
+    ///
+    /// See [`Self::storage_pool_stats`]
+    pub fn trim_storage_pool(&mut self) -> usize {
+        self.archetypes.pool.trim()
+    }
+
+    /// Sets the maximum number of bytes retained by the storage pool, and frees the allocations
+    /// above it.
+    ///
+    /// Allocations which would exceed the limit are freed rather than pooled. Defaults to 64 MiB.
+    pub fn set_storage_pool_limit(&mut self, max_bytes: usize) {
+        self.archetypes.pool.set_limit(max_bytes)
+    }
+
+    /// Returns the number of values of each component which have been moved into and out of the
+    /// world.
+    ///
+    /// Values are counted when they are inserted into an archetype, and when they are dropped or
+    /// taken back out, such as when the component is removed or the entity despawned. Moving an
+    /// entity between archetypes does not affect the counts.
+    ///
+    /// When the world is dropped all counts are asserted to balance, which catches values
+    /// which were leaked, or dropped twice, by unsafe code or hooks.
+    #[cfg(feature = "leak-detection")]
+    pub fn debug_component_counts(&self) -> BTreeMap<ComponentKey, ComponentCounts> {
+        self.archetypes.pool.component_counts()
+    }
+
+    /// Returns how often removing components found the destination archetype through a cached
+    /// edge rather than by searching for its component set.
+    pub fn edge_cache_stats(&self) -> EdgeCacheStats {
+        self.archetypes.edge_stats
+    }
+
+    /// Returns how many times entities have moved between archetypes due to each component being
+    /// added or removed, with the most frequent first.
+    ///
+    /// Components which are frequently toggled, such as markers, cause archetype thrashing and
+    /// are often better expressed as a value.
+    pub fn migration_stats(&self) -> Vec<MigrationStats> {
+        self.migrations
+            .values()
+            .copied()
+            .sorted_by_key(|v| core::cmp::Reverse(v.total()))
+            .collect_vec()
+    }
+
+    /// Resets the counters returned by [`Self::migration_stats`]
+    pub fn reset_migration_stats(&mut self) {
+        self.migrations.clear()
+    }
+
+    /// Performs the end-of-frame bookkeeping, which provides a single well defined frame
+    /// boundary.
+    ///
+    /// In order, this:
+    /// - applies the commands recorded in [`Self::deferred_commands`]
+    /// - flushes the [reserved](Self::reserve) entities
+    /// - removes all [`Transient`](crate::metadata::Transient) components
+    /// - prunes empty archetypes according to [`MaintainPolicy::prune`]
+    /// - compacts the storage of [`Blob`] components, see [`Self::compact_blobs`]
+    /// - dispatches the events deferred through [`Self::defer_events`]
+    /// - flushes the events held back by subscribers, such as coalesced events
+    /// - publishes the snapshots created through [`Self::create_snapshot`]
+    /// - discards change records older than [`MaintainPolicy::change_retention`]
+    ///
+    /// If a command fails the remaining commands are discarded, but the other steps are still
+    /// performed.
+    pub fn maintain(&mut self) -> anyhow::Result<()> {
+        profile_function!();
+        let res = mem::take(&mut self.commands)
+            .apply(self)
+            .context("Failed to apply deferred commands");
+
+        self.flush_reserved();
+        self.remove_transient();
+        self.archetypes.prune(self.maintain_policy.prune);
+        self.compact_blobs();
+
+        // The deferred events and snapshots are read from the change records, which must as such
+        // outlive them
+        if let Some(since) = self.deferred_since.take() {
+            self.archetypes.flush_deferred(since);
+        }
+
+        self.archetypes.flush_subscribers();
+        self.publish_snapshots();
+
+        if let Some(retention) = self.maintain_policy.change_retention {
+            let tick = self.change_tick().saturating_sub(retention);
+            self.archetypes.discard_changes(tick);
+        }
+
+        res
+    }
+
+    /// Creates a snapshot of the values of `component` which can be read without blocking, or
+    /// being blocked by, any access to the world.
+    ///
+    /// A new snapshot is published at each [`Self::maintain`], while readers holding an older
+    /// snapshot keep it alive until they are done with it. This is intended for threads such as
+    /// audio or rendering which must not wait on the simulation.
+    ///
+    /// Publishing stops once all readers have been dropped.
+    pub fn create_snapshot<T: ComponentValue + Clone>(
+        &mut self,
+        component: Component<T>,
+    ) -> SnapshotReader<T> {
+        let (writer, reader) = snapshot_channel(self, component);
+        self.snapshots.push(writer);
+        reader
+    }
+
+    /// Publishes new snapshots for [`Self::create_snapshot`] outside of [`Self::maintain`]
+    pub fn publish_snapshots(&mut self) {
+        profile_function!();
+        self.snapshots.retain(|v| !v.is_orphaned());
+        for snapshot in &self.snapshots {
+            snapshot.publish(self);
+        }
+    }
+
+    /// Moves newly set [`Blob`] payloads into the slab of their archetype, and reclaims the bytes
+    /// of blobs which were replaced or moved to another archetype.
+    ///
+    /// Returns the number of bytes reclaimed.
+    pub fn compact_blobs(&mut self) -> usize {
+        self.archetypes
+            .iter_mut()
+            .flat_map(|(_, arch)| arch.cells_mut().iter_mut())
+            .map(|cell| cell.data.get_mut().compact_blobs())
+            .sum()
+    }
+
+    /// Access the payload of a [`Blob`] component
+    pub fn get_blob(&self, id: Entity, component: Component<Blob>) -> Result<AtomicRef<'_, [u8]>> {
+        let loc = self.location(id)?;
+
+        self.archetypes
+            .get(loc.arch_id)
+            .cell(component.key())
+            .and_then(|cell| AtomicRef::filter_map(cell.data.borrow(), |v| v.blob(loc.slot)))
+            .ok_or_else(|| {
+                Error::MissingComponent(MissingComponent {
+                    id,
+                    desc: component.desc(),
+                })
+            })
+    }
+
+    /// Updates the payload of a [`Blob`] component in place
+    pub fn update_blob<U>(
+        &self,
+        id: Entity,
+        component: Component<Blob>,
+        f: impl FnOnce(&mut [u8]) -> U,
+    ) -> Result<U> {
+        if is_immutable(&component.desc()) {
+            return Err(Error::ImmutableComponent(component.desc()));
+        }
+
+        let tick = self.advance_change_tick();
+        let loc = self.location(id)?;
+
+        let cell = self
+            .archetypes
+            .get(loc.arch_id)
+            .cell(component.key())
+            .ok_or_else(|| {
+                Error::MissingComponent(MissingComponent {
+                    id,
+                    desc: component.desc(),
+                })
+            })?;
+
+        let mut data = cell.lock_data();
+        let res = f(data.blob_mut(loc.slot).expect("Invalid slot"));
+        data.set_modified(&[id], Slice::single(loc.slot), tick);
+
+        Ok(res)
+    }
+
+    /// Sets the bookkeeping performed by [`Self::maintain`]
+    pub fn set_maintain_policy(&mut self, policy: MaintainPolicy) {
+        self.maintain_policy = policy;
+    }
+
+    /// Returns the bookkeeping performed by [`Self::maintain`]
+    pub fn maintain_policy(&self) -> MaintainPolicy {
+        self.maintain_policy
+    }
+
+    /// Returns a command buffer which is applied at the next [`Self::maintain`]
+    pub fn deferred_commands(&mut self) -> &mut CommandBuffer {
+        &mut self.commands
+    }
+
+    /// Dispatches the events of a component to its subscribers in parallel when at least
+    /// `min_subscribers` are subscribed to it, or always serially if `None`, which is the default.
+    ///
+    /// The events of a single operation, such as spawning or despawning an entity, are dispatched
+    /// together. Each subscriber still receives its events in order, as all subscribers have
+    /// handled the events of an operation before it returns. Subscribers which do expensive work
+    /// per event benefit the most, as a parallel dispatch has a fixed cost per operation.
+    #[cfg(feature = "rayon")]
+    pub fn set_parallel_dispatch(&mut self, min_subscribers: Option<usize>) {
+        self.archetypes
+            .set_parallel_threshold(min_subscribers.unwrap_or(usize::MAX));
+    }
+
+    /// Defers added and modified events until the next [`Self::maintain`].
+    ///
+    /// Each subscriber then receives a single coalesced set of events for the whole frame, where a
+    /// component which was both added and modified is only reported as added.
+    ///
+    /// **Note**: removal events are still dispatched immediately as the removed values are not
+    /// available afterwards.
+    pub fn defer_events(&mut self) {
+        if self.deferred_since.is_some() {
+            return;
+        }
+
+        self.deferred_since = Some(self.change_tick());
+        self.archetypes.defer_events();
+    }
+
+    /// Moves all entities out of archetypes with transient components, one archetype at a time.
+    fn remove_transient(&mut self) {
+        let archetypes = self
+            .archetypes
+            .iter()
+            .filter(|(_, arch)| {
+                !arch.is_empty() && arch.components_desc().any(|v| is_transient(&v))
+            })
+            .map(|(arch_id, _)| arch_id)
+            .collect_vec();
+
+        for src_id in archetypes {
+            let removed = self
+                .archetypes
+                .get(src_id)
+                .components_desc()
+                .filter(is_transient)
+                .map(|v| v.key())
+                .collect_vec();
+
+            let dst_id = self.archetypes.find_removed(src_id, &removed);
+            let (src, dst) = self.archetypes.get_disjoint(src_id, dst_id).unwrap();
+
+            record_migration(&mut self.migrations, src, dst, src.len() as u64);
+
+            for (id, slot) in src.move_all(dst) {
+                *self.location_mut(id).expect("Entity id was not valid") = EntityLocation {
+                    slot,
+                    arch_id: dst_id,
+                }
+            }
+        }
     }
 
     pub(crate) fn retain_entity_components(
@@ -301,13 +792,23 @@ impl World {
     ) -> EntityLocation {
         let src = self.archetypes.get(loc.arch_id);
 
-        let dst_components: SmallVec<[ComponentDesc; 8]> =
-            src.components_desc().filter(|v| f(v.key())).collect();
+        let removed: SmallVec<[ComponentKey; 8]> = src
+            .components()
+            .keys()
+            .copied()
+            .filter(|&v| !f(v))
+            .collect();
+
+        if removed.is_empty() {
+            return loc;
+        }
 
-        let (dst_id, _) = self.archetypes.find_create(dst_components);
+        let dst_id = self.archetypes.find_removed(loc.arch_id, &removed);
 
         let (src, dst) = self.archetypes.get_disjoint(loc.arch_id, dst_id).unwrap();
 
+        record_migration(&mut self.migrations, src, dst, 1);
+
         let (dst_slot, swapped) = unsafe { src.move_to(dst, loc.slot, |c, p| c.drop(p)) };
 
         if let Some((swapped, slot)) = swapped {
@@ -329,6 +830,26 @@ impl World {
         loc
     }
 
+    /// Registers a component, eagerly creating its metadata entity.
+    ///
+    /// Components are otherwise registered lazily when first added to an entity. Registering them
+    /// up front allows tools to present all available components through
+    /// [`Self::registered_components`] before any entity uses them.
+    pub fn register<T: ComponentValue>(&mut self, component: Component<T>) -> ComponentDesc {
+        let desc = component.desc();
+        self.init_component(desc);
+        desc
+    }
+
+    /// Returns all registered components
+    pub fn registered_components(&self) -> impl Iterator<Item = ComponentDesc> + '_ {
+        self.archetypes.iter().flat_map(|(_, arch)| {
+            arch.borrow::<ComponentDesc>(component_info().key())
+                .map(|v| v.get().to_vec())
+                .unwrap_or_default()
+        })
+    }
+
     /// Set metadata for a given component if they do not already exist
     pub(crate) fn init_component(&mut self, desc: ComponentDesc) {
         assert!(
@@ -348,9 +869,25 @@ impl World {
         if id.is_static() {
             meta.set(is_static(), ());
         }
+        if desc.key().target.is_none() && is_unique(&desc) {
+            if let Some(prev) = self.unique_components.insert(desc.type_id(), desc) {
+                assert_eq!(
+                    prev.key(),
+                    desc.key(),
+                    "Multiple unique components of type {}",
+                    desc.type_name()
+                );
+            }
+        }
+
+        // Registering a component is not a migration the user would want to see in the stats
+        let migrations = mem::take(&mut self.migrations);
+
         self.spawn_at(id).unwrap();
 
         self.set_with(id, &mut meta).unwrap();
+
+        self.migrations = migrations;
     }
 
     /// Despawn an entity.
@@ -358,10 +895,16 @@ impl World {
     pub fn despawn(&mut self, id: Entity) -> Result<()> {
         profile_function!();
         self.flush_reserved();
+        let loc = self.init_location(id)?;
+        self.despawn_at(id, loc)
+    }
+
+    /// Despawns an entity at a known location
+    pub(crate) fn despawn_at(&mut self, id: Entity, loc: EntityLocation) -> Result<()> {
         let EntityLocation {
             arch_id: arch,
             slot,
-        } = self.init_location(id)?;
+        } = loc;
 
         // if id.is_static() {
         //     panic!("Attempt to despawn static component");
@@ -386,19 +929,22 @@ impl World {
 
         // self.archetypes.prune_arch(arch);
         self.entities.init(id.kind()).despawn(id)?;
+        self.archetypes
+            .entity_event(id, arch, EntityEventKind::Despawned);
         self.detach(id);
         Ok(())
     }
 
-    /// Despawns all entities which matches the filter
+    /// Despawns all entities which matches the filter, including
+    /// [`disabled`](crate::components::disabled) entities
     pub fn despawn_many<F>(&mut self, filter: F)
     where
         F: for<'x> Fetch<'x>,
     {
         profile_function!();
         self.flush_reserved();
-        let mut query = Query::new(entity_ids()).filter(filter);
-        let ids = query.borrow(self).iter().collect_vec();
+        let mut query = Query::new(entity_ids()).filter(filter).include_disabled();
+        let ids = query.borrow_unchecked(self).iter().collect_vec();
 
         for id in ids {
             self.despawn(id).expect("Invalid entity id");
@@ -446,6 +992,8 @@ impl World {
                 stack.extend(arch.entities());
                 for &id in arch.entities() {
                     self.entities.init(id.kind()).despawn(id).unwrap();
+                    self.archetypes
+                        .entity_event(id, arch_id, EntityEventKind::Despawned);
                 }
                 self.archetypes.despawn(arch_id).clear();
             }
@@ -478,6 +1026,8 @@ impl World {
 
             let (dst_id, dst) = self.archetypes.find_create(components);
 
+            record_migration(&mut self.migrations, &src, dst, src.len() as u64);
+
             for (id, slot) in src.move_all(dst) {
                 *self.location_mut(id).expect("Entity id was not valid") = EntityLocation {
                     slot,
@@ -487,6 +1037,70 @@ impl World {
         }
     }
 
+    /// Replaces `old` with `new` as the target of all relations, such as after an entity was
+    /// transplanted or remapped during deserialization.
+    ///
+    /// The archetypes with relations targeting `old` are found through the archetype index, and
+    /// each is moved in a single pass. The relation values are kept, and reported as removed for
+    /// `old` and added for `new`. If an entity already has the same relation to `new`, that value
+    /// is kept and the relation to `old` is removed.
+    pub fn retarget_relations(&mut self, old: Entity, new: Entity) {
+        profile_function!();
+        if old == new {
+            return;
+        }
+
+        let archetypes = self
+            .archetypes
+            .index
+            .find_relation_targets(old)
+            .into_iter()
+            .flat_map(|v| v.keys().copied())
+            .collect_vec();
+
+        let change_tick = self.advance_change_tick();
+
+        for src in archetypes {
+            let mut src = self.archetypes.despawn(src);
+
+            let retargeted = src
+                .relations()
+                .filter(|key| {
+                    key.target == Some(old) && !src.has(ComponentKey::new(key.id, Some(new)))
+                })
+                .collect::<BTreeSet<_>>();
+
+            let map = |key: ComponentKey| {
+                if retargeted.contains(&key) {
+                    ComponentKey::new(key.id, Some(new))
+                } else {
+                    key
+                }
+            };
+
+            let components = src
+                .components_desc()
+                .filter(|v| v.key.target != Some(old) || retargeted.contains(&v.key))
+                .map(|mut v| {
+                    v.key = map(v.key);
+                    v
+                })
+                .sorted()
+                .collect_vec();
+
+            let (dst_id, dst) = self.archetypes.find_create(components);
+
+            record_migration(&mut self.migrations, &src, dst, src.len() as u64);
+
+            for (id, slot) in src.move_all_mapped(dst, map, change_tick) {
+                *self.location_mut(id).expect("Entity id was not valid") = EntityLocation {
+                    slot,
+                    arch_id: dst_id,
+                }
+            }
+        }
+    }
+
     /// Updates a component in place
     pub fn update<T: ComponentValue, U>(
         &self,
@@ -494,6 +1108,7 @@ impl World {
         component: Component<T>,
         f: impl FnOnce(&mut T) -> U,
     ) -> Result<U> {
+        ensure_mutable(component)?;
         let change_tick = self.advance_change_tick();
 
         let EntityLocation {
@@ -517,6 +1132,7 @@ impl World {
         component: Component<T>,
         value: T,
     ) -> Result<()> {
+        ensure_mutable(component)?;
         let tick = self.advance_change_tick();
 
         let EntityLocation {
@@ -542,22 +1158,56 @@ impl World {
         component: Component<T>,
         value: T,
     ) -> Result<Option<T>> {
-        Ok(self
-            .set_with_writer(
-                id,
-                SingleComponentWriter::new(component.desc(), Replace::new(value)),
-            )?
-            .1
-            .left())
+        let mut required = ComponentBuffer::new();
+        self.collect_required(id, &[component.desc()], &mut required)?;
+
+        if required.is_empty() {
+            return Ok(self
+                .set_with_writer(
+                    id,
+                    SingleComponentWriter::new(component.desc(), Replace::new(value)),
+                )?
+                .1
+                .left());
+        }
+
+        // Requirements are only collected for new components, so there is no previous value.
+        // Insert the component along with its requirements in a single transition
+        required.set(component, value);
+        self.set_with_writer(id, writer::Buffered::new(&mut required))?;
+
+        Ok(None)
     }
 
     /// Add the components stored in a component buffer to an entity
     pub fn set_with(&mut self, id: Entity, buffer: &mut ComponentBuffer) -> Result<()> {
+        self.fill_required(id, buffer)?;
         self.set_with_writer(id, writer::Buffered::new(buffer))?;
 
         Ok(())
     }
 
+    /// Edit the components of an existing entity.
+    ///
+    /// The recorded modifications are applied as a single archetype transition.
+    ///
+    /// See [`EditBuilder`]
+    pub fn edit(&mut self, id: Entity) -> EditBuilder<'_> {
+        EditBuilder::new(self, id)
+    }
+
+    pub(crate) fn edit_with(
+        &mut self,
+        id: Entity,
+        buffer: &mut ComponentBuffer,
+        removed: &[ComponentKey],
+    ) -> Result<()> {
+        self.fill_required(id, buffer)?;
+        self.set_with_writer(id, writer::Edit::new(buffer, removed))?;
+
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn set_dyn(
         &mut self,
@@ -565,24 +1215,136 @@ impl World {
         desc: ComponentDesc,
         value: *mut u8,
     ) -> Result<EntityLocation> {
-        let (loc, _) =
-            self.set_with_writer(id, SingleComponentWriter::new(desc, ReplaceDyn { value }))?;
+        let mut required = ComponentBuffer::new();
+        if let Err(err) = self.collect_required(id, &[desc], &mut required) {
+            // Safety: the value is owned by this function
+            unsafe { desc.drop(value) };
+            return Err(err);
+        }
 
-        Ok(loc)
-    }
+        if required.is_empty() {
+            let (loc, _) =
+                self.set_with_writer(id, SingleComponentWriter::new(desc, ReplaceDyn { value }))?;
 
-    #[inline]
-    pub(crate) fn set_with_writer<U: EntityWriter>(
-        &mut self,
-        id: Entity,
-        writer: U,
-    ) -> Result<(EntityLocation, U::Output)> {
-        // We know things will change either way
-        let change_tick = self.advance_change_tick();
+            return Ok(loc);
+        }
 
-        let src_loc = self.init_location(id)?;
+        // Safety: the value is moved into the buffer
+        unsafe { required.set_dyn(desc, value) };
+        let (loc, _) = self.set_with_writer(id, writer::Buffered::new(&mut required))?;
 
-        Ok(writer.write(self, id, src_loc, change_tick))
+        Ok(loc)
+    }
+
+    /// Inserts the components required by the components in `buffer` into `buffer`.
+    ///
+    /// See [`Requires`](crate::metadata::Requires)
+    pub(crate) fn fill_required(&self, id: Entity, buffer: &mut ComponentBuffer) -> Result<()> {
+        let components = buffer.components().copied().collect_vec();
+
+        let mut required = ComponentBuffer::new();
+        self.collect_required(id, &components, &mut required)?;
+
+        for (desc, value) in required.drain() {
+            // Safety: the value is moved into the buffer
+            unsafe { buffer.set_dyn(desc, value) }
+        }
+
+        Ok(())
+    }
+
+    /// Fails if a component required by the components in `buffer` is missing and has no default.
+    ///
+    /// Unlike [`Self::fill_required`], `buffer` is left unchanged.
+    pub(crate) fn check_required(&self, id: Entity, buffer: &ComponentBuffer) -> Result<()> {
+        let components = buffer.components().copied().collect_vec();
+        self.collect_required(id, &components, &mut ComponentBuffer::new())
+    }
+
+    /// Collects the components required by `components` which are present neither on the entity
+    /// nor in `components`.
+    ///
+    /// Components which the entity already has are skipped, as their requirements were collected
+    /// when they were inserted.
+    ///
+    /// Fails if a missing component has no default.
+    fn collect_required(
+        &self,
+        id: Entity,
+        components: &[ComponentDesc],
+        dst: &mut ComponentBuffer,
+    ) -> Result<()> {
+        if !components
+            .iter()
+            .any(|v| v.meta_ref().has(required_components()))
+        {
+            return Ok(());
+        }
+
+        let arch = self
+            .location(id)
+            .ok()
+            .map(|loc| self.archetypes.get(loc.arch_id));
+
+        let mut stack = components
+            .iter()
+            .filter(|v| !arch.is_some_and(|arch| arch.has(v.key())))
+            .copied()
+            .collect_vec();
+
+        while let Some(desc) = stack.pop() {
+            let Some(requirements) = desc.meta_ref().get(required_components()) else {
+                continue;
+            };
+
+            for requirement in requirements {
+                let required = requirement.desc();
+                let key = required.key();
+
+                if arch.is_some_and(|v| v.has(key))
+                    || components.iter().any(|v| v.key() == key)
+                    || dst.components().any(|v| v.key() == key)
+                {
+                    continue;
+                }
+
+                if !requirement.insert_default(dst) && !insert_object_default(required, dst) {
+                    return Err(Error::MissingComponent(MissingComponent {
+                        id,
+                        desc: required,
+                    }));
+                }
+
+                stack.push(required);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub(crate) fn set_with_writer<U: EntityWriter>(
+        &mut self,
+        id: Entity,
+        writer: U,
+    ) -> Result<(EntityLocation, U::Output)> {
+        // We know things will change either way
+        let change_tick = self.advance_change_tick();
+
+        let src_loc = self.init_location(id)?;
+
+        let (loc, output) = writer.write(self, id, src_loc, change_tick);
+
+        if loc.arch_id != src_loc.arch_id {
+            record_migration(
+                &mut self.migrations,
+                self.archetypes.get(src_loc.arch_id),
+                self.archetypes.get(loc.arch_id),
+                1,
+            );
+        }
+
+        Ok((loc, output))
     }
 
     #[inline]
@@ -602,7 +1364,7 @@ impl World {
         let EntityLocation {
             arch_id: src_id,
             slot,
-        } = self.init_location(id).unwrap();
+        } = self.init_location(id)?;
 
         let src = self.archetypes.get(src_id);
 
@@ -610,25 +1372,17 @@ impl World {
             return Err(Error::MissingComponent(MissingComponent { id, desc }));
         }
 
-        let dst_id = match src.incoming(desc.key()) {
-            Some(dst) => dst,
-            None => {
-                let components = src
-                    .components_desc()
-                    .filter(|v| v.key != desc.key())
-                    .collect_vec();
-
-                let (dst_id, _) = self.archetypes.find_create(components);
+        if let Some(dependent) = find_dependent(src, desc.key()) {
+            return Err(Error::RequiredComponent(id, desc, dependent));
+        }
 
-                dst_id
-            }
-        };
+        let dst_id = self.archetypes.find_removed(src_id, &[desc.key()]);
 
         assert_ne!(src_id, dst_id);
         // Borrow disjoint
         let (src, dst) = self.archetypes.get_disjoint(src_id, dst_id).unwrap();
-        src.add_incoming(desc.key(), dst_id);
-        dst.add_outgoing(desc.key(), src_id);
+
+        record_migration(&mut self.migrations, src, dst, 1);
 
         // Take the value
         // This moves the differing value out of the archetype before it is
@@ -673,14 +1427,228 @@ impl World {
         Ok(res)
     }
 
+    /// Adds an edge to a [`Multi`] relation, inserting the relation if it does not exist.
+    pub fn add_edge<T: ComponentValue>(
+        &mut self,
+        id: Entity,
+        component: Component<Multi<T>>,
+        value: T,
+    ) -> Result<EdgeIndex> {
+        Ok(self.entry(id, component)?.or_default().insert(value))
+    }
+
+    /// Removes a single edge of a [`Multi`] relation.
+    ///
+    /// The relation is removed from the entity once it has no edges left.
+    pub fn remove_edge<T: ComponentValue>(
+        &mut self,
+        id: Entity,
+        component: Component<Multi<T>>,
+        index: EdgeIndex,
+    ) -> Result<Option<T>> {
+        let mut edges = self.get_mut(id, component)?;
+        let value = edges.remove(index);
+        let is_empty = edges.is_empty();
+        drop(edges);
+
+        if is_empty {
+            self.remove(id, component)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Exchanges the value of `component` between two entities.
+    ///
+    /// The values are swapped in place without moving either entity to another archetype, and
+    /// the component is marked as modified for both entities.
+    ///
+    /// The remaining change records, such as when the component was added or its
+    /// [`History`](crate::metadata::History), are not exchanged and stay with their entity.
+    ///
+    /// Fails if either entity does not have the component, or if the component is
+    /// [`Immutable`](crate::metadata::Immutable).
+    pub fn swap_components<T: ComponentValue>(
+        &mut self,
+        a: Entity,
+        b: Entity,
+        component: Component<T>,
+    ) -> Result<()> {
+        if is_immutable(&component.desc()) {
+            return Err(Error::ImmutableComponent(component.desc()));
+        }
+
+        let a_loc = self.location(a)?;
+        let b_loc = self.location(b)?;
+
+        for (id, loc) in [(a, a_loc), (b, b_loc)] {
+            if !self.archetypes.get(loc.arch_id).has(component.key()) {
+                return Err(Error::MissingComponent(MissingComponent {
+                    id,
+                    desc: component.desc(),
+                }));
+            }
+        }
+
+        self.swap_inner((a, a_loc), (b, b_loc), &[component.key()]);
+        Ok(())
+    }
+
+    /// Exchanges the values of all components which both entities have.
+    ///
+    /// Components which only one of the entities has are left untouched. Fails without swapping
+    /// anything if a shared component is [`Immutable`](crate::metadata::Immutable).
+    ///
+    /// See [`Self::swap_components`]
+    pub fn swap_all(&mut self, a: Entity, b: Entity) -> Result<()> {
+        let a_loc = self.location(a)?;
+        let b_loc = self.location(b)?;
+
+        let b_arch = self.archetypes.get(b_loc.arch_id);
+        let mut keys: SmallVec<[ComponentKey; 8]> = SmallVec::new();
+        for desc in self.archetypes.get(a_loc.arch_id).components_desc() {
+            if !b_arch.has(desc.key()) {
+                continue;
+            }
+
+            if is_immutable(&desc) {
+                return Err(Error::ImmutableComponent(desc));
+            }
+
+            keys.push(desc.key());
+        }
+
+        self.swap_inner((a, a_loc), (b, b_loc), &keys);
+        Ok(())
+    }
+
+    /// Exchanges the values of `keys`, which both entities must have and which must not be
+    /// immutable. Only the values and their modification are swapped, not the other change
+    /// records.
+    fn swap_inner(
+        &mut self,
+        (a, a_loc): (Entity, EntityLocation),
+        (b, b_loc): (Entity, EntityLocation),
+        keys: &[ComponentKey],
+    ) {
+        if a == b || keys.is_empty() {
+            return;
+        }
+
+        let tick = self.advance_change_tick();
+
+        if a_loc.arch_id == b_loc.arch_id {
+            let arch = self.archetypes.get_mut(a_loc.arch_id);
+            for &key in keys {
+                let data = arch.cell_mut(key).unwrap().data.get_mut();
+                let size = data.storage.desc().size();
+
+                // Safety: the slots are distinct and hold values of the same component
+                unsafe {
+                    let a_ptr = data.storage.at_mut(a_loc.slot).unwrap();
+                    let b_ptr = data.storage.at_mut(b_loc.slot).unwrap();
+                    ptr::swap_nonoverlapping(a_ptr, b_ptr, size);
+                }
+
+                data.set_modified(&[a], Slice::single(a_loc.slot), tick);
+                data.set_modified(&[b], Slice::single(b_loc.slot), tick);
+            }
+        } else {
+            let (a_arch, b_arch) = self
+                .archetypes
+                .get_disjoint(a_loc.arch_id, b_loc.arch_id)
+                .unwrap();
+
+            for &key in keys {
+                let a_data = a_arch.cell_mut(key).unwrap().data.get_mut();
+                let b_data = b_arch.cell_mut(key).unwrap().data.get_mut();
+
+                // The blobs can no longer refer to the slab of their archetype
+                if let (Some(a_blobs), Some(b_blobs)) = (&a_data.blobs, &b_data.blobs) {
+                    a_data.storage.downcast_mut::<Blob>()[a_loc.slot].detach(a_blobs);
+                    b_data.storage.downcast_mut::<Blob>()[b_loc.slot].detach(b_blobs);
+                }
+
+                let size = a_data.storage.desc().size();
+
+                // Safety: the storages are distinct and hold values of the same component
+                unsafe {
+                    let a_ptr = a_data.storage.at_mut(a_loc.slot).unwrap();
+                    let b_ptr = b_data.storage.at_mut(b_loc.slot).unwrap();
+                    ptr::swap_nonoverlapping(a_ptr, b_ptr, size);
+                }
+
+                a_data.set_modified(&[a], Slice::single(a_loc.slot), tick);
+                b_data.set_modified(&[b], Slice::single(b_loc.slot), tick);
+            }
+        }
+    }
+
     /// Randomly access an entity's component.
     pub fn get<T: ComponentValue>(
         &self,
         id: Entity,
         component: Component<T>,
-    ) -> Result<AtomicRef<T>> {
+    ) -> Result<AtomicRef<'_, T>> {
+        let loc = self.location(id)?;
+
+        self.get_at(loc, component).ok_or_else(|| {
+            Error::MissingComponent(MissingComponent {
+                id,
+                desc: component.desc(),
+            })
+        })
+    }
+
+    /// Returns the retained changes of an entity's component, oldest first.
+    ///
+    /// Changes are only retained for components declared with
+    /// [`History`](crate::metadata::History), otherwise the returned list is empty.
+    ///
+    /// The tick of each change can be correlated with [`BoxedSystem::last_run`] of the systems in
+    /// the schedule to find the system which made the change.
+    ///
+    /// [`BoxedSystem::last_run`]: crate::BoxedSystem::last_run
+    pub fn change_history<T: ComponentValue>(
+        &self,
+        id: Entity,
+        component: Component<T>,
+    ) -> Result<Vec<ChangeRecord>> {
         let loc = self.location(id)?;
 
+        let cell = self
+            .archetypes
+            .get(loc.arch_id)
+            .cell(component.key())
+            .ok_or_else(|| {
+                Error::MissingComponent(MissingComponent {
+                    id,
+                    desc: component.desc(),
+                })
+            })?;
+
+        let data = cell.data.borrow();
+        Ok(data
+            .history
+            .as_ref()
+            .map(|v| v.get(id).copied().collect())
+            .unwrap_or_default())
+    }
+
+    /// Access a component of the entity at a location yielded by [`entity_locs`].
+    ///
+    /// The location is validated against the entity, and if the entity has moved since the
+    /// location was fetched the entity is looked up as usual.
+    ///
+    /// [`entity_locs`]: crate::fetch::entity_locs
+    pub fn get_at_loc<T: ComponentValue>(
+        &self,
+        loc: EntityLoc,
+        component: Component<T>,
+    ) -> Result<AtomicRef<'_, T>> {
+        let id = loc.id;
+        let loc = self.resolve_loc(loc)?;
+
         self.get_at(loc, component).ok_or_else(|| {
             Error::MissingComponent(MissingComponent {
                 id,
@@ -689,6 +1657,63 @@ impl World {
         })
     }
 
+    /// Mutably access a component of the entity at a location yielded by [`entity_locs`].
+    ///
+    /// See [`Self::get_at_loc`]
+    ///
+    /// [`entity_locs`]: crate::fetch::entity_locs
+    pub fn get_mut_at_loc<T: ComponentValue>(
+        &self,
+        loc: EntityLoc,
+        component: Component<T>,
+    ) -> Result<RefMut<'_, T>> {
+        ensure_mutable(component)?;
+        let id = loc.id;
+        let loc = self.resolve_loc(loc)?;
+
+        self.get_mut_at(loc, component).ok_or_else(|| {
+            Error::MissingComponent(MissingComponent {
+                id,
+                desc: component.desc(),
+            })
+        })
+    }
+
+    /// Returns true if `loc` still refers to the location of the entity
+    pub fn is_loc_valid(&self, loc: EntityLoc) -> bool {
+        self.archetypes
+            .try_get(loc.arch_id)
+            .and_then(|arch| arch.entity(loc.slot))
+            == Some(loc.id)
+    }
+
+    /// Revalidates a query result handle yielded by [`item_handles`].
+    ///
+    /// Returns the refreshed handle if the entity is still alive and has not moved to another
+    /// archetype since the handle was created, which means it still satisfies the archetype level
+    /// filters of the query.
+    ///
+    /// [`item_handles`]: crate::fetch::item_handles
+    pub fn revalidate(&self, handle: QueryItemHandle) -> Option<QueryItemHandle> {
+        let loc = self.location(handle.id).ok()?;
+
+        (loc.arch_id == handle.arch_id).then(|| QueryItemHandle {
+            tick: self.change_tick(),
+            ..handle
+        })
+    }
+
+    fn resolve_loc(&self, loc: EntityLoc) -> Result<EntityLocation> {
+        if self.is_loc_valid(loc) {
+            Ok(EntityLocation {
+                slot: loc.slot,
+                arch_id: loc.arch_id,
+            })
+        } else {
+            self.location(loc.id)
+        }
+    }
+
     #[inline]
     pub(crate) fn get_at<T: ComponentValue>(
         &self,
@@ -697,7 +1722,7 @@ impl World {
             slot,
         }: EntityLocation,
         component: Component<T>,
-    ) -> Option<AtomicRef<T>> {
+    ) -> Option<AtomicRef<'_, T>> {
         self.archetypes.get(arch).get(slot, component)
     }
 
@@ -708,7 +1733,7 @@ impl World {
             slot,
         }: EntityLocation,
         component: Component<T>,
-    ) -> core::result::Result<Option<AtomicRef<T>>, BorrowError> {
+    ) -> core::result::Result<Option<AtomicRef<'_, T>>, BorrowError> {
         self.archetypes.get(arch).try_get(slot, component)
     }
 
@@ -717,7 +1742,8 @@ impl World {
         &self,
         id: Entity,
         component: Component<T>,
-    ) -> Result<RefMut<T>> {
+    ) -> Result<RefMut<'_, T>> {
+        ensure_mutable(component)?;
         let loc = self.location(id)?;
 
         self.get_mut_at(loc, component).ok_or_else(|| {
@@ -736,7 +1762,7 @@ impl World {
             slot,
         }: EntityLocation,
         component: Component<T>,
-    ) -> Option<RefMut<T>> {
+    ) -> Option<RefMut<'_, T>> {
         self.archetypes
             .get(arch)
             .get_mut(slot, component, self.advance_change_tick())
@@ -750,12 +1776,182 @@ impl World {
             slot,
         }: EntityLocation,
         component: Component<T>,
-    ) -> core::result::Result<Option<RefMut<T>>, BorrowMutError> {
+    ) -> core::result::Result<Option<RefMut<'_, T>>, BorrowMutError> {
         self.archetypes
             .get(arch)
             .try_get_mut(slot, component, self.advance_change_tick())
     }
 
+    /// Clones out `component` for all entities matching `filter`, including
+    /// [`disabled`](crate::components::disabled) entities.
+    ///
+    /// Useful for handing a dense column of values to a numerical library.
+    pub fn extract_column<T, F>(&self, filter: F, component: Component<T>) -> Vec<(Entity, T)>
+    where
+        T: ComponentValue + Clone,
+        F: for<'x> Fetch<'x>,
+    {
+        profile_function!();
+        Query::new((entity_ids(), component.cloned()))
+            .filter(filter)
+            .include_disabled()
+            .borrow_unchecked(self)
+            .iter()
+            .collect_vec()
+    }
+
+    /// Writes back a column of values, such as one returned by [`Self::extract_column`].
+    ///
+    /// The values are sorted by location and written one archetype at a time, borrowing each
+    /// storage once. No values are written if any entity is dead or lacks `component`.
+    pub fn write_column<T: ComponentValue + Clone>(
+        &self,
+        component: Component<T>,
+        values: &[(Entity, T)],
+    ) -> Result<()> {
+        profile_function!();
+        let mut locs = values
+            .iter()
+            .map(|(id, value)| {
+                let loc = self.location(*id)?;
+                if !self.archetypes.get(loc.arch_id).has(component.key()) {
+                    return Err(Error::MissingComponent(MissingComponent {
+                        id: *id,
+                        desc: component.desc(),
+                    }));
+                }
+
+                Ok((loc, *id, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        locs.sort_by_key(|(loc, _, _)| (loc.arch_id, loc.slot));
+
+        let tick = self.advance_change_tick();
+        for (arch_id, group) in &locs.iter().group_by(|(loc, _, _)| loc.arch_id) {
+            let mut storage = self
+                .archetypes
+                .get(arch_id)
+                .borrow_mut::<T>(component.key())
+                .expect("Component is present in archetype");
+
+            for &(loc, id, value) in group {
+                storage.get_mut()[loc.slot] = value.clone();
+                storage.set_modified(&[id], Slice::single(loc.slot), tick);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a field of an entity's component through a property path.
+    ///
+    /// The first segment of the path is the component name, and the remainder is resolved
+    /// through the component's [`Reflect`](crate::reflect::Reflect) implementation, e.g;
+    /// `"transform.translation.x"`.
+    ///
+    /// See [`crate::reflect`]
+    pub fn get_path(&self, id: Entity, path: &str) -> Result<Value> {
+        let loc = self.location(id)?;
+        reflect::get_path(self.archetypes.get(loc.arch_id), loc.slot, path)
+    }
+
+    /// Writes a field of an entity's component through a property path.
+    ///
+    /// The component is marked as modified.
+    ///
+    /// See [`Self::get_path`]
+    pub fn set_path(&self, id: Entity, path: &str, value: Value) -> Result<()> {
+        let loc = self.location(id)?;
+        reflect::set_path(
+            self.archetypes.get(loc.arch_id),
+            id,
+            loc.slot,
+            self.advance_change_tick(),
+            path,
+            value,
+        )
+    }
+
+    /// Interpolates the component of `id` from its current value to `target` over `duration`
+    /// seconds.
+    ///
+    /// The tween is advanced by [`tween::update_tweens`], and is cancelled if the component is
+    /// written by anything else in the meantime.
+    ///
+    /// Fails if the component is declared [`Immutable`](crate::Immutable) or
+    /// [`NoLock`](crate::NoLock), as tweens write through a shared world.
+    ///
+    /// See [`crate::tween`]
+    pub fn tween<T: ComponentValue + Lerp + Clone>(
+        &mut self,
+        id: Entity,
+        component: Component<T>,
+        target: T,
+        duration: f32,
+        easing: Easing,
+    ) -> Result<()> {
+        self.tween_field(id, component, |v| v, target, duration, easing)
+    }
+
+    /// Interpolates a field of the component of `id`, selected by `field`, from its current value
+    /// to `target` over `duration` seconds.
+    ///
+    /// The remainder of the component is left as is. As a tween is tracked per component, starting
+    /// a tween for another field of the same component replaces this one.
+    ///
+    /// See [`Self::tween`]
+    pub fn tween_field<T: ComponentValue + Clone, F: 'static + Send + Sync + Lerp + Clone>(
+        &mut self,
+        id: Entity,
+        component: Component<T>,
+        field: fn(&mut T) -> &mut F,
+        target: F,
+        duration: f32,
+        easing: Easing,
+    ) -> Result<()> {
+        ensure_mutable(component)?;
+        ensure_locked(component)?;
+        tween::start(self, id, component, field, target, duration, easing)
+    }
+
+    /// Explains why a component is missing from an entity by listing the components of the entity
+    /// with similar names, and the number of archetypes which do have the component.
+    ///
+    /// This is intended to speed up debugging of failing queries and component accesses.
+    #[cfg(feature = "diagnostics")]
+    pub fn explain_missing(
+        &self,
+        missing: &MissingComponent,
+    ) -> crate::error::MissingComponentHint {
+        let name = missing.desc.name();
+
+        let similar = match self.location(missing.id) {
+            Ok(loc) => self
+                .archetypes
+                .get(loc.arch_id)
+                .components_desc()
+                .filter(|v| v.key() != missing.desc.key())
+                .map(|v| v.name())
+                .filter(|v| crate::error::is_similar(v, name))
+                .map(Into::into)
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        let archetypes = self
+            .archetypes
+            .iter()
+            .filter(|(_, arch)| arch.has(missing.desc.key()))
+            .count();
+
+        crate::error::MissingComponentHint {
+            missing: missing.clone(),
+            similar,
+            archetypes,
+        }
+    }
+
     /// Returns true if the entity has the specified component.
     /// Returns false if the entity does not exist or it does not have the
     /// specified component
@@ -893,10 +2089,11 @@ impl World {
 
         let arch = self.archetypes.get_mut(arch_id);
 
-        for (_, mut storage) in chunk.take_all() {
-            unsafe {
-                arch.extend(&mut storage, change_tick);
-            }
+        unsafe { arch.extend_all(chunk.take_all().map(|(_, v)| v), change_tick) }
+
+        for &id in ids {
+            self.archetypes
+                .entity_event(id, arch_id, EntityEventKind::Spawned);
         }
 
         Ok(ids)
@@ -957,7 +2154,10 @@ impl World {
 
         arch.allocate(id);
 
-        (id, loc, arch)
+        self.archetypes
+            .entity_event(id, arch_id, EntityEventKind::Spawned);
+
+        (id, loc, self.archetypes.get_mut(arch_id))
     }
 
     /// Get a reference to the world's archetype generation
@@ -972,8 +2172,15 @@ impl World {
         (self.change_tick.fetch_or(1, Ordering::Relaxed) >> 1) + 1
     }
 
-    /// Increases the change tick and returns the new one
-    pub(crate) fn advance_change_tick(&self) -> u32 {
+    /// Increases the change tick and returns the new one.
+    ///
+    /// Used to mark changes made outside of queries, such as through
+    /// [`Archetype::get_slice_mut`].
+    pub fn advance_change_tick(&self) -> u32 {
+        if let Some(tick) = self.batch_tick {
+            return tick;
+        }
+
         let v = self
             .change_tick
             .fetch_update(Ordering::Acquire, Ordering::Relaxed, |v| {
@@ -993,16 +2200,82 @@ impl World {
         }
     }
 
-    /// Formats the world using the debug visitor.
-    pub fn format_debug<F>(&self, filter: F) -> WorldFormatter<F>
-    where
-        F: StaticFilter,
-    {
-        WorldFormatter {
-            world: self,
-            filter,
-        }
-    }
+    /// Performs a batch of operations as a single change.
+    ///
+    /// All changes made inside the closure share the same change tick, and added and modified
+    /// events are not dispatched per operation. Instead, a single coalesced set of events is
+    /// dispatched when the closure returns, where a component which was both added and modified
+    /// is only reported as added.
+    ///
+    /// This is useful for large setup phases where thousands of individual events would
+    /// overwhelm the subscribers.
+    ///
+    /// **Note**: removal events are still dispatched immediately as the removed values are not
+    /// available afterwards.
+    ///
+    /// As queries borrowed inside the closure also observe the shared change tick, a change
+    /// filtered query which is borrowed more than once inside the same scope does not yield the
+    /// changes made between the borrows. These changes are yielded by the first borrow after the
+    /// scope instead.
+    ///
+    /// If `f` panics, the events recorded so far are dispatched while unwinding and the world
+    /// leaves the batch.
+    pub fn batch_scope<R>(&mut self, f: impl FnOnce(&mut World) -> R) -> R {
+        if self.batch_tick.is_some() {
+            return f(self);
+        }
+
+        // Mark the current tick as read to acquire a distinct tick for the batch
+        let _ = self.change_tick();
+        let tick = self.advance_change_tick();
+
+        self.batch_tick = Some(tick);
+
+        // Events which are already deferred are dispatched at the next maintain
+        let defer = self.deferred_since.is_none();
+        if defer {
+            self.archetypes.defer_events();
+        }
+
+        let guard = BatchGuard {
+            world: self,
+            tick,
+            defer,
+        };
+
+        f(&mut *guard.world)
+    }
+
+    /// Hides a component or relation from inspection.
+    ///
+    /// Hidden components are omitted from debug formatting and from
+    /// [`EntityRef::components`], which is useful for engine internal bookkeeping. They can still
+    /// be accessed, queried and serialized when explicitly requested.
+    pub fn hide_component(&mut self, component: impl Into<Entity>) {
+        self.hidden_components.insert(component.into());
+    }
+
+    /// Reverts [`World::hide_component`]
+    pub fn show_component(&mut self, component: impl Into<Entity>) {
+        self.hidden_components.remove(&component.into());
+    }
+
+    /// Returns true if the component, or the relation it is an instance of, is hidden.
+    pub fn is_hidden(&self, key: ComponentKey) -> bool {
+        !self.hidden_components.is_empty() && self.hidden_components.contains(&key.id())
+    }
+
+    /// Formats the world using the debug visitor, including
+    /// [`disabled`](crate::components::disabled) entities.
+    pub fn format_debug<F>(&self, filter: F) -> WorldFormatter<'_, F>
+    where
+        F: StaticFilter,
+    {
+        WorldFormatter {
+            world: self,
+            filter,
+        }
+    }
 
     /// Formats a set of entities using the debug visitor.
     pub fn format_entities<'a>(&'a self, ids: &'a [Entity]) -> EntitiesFormatter<'a> {
@@ -1033,6 +2306,76 @@ impl World {
         self.archetypes.iter().map(|(k, v)| (k, v.desc())).collect()
     }
 
+    /// Access an archetype by id.
+    ///
+    /// Fails with [`Error::NoSuchArchetype`] if the archetype does not exist.
+    pub fn archetype(&self, arch_id: ArchetypeId) -> Result<&Archetype> {
+        self.archetypes.get_checked(arch_id)
+    }
+
+    /// Creates the archetype of an entity with exactly `components`, returning its id.
+    ///
+    /// Archetypes are otherwise created when the first entity with a new set of components is
+    /// spawned or modified. Creating them up front, such as during loading, avoids paying for the
+    /// archetype creation and subscriber matching, and invalidating the query caches, later on.
+    ///
+    /// The components are registered, but their required components are not added. The archetype
+    /// is empty, and is removed by [`Self::prune_archetypes`] if no entity is added to it.
+    pub fn create_archetype(&mut self, components: &[ComponentDesc]) -> ArchetypeId {
+        let mut components = components.to_vec();
+        components.sort();
+        components.dedup();
+
+        for &desc in &components {
+            self.init_component(desc);
+        }
+
+        self.archetypes.find_create(components).0
+    }
+
+    /// Returns the dirty ranges of `component` in an archetype, as `(slots, tick)` pairs.
+    ///
+    /// This allows consumers such as replication or GPU uploads to process changes as contiguous
+    /// slices rather than per entity. The ranges do not overlap and are ordered by slot.
+    ///
+    /// Yields nothing if the archetype does not exist or does not have the component.
+    ///
+    /// Modifications are only recorded once they are observed, so the first call for
+    /// [`ChangeKind::Modified`] enables tracking for the component in the archetype and does not
+    /// include earlier modifications.
+    pub fn changes(
+        &self,
+        arch_id: ArchetypeId,
+        component: ComponentKey,
+        kind: ChangeKind,
+    ) -> impl Iterator<Item = (Slice, u32)> {
+        let arch = self.archetypes.try_get(arch_id);
+
+        if kind.is_modified() {
+            if let Some(cell) = arch.and_then(|arch| arch.cell(component)) {
+                cell.data.borrow().changes.set_track_modified();
+            }
+        }
+
+        arch.and_then(|arch| arch.changes(component, kind))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|change| (change.slice, change.tick))
+    }
+
+    /// Returns a human friendly description of a single archetype.
+    ///
+    /// Fails with [`Error::NoSuchArchetype`] if the archetype does not exist, such as when it was
+    /// pruned after the id was acquired.
+    pub fn archetype_desc(&self, arch_id: ArchetypeId) -> Result<ArchetypeInfo> {
+        self.archetypes.get_checked(arch_id).map(|v| v.desc())
+    }
+
+    /// Decodes an entity encoded by [`Entity::to_bits`], returning it if alive in this world
+    pub fn entity_from_bits(&self, bits: u64) -> Option<Entity> {
+        Entity::from_bits(bits).filter(|&id| self.is_alive(id))
+    }
+
     /// Attempt to find an alive entity given the id
     pub fn reconstruct(&self, index: EntityIndex, kind: EntityKind) -> Option<Entity> {
         let ns = self.entities.get(kind)?;
@@ -1054,8 +2397,31 @@ impl World {
         Some(Component::from_raw_parts(id, desc.vtable))
     }
 
+    /// Returns the component of type `T` marked as [`Unique`](crate::metadata::Unique).
+    ///
+    /// Returns `None` if no such component has been registered in the world yet.
+    pub fn unique_component<T: ComponentValue>(&self) -> Option<Component<T>> {
+        let desc = self.unique_components.get(&TypeId::of::<T>())?;
+        Some(Component::from_raw_parts(desc.key(), desc.vtable))
+    }
+
+    /// Access the [`Unique`](crate::metadata::Unique) component of type `T` of an entity
+    pub fn get_unique<T: ComponentValue>(&self, id: Entity) -> Result<AtomicRef<'_, T>> {
+        self.get(id, self.try_unique_component()?)
+    }
+
+    /// Mutably access the [`Unique`](crate::metadata::Unique) component of type `T` of an entity
+    pub fn get_unique_mut<T: ComponentValue>(&self, id: Entity) -> Result<RefMut<'_, T>> {
+        self.get_mut(id, self.try_unique_component()?)
+    }
+
+    fn try_unique_component<T: ComponentValue>(&self) -> Result<Component<T>> {
+        self.unique_component()
+            .ok_or(Error::NoUniqueComponent(core::any::type_name::<T>()))
+    }
+
     /// Access, insert, and remove all components of an entity
-    pub fn entity_mut(&mut self, id: Entity) -> Result<EntityRefMut> {
+    pub fn entity_mut(&mut self, id: Entity) -> Result<EntityRefMut<'_>> {
         let loc = self.init_location(id)?;
         Ok(EntityRefMut {
             world: self,
@@ -1067,7 +2433,7 @@ impl World {
     /// Access all components of an entity
     ///
     /// **Note**: Fails for static entities if they have not yet been spawned into the world
-    pub fn entity(&self, id: Entity) -> Result<EntityRef> {
+    pub fn entity(&self, id: Entity) -> Result<EntityRef<'_>> {
         let loc = self.location(id)?;
         let arch = self.archetypes.get(loc.arch_id);
 
@@ -1079,6 +2445,127 @@ impl World {
         })
     }
 
+    /// Iterates all live entities in the world, including components.
+    ///
+    /// Entities are visited archetype by archetype, which keeps the access of each archetype's
+    /// storage contiguous.
+    pub fn iter(&self) -> impl Iterator<Item = EntityRef<'_>> {
+        self.iter_kind(EntityKind::empty())
+    }
+
+    /// Iterates all live entities whose kind contains all the bits of `kind`.
+    ///
+    /// See [`Self::iter`]
+    pub fn iter_kind(&self, kind: EntityKind) -> impl Iterator<Item = EntityRef<'_>> {
+        let reserved = self.archetypes.reserved;
+        self.archetypes
+            .iter()
+            .filter(move |&(arch_id, _)| arch_id != reserved)
+            .flat_map(move |(arch_id, arch)| {
+                arch.entities()
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, id)| id.kind().contains(kind))
+                    .map(move |(slot, &id)| EntityRef {
+                        world: self,
+                        arch,
+                        loc: EntityLocation { slot, arch_id },
+                        id,
+                    })
+            })
+    }
+
+    /// Returns the first entity which matches `filter`.
+    ///
+    /// Visits the same entities as a [`Query`] with [`Query::include_disabled`] would, but stops
+    /// at the first matching slot rather than preparing and iterating all archetypes. Useful for
+    /// one-shot lookups in setup code.
+    pub fn find<F>(&self, filter: F) -> Option<Entity>
+    where
+        F: for<'x> Fetch<'x>,
+    {
+        self.find_filtered(entity_ids(), filter, Some)
+    }
+
+    /// Returns the first `Some` returned by `func` for the entities matching `fetch`.
+    ///
+    /// Entities are visited in query order, and the search stops as soon as `func` returns a
+    /// value.
+    pub fn find_map<Q, R>(
+        &self,
+        fetch: Q,
+        func: impl for<'x> FnMut(<Q as FetchItem<'x>>::Item) -> Option<R>,
+    ) -> Option<R>
+    where
+        Q: for<'x> Fetch<'x>,
+    {
+        self.find_filtered(fetch, All, func)
+    }
+
+    fn find_filtered<Q, F, R>(
+        &self,
+        fetch: Q,
+        filter: F,
+        mut func: impl for<'x> FnMut(<Q as FetchItem<'x>>::Item) -> Option<R>,
+    ) -> Option<R>
+    where
+        Q: for<'x> Fetch<'x>,
+        F: for<'x> Fetch<'x>,
+    {
+        let fetch = Filtered::new(fetch, filter, false, true, true);
+
+        let reserved = self.archetypes.reserved;
+
+        let new_tick = if <Filtered<Q, F> as Fetch<'static>>::MUTABLE {
+            self.advance_change_tick()
+        } else {
+            self.change_tick()
+        };
+
+        for (arch_id, arch) in self.archetypes.iter() {
+            if arch_id == reserved
+                || arch.is_empty()
+                || !fetch.filter_arch(FetchAccessData {
+                    world: self,
+                    arch,
+                    arch_id,
+                })
+            {
+                continue;
+            }
+
+            let Some(mut prepared) = fetch.prepare(FetchPrepareData {
+                world: self,
+                arch,
+                arch_id,
+                old_tick: 0,
+                new_tick,
+                exclusive: false,
+            }) else {
+                continue;
+            };
+
+            let mut slots = arch.slots();
+            while let Some(slice) = next_slice(&mut slots, &mut prepared) {
+                // Safety: each chunk is dropped before the next slice is split off
+                let mut chunk = unsafe { prepared.create_chunk(slice) };
+
+                for _ in slice.iter() {
+                    let item = unsafe {
+                        <<Filtered<Q, F> as Fetch<'_>>::Prepared as PreparedFetch<'_>>::fetch_next(
+                            &mut chunk,
+                        )
+                    };
+                    if let Some(value) = func(item) {
+                        return Some(value);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
     /// Returns an entry for a given component of an entity allowing for
     /// in-place manipulation, insertion or removal.
     ///
@@ -1087,20 +2574,20 @@ impl World {
         &mut self,
         id: Entity,
         component: Component<T>,
-    ) -> Result<Entry<T>> {
+    ) -> Result<Entry<'_, T>> {
         let loc = self.init_location(id)?;
         let arch = self.archetypes.get(loc.arch_id);
         if arch.has(component.key()) {
-            return Ok(Entry::Occupied(OccupiedEntry {
+            Ok(Entry::Occupied(OccupiedEntry {
                 borrow: self.get_mut(id, component).unwrap(),
-            }));
+            }))
         } else {
-            return Ok(Entry::Vacant(VacantEntry {
+            Ok(Entry::Vacant(VacantEntry {
                 world: self,
                 id,
                 component,
-            }));
-        };
+            }))
+        }
     }
 
     /// Subscribe to events in the world using the provided event handler.
@@ -1113,6 +2600,81 @@ impl World {
         self.archetypes.add_subscriber(Arc::new(subscriber))
     }
 
+    /// Clones the given entities into `tree`, which can later be spawned into this or another
+    /// world.
+    ///
+    /// Relations between the cloned entities are preserved, which allows copying a selection of
+    /// entities as a whole. Only components with the [`Cloneable`](crate::metadata::Cloneable)
+    /// metadata are cloned.
+    pub fn clone_entities_into(
+        &self,
+        ids: impl IntoIterator<Item = Entity>,
+        tree: &mut EntityTree,
+    ) -> Result<()> {
+        profile_function!();
+        for id in ids {
+            let loc = self.location(id)?;
+            let arch = self.archetypes.get(loc.arch_id);
+
+            let mut buffer = ComponentBuffer::new();
+            for desc in arch.components_desc() {
+                if let Some(vtable) = desc.meta_ref().get(cloneable()) {
+                    vtable.clone_from_arch(arch, desc, loc.slot, None, &mut buffer);
+                }
+            }
+
+            tree.push(id, buffer);
+        }
+
+        Ok(())
+    }
+
+    /// Creates an index from the values of `component` to the entities which have them.
+    ///
+    /// The index is kept up to date through change events, and is used by [`Self::lookup`] and
+    /// the [`equals_indexed`](crate::index::equals_indexed) filter. Does nothing if the index
+    /// already exists.
+    #[cfg(feature = "flume")]
+    pub fn create_index<T: ComponentValue + Ord + Clone>(&mut self, component: Component<T>) {
+        if self.indexes.contains_key(&component.key()) {
+            return;
+        }
+
+        let index = ValueIndex::new(self, component);
+        self.indexes.insert(component.key(), Arc::new(index));
+    }
+
+    /// Returns the entities whose `component` equals `value`, in order.
+    ///
+    /// Returns `None` if no index exists for `component`.
+    #[cfg(feature = "flume")]
+    pub fn lookup<T: ComponentValue + Ord + Clone>(
+        &self,
+        component: Component<T>,
+        value: &T,
+    ) -> Option<Vec<Entity>> {
+        let index = self
+            .indexes
+            .get(&component.key())?
+            .downcast_ref::<ValueIndex<T>>()?;
+
+        Some(index.lookup(self, value))
+    }
+
+    /// Creates a map from the values of a key component, such as [`name`], to the entities which
+    /// have them.
+    ///
+    /// The map is kept up to date through change events, and is dropped along with its
+    /// subscription when no longer needed. See [`LabelMap::with_policy`] for how labels shared by
+    /// several entities are resolved.
+    #[cfg(feature = "flume")]
+    pub fn label_map<T: ComponentValue + Ord + Clone>(
+        &mut self,
+        component: Component<T>,
+    ) -> LabelMap<T> {
+        LabelMap::new(self, component)
+    }
+
     /// Merges `other` into `self`.
     ///
     /// Colliding entities will be migrated to a new entity id. Static entities will not be
@@ -1232,14 +2794,21 @@ impl World {
     }
 
     /// Converts all reserved entity ids into actual empty entities placed in a special archetype.
+    ///
+    /// This is done automatically on any structural change of the world, but may be called
+    /// explicitly to make reserved ids accessible through [`Self::entity`] before then.
+    ///
+    /// Flushed entities without components are not visited by queries unless
+    /// [`Query::include_reserved`] is used.
     #[inline]
-    fn flush_reserved(&mut self) {
+    pub fn flush_reserved(&mut self) {
         if !self.has_reserved.swap(false, Relaxed) {
             return;
         }
 
         let reserved = self.archetypes.reserved;
         let arch = self.archetypes.get_mut(reserved);
+        let base = arch.len();
 
         for store in self.entities.inner.values_mut() {
             store.flush_reserved(|id| {
@@ -1251,6 +2820,12 @@ impl World {
                 }
             })
         }
+
+        let arch = self.archetypes.get(reserved);
+        for &id in &arch.entities()[base..] {
+            self.archetypes
+                .entity_event(id, reserved, EntityEventKind::Spawned);
+        }
     }
 
     fn reserve_at(&mut self, id: Entity) -> Result<()> {
@@ -1268,6 +2843,114 @@ impl World {
     }
 }
 
+type SpawnValidator = dyn Fn(&[ComponentDesc]) -> core::result::Result<(), String> + Send + Sync;
+
+/// Determines which empty archetypes are removed by [`World::prune_archetypes_with`].
+///
+/// An archetype is only removed if all archetypes further down the archetype graph are removed
+/// as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrunePolicy {
+    /// Keep all archetypes
+    Never,
+    /// Remove all empty archetypes
+    #[default]
+    Aggressive,
+    /// Only remove empty archetypes which are leaves of the archetype graph, keeping the
+    /// intermediate archetypes around
+    OnlyLeaves,
+    /// Only remove empty archetypes which have allocated space for at most `n` entities.
+    ///
+    /// This keeps archetypes which have been highly populated warm.
+    SizeThreshold(usize),
+}
+
+/// Configures the bookkeeping performed by [`World::maintain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintainPolicy {
+    /// Which empty archetypes to remove.
+    ///
+    /// Defaults to [`PrunePolicy::Never`]
+    pub prune: PrunePolicy,
+    /// Number of change ticks for which change records are kept.
+    ///
+    /// Queries which have not run within this many ticks may miss older changes, in exchange for
+    /// bounding the size of the change lists of frequently modified components. Defaults to
+    /// keeping all changes.
+    pub change_retention: Option<u32>,
+}
+
+impl Default for MaintainPolicy {
+    fn default() -> Self {
+        Self {
+            prune: PrunePolicy::Never,
+            change_retention: None,
+        }
+    }
+}
+
+/// Counts the archetype migrations caused by a component.
+///
+/// See [`World::migration_stats`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationStats {
+    component: ComponentDesc,
+    added: u64,
+    removed: u64,
+}
+
+impl MigrationStats {
+    fn new(component: ComponentDesc) -> Self {
+        Self {
+            component,
+            added: 0,
+            removed: 0,
+        }
+    }
+
+    /// Returns the component which caused the migrations
+    pub fn component(&self) -> ComponentDesc {
+        self.component
+    }
+
+    /// Returns the number of entities which moved to another archetype as the component was added
+    pub fn added(&self) -> u64 {
+        self.added
+    }
+
+    /// Returns the number of entities which moved to another archetype as the component was
+    /// removed
+    pub fn removed(&self) -> u64 {
+        self.removed
+    }
+
+    /// Returns the total number of migrations
+    pub fn total(&self) -> u64 {
+        self.added + self.removed
+    }
+}
+
+/// Counts the lookups of the archetype reached by removing components.
+///
+/// See [`World::edge_cache_stats`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeCacheStats {
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+}
+
+impl EdgeCacheStats {
+    /// Returns the number of removals which followed a cached edge
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of removals which searched for the destination archetype
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
 /// Holds the migrated components
 #[derive(Debug, Clone)]
 pub struct MigratedEntities {
@@ -1310,12 +2993,51 @@ impl MigratedEntities {
     }
 }
 
+/// Leaves the batch of [`World::batch_scope`], even if the closure panics
+struct BatchGuard<'a> {
+    world: &'a mut World,
+    tick: u32,
+    defer: bool,
+}
+
+impl Drop for BatchGuard<'_> {
+    fn drop(&mut self) {
+        self.world.batch_tick = None;
+        if self.defer {
+            self.world.archetypes.flush_deferred(self.tick - 1);
+        }
+    }
+}
+
 impl Default for World {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(feature = "leak-detection")]
+impl Drop for World {
+    fn drop(&mut self) {
+        for (_, arch) in self.archetypes.iter_mut() {
+            arch.clear();
+        }
+
+        // Avoid turning a panic into an abort
+        #[cfg(feature = "std")]
+        if std::thread::panicking() {
+            return;
+        }
+
+        for (key, counts) in self.debug_component_counts() {
+            assert_eq!(
+                counts.constructed(),
+                counts.dropped(),
+                "Values of component {key} were not dropped exactly once",
+            );
+        }
+    }
+}
+
 impl fmt::Debug for World {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.format_debug(component_info().without()).fmt(f)
@@ -1348,7 +3070,7 @@ mod tests {
 
     use alloc::{string::String, sync::Arc};
 
-    use crate::{component, CommandBuffer, EntityBuilder, FetchExt, Query};
+    use crate::{CommandBuffer, EntityBuilder, FetchExt, Query};
 
     use super::*;
 
@@ -1360,6 +3082,34 @@ mod tests {
         e: Arc<String>,
     }
 
+    #[test]
+    #[cfg(feature = "leak-detection")]
+    fn debug_component_counts() {
+        let mut world = World::new();
+
+        let ids = (0..4)
+            .map(|i| {
+                Entity::builder()
+                    .set(a(), i)
+                    .set(c(), "value".into())
+                    .spawn(&mut world)
+            })
+            .collect::<Vec<_>>();
+
+        world.set(ids[0], b(), 1.0).unwrap();
+        world.remove(ids[1], c()).unwrap();
+        world.despawn(ids[2]).unwrap();
+
+        let counts = world.debug_component_counts();
+        let c_counts = counts[&c().key()];
+        assert_eq!(c_counts.constructed(), 4);
+        assert_eq!(c_counts.dropped(), 2);
+        assert_eq!(c_counts.live(), 2);
+
+        assert_eq!(counts[&a().key()].live(), 3);
+        assert_eq!(counts[&b().key()].live(), 1);
+    }
+
     #[test]
     fn world_archetype_graph() {
         let mut world = World::new();
@@ -1505,11 +3255,140 @@ mod tests {
         // // Remove id
 
         let mut query = Query::new((a().cloned(), c().cloned()));
-        let items = query.borrow(&world).iter().collect_vec();
+        let items = query.borrow(&world).unwrap().iter().collect_vec();
 
         assert_eq!(items, [(6, "Bar".into())]);
     }
 
+    #[test]
+    fn iter_entities() {
+        let mut world = World::new();
+
+        let id1 = Entity::builder().set(a(), 5).spawn(&mut world);
+        let id2 = Entity::builder()
+            .set(a(), 2)
+            .set(b(), 1.0)
+            .spawn(&mut world);
+        let id3 = world.spawn();
+        let reserved = world.reserve_one(EntityKind::empty());
+
+        let despawned = world.spawn();
+        world.despawn(despawned).unwrap();
+
+        let ids = world
+            .iter_kind(EntityKind::empty())
+            .map(|v| v.id())
+            .filter(|v| !v.is_component())
+            .sorted()
+            .collect_vec();
+
+        assert_eq!(ids, [id1, id2, id3]);
+        assert!(!ids.contains(&reserved));
+
+        let components = world
+            .iter_kind(EntityKind::COMPONENT)
+            .map(|v| v.id())
+            .collect_vec();
+
+        assert!(components.contains(&a().id()));
+        assert!(components.contains(&b().id()));
+        assert!(components.iter().all(|v| v.is_component()));
+
+        assert_eq!(
+            world
+                .iter()
+                .filter_map(|v| v.get_copy(a()).ok())
+                .sum::<i32>(),
+            7
+        );
+    }
+
+    #[test]
+    fn invalid_ids() {
+        let mut world = World::new();
+
+        let id = Entity::builder().set(a(), 1).spawn(&mut world);
+        let arch_id = world.location(id).unwrap().arch_id;
+        assert_eq!(world.archetype_desc(arch_id).unwrap().components().len(), 1);
+
+        world.despawn(id).unwrap();
+
+        assert_eq!(world.remove(id, a()), Err(Error::NoSuchEntity(id)));
+        assert_eq!(world.get(id, a()).err(), Some(Error::NoSuchEntity(id)));
+
+        world.prune_archetypes();
+        assert_eq!(
+            world.archetype_desc(arch_id).err(),
+            Some(Error::NoSuchArchetype(arch_id))
+        );
+
+        let id = world.spawn();
+        let err = world
+            .remove(id, a())
+            .map_err(|v| v.context("remove health"))
+            .unwrap_err();
+
+        assert_eq!(err.operation(), Some("remove health"));
+        assert_eq!(err.entity(), Some(id));
+        assert_eq!(err.component(), Some(a().desc()));
+        assert_eq!(
+            err.root(),
+            &Error::MissingComponent(MissingComponent {
+                id,
+                desc: a().desc()
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "diagnostics")]
+    fn explain_missing() {
+        component! {
+            position: f32,
+            positon: f32,
+        }
+
+        let mut world = World::new();
+
+        let id = Entity::builder()
+            .set(positon(), 1.0)
+            .set(a(), 1)
+            .spawn(&mut world);
+
+        Entity::builder().set(position(), 1.0).spawn(&mut world);
+
+        let Err(Error::MissingComponent(missing)) = world.get(id, position()) else {
+            panic!("Expected a missing component");
+        };
+
+        let hint = world.explain_missing(&missing);
+        assert_eq!(hint.similar, ["positon"]);
+        assert_eq!(hint.archetypes, 1);
+    }
+
+    #[test]
+    fn register_components() {
+        component! {
+            unused: f32,
+        }
+
+        let mut world = World::new();
+        assert!(!world.registered_components().any(|v| v == unused().desc()));
+
+        assert_eq!(world.register(unused()), unused().desc());
+        assert!(world.is_alive(unused().id()));
+        assert_eq!(
+            world.get(unused().id(), name()).as_deref(),
+            Ok(&String::from("unused"))
+        );
+
+        Entity::builder().set(a(), 1).spawn(&mut world);
+
+        let components = world.registered_components().collect_vec();
+        assert!(components.contains(&unused().desc()));
+        assert!(components.contains(&a().desc()));
+    }
+
     #[test]
     fn reserve() {
         let mut world = World::new();
@@ -1540,6 +3419,7 @@ mod tests {
 
         let items: Vec<(Entity, String)> = Query::new((entity_ids(), name()))
             .borrow(&world)
+            .unwrap()
             .iter()
             .map(|(id, name)| (id, name.into()))
             .sorted()
@@ -1583,6 +3463,7 @@ mod tests {
 
         let items: Vec<(Entity, String)> = Query::new((entity_ids(), name()))
             .borrow(&world)
+            .unwrap()
             .iter()
             .map(|(id, name)| (id, name.into()))
             .sorted()
@@ -1600,4 +3481,332 @@ mod tests {
                 .collect_vec()
         );
     }
+
+    #[test]
+    fn column_roundtrip() {
+        let mut world = World::new();
+
+        let ids = (0..6)
+            .map(|i| {
+                let mut builder = Entity::builder();
+                builder.set(a(), i);
+                if i % 2 == 0 {
+                    builder.set(b(), 0.5);
+                }
+                builder.spawn(&mut world)
+            })
+            .collect_vec();
+
+        let mut column = world.extract_column(b().with(), a());
+        column.sort();
+        assert_eq!(column, [(ids[0], 0), (ids[2], 2), (ids[4], 4)]);
+
+        // Write back in reverse to make sure the order does not matter
+        let values = column
+            .iter()
+            .rev()
+            .map(|&(id, v)| (id, v * 10))
+            .collect_vec();
+
+        let mut changed = Query::new(entity_ids()).filter(a().modified());
+        changed.borrow(&world).unwrap().iter().for_each(drop);
+
+        world.write_column(a(), &values).unwrap();
+
+        assert_eq!(
+            changed
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .sorted()
+                .collect_vec(),
+            [ids[0], ids[2], ids[4]]
+        );
+
+        assert_eq!(
+            ids.iter()
+                .map(|&id| *world.get(id, a()).unwrap())
+                .collect_vec(),
+            [0, 1, 20, 3, 40, 5]
+        );
+
+        // Nothing is written if an entity lacks the component
+        assert_eq!(
+            world.write_column(b(), &[(ids[0], 1.0), (ids[1], 1.0)]),
+            Err(Error::MissingComponent(MissingComponent {
+                id: ids[1],
+                desc: b().desc()
+            }))
+        );
+        assert_eq!(world.get(ids[0], b()).as_deref(), Ok(&0.5));
+    }
+
+    #[test]
+    fn swap_components() {
+        let mut world = World::new();
+
+        let x = Entity::builder()
+            .set(a(), 1)
+            .set(c(), "x".into())
+            .spawn(&mut world);
+
+        let y = Entity::builder()
+            .set(a(), 2)
+            .set(c(), "y".into())
+            .spawn(&mut world);
+
+        let z = Entity::builder()
+            .set(a(), 3)
+            .set(b(), 0.5)
+            .set(c(), "z".into())
+            .spawn(&mut world);
+
+        let mut changed = Query::new(entity_ids()).filter(c().modified());
+        changed.borrow(&world).unwrap().iter().for_each(drop);
+
+        // Same archetype
+        world.swap_components(x, y, c()).unwrap();
+        assert_eq!(*world.get(x, c()).unwrap(), "y");
+        assert_eq!(*world.get(y, c()).unwrap(), "x");
+        assert_eq!(*world.get(x, a()).unwrap(), 1);
+
+        assert_eq!(
+            changed
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .sorted()
+                .collect_vec(),
+            [x, y]
+        );
+
+        // Different archetypes
+        world.swap_all(x, z).unwrap();
+        assert_eq!(*world.get(x, a()).unwrap(), 3);
+        assert_eq!(*world.get(x, c()).unwrap(), "z");
+        assert_eq!(*world.get(z, a()).unwrap(), 1);
+        assert_eq!(*world.get(z, c()).unwrap(), "y");
+        assert_eq!(world.get(z, b()).as_deref(), Ok(&0.5));
+        assert!(!world.has(x, b()));
+
+        assert_eq!(
+            changed
+                .borrow(&world)
+                .unwrap()
+                .iter()
+                .sorted()
+                .collect_vec(),
+            [x, z]
+        );
+
+        assert_eq!(
+            world.swap_components(x, z, b()),
+            Err(Error::MissingComponent(MissingComponent {
+                id: x,
+                desc: b().desc()
+            }))
+        );
+
+        component! {
+            frozen: i32 => [ crate::metadata::Immutable ],
+        }
+
+        world.set(x, frozen(), 1).unwrap();
+        world.set(z, frozen(), 2).unwrap();
+
+        assert_eq!(
+            world.swap_components(x, z, frozen()),
+            Err(Error::ImmutableComponent(frozen().desc()))
+        );
+        assert_eq!(
+            world.swap_all(x, z),
+            Err(Error::ImmutableComponent(frozen().desc()))
+        );
+
+        // Nothing was swapped
+        assert_eq!(world.get(x, frozen()).as_deref(), Ok(&1));
+        assert_eq!(world.get(x, a()).as_deref(), Ok(&3));
+    }
+
+    #[test]
+    fn retarget_relations() {
+        component! {
+            weight(target): i32,
+        }
+
+        let mut world = World::new();
+        let old = world.spawn();
+        let new = world.spawn();
+        let other = world.spawn();
+
+        let x = Entity::builder()
+            .set(a(), 1)
+            .set(weight(old), 5)
+            .set(weight(other), 6)
+            .spawn(&mut world);
+
+        let y = Entity::builder()
+            .set(weight(old), 7)
+            .set(weight(new), 8)
+            .spawn(&mut world);
+
+        world.retarget_relations(old, new);
+
+        assert_eq!(world.get(x, weight(new)).as_deref(), Ok(&5));
+        assert_eq!(world.get(x, weight(other)).as_deref(), Ok(&6));
+        assert_eq!(world.get(x, a()).as_deref(), Ok(&1));
+        assert!(!world.has(x, weight(old)));
+
+        // The existing relation to `new` is kept
+        assert_eq!(world.get(y, weight(new)).as_deref(), Ok(&8));
+        assert!(!world.has(y, weight(old)));
+
+        let mut query = Query::new((entity_ids(), weight(new).copied()));
+        assert_eq!(
+            query.borrow(&world).unwrap().iter().sorted().collect_vec(),
+            [(x, 5), (y, 8)]
+        );
+    }
+
+    #[test]
+    fn create_archetype() {
+        let mut world = World::new();
+
+        let arch_id = world.create_archetype(&[b().desc(), a().desc(), b().desc()]);
+        let gen = world.archetype_gen();
+
+        assert_eq!(world.create_archetype(&[a().desc(), b().desc()]), arch_id);
+        assert_eq!(world.archetype_gen(), gen);
+
+        let id = Entity::builder()
+            .set(a(), 1)
+            .set(b(), 2.0)
+            .spawn(&mut world);
+
+        assert_eq!(world.location(id).unwrap().arch_id, arch_id);
+        assert_eq!(world.archetype_gen(), gen);
+    }
+
+    #[test]
+    fn find() {
+        let mut world = World::new();
+
+        let x = Entity::builder().set(a(), 1).spawn(&mut world);
+        let y = Entity::builder()
+            .set(a(), 2)
+            .set(c(), "y".into())
+            .spawn(&mut world);
+        let z = Entity::builder()
+            .set(a(), 3)
+            .set(b(), 0.5)
+            .spawn(&mut world);
+
+        assert_eq!(world.find(c().with()), Some(y));
+        assert_eq!(world.find(a().gt(2)), Some(z));
+        assert_eq!(world.find(a().eq(1)), Some(x));
+        assert_eq!(world.find(a().gt(5)), None);
+        assert_eq!(world.find(d().with()), None);
+
+        assert_eq!(
+            world.find_map((a(), c()), |(&a, c)| (a == 2).then(|| c.clone())),
+            Some("y".into())
+        );
+        assert_eq!(world.find_map(b().copied(), |v| Some(v * 2.0)), Some(1.0));
+        assert_eq!(world.find_map(a().copied(), |v| (v > 5).then_some(v)), None);
+
+        // Component metadata entities are skipped, just like for queries
+        assert_eq!(world.find(component_info().with()), None);
+
+        world.despawn(y).unwrap();
+        assert_eq!(world.find(c().with()), None);
+    }
+
+    #[test]
+    fn disabled_entities() {
+        use crate::components::disabled;
+
+        let mut world = World::new();
+
+        let x = Entity::builder().set(a(), 1).spawn(&mut world);
+        let y = Entity::builder()
+            .set(a(), 2)
+            .set(disabled(), ())
+            .spawn(&mut world);
+
+        assert_eq!(world.find(a().eq(2)), Some(y));
+        assert_eq!(
+            world.find_map(a().copied(), |v| (v > 1).then_some(v)),
+            Some(2)
+        );
+
+        let mut column = world.extract_column(All, a());
+        column.sort();
+        assert_eq!(column, [(x, 1), (y, 2)]);
+
+        let s = alloc::format!("{world:?}");
+        assert!(s.contains(&alloc::format!("{y}")), "{s}");
+
+        world.despawn_many(a().with());
+        assert!(!world.is_alive(x));
+        assert!(!world.is_alive(y));
+    }
+
+    #[test]
+    fn changes() {
+        let mut world = World::new();
+
+        let ids = (0..4)
+            .map(|i| Entity::builder().set(a(), i).spawn(&mut world))
+            .collect_vec();
+
+        let arch_id = world.location(ids[0]).unwrap().arch_id;
+
+        assert_eq!(
+            world
+                .changes(arch_id, a().key(), ChangeKind::Added)
+                .flat_map(|(slots, _)| slots.iter())
+                .collect_vec(),
+            [0, 1, 2, 3]
+        );
+
+        // Start tracking modifications, inserted values are always modified
+        assert_eq!(
+            world
+                .changes(arch_id, a().key(), ChangeKind::Modified)
+                .flat_map(|(slots, _)| slots.iter())
+                .collect_vec(),
+            [0, 1, 2, 3]
+        );
+
+        // Observe the current tick so that the write receives a later one
+        let spawned = world.change_tick();
+        let tick = world.advance_change_tick();
+        assert!(tick > spawned);
+
+        let arch = world.archetype(arch_id).unwrap();
+        let mut values = arch.get_slice_mut(a(), Slice::new(1, 3), tick).unwrap();
+        values.get_mut().iter_mut().for_each(|v| *v *= 2);
+        drop(values);
+
+        assert_eq!(
+            world
+                .changes(arch_id, a().key(), ChangeKind::Modified)
+                .filter(|&(_, t)| t == tick)
+                .collect_vec(),
+            [(Slice::new(1, 3), tick)]
+        );
+
+        assert_eq!(
+            world
+                .changes(arch_id, b().key(), ChangeKind::Modified)
+                .count(),
+            0
+        );
+        assert_eq!(
+            world
+                .changes(arch_id, a().key(), ChangeKind::Removed)
+                .count(),
+            0
+        );
+    }
 }
@@ -0,0 +1,167 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use atomic_refcell::AtomicRef;
+
+use crate::{
+    component::{ComponentDesc, ComponentKey, ComponentValue},
+    error::Result,
+    query::QueryStrategy,
+    system::{Access, AccessKind, SystemAccess},
+    Component, Entity, Error, Fetch, Query, RefMut, World,
+};
+
+/// Declares the components a [`WorldCell`] may access.
+///
+/// See [`World::split`]
+#[derive(Debug, Clone, Default)]
+pub struct CellAccess {
+    /// Maps each component to whether it is accessed mutably
+    components: BTreeMap<ComponentKey, (ComponentDesc, bool)>,
+}
+
+impl CellAccess {
+    /// Creates an empty access declaration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares read access to `component`
+    pub fn read<T: ComponentValue>(mut self, component: Component<T>) -> Self {
+        self.components
+            .entry(component.key())
+            .or_insert((component.desc(), false));
+        self
+    }
+
+    /// Declares read and write access to `component`
+    pub fn write<T: ComponentValue>(mut self, component: Component<T>) -> Self {
+        self.components
+            .insert(component.key(), (component.desc(), true));
+        self
+    }
+
+    /// Returns a component which is accessed by both, where at least one access is mutable
+    fn conflict(&self, other: &Self) -> Option<ComponentDesc> {
+        self.components
+            .iter()
+            .find(|(key, &(_, mutable))| {
+                other
+                    .components
+                    .get(key)
+                    .is_some_and(|&(_, other_mutable)| mutable || other_mutable)
+            })
+            .map(|(_, &(desc, _))| desc)
+    }
+
+    fn is_readable(&self, key: ComponentKey) -> bool {
+        self.components.contains_key(&key)
+    }
+
+    fn is_writable(&self, key: ComponentKey) -> bool {
+        self.components.get(&key).is_some_and(|v| v.1)
+    }
+}
+
+/// A handle to a world which is restricted to a declared set of components.
+///
+/// Cells created by the same [`World::split`] access disjoint components, and can as such be used
+/// simultaneously on different threads, such as from the jobs of an external job system.
+///
+/// Using a component which was not declared panics.
+#[derive(Debug)]
+pub struct WorldCell<'w> {
+    world: &'w World,
+    access: CellAccess,
+}
+
+impl<'w> WorldCell<'w> {
+    pub(crate) fn split<const N: usize>(
+        world: &'w World,
+        accesses: [CellAccess; N],
+    ) -> Result<[Self; N]> {
+        for (i, a) in accesses.iter().enumerate() {
+            for b in &accesses[i + 1..] {
+                if let Some(desc) = a.conflict(b) {
+                    return Err(Error::ConflictingAccess(desc));
+                }
+            }
+        }
+
+        Ok(accesses.map(|access| Self { world, access }))
+    }
+
+    /// Returns the declared access of the cell
+    pub fn access(&self) -> &CellAccess {
+        &self.access
+    }
+
+    /// Access a component of an entity.
+    ///
+    /// Panics if the component was not declared.
+    pub fn get<T: ComponentValue>(
+        &self,
+        id: Entity,
+        component: Component<T>,
+    ) -> Result<AtomicRef<'w, T>> {
+        assert!(
+            self.access.is_readable(component.key()),
+            "Component {} is not declared as read or written by the world cell",
+            component.name()
+        );
+
+        self.world.get(id, component)
+    }
+
+    /// Mutably access a component of an entity.
+    ///
+    /// Panics if the component was not declared as written.
+    pub fn get_mut<T: ComponentValue>(
+        &self,
+        id: Entity,
+        component: Component<T>,
+    ) -> Result<RefMut<'w, T>> {
+        assert!(
+            self.access.is_writable(component.key()),
+            "Component {} is not declared as written by the world cell",
+            component.name()
+        );
+
+        self.world.get_mut(id, component)
+    }
+
+    /// Borrow a query for the cell.
+    ///
+    /// Panics if the query accesses components which were not declared, or requires any other
+    /// access than a shared borrow of the world.
+    pub fn borrow<'q, Q, F, S>(
+        &'q self,
+        query: &'q mut Query<Q, F, S>,
+    ) -> <S as QueryStrategy<'q, Q, F>>::Borrow
+    where
+        Q: 'static + for<'x> Fetch<'x>,
+        F: 'static + for<'x> Fetch<'x>,
+        S: for<'x> QueryStrategy<'x, Q, F>,
+    {
+        let mut accesses = Vec::new();
+        query.access(self.world, &mut accesses);
+
+        for access in &accesses {
+            assert!(
+                self.is_permitted(access),
+                "Query access {access:?} is not declared by the world cell"
+            );
+        }
+
+        query.borrow_unchecked(self.world)
+    }
+
+    fn is_permitted(&self, access: &Access) -> bool {
+        match access.kind {
+            AccessKind::Archetype { component, .. } if access.mutable => {
+                self.access.is_writable(component)
+            }
+            AccessKind::Archetype { component, .. } => self.access.is_readable(component),
+            AccessKind::World => !access.mutable,
+            _ => false,
+        }
+    }
+}
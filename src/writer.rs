@@ -4,13 +4,13 @@ use alloc::vec::Vec;
 use itertools::{Either, Itertools};
 
 use crate::{
-    archetype::{ArchetypeId, CellData, Slice, Slot},
+    archetype::{CellData, Slice, Slot},
     buffer::ComponentBuffer,
-    component::{ComponentDesc, ComponentValue},
+    component::{ComponentDesc, ComponentKey, ComponentValue},
     entity::EntityLocation,
     metadata::exclusive,
     world::update_entity_loc,
-    Entity, World,
+    Blob, Entity, World,
 };
 
 /// Describes a modification to the components of an entity within the context of an archetype
@@ -174,18 +174,6 @@ unsafe impl<W: ComponentUpdater + ComponentPusher> EntityWriter for SingleCompon
     }
 }
 
-/// # Safety
-/// *All* components of the new slot must be initialized
-pub(crate) unsafe trait MigrateEntity {
-    fn migrate(
-        self,
-        world: &mut World,
-        src_id: ArchetypeId,
-        src_slot: Slot,
-        tick: u32,
-    ) -> (EntityLocation, Option<(Entity, Slot)>);
-}
-
 pub(crate) struct Replace<T: ComponentValue> {
     pub(crate) value: T,
 }
@@ -200,6 +188,11 @@ impl<T: ComponentValue> ComponentUpdater for Replace<T> {
     type Updated = T;
 
     unsafe fn update(self, data: &mut CellData, slot: Slot, id: Entity, tick: u32) -> T {
+        // The replaced blob can no longer refer to the slab of the cell
+        if let Some(blobs) = &data.blobs {
+            data.storage.downcast_mut::<Blob>()[slot].detach(blobs);
+        }
+
         let storage = data.storage.downcast_mut::<T>();
         let old = mem::replace(&mut storage[slot], self.value);
 
@@ -216,6 +209,7 @@ impl<T: ComponentValue> ComponentPusher for Replace<T> {
         let slot = data.storage.len();
 
         data.storage.extend(&mut self.value as *mut T as *mut u8, 1);
+        data.storage.record_constructed(1);
 
         mem::forget(self.value);
 
@@ -240,6 +234,7 @@ impl<T: ComponentValue> ComponentPusher for Missing<T> {
         let slot = data.storage.len();
 
         data.storage.extend(&mut self.value as *mut T as *mut u8, 1);
+        data.storage.record_constructed(1);
 
         mem::forget(self.value);
 
@@ -278,6 +273,7 @@ impl<T: ComponentValue + PartialEq> ComponentPusher for WriteDedup<T> {
         let slot = data.storage.len();
 
         data.storage.extend(&mut self.value as *mut T as *mut u8, 1);
+        data.storage.record_constructed(1);
 
         mem::forget(self.value);
 
@@ -318,6 +314,7 @@ impl ComponentPusher for WriteDedupDyn {
     unsafe fn push(self, data: &mut CellData, id: Entity, tick: u32) {
         let slot = data.storage.len();
         data.storage.extend(self.value, 1);
+        data.storage.record_constructed(1);
 
         data.set_added(&[id], Slice::single(slot), tick);
     }
@@ -350,6 +347,7 @@ impl ComponentPusher for ReplaceDyn {
     unsafe fn push(self, data: &mut CellData, id: Entity, tick: u32) {
         let slot = data.storage.len();
         data.storage.extend(self.value, 1);
+        data.storage.record_constructed(1);
 
         data.set_added(&[id], Slice::single(slot), tick);
     }
@@ -376,6 +374,7 @@ impl ComponentPusher for MissingDyn {
     unsafe fn push(self, data: &mut CellData, id: Entity, tick: u32) {
         let slot = data.storage.len();
         data.storage.extend(self.value, 1);
+        data.storage.record_constructed(1);
 
         data.set_added(&[id], Slice::single(slot), tick);
     }
@@ -460,11 +459,7 @@ unsafe impl<'b> EntityWriter for Buffered<'b> {
         let (dst_slot, swapped) = unsafe { src.move_to(dst, src_loc.slot, |c, ptr| c.drop(ptr)) };
 
         // Insert the missing components
-        for (desc, src) in self.buffer.drain() {
-            unsafe {
-                dst.push(desc.key, src, tick);
-            }
-        }
+        unsafe { dst.push_all(self.buffer.drain(), tick) }
 
         let dst_loc = EntityLocation {
             arch_id: dst_id,
@@ -478,6 +473,98 @@ unsafe impl<'b> EntityWriter for Buffered<'b> {
     }
 }
 
+/// Sets the components of a buffer and removes a set of components in a single migration
+pub(crate) struct Edit<'b> {
+    buffer: &'b mut ComponentBuffer,
+    /// Disjoint from the components in `buffer`
+    removed: &'b [ComponentKey],
+}
+
+impl<'b> Edit<'b> {
+    pub(crate) fn new(buffer: &'b mut ComponentBuffer, removed: &'b [ComponentKey]) -> Self {
+        Self { buffer, removed }
+    }
+}
+
+unsafe impl<'b> EntityWriter for Edit<'b> {
+    type Output = ();
+
+    fn write(
+        self,
+        world: &mut World,
+        id: Entity,
+        src_loc: EntityLocation,
+        tick: u32,
+    ) -> (EntityLocation, ()) {
+        let arch = world.archetypes.get(src_loc.arch_id);
+        if !self.removed.iter().any(|&key| arch.has(key)) {
+            // No migration is needed to remove components
+            return Buffered::new(self.buffer).write(world, id, src_loc, tick);
+        }
+
+        let mut exclusive_relations = Vec::new();
+
+        let arch = world.archetypes.get_mut(src_loc.arch_id);
+        unsafe {
+            self.buffer.retain(|desc, src| {
+                let key = desc.key;
+                if let Some(cell) = arch.cell_mut(key) {
+                    let data = cell.data.get_mut();
+
+                    let dst = data.storage.at_mut(src_loc.slot).unwrap();
+                    desc.drop(dst);
+                    ptr::copy_nonoverlapping(src, dst, desc.size());
+
+                    data.set_modified(&[id], Slice::single(src_loc.slot), tick);
+                    false
+                } else {
+                    if key.target.is_some() && desc.meta_ref().has(exclusive()) {
+                        if exclusive_relations.contains(&key.id) {
+                            panic!("Multiple exclusive relations");
+                        }
+
+                        exclusive_relations.push(key.id);
+                    }
+
+                    true
+                }
+            });
+        }
+
+        let (components, _) = find_archetype_components(
+            arch.components_desc()
+                .filter(|v| !self.removed.contains(&v.key)),
+            self.buffer.components().copied(),
+            &exclusive_relations,
+        );
+
+        for &desc in self.buffer.components() {
+            world.init_component(desc);
+        }
+
+        let (dst_id, _) = world.archetypes.find_create(components);
+
+        let (src, dst) = world
+            .archetypes
+            .get_disjoint(src_loc.arch_id, dst_id)
+            .unwrap();
+
+        // Drops the removed components
+        let (dst_slot, swapped) = unsafe { src.move_to(dst, src_loc.slot, |c, ptr| c.drop(ptr)) };
+
+        unsafe { dst.push_all(self.buffer.drain(), tick) }
+
+        let dst_loc = EntityLocation {
+            arch_id: dst_id,
+            slot: dst_slot,
+        };
+
+        update_entity_loc(world, id, dst_loc, swapped);
+
+        (dst_loc, ())
+    }
+}
+
 fn find_archetype_components(
     current_components: impl IntoIterator<Item = ComponentDesc>,
     new_components: impl IntoIterator<Item = ComponentDesc>,
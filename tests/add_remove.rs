@@ -22,12 +22,181 @@ fn add_remove() {
     }
 
     let mut q = Query::new(b());
-    assert_eq!(q.borrow(&world).count(), COUNT);
+    assert_eq!(q.borrow(&world).unwrap().count(), COUNT);
 
     for id in &ids {
         world.remove(*id, b()).unwrap();
     }
 
-    assert_eq!(q.borrow(&world).count(), 0);
-    assert_eq!(Query::new(a()).borrow(&world).count(), COUNT);
+    assert_eq!(q.borrow(&world).unwrap().count(), 0);
+    assert_eq!(Query::new(a()).borrow(&world).unwrap().count(), COUNT);
+}
+
+#[test]
+fn migration_stats() {
+    component! {
+        position: f32,
+        selected: (),
+        health: f32,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..4)
+        .map(|i| {
+            Entity::builder()
+                .set(position(), i as f32)
+                .spawn(&mut world)
+        })
+        .collect::<Vec<_>>();
+
+    world.reset_migration_stats();
+
+    for _ in 0..3 {
+        for &id in &ids {
+            world.set(id, selected(), ()).unwrap();
+        }
+
+        for &id in &ids {
+            world.remove(id, selected()).unwrap();
+        }
+    }
+
+    world.set(ids[0], health(), 1.0).unwrap();
+    // Not a migration
+    world.set(ids[0], health(), 2.0).unwrap();
+
+    world.clear(ids[0]).unwrap();
+
+    let stats = world.migration_stats();
+    let counts = stats
+        .iter()
+        .map(|v| (v.component().key(), v.added(), v.removed()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        counts,
+        [
+            (selected().key(), 12, 12),
+            (health().key(), 1, 1),
+            (position().key(), 0, 1),
+        ]
+    );
+
+    world.reset_migration_stats();
+    assert!(world.migration_stats().is_empty());
+}
+
+#[test]
+fn edit() {
+    component! {
+        position: f32,
+        velocity: f32,
+        health: f32,
+        is_dead: (),
+    }
+
+    let mut world = World::new();
+
+    let id = Entity::builder()
+        .set(position(), 1.0)
+        .set(velocity(), 2.0)
+        .set(health(), 0.0)
+        .spawn(&mut world);
+
+    let mut changes = Query::new(entity_ids()).filter(position().modified());
+    changes.borrow(&world).unwrap().for_each(|_| {});
+
+    world.reset_migration_stats();
+
+    world
+        .edit(id)
+        .set(position(), 5.0)
+        .remove(velocity())
+        .remove(health())
+        .tag(is_dead())
+        .apply()
+        .unwrap();
+
+    assert_eq!(world.get(id, position()).as_deref(), Ok(&5.0));
+    assert!(!world.has(id, velocity()));
+    assert!(!world.has(id, health()));
+    assert!(world.has(id, is_dead()));
+    assert_eq!(
+        changes.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+        [id]
+    );
+
+    // The entity migrated once, directly to the final archetype
+    assert!(world.migration_stats().iter().all(|v| v.total() == 1));
+
+    // Later operations cancel earlier ones
+    world
+        .edit(id)
+        .remove(position())
+        .set(position(), 6.0)
+        .set(health(), 1.0)
+        .remove(health())
+        .remove(velocity())
+        .apply()
+        .unwrap();
+
+    assert_eq!(world.get(id, position()).as_deref(), Ok(&6.0));
+    assert!(!world.has(id, health()));
+
+    world.despawn(id).unwrap();
+    assert!(world.edit(id).tag(is_dead()).apply().is_err());
+}
+
+#[test]
+fn edge_cache() {
+    component! {
+        position: f32,
+        health: f32,
+        selected: (),
+    }
+
+    let mut world = World::new();
+
+    let spawn = |world: &mut World| {
+        Entity::builder()
+            .set(position(), 0.0)
+            .set(health(), 1.0)
+            .tag(selected())
+            .spawn(world)
+    };
+
+    let a = spawn(&mut world);
+    world
+        .entity_mut(a)
+        .unwrap()
+        .retain(|key| key == position().key());
+
+    let stats = world.edge_cache_stats();
+
+    // The removal edges are reused
+    let b = spawn(&mut world);
+    world
+        .entity_mut(b)
+        .unwrap()
+        .retain(|key| key == position().key());
+
+    let new_stats = world.edge_cache_stats();
+    assert_eq!(new_stats.misses(), stats.misses());
+    assert_eq!(new_stats.hits(), stats.hits() + 2);
+
+    for id in [a, b] {
+        assert!(world.has(id, position()));
+        assert!(!world.has(id, health()));
+        assert!(!world.has(id, selected()));
+    }
+
+    let c = spawn(&mut world);
+    world.remove(c, selected()).unwrap();
+    world.remove(c, health()).unwrap();
+    assert!(world.has(c, position()));
+
+    // Retaining all components is not a migration
+    world.entity_mut(c).unwrap().retain(|_| true);
+    assert_eq!(world.get(c, position()).as_deref(), Ok(&0.0));
 }
@@ -0,0 +1,100 @@
+use std::iter::repeat;
+
+use flax::{component, BatchSpawn, CommandBuffer, Entity, EntityBuilder, Query, World};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C, align(64))]
+struct Aligned64([f32; 4]);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(align(32))]
+struct Aligned32;
+
+component! {
+    wide: Aligned64,
+    marker: Aligned32,
+    small: u8,
+}
+
+fn assert_aligned(world: &World) {
+    let mut query = Query::new(wide());
+    for v in &mut query.borrow(world).unwrap() {
+        assert_eq!(v as *const Aligned64 as usize % 64, 0);
+    }
+
+    let mut query = Query::new(marker());
+    for v in &mut query.borrow(world).unwrap() {
+        assert_eq!(v as *const Aligned32 as usize % 32, 0);
+    }
+}
+
+#[test]
+fn over_aligned_components() {
+    let mut world = World::new();
+
+    assert_eq!(wide().desc().align(), 64);
+    assert_eq!(marker().desc().align(), 32);
+
+    let ids = (0..100)
+        .map(|i| {
+            Entity::builder()
+                .set(small(), i as u8)
+                .set(wide(), Aligned64([i as f32; 4]))
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    let mut batch = BatchSpawn::new(50);
+    batch
+        .set(wide(), (0..50).map(|i| Aligned64([i as f32; 4])))
+        .unwrap();
+    batch.set(marker(), repeat(Aligned32)).unwrap();
+    batch.spawn(&mut world);
+
+    // Migrate entities between archetypes
+    for &id in ids.iter().step_by(3) {
+        world.set(id, marker(), Aligned32).unwrap();
+    }
+
+    for &id in ids.iter().step_by(2) {
+        world.remove(id, small()).unwrap();
+    }
+
+    let mut cmd = CommandBuffer::new();
+    for i in 0..10 {
+        EntityBuilder::new()
+            .set(small(), 1)
+            .set(wide(), Aligned64([i as f32; 4]))
+            .spawn_into(&mut cmd);
+    }
+    cmd.apply(&mut world).unwrap();
+
+    assert_aligned(&world);
+
+    for (i, &id) in ids.iter().enumerate() {
+        assert_eq!(*world.get(id, wide()).unwrap(), Aligned64([i as f32; 4]));
+    }
+
+    // Recycled allocations retain their alignment
+    for &id in &ids {
+        world.despawn(id).unwrap();
+    }
+
+    world.prune_archetypes();
+
+    for i in 0..100 {
+        Entity::builder()
+            .set(small(), i as u8)
+            .set(wide(), Aligned64([i as f32; 4]))
+            .spawn(&mut world);
+    }
+
+    assert_aligned(&world);
+
+    for info in world.archetype_info().values() {
+        for (desc, storage) in info.components().iter().zip(info.storage()) {
+            assert_eq!(storage.align(), desc.align());
+        }
+    }
+}
@@ -36,7 +36,7 @@ fn query() {
 
     let mut query = Query::new((entity_ids(), a()));
     let mut world = World::new();
-    query.borrow(&world).iter().for_each(|_| {});
+    query.borrow(&world).unwrap().iter().for_each(|_| {});
 
     let id1 = world.spawn();
     let id2 = world.spawn();
@@ -50,6 +50,7 @@ fn query() {
 
     let items = query
         .borrow(&world)
+        .unwrap()
         .iter()
         .map(|(a, b)| (a, *b))
         .inspect(|v| println!("{v:?}"))
@@ -93,7 +94,7 @@ fn builder() {
     assert!(world.has(c().id(), debuggable()));
 
     let mut query = Query::new((a(), c()));
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
     let components = query.iter().sorted().collect_vec();
 
     assert_eq!(
@@ -110,7 +111,7 @@ fn builder() {
 
     {
         let mut query = Query::new((a(), c().as_mut()));
-        let mut prepared = query.borrow(&world);
+        let mut prepared = query.borrow(&world).unwrap();
         let items = prepared.get(id).unwrap();
         *items.1 = items.1.repeat(*items.0);
     }
@@ -149,7 +150,7 @@ fn tags() {
         .collect_vec();
 
     let mut query = Query::new(health());
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
     let items = query.into_iter().sorted().collect_vec();
 
     let expected = ([50; 16]).iter().chain(&[100]).collect_vec();
@@ -175,7 +176,7 @@ fn query_view() {
         .collect_vec();
 
     let mut query = Query::new((pos().as_mut(), vel()));
-    let mut prepared = query.borrow(&world);
+    let mut prepared = query.borrow(&world).unwrap();
 
     // Perform integration
     for (pos, vel) in &mut prepared {
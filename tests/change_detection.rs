@@ -67,6 +67,7 @@ fn change_detection() {
     assert_eq!(
         query
             .borrow(&world)
+            .unwrap()
             .iter()
             .map(|v| v.0)
             .sorted()
@@ -81,6 +82,7 @@ fn change_detection() {
     assert_eq!(
         query
             .borrow(&world)
+            .unwrap()
             .iter()
             .map(|v| v.0)
             .sorted()
@@ -207,7 +209,7 @@ fn query_changes() {
 
     let mut changed = Query::new((entity_ids(), a().modified().copied()));
 
-    let mut changed = |w| changed.borrow(w).iter().map(|v| v.0).collect_vec();
+    let mut changed = |w| changed.borrow(w).unwrap().iter().map(|v| v.0).collect_vec();
 
     assert_eq!(changed(&world), ids);
 
@@ -215,6 +217,7 @@ fn query_changes() {
         .with(b())
         .without(c())
         .borrow(&world)
+        .unwrap()
         .for_each(|v| *v *= 2);
 
     assert_eq!(changed(&world), &ids[10..40]);
@@ -223,6 +226,7 @@ fn query_changes() {
         .with(b())
         .filter(!c().with() | c().gt(40.0))
         .borrow(&world)
+        .unwrap()
         .for_each(|v| *v *= 2);
 
     assert_eq!(
@@ -245,13 +249,19 @@ fn added_opt_union() {
 
     let id = Entity::builder().set(a(), 5).spawn(&mut world);
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), [(&5, &0)]);
-    assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().collect_vec(),
+        [(&5, &0)]
+    );
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 
     world.set(id, b(), 2).unwrap();
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), [(&5, &2)]);
-    assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().collect_vec(),
+        [(&5, &2)]
+    );
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 }
 
 #[test]
@@ -268,10 +278,138 @@ fn added_opt_and() {
 
     let id = Entity::builder().set(a(), 5).spawn(&mut world);
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 
     world.set(id, b(), 2).unwrap();
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), [(&5, &2)]);
-    assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().collect_vec(),
+        [(&5, &2)]
+    );
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
+}
+
+#[test]
+fn modified_relation() {
+    component! {
+        child_of(parent): f32,
+    }
+
+    let mut world = World::new();
+
+    let parent = world.spawn();
+    let other = world.spawn();
+
+    let a = Entity::builder()
+        .set(child_of(parent), 1.0)
+        .spawn(&mut world);
+    let b = Entity::builder()
+        .set(child_of(parent), 1.0)
+        .set(child_of(other), 1.0)
+        .spawn(&mut world);
+    let c = world.spawn();
+
+    let mut query = Query::new(entity_ids()).filter(child_of.modified_relation());
+
+    assert_eq!(query.collect_sorted_vec(&world), [a, b]);
+    assert_eq!(query.collect_vec(&world), []);
+
+    // Modifying any edge matches, regardless of the target
+    *world.get_mut(b, child_of(other)).unwrap() = 2.0;
+    assert_eq!(query.collect_vec(&world), [b]);
+
+    world.set(c, child_of(other), 1.0).unwrap();
+    world.set(a, child_of(other), 1.0).unwrap();
+    assert_eq!(query.collect_sorted_vec(&world), [a, c]);
+    assert_eq!(query.collect_vec(&world), []);
+}
+
+#[test]
+fn change_feed() {
+    use flax::events::ChangeFeed;
+
+    component! {
+        a: i32,
+        b: i32,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..3)
+        .map(|i| {
+            // Separate the ticks of the spawns
+            let _ = world.change_tick();
+            Entity::builder().set(a(), i).spawn(&mut world)
+        })
+        .collect_vec();
+
+    let mut feed = ChangeFeed::modified(a());
+
+    let records = feed.poll(&world);
+    assert_eq!(records.iter().map(|v| v.0).collect_vec(), ids);
+    assert!(records.windows(2).all(|v| v[0].1 < v[1].1));
+    assert_eq!(feed.cursor(), world.change_tick());
+
+    assert_eq!(feed.poll(&world), []);
+
+    world.set(ids[1], a(), 5).unwrap();
+    let _ = world.change_tick();
+    *world.get_mut(ids[2], a()).unwrap() = 7;
+    let _ = world.change_tick();
+    // Moves the entity, but does not change `a`
+    world.set(ids[0], b(), 5).unwrap();
+
+    assert_eq!(
+        feed.poll(&world).iter().map(|v| v.0).collect_vec(),
+        [ids[1], ids[2]]
+    );
+
+    // A consumer which falls behind
+    let mut feed = ChangeFeed::added(a()).with_max_retained(2);
+    // The history before the feed was created is too old as well
+    assert_eq!(feed.poll(&world), []);
+    assert_eq!(feed.dropped(), ids.len());
+
+    let stale = Entity::builder().set(a(), 1).spawn(&mut world);
+    for _ in 0..3 {
+        let _ = world.change_tick();
+        Entity::builder().set(b(), 1).spawn(&mut world);
+    }
+
+    let _ = world.change_tick();
+    let fresh = Entity::builder().set(a(), 2).spawn(&mut world);
+
+    assert_ne!(stale, fresh);
+    assert_eq!(feed.poll(&world).iter().map(|v| v.0).collect_vec(), [fresh]);
+    assert_eq!(feed.dropped(), ids.len() + 1);
+}
+
+#[test]
+fn unchanged() {
+    component! {
+        a: i32,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..4)
+        .map(|i| Entity::builder().set(a(), i).spawn(&mut world))
+        .collect_vec();
+
+    let mut query = Query::new(entity_ids()).filter(a().unchanged());
+
+    // All entities were added since the query last ran
+    assert_eq!(query.collect_vec(&world), []);
+
+    let tick = world.change_tick();
+    *world.get_mut(ids[1], a()).unwrap() += 1;
+
+    assert_eq!(query.collect_vec(&world), [ids[0], ids[2], ids[3]]);
+    assert_eq!(query.collect_vec(&world), ids);
+
+    let mut query = Query::new(entity_ids()).filter(a().static_since(tick));
+    assert_eq!(query.collect_vec(&world), [ids[0], ids[2], ids[3]]);
+
+    let mut query = Query::new(entity_ids()).filter(a().static_since(world.change_tick()));
+    assert_eq!(query.collect_vec(&world), ids);
 }
@@ -43,16 +43,22 @@ fn commandbuffer() {
     let mut query = Query::new(entity_ids()).filter(name().without());
 
     // Deferred world modification while iterating
-    query.borrow(&world).iter().enumerate().for_each(|(i, id)| {
-        eprintln!("Adding name to id: {id}");
-        cmd.set(id, name(), format!("Unnamed: {i}"));
-    });
+    query
+        .borrow(&world)
+        .unwrap()
+        .iter()
+        .enumerate()
+        .for_each(|(i, id)| {
+            eprintln!("Adding name to id: {id}");
+            cmd.set(id, name(), format!("Unnamed: {i}"));
+        });
 
     cmd.apply(&mut world).unwrap();
 
     let mut name_query = Query::new(name());
     let names = name_query
         .borrow(&world)
+        .unwrap()
         .iter()
         .cloned()
         .sorted()
@@ -77,6 +83,7 @@ fn commandbuffer() {
     Query::new((entity_ids(), name()))
         .filter(name().cmp(|name: &String| name.contains("Unnamed")))
         .borrow(&world)
+        .unwrap()
         .iter()
         .for_each(|(id, n)| {
             eprintln!("Removing name for entity: {id} {n}");
@@ -89,6 +96,7 @@ fn commandbuffer() {
 
     let names = name_query
         .borrow(&world)
+        .unwrap()
         .iter()
         .cloned()
         .sorted()
@@ -116,6 +124,7 @@ fn commandbuffer() {
     let soldiers = Query::new(health())
         .filter(soldier().with())
         .borrow(&world)
+        .unwrap()
         .iter()
         .copied()
         .collect_vec();
@@ -128,6 +137,7 @@ fn commandbuffer() {
     if let Some(health) = Query::new(health().as_mut())
         .filter(soldier().with())
         .borrow(&world)
+        .unwrap()
         .iter()
         .nth(42)
     {
@@ -139,6 +149,7 @@ fn commandbuffer() {
     let soldiers = Query::new(name())
         .filter(soldier().with() & health().ge(100.0))
         .borrow(&world)
+        .unwrap()
         .iter()
         .cloned()
         .collect_vec();
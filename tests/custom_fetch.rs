@@ -0,0 +1,93 @@
+use core::fmt::{self, Formatter};
+
+use flax::{
+    archetype::{CellGuard, Slice},
+    component,
+    component::ComponentValue,
+    fetch::{FetchAccessData, FetchPrepareData, PreparedFetch},
+    system::{Access, AccessKind},
+    Component, Entity, Fetch, FetchItem, Query, World,
+};
+
+component! {
+    health: f32,
+}
+
+/// Yields the value of a component along with whether it changed since the last run
+struct Tracked<T>(Component<T>);
+
+struct PreparedTracked<'w, T> {
+    borrow: CellGuard<'w, [T]>,
+    changed: bool,
+}
+
+impl<'q, T: ComponentValue> FetchItem<'q> for Tracked<T> {
+    type Item = (&'q T, bool);
+}
+
+impl<'w, T: ComponentValue> Fetch<'w> for Tracked<T> {
+    const MUTABLE: bool = false;
+
+    type Prepared = PreparedTracked<'w, T>;
+
+    fn prepare(&'w self, data: FetchPrepareData<'w>) -> Option<Self::Prepared> {
+        Some(PreparedTracked {
+            borrow: data.arch.borrow(self.0.key())?,
+            changed: data.old_tick < data.new_tick,
+        })
+    }
+
+    fn filter_arch(&self, data: FetchAccessData) -> bool {
+        data.arch.has(self.0.key())
+    }
+
+    fn access(&self, data: FetchAccessData, dst: &mut Vec<Access>) {
+        if data.arch.has(self.0.key()) {
+            dst.push(Access {
+                kind: AccessKind::Archetype {
+                    id: data.arch_id,
+                    component: self.0.key(),
+                },
+                mutable: false,
+            })
+        }
+    }
+
+    fn describe(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "tracked {}", self.0.name())
+    }
+}
+
+impl<'w, 'q, T: 'q> PreparedFetch<'q> for PreparedTracked<'w, T> {
+    type Item = (&'q T, bool);
+    type Chunk = (core::slice::Iter<'q, T>, bool);
+
+    const HAS_FILTER: bool = false;
+
+    unsafe fn create_chunk(&mut self, slots: Slice) -> Self::Chunk
+    where
+        Self: 'q,
+    {
+        // Safety: the guard is held by the prepared fetch, which outlives the chunk
+        let storage = unsafe { &*(self.borrow.get() as *const [T]) };
+        (storage[slots.as_range()].iter(), self.changed)
+    }
+
+    unsafe fn fetch_next(chunk: &mut Self::Chunk) -> Self::Item {
+        (chunk.0.next().unwrap(), chunk.1)
+    }
+}
+
+#[test]
+fn custom_fetch() {
+    let mut world = World::new();
+
+    let id = Entity::builder().set(health(), 50.0).spawn(&mut world);
+
+    let mut query = Query::new((flax::entity_ids(), Tracked(health())));
+
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().collect::<Vec<_>>(),
+        [(id, (&50.0, true))]
+    );
+}
@@ -36,7 +36,7 @@ fn derive_fetch() {
         scale: scale().opt(),
     });
 
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
 
     assert_eq!(
         query.get(id1),
@@ -55,4 +55,23 @@ fn derive_fetch() {
             scale: None
         })
     );
+
+    drop(query);
+
+    // Derived fetches of built-in fetches can be read without acquiring the guards
+    let mut query = Query::new(TransformQuery {
+        pos: position(),
+        rot: rotation().opt(),
+        scale: scale().opt(),
+    })
+    .filter(
+        TransformQuery {
+            pos: position(),
+            rot: rotation().opt(),
+            scale: scale().opt(),
+        }
+        .added(),
+    );
+
+    assert_eq!(query.borrow_exclusive(&mut world).iter().count(), 2);
 }
@@ -43,7 +43,7 @@ fn derive_fetch_generic() {
         scale: scale().opt(),
     });
 
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
 
     assert_eq!(
         query.get(id1),
@@ -43,7 +43,7 @@ fn entity_ref() {
         ]
     );
 
-    // assert_eq!(query.borrow(&world).iter().collect_vec(), []);
+    // assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), []);
 
     world.clear(id).unwrap();
 
@@ -63,7 +63,7 @@ fn entity_ref() {
         ]
     );
 
-    // assert_eq!(query.borrow(&world).iter().collect_vec(), [id]);
+    // assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), [id]);
 }
 
 #[test]
@@ -113,6 +113,7 @@ fn entity_hierarchy() {
     assert_eq!(
         Query::new(name())
             .borrow(&world)
+            .unwrap()
             .iter()
             .cloned()
             .collect_vec(),
@@ -136,3 +137,133 @@ fn entity_hierarchy() {
 
     assert_eq!(rx.drain().collect_vec(), []);
 }
+
+fn sorted<const N: usize>(mut ids: [Entity; N]) -> [Entity; N] {
+    ids.sort();
+    ids
+}
+
+#[test]
+fn user_kinds() {
+    use flax::{entity::EntityKind, filter::kind_filter};
+
+    component! {
+        health: f32,
+    }
+
+    let editor = EntityKind::USER_0;
+    let networked = EntityKind::USER_1;
+
+    let mut world = World::new();
+
+    let a = Entity::builder()
+        .set(health(), 1.0)
+        .kind(editor)
+        .spawn(&mut world);
+    let b = Entity::builder().set(health(), 2.0).spawn(&mut world);
+    let c = Entity::builder()
+        .set(health(), 3.0)
+        .kind(editor | networked)
+        .spawn(&mut world);
+    let d = world.spawn_kind(networked);
+    world.set(d, health(), 4.0).unwrap();
+
+    assert_eq!(a.kind(), editor);
+    assert_eq!(b.kind(), EntityKind::empty());
+
+    // All in the same archetype
+    assert_eq!(world.get(c, health()).as_deref(), Ok(&3.0));
+
+    let mut query = Query::new(entity_ids())
+        .with(health())
+        .filter(kind_filter(editor));
+    assert_eq!(query.collect_sorted_vec(&world), sorted([a, c]));
+
+    let mut query = Query::new(entity_ids())
+        .with(health())
+        .filter(kind_filter(networked));
+    assert_eq!(query.collect_sorted_vec(&world), sorted([c, d]));
+
+    let mut query = Query::new(health())
+        .with(health())
+        .filter(!kind_filter(editor));
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().copied().sum::<f32>(),
+        6.0
+    );
+
+    // Negating slot level filters through the generic combinators
+    let mut query = Query::new(entity_ids())
+        .with(health())
+        .filter(!(kind_filter(editor) | kind_filter(networked)));
+    assert_eq!(query.collect_sorted_vec(&world), [b]);
+
+    let mut query = Query::new(entity_ids())
+        .with(health())
+        .filter(!(kind_filter(editor) & kind_filter(networked)));
+    assert_eq!(query.collect_sorted_vec(&world), sorted([a, b, d]));
+}
+
+#[test]
+#[should_panic]
+fn spawn_reserved_kind() {
+    let mut world = World::new();
+    world.spawn_kind(flax::entity::EntityKind::COMPONENT);
+}
+
+#[test]
+fn clone_entities() {
+    use flax::{
+        components::{child_of, name},
+        metadata::Cloneable,
+    };
+
+    component! {
+        health: f32 => [ Cloneable ],
+        not_cloned: i32,
+    }
+
+    let mut world = World::new();
+
+    let outside = Entity::builder()
+        .set(name(), "outside".into())
+        .spawn(&mut world);
+
+    let root = Entity::builder()
+        .set(name(), "root".into())
+        .set(health(), 10.0)
+        .set(not_cloned(), 1)
+        .set(child_of(outside), ())
+        .spawn(&mut world);
+
+    let child = Entity::builder()
+        .set(name(), "child".into())
+        .set(health(), 5.0)
+        .set(child_of(root), ())
+        .spawn(&mut world);
+
+    let mut tree = EntityTree::new();
+    world.clone_entities_into([root, child], &mut tree).unwrap();
+    assert_eq!(tree.len(), 2);
+
+    // The tree can be spawned several times
+    for _ in 0..2 {
+        let map = tree.spawn(&mut world);
+
+        let new_root = map[&root];
+        let new_child = map[&child];
+        assert_ne!(new_root, root);
+
+        assert_eq!(world.get(new_root, name()).as_deref(), Ok(&"root".into()));
+        assert_eq!(world.get(new_root, health()).as_deref(), Ok(&10.0));
+        assert!(!world.has(new_root, not_cloned()));
+
+        // Relations outside the selection are kept
+        assert!(world.has(new_root, child_of(outside)));
+
+        // Relations inside the selection are remapped
+        assert!(world.has(new_child, child_of(new_root)));
+        assert!(!world.has(new_child, child_of(root)));
+        assert_eq!(world.get(new_child, health()).as_deref(), Ok(&5.0));
+    }
+}
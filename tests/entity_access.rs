@@ -1,4 +1,6 @@
-use flax::{component, components::name, Entity, FetchExt, World};
+use flax::{
+    component, components::name, error::Error, fetch::entity_locs, Entity, FetchExt, Query, World,
+};
 
 #[test]
 fn entity_access() {
@@ -34,3 +36,133 @@ fn entity_access() {
     let mut query = entity.query(query);
     assert_eq!(query.get(), Some(("a".into(), &6)));
 }
+
+#[test]
+fn entity_components() {
+    component! {
+        a: i32,
+        b: String,
+    }
+
+    let mut world = World::new();
+
+    let id = Entity::builder()
+        .set(a(), 5)
+        .set(b(), "Foo".into())
+        .spawn(&mut world);
+
+    let entity = world.entity(id).unwrap();
+    assert_eq!(entity.len(), 2);
+
+    let mut components = entity.components().map(|v| v.key()).collect::<Vec<_>>();
+    components.sort();
+
+    let mut expected = [a().key(), b().key()];
+    expected.sort();
+    assert_eq!(components, expected);
+
+    let mut entity = world.entity_mut(id).unwrap();
+    entity.remove(b()).unwrap();
+
+    assert_eq!(entity.len(), 1);
+    assert_eq!(
+        entity.components().map(|v| v.name()).collect::<Vec<_>>(),
+        ["a"]
+    );
+
+    let empty = world.spawn();
+    assert!(world.entity(empty).unwrap().is_empty());
+}
+
+#[test]
+fn entity_locations() {
+    component! {
+        a: i32,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..3)
+        .map(|i| Entity::builder().set(a(), i).spawn(&mut world))
+        .collect::<Vec<_>>();
+
+    let locs = Query::new(entity_locs())
+        .borrow(&world)
+        .unwrap()
+        .iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(locs.iter().map(|v| v.id).collect::<Vec<_>>(), ids);
+
+    for (i, &loc) in locs.iter().enumerate() {
+        assert!(world.is_loc_valid(loc));
+        assert_eq!(world.get_at_loc(loc, a()).as_deref(), Ok(&(i as i32)));
+    }
+
+    *world.get_mut_at_loc(locs[1], a()).unwrap() = 10;
+    assert_eq!(world.get(ids[1], a()).as_deref(), Ok(&10));
+
+    // The last entity is moved into the despawned entity's slot
+    world.despawn(ids[0]).unwrap();
+
+    assert!(!world.is_loc_valid(locs[0]));
+    assert!(world.is_loc_valid(locs[1]));
+    assert!(!world.is_loc_valid(locs[2]));
+
+    assert_eq!(
+        world.get_at_loc(locs[0], a()).as_deref(),
+        Err(&Error::NoSuchEntity(ids[0]))
+    );
+    assert_eq!(world.get_at_loc(locs[2], a()).as_deref(), Ok(&2));
+}
+
+#[test]
+fn despawn_ref() {
+    component! {
+        a: i32,
+    }
+
+    let mut world = World::new();
+
+    let id = Entity::builder().set(a(), 1).spawn(&mut world);
+    let other = Entity::builder().set(a(), 2).spawn(&mut world);
+
+    let entity = world.entity_mut(id).unwrap();
+    assert_eq!(entity.get_copy(a()), Ok(1));
+    entity.despawn().unwrap();
+
+    assert!(!world.is_alive(id));
+    assert_eq!(world.entity_mut(id).err(), Some(Error::NoSuchEntity(id)));
+
+    // The swapped entity is still accessible
+    assert_eq!(world.get(other, a()).as_deref(), Ok(&2));
+}
+
+#[test]
+fn get_cloned() {
+    component! {
+        a: i32,
+        b: String,
+    }
+
+    let mut world = World::new();
+
+    let id = Entity::builder()
+        .set(a(), 1)
+        .set(b(), "Foo".into())
+        .spawn(&mut world);
+
+    let mut entity = world.entity_mut(id).unwrap();
+    let value = entity.get_cloned(b()).unwrap();
+
+    // No borrow is held
+    entity.set(b(), value + "Bar");
+    entity.set(a(), entity.get_copy(a()).unwrap() + 1);
+
+    assert_eq!(entity.get_cloned(b()).as_deref(), Ok("FooBar"));
+
+    let entity = world.entity(id).unwrap();
+    assert_eq!(entity.get_copy(a()), Ok(2));
+    assert_eq!(entity.get_cloned(b()), Ok("FooBar".to_string()));
+    assert!(entity.get_cloned(name()).is_err());
+}
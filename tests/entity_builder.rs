@@ -156,3 +156,31 @@ fn test_append() {
     assert!(!world.has(id3, relation(id2)));
     assert!(world.has(id3, relation(id1)));
 }
+
+#[test]
+fn conditional() {
+    let mut world = World::new();
+
+    fn with_name(name: Option<&str>) -> impl FnOnce(&mut flax::EntityBuilder) + '_ {
+        move |builder| {
+            builder
+                .set_opt(b(), name.map(Into::into))
+                .set_if(name.is_some(), a(), 1);
+        }
+    }
+
+    let id1 = Entity::builder()
+        .with(with_name(Some("foo")))
+        .spawn(&mut world);
+
+    let id2 = Entity::builder()
+        .set(a(), 2)
+        .with(with_name(None))
+        .set_if(false, b(), "bar".into())
+        .spawn(&mut world);
+
+    assert_eq!(world.get(id1, a()).as_deref(), Ok(&1));
+    assert_eq!(world.get(id1, b()).as_deref(), Ok(&String::from("foo")));
+    assert_eq!(world.get(id2, a()).as_deref(), Ok(&2));
+    assert!(!world.has(id2, b()));
+}
@@ -32,7 +32,7 @@ fn filters() {
 
     let mut query = Query::new(a().cloned()).filter(a().modified());
 
-    let items = query.borrow(&world).iter().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().collect_vec();
 
     // All changed entities
     assert_eq!(items.len(), 11);
@@ -42,7 +42,7 @@ fn filters() {
 
     eprintln!("Current change: {}", world.change_tick());
 
-    let items = query.borrow(&world).iter().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().collect_vec();
 
     assert_eq!(items, &[34.0]);
 
@@ -51,7 +51,7 @@ fn filters() {
         *a = -*a;
     });
 
-    let items = query.borrow(&world).iter().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().collect_vec();
 
     eprintln!("Items: {items:?}");
 
@@ -62,7 +62,7 @@ fn filters() {
         *a *= 10.0;
     });
 
-    let items = query.borrow(&world).iter().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().collect_vec();
     assert_eq!(items, &[-30.0, -40.0]);
 
     // Construct a new interted query
@@ -71,6 +71,7 @@ fn filters() {
 
     let items = query
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted_by_key(|v| (v * 256.0) as i64)
         .collect_vec();
@@ -123,17 +124,20 @@ fn combinations() {
     let mut query = Query::new(entity_ids()).filter(a().modified() | b().modified());
 
     // eprintln!("Items: {:?}", query.iter(&world).sorted().collect_vec());
-    assert_eq!(query.borrow(&world).iter().sorted().collect_vec(), ids);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().sorted().collect_vec(),
+        ids
+    );
 
     for &id in &ids[50..67] {
         *world.get_mut(id, a()).unwrap() *= -2;
     }
 
-    let items = query.borrow(&world).iter().sorted().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().sorted().collect_vec();
     eprintln!("Items: {items:?}");
 
     assert_eq!(items, ids[50..67]);
-    let items = query.borrow(&world).iter().sorted().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().sorted().collect_vec();
     assert_eq!(items, []);
 
     for &id in &ids[20..43] {
@@ -144,7 +148,7 @@ fn combinations() {
         world.get_mut(id, b()).unwrap().push_str("...");
     }
 
-    let items = query.borrow(&world).iter().sorted().collect_vec();
+    let items = query.borrow(&world).unwrap().iter().sorted().collect_vec();
 
     assert_eq!(items, ids[20..89]);
 }
@@ -171,6 +175,7 @@ fn bitops() {
         Query::new(entity_ids())
             .filter(a().gt(1.1) & a().lt(5.0))
             .borrow(&world)
+            .unwrap()
             .iter()
             .sorted()
             .collect_vec(),
@@ -181,6 +186,7 @@ fn bitops() {
         Query::new(entity_ids())
             .filter((a().gt(1.1) & a().lt(5.0)) | (d().without() & b().without()))
             .borrow(&world)
+            .unwrap()
             .iter()
             .sorted()
             .collect_vec(),
@@ -191,6 +197,7 @@ fn bitops() {
         Query::new(entity_ids())
             .filter((a().cmp(|&v: &f32| v > 5.1 && v < 9.0)) | (d().without() & b().without()))
             .borrow(&world)
+            .unwrap()
             .iter()
             .sorted()
             .collect_vec(),
@@ -213,7 +220,7 @@ fn sparse_or() {
 
     let mut query = Query::new(entity_ids()).filter(a().modified() | b().modified());
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), ids);
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), ids);
 
     // ###--------
     // --###---##
@@ -229,7 +236,7 @@ fn sparse_or() {
     world.set(ids[9], b(), "Bar".into()).unwrap();
 
     {
-        let mut batches = query.borrow(&world);
+        let mut batches = query.borrow(&world).unwrap();
         let batches = batches.iter_batched();
 
         let slots = batches.map(|v| v.collect_vec()).collect_vec();
@@ -276,7 +283,7 @@ fn sparse_and() {
 
     let mut query = Query::new(entity_ids()).filter(a().modified() & b().modified());
 
-    assert_eq!(query.borrow(&world).iter().collect_vec(), ids);
+    assert_eq!(query.borrow(&world).unwrap().iter().collect_vec(), ids);
 
     // ###--------
     // --###---##
@@ -292,7 +299,7 @@ fn sparse_and() {
     world.set(ids[9], b(), "Bar".into()).unwrap();
 
     {
-        let mut batches = query.borrow(&world);
+        let mut batches = query.borrow(&world).unwrap();
         let mut batches = batches.iter_batched();
 
         assert_eq!(batches.next().unwrap().collect_vec(), ids[2..=2]);
@@ -348,7 +355,10 @@ fn entity_filter() {
 
     let mut query = Query::new(index().copied()).filter(Or((ids[5], ids2[7])));
 
-    assert_eq!(query.borrow(&world).iter().sorted().collect_vec(), &[5, 17]);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().sorted().collect_vec(),
+        &[5, 17]
+    );
     let mut query = Query::new((entity_ids(), Or((ids[5], ids2[7])).satisfied()));
 
     let all_ids = ids.iter().chain(ids2.iter()).chain(ids3.iter());
@@ -356,5 +366,104 @@ fn entity_filter() {
         .map(|&id| (id, id == ids[5] || id == ids2[7]))
         .collect_vec();
 
-    assert_eq!(query.borrow(&world).iter().sorted().collect_vec(), expected);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().sorted().collect_vec(),
+        expected
+    );
+}
+
+#[test]
+fn exactly_one_of() {
+    use flax::filter::ExactlyOneOf;
+
+    component! {
+        idle: (),
+        walking: (),
+        running: (),
+    }
+
+    let mut world = World::new();
+
+    let id1 = Entity::builder().tag(idle()).spawn(&mut world);
+    let id2 = Entity::builder().tag(walking()).spawn(&mut world);
+    let id3 = Entity::builder()
+        .tag(walking())
+        .tag(running())
+        .spawn(&mut world);
+    Entity::builder()
+        .tag(idle())
+        .tag(walking())
+        .tag(running())
+        .spawn(&mut world);
+    let id5 = Entity::builder().tag(running()).spawn(&mut world);
+
+    let mut query = Query::new(entity_ids()).filter(idle().with() ^ walking().with());
+    assert_eq!(query.collect_sorted_vec(&world), [id1, id2, id3]);
+
+    let mut query = Query::new(entity_ids()).filter(ExactlyOneOf((
+        idle().with(),
+        walking().with(),
+        running().with(),
+    )));
+    assert_eq!(query.collect_sorted_vec(&world), [id1, id2, id5]);
+
+    // Slot level filters
+    component! {
+        x: i32,
+        y: i32,
+    }
+
+    let ids = (0..3)
+        .map(|_| Entity::builder().set(x(), 0).set(y(), 0).spawn(&mut world))
+        .collect_vec();
+
+    let mut query = Query::new(entity_ids()).filter(x().modified() ^ y().modified());
+    assert_eq!(query.collect_sorted_vec(&world), []);
+
+    world.set(ids[0], x(), 1).unwrap();
+    world.set(ids[1], y(), 1).unwrap();
+    world.set(ids[2], x(), 1).unwrap();
+    world.set(ids[2], y(), 1).unwrap();
+
+    assert_eq!(query.collect_sorted_vec(&world), [ids[0], ids[1]]);
+}
+
+#[test]
+fn negated_change_filters() {
+    let mut world = World::new();
+
+    let ids = (0..10)
+        .map(|i| Entity::builder().set(a(), i as f32).spawn(&mut world))
+        .collect_vec();
+
+    let mut unmodified = Query::new(entity_ids()).filter(!a().modified());
+    let mut existing = Query::new(entity_ids()).filter(!a().added());
+
+    // Everything was just added
+    assert_eq!(unmodified.collect_sorted_vec(&world), []);
+    assert_eq!(existing.collect_sorted_vec(&world), []);
+
+    for &id in ids[2..4].iter().chain([&ids[7]]) {
+        *world.get_mut(id, a()).unwrap() *= 2.0;
+    }
+
+    let new = (10..13)
+        .map(|i| Entity::builder().set(a(), i as f32).spawn(&mut world))
+        .collect_vec();
+
+    // The slots around the modified ones are yielded
+    assert_eq!(
+        unmodified.collect_sorted_vec(&world),
+        [ids[0], ids[1], ids[4], ids[5], ids[6], ids[8], ids[9]]
+    );
+    assert_eq!(existing.collect_sorted_vec(&world), ids);
+
+    assert_eq!(
+        unmodified.collect_sorted_vec(&world),
+        ids.iter().chain(&new).copied().collect_vec()
+    );
+    assert_eq!(
+        existing.collect_sorted_vec(&world),
+        ids.iter().chain(&new).copied().collect_vec()
+    );
 }
@@ -117,6 +117,7 @@ fn relations() {
 
     let items = query
         .borrow(&world)
+        .unwrap()
         .iter()
         .map(|(a, b)| (a.clone(), *b))
         .sorted()
@@ -134,7 +135,7 @@ fn relations() {
 
     // Visit the first parent of the children
     let mut query = Query::new((name(), relations_like(child_of)));
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
 
     let items = query
         .iter()
@@ -217,7 +218,7 @@ fn build_hierarchy() {
     );
 
     let mut query = Query::new((name(), child_of(parent)));
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
     let children = query.iter().sorted().collect_vec();
 
     assert_eq!(
@@ -247,19 +248,19 @@ fn hierarchy_manipulation() {
     // Query all entities with no `child_of` relation
     let mut q = Query::new(entity_ids()).without_relation(child_of);
 
-    let roots = q.borrow(&world).iter().sorted().collect_vec();
+    let roots = q.borrow(&world).unwrap().iter().sorted().collect_vec();
     assert_eq!(roots, [a, b]);
 
     // Attach a under b
     world.set(a, child_of(b), ()).unwrap();
-    let roots = q.borrow(&world).iter().sorted().collect_vec();
+    let roots = q.borrow(&world).unwrap().iter().sorted().collect_vec();
     assert_eq!(roots, [b]);
 
     world.detach(b);
     assert_eq!(world.get(b, name()).as_deref(), Ok(&"b".to_string()));
 
     let mut q = Query::new(name()).without_relation(child_of);
-    let mut roots = q.borrow(&world);
+    let mut roots = q.borrow(&world).unwrap();
     let roots = roots.iter().sorted().collect_vec();
 
     assert_eq!(roots, ["a", "b", "b.a", "b.b"]);
@@ -0,0 +1,159 @@
+use flax::{
+    component,
+    entity::EntityKind,
+    entity_ids,
+    world::{MaintainPolicy, PrunePolicy},
+    Entity, FetchExt, Query, World,
+};
+
+component! {
+    a: i32,
+    b: String,
+}
+
+#[test]
+fn maintain() {
+    let mut world = World::new();
+
+    let id = Entity::builder().set(a(), 1).spawn(&mut world);
+    world.deferred_commands().set(id, a(), 2);
+
+    let reserved = world.reserve_one(EntityKind::empty());
+
+    let temporary = Entity::builder()
+        .set(a(), 1)
+        .set(b(), "temporary".into())
+        .spawn(&mut world);
+    world.despawn(temporary).unwrap();
+
+    let archetypes = world.archetype_info().len();
+
+    // Nothing is pruned by default
+    world.maintain().unwrap();
+    assert_eq!(world.get(id, a()).as_deref(), Ok(&2));
+    assert!(world.is_alive(reserved));
+    assert_eq!(world.archetype_info().len(), archetypes);
+
+    world.set_maintain_policy(MaintainPolicy {
+        prune: PrunePolicy::Aggressive,
+        change_retention: Some(0),
+    });
+
+    let mut modified = Query::new(entity_ids()).filter(a().modified());
+    assert_eq!(modified.collect_vec(&world), [id]);
+
+    world.set(id, a(), 3).unwrap();
+    world.maintain().unwrap();
+
+    assert!(world.archetype_info().len() < archetypes);
+    // The change was discarded
+    assert_eq!(modified.collect_vec(&world), []);
+
+    // Failing commands are reported
+    world.despawn(id).unwrap();
+    world.deferred_commands().set(id, a(), 4).despawn(id);
+    assert!(world.maintain().is_err());
+    assert!(world.maintain().is_ok());
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn deferred_events() {
+    use flax::events::{Event, EventKind, EventSubscriber};
+    use itertools::Itertools;
+
+    let mut world = World::new();
+
+    let (tx, rx) = flume::unbounded();
+    world.subscribe(tx.filter_components([a().key()]));
+
+    let existing = Entity::builder().set(a(), 1).spawn(&mut world);
+    rx.drain().for_each(drop);
+
+    world.defer_events();
+
+    let id = Entity::builder().set(a(), 1).spawn(&mut world);
+    let _ = world.change_tick();
+    *world.get_mut(id, a()).unwrap() = 2;
+    let _ = world.change_tick();
+    *world.get_mut(existing, a()).unwrap() = 2;
+
+    // Batches do not dispatch the events deferred for the frame
+    world.batch_scope(|world| {
+        world.set(existing, b(), "Foo".into()).unwrap();
+    });
+
+    assert!(rx.is_empty());
+
+    world.maintain().unwrap();
+
+    let events = rx.drain().sorted_by_key(|v| v.id).collect_vec();
+    assert_eq!(
+        events,
+        [
+            Event {
+                id: existing,
+                key: a().key(),
+                kind: EventKind::Modified,
+            },
+            Event {
+                id,
+                key: a().key(),
+                kind: EventKind::Added,
+            }
+        ]
+    );
+
+    // Events are dispatched immediately again
+    *world.get_mut(id, a()).unwrap() = 3;
+    assert_eq!(rx.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn deferred_events_with_retention() {
+    use flax::events::{Event, EventKind, EventSubscriber};
+    use itertools::Itertools;
+
+    let mut world = World::new();
+    world.set_maintain_policy(MaintainPolicy {
+        change_retention: Some(0),
+        ..Default::default()
+    });
+
+    let (tx, rx) = flume::unbounded();
+    world.subscribe(tx.filter_components([a().key()]));
+
+    let id = Entity::builder().set(a(), 1).spawn(&mut world);
+    rx.drain().for_each(drop);
+
+    let snapshot = world.create_snapshot(a());
+    world.defer_events();
+
+    *world.get_mut(id, a()).unwrap() = 2;
+    let spawned = Entity::builder().set(a(), 1).spawn(&mut world);
+
+    // The changes are older than the retention, but are dispatched before being discarded
+    world.maintain().unwrap();
+
+    let events = rx.drain().sorted_by_key(|v| v.id).collect_vec();
+    assert_eq!(
+        events,
+        [
+            Event {
+                id,
+                key: a().key(),
+                kind: EventKind::Modified,
+            },
+            Event {
+                id: spawned,
+                key: a().key(),
+                kind: EventKind::Added,
+            }
+        ]
+    );
+
+    let snapshot = snapshot.load();
+    assert_eq!(snapshot.get(id), Some(&2));
+    assert_eq!(snapshot.get(spawned), Some(&1));
+}
@@ -63,8 +63,8 @@ fn merge() {
 
     let _new_ids = world1.merge_with(&mut world2);
 
-    assert_eq!(Query::new(position()).borrow(&world2).count(), 0);
-    assert_eq!(Query::new(name()).borrow(&world1).count(), 80);
+    assert_eq!(Query::new(position()).borrow(&world2).unwrap().count(), 0);
+    assert_eq!(Query::new(name()).borrow(&world1).unwrap().count(), 80);
 }
 
 #[test]
@@ -103,15 +103,15 @@ fn merge_empty() -> anyhow::Result<()> {
             .allow_trailing_bytes(),
     ))?;
 
-    assert_eq!(Query::new(()).borrow(&new_world).count(), 128);
-    assert_eq!(Query::new(()).borrow(&world).count(), 0);
+    assert_eq!(Query::new(()).borrow(&new_world).unwrap().count(), 128);
+    assert_eq!(Query::new(()).borrow(&world).unwrap().count(), 0);
 
     let migrated = world.merge_with(&mut new_world);
     // Since the destination is empty there will be no migrated entities
     assert!(migrated.ids().is_empty());
 
-    assert_eq!(Query::new(()).borrow(&new_world).count(), 0);
-    assert_eq!(Query::new(()).borrow(&world).count(), 128);
+    assert_eq!(Query::new(()).borrow(&new_world).unwrap().count(), 0);
+    assert_eq!(Query::new(()).borrow(&world).unwrap().count(), 128);
 
     Ok(())
 }
@@ -156,14 +156,15 @@ fn merge_hierarchy() -> anyhow::Result<()> {
 
     let migrated = world.merge_with(&mut src_world);
 
-    assert_eq!(Query::new(()).borrow(&src_world).count(), 0);
-    assert_eq!(Query::new(()).borrow(&world).count(), 104);
+    assert_eq!(Query::new(()).borrow(&src_world).unwrap().count(), 0);
+    assert_eq!(Query::new(()).borrow(&world).unwrap().count(), 104);
 
     let new_root = migrated.get(root);
 
     let children = Query::new(name())
         .with(child_of(new_root))
         .borrow(&world)
+        .unwrap()
         .iter()
         .cloned()
         .collect_vec();
@@ -173,6 +174,7 @@ fn merge_hierarchy() -> anyhow::Result<()> {
     let child_1_1 = Query::new(position())
         .filter(name().eq("child.1.1".to_string()))
         .borrow(&world)
+        .unwrap()
         .iter()
         .copied()
         .next();
@@ -278,6 +280,7 @@ fn merge_custom() {
     let custom_children = Query::new(name())
         .with(new_custom_relation(new_root))
         .borrow(&world)
+        .unwrap()
         .iter()
         .cloned()
         .collect_vec();
@@ -1,4 +1,4 @@
-use flax::{component, Entity, World};
+use flax::{component, world::PrunePolicy, Entity, World};
 
 #[test]
 fn prune_archetypes() {
@@ -57,3 +57,109 @@ fn prune_archetypes() {
     assert_eq!(world.prune_archetypes(), 2);
     assert_eq!(world.prune_archetypes(), 0);
 }
+
+#[test]
+fn prune_policies() {
+    component! {
+        a: (),
+        b: (),
+        c: (),
+    }
+
+    let mut world = World::new();
+
+    let id_a = Entity::builder().tag(a()).spawn(&mut world);
+    let id_ab = Entity::builder().tag(a()).tag(b()).spawn(&mut world);
+
+    world.despawn(id_a).unwrap();
+    world.despawn(id_ab).unwrap();
+
+    // A(0)
+    //  A_B(0)
+    assert_eq!(world.prune_archetypes_with(PrunePolicy::Never), 0);
+    assert_eq!(world.prune_archetypes_with(PrunePolicy::OnlyLeaves), 1);
+    assert_eq!(world.prune_archetypes_with(PrunePolicy::OnlyLeaves), 1);
+    assert_eq!(world.prune_archetypes_with(PrunePolicy::OnlyLeaves), 0);
+
+    let ids = (0..100)
+        .map(|_| Entity::builder().tag(b()).spawn(&mut world))
+        .collect::<Vec<_>>();
+
+    let id_c = Entity::builder().tag(c()).spawn(&mut world);
+
+    for id in ids {
+        world.despawn(id).unwrap();
+    }
+
+    world.despawn(id_c).unwrap();
+
+    // B(0), with capacity for 100 entities
+    // C(0)
+    assert_eq!(
+        world.prune_archetypes_with(PrunePolicy::SizeThreshold(10)),
+        1
+    );
+    assert_eq!(
+        world.prune_archetypes_with(PrunePolicy::SizeThreshold(10)),
+        0
+    );
+    assert_eq!(world.prune_archetypes_with(PrunePolicy::Aggressive), 1);
+}
+
+#[test]
+fn storage_pool() {
+    component! {
+        position: [f32; 3],
+        health: f32,
+    }
+
+    let mut world = World::new();
+
+    let spawn = |world: &mut World| {
+        (0..16)
+            .map(|i| {
+                Entity::builder()
+                    .set(position(), [i as f32; 3])
+                    .set(health(), 100.0)
+                    .spawn(world)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let ids = spawn(&mut world);
+    assert_eq!(world.storage_pool_stats().released(), 0);
+
+    for id in ids {
+        world.despawn(id).unwrap();
+    }
+
+    world.prune_archetypes();
+
+    // Allocations are rounded up to a size class, and grow the capacity to fill it
+    let stats = world.storage_pool_stats();
+    assert_eq!(stats.blocks(), 2);
+    assert_eq!(stats.bytes(), 256 + 64);
+
+    // The recreated archetype reuses the allocations as it grows, including blocks of the same
+    // size class freed by the other component, and releases the smaller allocations it outgrew
+    let ids = spawn(&mut world);
+    let stats = world.storage_pool_stats();
+    assert_eq!(stats.reused(), 3);
+    assert_eq!(stats.blocks(), 2);
+    assert_eq!(stats.bytes(), 16 + 128);
+    assert_eq!(world.get(ids[3], position()).as_deref(), Ok(&[3.0; 3]));
+
+    // Lowering the limit frees the largest blocks first
+    world.set_storage_pool_limit(100);
+    assert_eq!(world.storage_pool_stats().bytes(), 16);
+
+    for id in ids {
+        world.despawn(id).unwrap();
+    }
+
+    // The released allocations exceeding the limit are freed
+    world.prune_archetypes();
+    assert_eq!(world.storage_pool_stats().bytes(), 16 + 64);
+    assert_eq!(world.trim_storage_pool(), 16 + 64);
+    assert_eq!(world.storage_pool_stats().bytes(), 0);
+}
@@ -46,10 +46,11 @@ fn query_change() {
     let mut consumer = Query::new((name(), pos(), distance().as_mut())).filter(pos().modified());
 
     // Ignore spawn changes to only capture `move_alive`
-    consumer.borrow(&world).iter().for_each(|_| {});
+    consumer.borrow(&world).unwrap().iter().for_each(|_| {});
 
     let moved = move_alive
         .borrow(&world)
+        .unwrap()
         .iter()
         .map(|(name, pos)| {
             pos.0 += 1.0;
@@ -65,6 +66,7 @@ fn query_change() {
 
     let consumed = consumer
         .borrow(&world)
+        .unwrap()
         .iter()
         .map(|(name, pos, distance)| {
             *distance = (pos.0 * pos.0 + pos.1 * pos.1).sqrt();
@@ -108,6 +110,7 @@ fn query_opt() {
 
     let items = query
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted_by_key(|v| v.0)
         .map(|(a, b, c)| (a.clone(), *b, *c))
@@ -123,7 +126,7 @@ fn query_opt() {
     );
 
     let mut query = Query::new((name(), status_effect().opt()));
-    let mut query = query.borrow(&world);
+    let mut query = query.borrow(&world).unwrap();
     let items = query.iter().sorted().collect_vec();
 
     assert_eq!(
@@ -135,3 +138,409 @@ fn query_opt() {
         ]
     );
 }
+
+#[test]
+fn item_handles() {
+    use flax::fetch::item_handles;
+
+    component! {
+        health: f32,
+        selected: (),
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..4)
+        .map(|i| {
+            EntityBuilder::new()
+                .set(health(), i as f32 * 10.0)
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    let mut query = Query::new(item_handles()).with(health());
+    let handles = query.borrow(&world).unwrap().iter().collect_vec();
+
+    assert_eq!(handles.iter().map(|v| v.id()).collect_vec(), ids);
+
+    // Moves the entity to another archetype
+    world.set(ids[1], selected(), ()).unwrap();
+    world.despawn(ids[2]).unwrap();
+
+    let valid = handles
+        .iter()
+        .filter_map(|&v| world.revalidate(v))
+        .map(|v| v.id())
+        .collect_vec();
+
+    assert_eq!(valid, [ids[0], ids[3]]);
+
+    let handle = world.revalidate(handles[0]).unwrap();
+    assert!(handle.tick() >= handles[0].tick());
+    assert_eq!(handle.arch_id(), handles[0].arch_id());
+}
+
+#[test]
+fn empty_archetypes() {
+    component! {
+        health: f32,
+        armor: f32,
+        shield: f32,
+    }
+
+    let mut world = World::new();
+
+    let a = EntityBuilder::new().set(health(), 1.0).spawn(&mut world);
+    let b = EntityBuilder::new()
+        .set(health(), 2.0)
+        .set(armor(), 1.0)
+        .spawn(&mut world);
+
+    // Leaves behind empty archetypes
+    world.set(b, shield(), 1.0).unwrap();
+    world.remove(b, armor()).unwrap();
+
+    let mut query = Query::new(health());
+    let mut borrow = query.borrow(&world).unwrap();
+
+    assert_eq!(
+        borrow
+            .iter()
+            .copied()
+            .sorted_by(f32::total_cmp)
+            .collect_vec(),
+        [1.0, 2.0]
+    );
+    // The prepared archetypes are reused
+    assert_eq!(borrow.count(), 2);
+    assert_eq!(borrow.get(a), Ok(&1.0));
+    assert_eq!(borrow.iter().count(), 2);
+}
+
+#[test]
+fn disabled_entities() {
+    use flax::{components::disabled, entity_ids};
+
+    component! {
+        health: f32,
+    }
+
+    let mut world = World::new();
+
+    let a = EntityBuilder::new().set(health(), 1.0).spawn(&mut world);
+    let b = EntityBuilder::new().set(health(), 2.0).spawn(&mut world);
+
+    let mut query = Query::new(entity_ids()).with(health());
+    let mut all = Query::new(entity_ids()).with(health()).include_disabled();
+
+    assert_eq!(query.collect_sorted_vec(&world), [a, b]);
+
+    world.set(b, disabled(), ()).unwrap();
+
+    assert_eq!(query.collect_sorted_vec(&world), [a]);
+    assert_eq!(all.collect_sorted_vec(&world), [a, b]);
+
+    // Disabling is not required to be handled by each query
+    assert_eq!(Query::new(health().copied()).collect_vec(&world), [1.0]);
+
+    // Mentioning `disabled` does not opt in
+    let mut disabled_only = Query::new(entity_ids()).with(disabled());
+    assert_eq!(disabled_only.collect_vec(&world), []);
+    assert_eq!(disabled_only.include_disabled().collect_vec(&world), [b]);
+
+    world.remove(b, disabled()).unwrap();
+    assert_eq!(query.collect_sorted_vec(&world), [a, b]);
+}
+
+#[test]
+fn combinations() {
+    use flax::entity_ids;
+
+    component! {
+        value: i32,
+        other: (),
+        hits: usize,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..5)
+        .map(|i| {
+            let mut builder = EntityBuilder::new();
+            builder.set(value(), i).set_default(hits());
+            if i % 2 == 0 {
+                builder.tag(other());
+            }
+            builder.spawn(&mut world)
+        })
+        .collect_vec();
+
+    // Entities which are not matched
+    EntityBuilder::new().set(value(), 9).spawn(&mut world);
+
+    let mut query = Query::new((entity_ids(), hits().as_mut())).with(value());
+    let mut borrow = query.borrow(&world).unwrap();
+    let mut combinations = borrow.iter_combinations::<2>();
+
+    assert_eq!(combinations.total(), 10);
+
+    let mut pairs = Vec::new();
+    while let Some([(a, a_hits), (b, b_hits)]) = combinations.fetch_next() {
+        assert_ne!(a, b);
+        *a_hits += 1;
+        *b_hits += 1;
+        pairs.push(if a < b { (a, b) } else { (b, a) });
+    }
+
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        ids.iter().copied().tuple_combinations().collect_vec()
+    );
+    assert!(combinations.fetch_next().is_none());
+    drop(borrow);
+
+    // Each entity is paired with every other entity exactly once
+    assert_eq!(Query::new(hits().copied()).collect_vec(&world), [4; 5]);
+
+    let mut query = Query::new(value());
+    assert!(query
+        .borrow(&world)
+        .unwrap()
+        .iter_combinations::<7>()
+        .fetch_next()
+        .is_none());
+}
+
+#[test]
+fn column_reductions() {
+    component! {
+        value: f32,
+        count: u32,
+        other: (),
+    }
+
+    let mut world = World::new();
+
+    for i in 0..10 {
+        let mut builder = EntityBuilder::new();
+        builder.set(value(), i as f32 - 4.0).set(count(), i);
+        if i % 3 == 0 {
+            builder.tag(other());
+        }
+        builder.spawn(&mut world);
+    }
+
+    let mut query = Query::new(value());
+    let mut borrow = query.borrow(&world).unwrap();
+
+    assert_eq!(borrow.min(), Some(-4.0));
+    assert_eq!(borrow.max(), Some(5.0));
+    assert_eq!(borrow.sum(), 5.0);
+
+    let len = borrow.fold_chunks(0, |acc, values| acc + values.len());
+    assert_eq!(len, 10);
+
+    let mut query = Query::new(count()).with(other());
+    let mut borrow = query.borrow(&world).unwrap();
+    assert_eq!(borrow.sum(), 18);
+    assert_eq!(borrow.min(), Some(0));
+
+    let mut query = Query::new(count()).with(value()).without(count());
+    assert_eq!(query.borrow(&world).unwrap().max(), None);
+}
+
+#[test]
+fn iter_step() {
+    use flax::entity_ids;
+
+    component! {
+        value: i32,
+        other: (),
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..10)
+        .map(|i| {
+            let mut builder = EntityBuilder::new();
+            builder.set(value(), i);
+            if i >= 6 {
+                builder.tag(other());
+            }
+            builder.spawn(&mut world)
+        })
+        .collect_vec();
+
+    let mut query = Query::new((entity_ids(), value().as_mut()));
+    let mut changed = Query::new(entity_ids()).filter(value().modified());
+    changed.borrow(&world).unwrap().count();
+
+    let mut visited = Vec::new();
+    for offset in 0..3 {
+        let items = query
+            .borrow(&world)
+            .unwrap()
+            .iter_step(3, offset)
+            .map(|(id, v)| {
+                *v += 100;
+                id
+            })
+            .collect_vec();
+
+        // Only the visited entities are modified
+        assert_eq!(
+            changed.collect_sorted_vec(&world),
+            items.iter().copied().sorted().collect_vec()
+        );
+        visited.extend(items);
+    }
+
+    visited.sort();
+    assert_eq!(visited, ids);
+    assert!(Query::new(value())
+        .borrow(&world)
+        .unwrap()
+        .iter()
+        .all(|&v| v >= 100));
+
+    // Each archetype is stepped separately
+    let ids = Query::new(entity_ids())
+        .with(value())
+        .borrow(&world)
+        .unwrap()
+        .iter_step(4, 1)
+        .collect_vec();
+    assert_eq!(ids.len(), 3);
+}
+
+#[test]
+fn zip_disjoint() {
+    use flax::{error::Error, Entity};
+
+    component! {
+        position: f32,
+        predator: (),
+        prey: (),
+    }
+
+    let mut world = World::new();
+
+    let predators = (0..3)
+        .map(|i| {
+            Entity::builder()
+                .set(position(), i as f32)
+                .tag(predator())
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    let preys = (0..4)
+        .map(|i| {
+            Entity::builder()
+                .set(position(), 10.0 + i as f32)
+                .tag(prey())
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    let mut hunters = Query::new(position().as_mut()).with(predator());
+    let mut hunted = Query::new(position().as_mut()).with(prey());
+
+    {
+        let mut zip = hunters.zip_disjoint(&mut hunted, &world).unwrap();
+        for (hunter, target) in zip.iter() {
+            *hunter = (*hunter + *target) / 2.0;
+            *target += 1.0;
+        }
+    }
+
+    assert_eq!(world.get(predators[0], position()).as_deref(), Ok(&5.0));
+    assert_eq!(world.get(preys[0], position()).as_deref(), Ok(&11.0));
+    // The prey which had no hunter is untouched
+    assert_eq!(world.get(preys[3], position()).as_deref(), Ok(&13.0));
+
+    let mut all = Query::new(position());
+    assert!(matches!(
+        hunters.zip_disjoint(&mut all, &world).err(),
+        Some(Error::ConflictingAccess(desc)) if desc.key() == position().key()
+    ));
+
+    // Shared accesses do not conflict
+    let mut hunter_positions = Query::new(position()).with(predator());
+    let mut zip = all.zip_disjoint(&mut hunter_positions, &world).unwrap();
+    assert_eq!(zip.iter().count(), 3);
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn zip_disjoint_index() {
+    use flax::{entity_ids, error::Error, index::equals_indexed, system::AccessKind, Entity};
+
+    component! {
+        team: u32,
+    }
+
+    let mut world = World::new();
+    for i in 0..4 {
+        Entity::builder().set(team(), i % 2).spawn(&mut world);
+    }
+
+    world.create_index(team());
+
+    // Both queries update the same index, even though the entities are disjoint
+    let mut first = Query::new(entity_ids()).filter(equals_indexed(team(), 0));
+    let mut second = Query::new(entity_ids()).filter(equals_indexed(team(), 1));
+    assert!(matches!(
+        first.zip_disjoint(&mut second, &world).err(),
+        Some(Error::ConflictingAccessKind(AccessKind::External(_)))
+    ));
+}
+
+#[test]
+fn archetype_counts() {
+    use flax::Entity;
+
+    component! {
+        position: f32,
+        predator: (),
+        prey: (),
+    }
+
+    let mut world = World::new();
+
+    let predators = (0..3)
+        .map(|i| {
+            Entity::builder()
+                .set(position(), i as f32)
+                .tag(predator())
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    (0..4).for_each(|i| {
+        Entity::builder()
+            .set(position(), i as f32)
+            .tag(prey())
+            .spawn(&mut world);
+    });
+
+    let mut query = Query::new(position().modified());
+
+    let mut counts = |world: &World| {
+        query
+            .borrow(world)
+            .unwrap()
+            .archetype_counts()
+            .into_iter()
+            .map(|v| (v.entities, v.matched))
+            .sorted()
+            .collect_vec()
+    };
+
+    assert_eq!(counts(&world), [(3, 3), (4, 4)]);
+    assert_eq!(counts(&world), [(3, 0), (4, 0)]);
+
+    *world.get_mut(predators[1], position()).unwrap() = 5.0;
+    assert_eq!(counts(&world), [(3, 1), (4, 0)]);
+}
@@ -35,6 +35,7 @@ fn relations() {
     let children = Query::new(entity_ids())
         .with(child_of(parent))
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec();
@@ -45,6 +46,7 @@ fn relations() {
     let parents = Query::new(entity_ids())
         .filter(child_of.without_relation())
         .borrow(&world)
+        .unwrap()
         .iter()
         .collect_vec();
 
@@ -67,7 +69,7 @@ fn relations() {
     world.despawn_many(All);
 
     assert_eq!(
-        Query::new(()).borrow(&world).count(),
+        Query::new(()).borrow(&world).unwrap().count(),
         0,
         "World was not empty"
     );
@@ -83,12 +85,16 @@ fn relations() {
         .attach(child_of, Entity::builder().set(name(), "child2".into()))
         .spawn(&mut world);
 
-    assert_eq!(Query::new(child_of(root)).borrow(&world).count(), 2);
+    assert_eq!(
+        Query::new(child_of(root)).borrow(&world).unwrap().count(),
+        2
+    );
     assert_eq!(
         Query::new(())
             .filter(child_of.with_relation())
             .batch_size(1)
             .borrow(&world)
+            .unwrap()
             .count(),
         3
     );
@@ -196,6 +202,7 @@ fn many_detach() {
     assert_eq!(
         Query::new(relations_like(child_of))
             .borrow(&world)
+            .unwrap()
             .get(child1)
             .unwrap()
             .collect_vec(),
@@ -209,6 +216,7 @@ fn many_detach() {
             child_of.nth_relation(2).opt(),
         ))
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec(),
@@ -224,6 +232,7 @@ fn many_detach() {
     let children_of_parent: Vec<Entity> = Query::new(entity_ids())
         .with(child_of(parent))
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec();
@@ -233,6 +242,7 @@ fn many_detach() {
     let children_of_parent2: Vec<Entity> = Query::new(entity_ids())
         .with(child_of(parent2))
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec();
@@ -243,6 +253,7 @@ fn many_detach() {
     let all_children: Vec<Entity> = Query::new(entity_ids())
         .filter(child_of.with_relation())
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec();
@@ -252,6 +263,7 @@ fn many_detach() {
     let roots = Query::new(entity_ids())
         .filter(child_of.without_relation())
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec();
@@ -296,7 +308,7 @@ fn despawn_recursive() {
     let mut query = Query::new(name()).filter(parent.traverse(child_of));
 
     assert_eq!(
-        query.borrow(&world).iter().sorted().collect_vec(),
+        query.borrow(&world).unwrap().iter().sorted().collect_vec(),
         &["child1", "child2", "parent",]
     );
 
@@ -304,6 +316,7 @@ fn despawn_recursive() {
 
     assert!(query
         .borrow(&world)
+        .unwrap()
         .iter()
         .sorted()
         .collect_vec()
@@ -352,3 +365,120 @@ fn exclusive() {
     let entity = world.entity_mut(id3).unwrap();
     assert_eq!(entity.relations(child_of).map(|v| v.0).collect_vec(), [id2])
 }
+
+#[test]
+fn multi_edges() {
+    use flax::relation::Multi;
+
+    component! {
+        buff_from(caster): Multi<&'static str>,
+    }
+
+    let mut world = World::new();
+
+    let caster1 = world.spawn();
+    let caster2 = world.spawn();
+    let id = world.spawn();
+
+    let a = world.add_edge(id, buff_from(caster1), "haste").unwrap();
+    let b = world.add_edge(id, buff_from(caster1), "shield").unwrap();
+    let c = world.add_edge(id, buff_from(caster2), "haste").unwrap();
+
+    assert_ne!(a, b);
+
+    let entity = world.entity(id).unwrap();
+    let buffs = entity
+        .relations(buff_from)
+        .flat_map(|(caster, edges)| {
+            edges
+                .iter()
+                .map(|(index, &buff)| (caster, index, buff))
+                .collect_vec()
+        })
+        .collect_vec();
+
+    assert_eq!(
+        buffs,
+        [
+            (caster1, a, "haste"),
+            (caster1, b, "shield"),
+            (caster2, c, "haste")
+        ]
+    );
+
+    assert_eq!(
+        world.remove_edge(id, buff_from(caster1), a),
+        Ok(Some("haste"))
+    );
+    assert_eq!(world.remove_edge(id, buff_from(caster1), a), Ok(None));
+
+    // Indices are not reused
+    let d = world.add_edge(id, buff_from(caster1), "haste").unwrap();
+    assert_ne!(a, d);
+
+    assert_eq!(
+        world
+            .get(id, buff_from(caster1))
+            .unwrap()
+            .iter()
+            .map(|v| *v.1)
+            .collect_vec(),
+        ["shield", "haste"]
+    );
+
+    world.remove_edge(id, buff_from(caster2), c).unwrap();
+    assert!(!world.has(id, buff_from(caster2)));
+    assert!(world.has(id, buff_from(caster1)));
+}
+
+#[test]
+fn relations_mut() {
+    component! {
+        spring_to(id): f32,
+        stiffness: f32,
+    }
+
+    let mut world = World::new();
+
+    let a = world.spawn();
+    let b = world.spawn();
+
+    let id1 = Entity::builder()
+        .set(spring_to(a), 1.0)
+        .set(spring_to(b), 2.0)
+        .spawn(&mut world);
+
+    let id2 = Entity::builder()
+        .set(spring_to(a), 3.0)
+        .set(stiffness(), 0.5)
+        .spawn(&mut world);
+
+    let mut modified = Query::new(entity_ids()).filter(spring_to(a).modified());
+    assert_eq!(modified.collect_sorted_vec(&world), [id1, id2]);
+
+    let mut query = Query::new(relations_like_mut(spring_to)).with(stiffness());
+    for springs in query.borrow(&world).unwrap().iter() {
+        for (_, value) in springs {
+            *value *= 2.0;
+        }
+    }
+
+    // Only the relations of the visited entities are modified
+    assert_eq!(modified.collect_vec(&world), [id2]);
+    assert_eq!(world.get(id1, spring_to(a)).as_deref(), Ok(&1.0));
+    assert_eq!(world.get(id2, spring_to(a)).as_deref(), Ok(&6.0));
+
+    let mut query = Query::new((entity_ids(), relations_like_mut(spring_to)));
+    for (id, springs) in query.borrow(&world).unwrap().iter() {
+        let springs = springs.map(|(target, &mut v)| (target, v)).collect_vec();
+        if id == id1 {
+            assert_eq!(springs, [(a, 1.0), (b, 2.0)]);
+        } else if id == id2 {
+            assert_eq!(springs, [(a, 6.0)]);
+        } else {
+            assert_eq!(springs, []);
+        }
+    }
+
+    assert_eq!(modified.collect_sorted_vec(&world), [id1, id2]);
+}
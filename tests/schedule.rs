@@ -128,10 +128,10 @@ fn command_flushing() {
             .build();
 
         schedule.execute_seq(&mut world).unwrap();
-        assert_eq!(Query::new(name()).borrow(&world).iter().count(), 2);
+        assert_eq!(Query::new(name()).borrow(&world).unwrap().iter().count(), 2);
 
         schedule.execute_seq(&mut world).unwrap();
-        assert_eq!(Query::new(name()).borrow(&world).iter().count(), 4);
+        assert_eq!(Query::new(name()).borrow(&world).unwrap().iter().count(), 4);
     }
 }
 
@@ -169,10 +169,10 @@ fn command_flushing_par() {
             .build();
 
         schedule.execute_par(&mut world).unwrap();
-        assert_eq!(Query::new(name()).borrow(&world).iter().count(), 2);
+        assert_eq!(Query::new(name()).borrow(&world).unwrap().iter().count(), 2);
 
         schedule.execute_par(&mut world).unwrap();
-        assert_eq!(Query::new(name()).borrow(&world).iter().count(), 4);
+        assert_eq!(Query::new(name()).borrow(&world).unwrap().iter().count(), 4);
     }
 }
 
@@ -212,7 +212,7 @@ fn schedule_seq() {
     schedule.execute_seq(&mut world).unwrap();
 
     world.despawn(id).unwrap();
-    let result: anyhow::Result<()> = schedule.execute_seq(&mut world).map_err(Into::into);
+    let result: anyhow::Result<()> = schedule.execute_seq(&mut world);
 
     assert!(result.is_err());
 }
@@ -486,3 +486,277 @@ fn into_anyhow(v: flax::Error) -> anyhow::Error {
     #[cfg(feature = "std")]
     return anyhow::Error::new(v);
 }
+
+#[test]
+fn schedule_debugger() {
+    use flax::archetype::ChangeKind;
+
+    component! {
+        health: f32,
+    }
+
+    let mut world = World::new();
+
+    let ids = (0..4)
+        .map(|i| {
+            EntityBuilder::new()
+                .set(health(), i as f32)
+                .spawn(&mut world)
+        })
+        .collect_vec();
+
+    let heal = System::builder()
+        .with_name("heal")
+        .with_query(Query::new(health().as_mut()))
+        .for_each(|v| *v += 1.0)
+        .boxed();
+
+    let spawn = System::builder()
+        .with_name("spawn")
+        .with_cmd_mut()
+        .build(|cmd: &mut CommandBuffer| {
+            cmd.spawn(EntityBuilder::new().set(health(), 100.0));
+        })
+        .boxed();
+
+    let mut schedule = Schedule::from([heal, spawn]);
+    let mut debugger = schedule.debugger();
+
+    assert_eq!(debugger.next_system(), Some("heal"));
+    assert!(debugger.next_access(&world).is_some());
+
+    let step = debugger.step(&mut world).unwrap().unwrap();
+    assert_eq!(step.name(), "heal");
+    assert_eq!(
+        step.changes()
+            .iter()
+            .map(|v| (v.component, v.kind, v.slice.len()))
+            .collect_vec(),
+        [(health().desc(), ChangeKind::Modified, 4)]
+    );
+    assert_eq!(*world.get(ids[3], health()).unwrap(), 4.0);
+
+    assert_eq!(debugger.next_system(), Some("spawn"));
+    let step = debugger.step(&mut world).unwrap().unwrap();
+    assert!(step.changes().is_empty());
+    assert!(debugger.is_finished());
+
+    // Commands are applied at the end of the frame
+    assert_eq!(Query::new(health()).borrow(&world).unwrap().count(), 4);
+    assert!(debugger.step(&mut world).unwrap().is_none());
+    assert_eq!(Query::new(health()).borrow(&world).unwrap().count(), 5);
+
+    assert_eq!(debugger.next_system(), Some("heal"));
+    assert_eq!(debugger.run_to_end(&mut world).unwrap().len(), 2);
+}
+
+#[test]
+fn access_graph() {
+    component! {
+        a: f32,
+        b: f32,
+    }
+
+    let mut world = World::new();
+    Entity::builder()
+        .set(a(), 1.0)
+        .set(b(), 2.0)
+        .spawn(&mut world);
+
+    let mut schedule = Schedule::builder()
+        .with_system(
+            System::builder()
+                .with_name("write_a")
+                .with_query(Query::new(a().as_mut()))
+                .for_each(|a| *a += 1.0),
+        )
+        .with_system(
+            System::builder()
+                .with_name("read_b")
+                .with_query(Query::new(b()))
+                .for_each(|_| {}),
+        )
+        .with_system(
+            System::builder()
+                .with_name("read_a")
+                .with_query(Query::new(a()))
+                .for_each(|_| {}),
+        )
+        .build();
+
+    let graph = schedule.access_graph(&world);
+
+    let systems = graph
+        .systems()
+        .iter()
+        .map(|v| (v.name(), v.batch()))
+        .collect_vec();
+
+    assert_eq!(systems, [("write_a", 0), ("read_b", 0), ("read_a", 1)]);
+
+    let write_a = &graph.systems()[0];
+    assert!(write_a.writes().contains("a"));
+    // Queries always read the world shared
+    assert_eq!(write_a.reads().iter().collect_vec(), ["world"]);
+
+    let conflicts = graph.conflicts();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!((conflicts[0].before(), conflicts[0].after()), (0, 2));
+    assert_eq!(conflicts[0].resources().iter().collect_vec(), ["a"]);
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph schedule {"));
+    assert!(dot.contains("s0 -> s2 [label=\"a\"];"));
+}
+
+#[test]
+fn schedule_async() {
+    use core::{
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// Yields to the executor once before completing
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    component! {
+        contents: String,
+    }
+
+    let mut world = World::new();
+    for path in ["a.png", "b.png"] {
+        Entity::builder().set(name(), path.into()).spawn(&mut world);
+    }
+
+    let load = System::builder()
+        .with_name("load")
+        .with_query(Query::new((flax::entity_ids(), name())).without(contents()))
+        .build_async(|mut q: QueryBorrow<_, _>| {
+            let paths = q
+                .iter()
+                .map(|(id, path): (Entity, &String)| (id, path.clone()))
+                .collect_vec();
+
+            async move {
+                let mut cmd = CommandBuffer::new();
+                for (id, path) in paths {
+                    YieldNow(false).await;
+                    cmd.set(id, contents(), format!("contents of {path}"));
+                }
+
+                Ok(cmd)
+            }
+        })
+        .boxed();
+
+    let check = System::builder()
+        .with_query(Query::new(contents()))
+        .build(|mut q: QueryBorrow<_>| {
+            assert_eq!(
+                q.iter().sorted().collect_vec(),
+                ["contents of a.png", "contents of b.png"]
+            );
+        })
+        .boxed();
+
+    let mut schedule = Schedule::builder()
+        .with_system(load)
+        .flush()
+        .with_system(check)
+        .build();
+
+    futures::executor::block_on(schedule.execute_async(&mut world)).unwrap();
+
+    // Asynchronous systems can not be driven by the synchronous executors
+    assert!(schedule.execute_seq(&mut world).is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn schedule_timeline() {
+    component! {
+        health: f32,
+    }
+
+    let mut world = World::new();
+    let id = Entity::builder().set(health(), 100.0).spawn(&mut world);
+
+    let damage = System::builder()
+        .with_name("damage")
+        .with_query(Query::new(health().as_mut()))
+        .for_each(|health| *health -= 10.0)
+        .boxed();
+
+    let report = System::builder()
+        .with_name("report")
+        .with_query(Query::new(health()))
+        .for_each(|_| {})
+        .boxed();
+
+    let mut schedule = Schedule::builder()
+        .with_system(damage)
+        .with_system(report)
+        .build();
+
+    schedule.record_timeline(16);
+    schedule.execute_seq(&mut world).unwrap();
+    schedule.execute_seq(&mut world).unwrap();
+    assert_eq!(*world.get(id, health()).unwrap(), 80.0);
+
+    let timeline = schedule.timeline().unwrap();
+    assert_eq!(
+        timeline.entries().map(|v| v.system()).collect_vec(),
+        [
+            "damage",
+            "report",
+            "apply commands",
+            "damage",
+            "report",
+            "apply commands"
+        ]
+    );
+
+    // The last modification was made by the second execution of `damage`
+    let tick = world.change_tick();
+    let damage = timeline.entries().nth(3).unwrap();
+    assert!(damage.start_tick() < damage.end_tick());
+    assert_eq!(
+        timeline
+            .lookup(damage.end_tick())
+            .map(|v| v.system())
+            .collect_vec(),
+        ["damage"]
+    );
+    assert!(timeline.lookup(tick + 1).next().is_none());
+
+    let csv = schedule.timeline().unwrap().to_csv();
+    assert_eq!(csv.lines().count(), 7);
+    assert!(csv.lines().nth(1).unwrap().starts_with("\"damage\","));
+
+    schedule.record_timeline(2);
+    schedule.execute_seq(&mut world).unwrap();
+    assert_eq!(
+        schedule
+            .stop_timeline()
+            .unwrap()
+            .entries()
+            .map(|v| v.system())
+            .collect_vec(),
+        ["report", "apply commands"]
+    );
+}
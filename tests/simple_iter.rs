@@ -31,7 +31,7 @@ pub fn simple_iter() {
     batch.spawn(&mut world);
     let mut query = Query::new((velocity(), position().as_mut()));
 
-    for (&velocity, position) in &mut query.borrow(&world) {
+    for (&velocity, position) in &mut query.borrow(&world).unwrap() {
         *position += velocity * 0.5
     }
 
@@ -11,11 +11,14 @@ fn static_entity_set() {
 
     let mut query = Query::new(value());
 
-    assert!(query.borrow(&world).get(resources()).is_err());
+    assert!(query.borrow(&world).unwrap().get(resources()).is_err());
 
     world.set(resources(), value(), "FooBar".into()).unwrap();
 
-    assert_eq!(query.borrow(&world).get(resources()).unwrap(), "FooBar");
+    assert_eq!(
+        query.borrow(&world).unwrap().get(resources()).unwrap(),
+        "FooBar"
+    );
 
     assert_eq!(
         world.entity(resources()).unwrap().get(value()).as_deref(),
@@ -29,12 +32,12 @@ fn query() {
 
     let mut query = Query::new(entity_ids());
 
-    assert!(query.borrow(&world).get(resources()).is_err());
+    assert!(query.borrow(&world).unwrap().get(resources()).is_err());
 
     world
         .entity_mut(resources())
         .unwrap()
         .set(value(), "Baz".into());
 
-    assert!(query.borrow(&world).get(resources()).is_ok());
+    assert!(query.borrow(&world).unwrap().get(resources()).is_ok());
 }
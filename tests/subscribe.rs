@@ -240,3 +240,238 @@ fn subscribe_filter() {
         ]
     );
 }
+
+#[test]
+#[cfg(all(feature = "flume", feature = "std"))]
+fn subscribe_coalesced() {
+    use flax::events::{Coalesce, Event, EventKind, EventSubscriber};
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+
+    let (tx, rx) = flume::unbounded::<Event>();
+    world.subscribe(Coalesce::new(tx).filter_components([a().key(), b().key()]));
+
+    let id = Entity::builder().set(a(), 1.0).spawn(&mut world);
+    let id2 = Entity::builder().set(a(), 2.0).spawn(&mut world);
+
+    for i in 0..10 {
+        *world.get_mut(id, a()).unwrap() += i as f32;
+    }
+
+    world.set(id2, b(), 5).unwrap();
+    world.remove(id2, b()).unwrap();
+
+    assert_eq!(rx.try_recv(), Err(flume::TryRecvError::Empty));
+
+    world.maintain().unwrap();
+
+    assert_eq!(
+        rx.drain().collect_vec(),
+        [
+            Event {
+                id,
+                key: a().key(),
+                kind: EventKind::Added
+            },
+            Event {
+                id: id2,
+                key: a().key(),
+                kind: EventKind::Added
+            },
+        ]
+    );
+
+    for _ in 0..10 {
+        *world.get_mut(id2, a()).unwrap() += 1.0;
+    }
+
+    world.remove(id, a()).unwrap();
+    world.maintain().unwrap();
+
+    let mut events = rx.drain().collect_vec();
+    events.sort_by_key(|v| v.id);
+
+    assert_eq!(
+        events,
+        [
+            Event {
+                id,
+                key: a().key(),
+                kind: EventKind::Removed
+            },
+            Event {
+                id: id2,
+                key: a().key(),
+                kind: EventKind::Modified
+            },
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn subscribe_entity_events() {
+    use flax::{
+        entity::EntityKind,
+        events::{EntityEvent, EntityEventKind, EntityEvents, EventSubscriber},
+    };
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+
+    let (tx, rx) = flume::unbounded::<EntityEvent>();
+    world.subscribe(EntityEvents::new(tx).filter_arch(a().with()));
+
+    let id = Entity::builder()
+        .set(a(), 1.5)
+        .set(b(), 7)
+        .spawn(&mut world);
+
+    // Not matched by the archetype filter
+    let id2 = Entity::builder().set(b(), 4).spawn(&mut world);
+
+    let events = rx.drain().collect_vec();
+    assert_eq!(
+        events
+            .iter()
+            .map(|v| (v.id, v.entity_kind, v.kind))
+            .collect_vec(),
+        [(id, EntityKind::empty(), EntityEventKind::Spawned)]
+    );
+
+    let arch = world.archetype_desc(events[0].arch_id).unwrap();
+    assert_eq!(
+        arch.components()
+            .iter()
+            .map(|v| v.key())
+            .sorted()
+            .collect_vec(),
+        [a().key(), b().key()].into_iter().sorted().collect_vec()
+    );
+
+    // Component events are not sent
+    world.set(id, a(), 2.0).unwrap();
+    world.remove(id, b()).unwrap();
+    assert_eq!(rx.try_recv(), Err(flume::TryRecvError::Empty));
+
+    world.despawn(id).unwrap();
+    world.despawn(id2).unwrap();
+
+    let events = rx.drain().collect_vec();
+    assert_eq!(
+        events.iter().map(|v| (v.id, v.kind)).collect_vec(),
+        [(id, EntityEventKind::Despawned)]
+    );
+
+    // The final archetype of the entity
+    let arch = world.archetype_desc(events[0].arch_id).unwrap();
+    assert_eq!(
+        arch.components().iter().map(|v| v.key()).collect_vec(),
+        [a().key()]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "flume", feature = "rayon"))]
+fn subscribe_parallel() {
+    use flax::events::{Event, EventKind, EventSubscriber};
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+    world.set_parallel_dispatch(Some(2));
+
+    let rxs = (0..8)
+        .map(|_| {
+            let (tx, rx) = flume::unbounded::<Event>();
+            world.subscribe(tx.filter_components([a().key()]));
+            rx
+        })
+        .collect_vec();
+
+    let ids = (0..16)
+        .map(|i| Entity::builder().set(a(), i as f32).spawn(&mut world))
+        .collect_vec();
+
+    for &id in ids.iter().rev() {
+        world.set(id, a(), 0.0).unwrap();
+    }
+
+    world.despawn(ids[0]).unwrap();
+
+    let expected = ids
+        .iter()
+        .map(|&id| (id, EventKind::Added))
+        .chain(ids.iter().rev().map(|&id| (id, EventKind::Modified)))
+        .chain([(ids[0], EventKind::Removed)])
+        .collect_vec();
+
+    // Every subscriber receives all events in the order they occurred
+    for rx in rxs {
+        assert_eq!(rx.drain().map(|v| (v.id, v.kind)).collect_vec(), expected);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "flume", feature = "rayon"))]
+fn subscribe_parallel_batch() {
+    use flax::events::{Event, EventKind, EventSubscriber};
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+    world.set_parallel_dispatch(Some(2));
+
+    // Subscribers of different sets of components receive events from the same operations
+    let mut both = Vec::new();
+    let mut only_b = Vec::new();
+    for _ in 0..4 {
+        let (tx, rx) = flume::unbounded::<Event>();
+        world.subscribe(tx.filter_components([a().key(), b().key()]));
+        both.push(rx);
+
+        let (tx, rx) = flume::unbounded::<Event>();
+        world.subscribe(tx.filter_components([b().key()]));
+        only_b.push(rx);
+    }
+
+    let id = Entity::builder()
+        .set(a(), 1.0)
+        .set(b(), 1)
+        .spawn(&mut world);
+
+    world.remove(id, a()).unwrap();
+    world.set(id, a(), 2.0).unwrap();
+    world.despawn(id).unwrap();
+
+    let expected = [
+        (a().key(), EventKind::Added),
+        (b().key(), EventKind::Added),
+        (a().key(), EventKind::Removed),
+        (a().key(), EventKind::Added),
+        (a().key(), EventKind::Removed),
+        (b().key(), EventKind::Removed),
+    ]
+    .map(|(key, kind)| (id, key, kind));
+
+    for rx in both {
+        assert_eq!(
+            rx.drain().map(|v| (v.id, v.key, v.kind)).collect_vec(),
+            expected
+        );
+    }
+
+    for rx in only_b {
+        assert_eq!(
+            rx.drain().map(|v| (v.id, v.key, v.kind)).collect_vec(),
+            expected
+                .iter()
+                .filter(|v| v.1 == b().key())
+                .copied()
+                .collect_vec()
+        );
+    }
+}
@@ -79,6 +79,7 @@ fn subscribing() {
 
     Query::new(a().as_mut())
         .borrow(&world)
+        .unwrap()
         .iter()
         .for_each(|v| *v *= -1);
 
@@ -93,6 +94,7 @@ fn subscribing() {
 
     Query::new(b().as_mut())
         .borrow(&world)
+        .unwrap()
         .iter()
         .for_each(|v| v.push('!'));
 
@@ -167,11 +169,13 @@ fn subscribing_with_value() {
 
     Query::new(a().as_mut())
         .borrow(&world)
+        .unwrap()
         .iter()
         .for_each(|v| *v *= -1);
 
     Query::new(b().as_mut())
         .borrow(&world)
+        .unwrap()
         .iter()
         .for_each(|v| v.push('!'));
 
@@ -288,7 +292,7 @@ fn moving_changes() {
     );
 
     assert_eq!(
-        query.borrow(&world).iter().collect_vec(),
+        query.borrow(&world).unwrap().iter().collect_vec(),
         ids.iter().copied().zip(repeat(&5)).collect_vec()
     );
 
@@ -324,5 +328,120 @@ fn moving_changes() {
     );
 
     // Make sure the change survived the migrations
-    assert_eq!(query.borrow(&world).iter().collect_vec(), [(ids[3], &7)]);
+    assert_eq!(
+        query.borrow(&world).unwrap().iter().collect_vec(),
+        [(ids[3], &7)]
+    );
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn batch_scope() {
+    use flax::{
+        events::{Event, EventKind, EventSubscriber},
+        Entity, World,
+    };
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+
+    let (tx, rx) = flume::unbounded();
+    world.subscribe(tx.filter_components([a().key()]));
+
+    let existing = Entity::builder().set(a(), 1).spawn(&mut world);
+    rx.drain().for_each(drop);
+
+    let ids = world.batch_scope(|world| {
+        let ids = (0..3)
+            .map(|i| Entity::builder().set(a(), i).spawn(world))
+            .collect_vec();
+
+        for &id in &ids {
+            *world.get_mut(id, a()).unwrap() += 10;
+        }
+
+        *world.get_mut(existing, a()).unwrap() = 2;
+        world.set(existing, b(), "Foo".into()).unwrap();
+        *world.get_mut(existing, a()).unwrap() = 3;
+
+        // Nothing is dispatched inside the scope
+        assert!(rx.is_empty());
+
+        ids
+    });
+
+    let events = rx.drain().sorted_by_key(|v| (v.id, v.kind)).collect_vec();
+    let expected = ids
+        .iter()
+        .map(|&id| Event {
+            id,
+            key: a().key(),
+            kind: EventKind::Added,
+        })
+        .chain([Event {
+            id: existing,
+            key: a().key(),
+            kind: EventKind::Modified,
+        }])
+        .sorted_by_key(|v| (v.id, v.kind))
+        .collect_vec();
+
+    assert_eq!(events, expected);
+    assert_eq!(*world.get(ids[1], a()).unwrap(), 11);
+
+    // Events are dispatched as usual afterwards
+    *world.get_mut(existing, a()).unwrap() = 4;
+    assert_eq!(
+        rx.drain().collect_vec(),
+        [Event {
+            id: existing,
+            key: a().key(),
+            kind: EventKind::Modified,
+        }]
+    );
+}
+
+#[test]
+#[cfg(feature = "flume")]
+fn batch_scope_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use flax::{
+        events::{Event, EventKind, EventSubscriber},
+        Entity, World,
+    };
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    let mut world = World::new();
+
+    let (tx, rx) = flume::unbounded();
+    world.subscribe(tx.filter_components([a().key()]));
+
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        world.batch_scope(|world| {
+            Entity::builder().set(a(), 1).spawn(world);
+            panic!("Failed during the batch");
+        })
+    }));
+
+    assert!(res.is_err());
+
+    // The events recorded before the panic are dispatched when unwinding
+    let id = rx.drain().exactly_one().unwrap().id;
+
+    // The world is no longer batched
+    let tick = world.change_tick();
+    *world.get_mut(id, a()).unwrap() = 2;
+    assert_ne!(world.change_tick(), tick);
+
+    assert_eq!(
+        rx.drain().collect_vec(),
+        [Event {
+            id,
+            key: a().key(),
+            kind: EventKind::Modified,
+        }]
+    );
 }
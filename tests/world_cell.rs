@@ -0,0 +1,69 @@
+use flax::{component, entity_ids, CellAccess, Entity, Error, Query, World};
+
+component! {
+    position: f32,
+    velocity: f32,
+    health: f32,
+}
+
+#[test]
+fn split_world() {
+    let mut world = World::new();
+
+    let ids = (0..8)
+        .map(|i| {
+            Entity::builder()
+                .set(position(), i as f32)
+                .set(velocity(), 1.0)
+                .set(health(), 100.0)
+                .spawn(&mut world)
+        })
+        .collect::<Vec<_>>();
+
+    let [movement, damage] = world
+        .split([
+            CellAccess::new().read(velocity()).write(position()),
+            CellAccess::new().write(health()),
+        ])
+        .unwrap();
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut query = Query::new((position().as_mut(), velocity()));
+            for (pos, vel) in &mut movement.borrow(&mut query) {
+                *pos += *vel;
+            }
+        });
+
+        s.spawn(|| {
+            let mut query = Query::new((entity_ids(), health().as_mut()));
+            for (id, health) in &mut damage.borrow(&mut query) {
+                if id == ids[0] {
+                    *health -= 50.0;
+                }
+            }
+        });
+    });
+
+    assert_eq!(*movement.get(ids[2], position()).unwrap(), 3.0);
+    assert_eq!(*damage.get(ids[0], health()).unwrap(), 50.0);
+
+    let res = world.split([
+        CellAccess::new().read(velocity()),
+        CellAccess::new().read(velocity()).read(position()),
+        CellAccess::new().write(position()),
+    ]);
+
+    assert_eq!(res.err(), Some(Error::ConflictingAccess(position().desc())));
+}
+
+#[test]
+#[should_panic(expected = "not declared")]
+fn undeclared_access() {
+    let mut world = World::new();
+    let id = Entity::builder().set(position(), 1.0).spawn(&mut world);
+
+    let [cell] = world.split([CellAccess::new().read(position())]).unwrap();
+
+    let _ = cell.get_mut(id, position());
+}